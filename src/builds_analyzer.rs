@@ -1,21 +1,31 @@
 use super::{
-    champion_optimizer::{get_normalized_weights, BuildContainer},
-    cli::{CHECK_MARK_CHAR, UNCHECKED_MARK_CHAR},
+    champion_optimizer::{get_normalized_weights, BuildContainer, BuildsGenerationSettings},
     game_data::{
-        units_data::items_data::{BuildHash, ItemUtils},
+        units_data::{
+            items_data::{BuildHash, ItemUtils},
+            UnitProperties,
+        },
         STARTING_GOLDS,
     },
+    presets::SettingsPreset,
 };
 
 use enumset::EnumSet;
 use rustc_hash::{FxBuildHasher, FxHashMap};
+use serde::Serialize;
 
+use core::fmt::Write;
 use core::num::NonZeroUsize;
+use std::fs;
+use std::path::Path;
+
+pub const CHECK_MARK_CHAR: char = '●';
+pub const UNCHECKED_MARK_CHAR: char = ' ';
 
 //todo: tier list and save it in file
 
 /// Sort the provided pareto builds by their average score.
-pub fn sort_builds_by_score(builds: &mut [BuildContainer], weights: (f32, f32, f32)) {
+pub fn sort_builds_by_score(builds: &mut [BuildContainer], weights: (f32, f32, f32, f32)) {
     //sanity check
     if builds.is_empty() {
         return;
@@ -27,7 +37,7 @@ pub fn sort_builds_by_score(builds: &mut [BuildContainer], weights: (f32, f32, f
         .map(|build| build.golds[n_items])
         .max_by(|a, b| a.partial_cmp(b).expect("Failed to compare floats"))
         .unwrap_or(STARTING_GOLDS);
-    let normalized_weights: (f32, f32, f32) = get_normalized_weights(weights);
+    let normalized_weights: (f32, f32, f32, f32) = get_normalized_weights(weights);
 
     //maybe using a hashmap is overkill to store average scores (but it allows to sanity check duplicates)
     let mut average_scores: FxHashMap<BuildHash, f32> =
@@ -60,7 +70,7 @@ pub fn sort_builds_by_score(builds: &mut [BuildContainer], weights: (f32, f32, f
 pub fn print_builds_scores(
     builds: &[BuildContainer],
     champ_name: &str,
-    weights: (f32, f32, f32),
+    weights: (f32, f32, f32, f32),
     n_to_print: NonZeroUsize,
     must_have_utils: EnumSet<ItemUtils>,
 ) {
@@ -72,8 +82,8 @@ pub fn print_builds_scores(
     let n_to_print: usize = usize::min(n_to_print.get(), filtered_len);
     println!(
         "Showing the best {champ_name} builds found ({n_to_print}/{filtered_len}):\n\
-         score | !h/s | surv | spec | build\n\
-         ---------------------------------------------------"
+         score |  ttk  | !h/s | surv | spec | build\n\
+         -----------------------------------------------------------"
     );
 
     //sanity check
@@ -88,15 +98,16 @@ pub fn print_builds_scores(
         .map(|build| build.golds[n_items])
         .max_by(|a, b| a.partial_cmp(b).expect("Failed to compare floats"))
         .unwrap_or(STARTING_GOLDS);
-    let normalized_judgement_weights: (f32, f32, f32) = get_normalized_weights(weights);
+    let normalized_judgement_weights: (f32, f32, f32, f32) = get_normalized_weights(weights);
     for container in filtered_builds.take(n_to_print) {
         print!(
-            "{:5.0} | {:^4} | {:^4} | {:^4} | ",
+            "{:5.0} | {:4.1}s | {:^4} | {:^4} | {:^4} | ",
             container._get_avg_score_with_normalized_weights(
                 n_items,
                 max_golds,
                 normalized_judgement_weights
             ),
+            container.ttk[n_items],
             if container.cum_utils.contains(ItemUtils::AntiHealShield) {
                 CHECK_MARK_CHAR
             } else {
@@ -135,3 +146,248 @@ pub fn print_builds_scores(
         );
     }
 }
+
+/// Returns the average score of each build (in the same order as the given builds).
+/// Assumes all builds have the same number of items.
+fn get_builds_avg_scores(builds: &[BuildContainer], weights: (f32, f32, f32, f32)) -> Vec<f32> {
+    //sanity check
+    if builds.is_empty() {
+        return Vec::new();
+    }
+
+    let n_items: usize = builds[0].build.item_count(); //assumes all builds have the same length as the first of the list
+    let max_golds: f32 = builds
+        .iter()
+        .map(|build| build.golds[n_items])
+        .max_by(|a, b| a.partial_cmp(b).expect("Failed to compare floats"))
+        .unwrap_or(STARTING_GOLDS);
+    let normalized_weights: (f32, f32, f32, f32) = get_normalized_weights(weights);
+    builds
+        .iter()
+        .map(|container| {
+            container._get_avg_score_with_normalized_weights(n_items, max_golds, normalized_weights)
+        })
+        .collect()
+}
+
+/// Exported representation of a build, used in JSON exports.
+#[derive(Debug, Serialize)]
+struct BuildExport {
+    rank: usize,
+    avg_score: f32,
+    items: Vec<&'static str>,
+    cum_utils: Vec<String>,
+    golds: Vec<f32>,
+    dps: Vec<f32>,
+    def: Vec<f32>,
+    ms: Vec<f32>,
+    ttk: Vec<f32>,
+}
+
+/// Exported representation of the builds generation results, used in JSON exports.
+#[derive(Debug, Serialize)]
+struct BuildsExport<'a> {
+    champion: &'a str,
+    settings: SettingsPreset,
+    builds: Vec<BuildExport>,
+}
+
+fn get_builds_exports(
+    builds: &[BuildContainer],
+    weights: (f32, f32, f32, f32),
+) -> Vec<BuildExport> {
+    let n_values: usize = builds
+        .first()
+        .map_or(0, |container| container.build.item_count() + 1); //starting values + 1 value per item
+    builds
+        .iter()
+        .zip(get_builds_avg_scores(builds, weights))
+        .enumerate()
+        .map(|(idx, (container, avg_score))| BuildExport {
+            rank: idx + 1,
+            avg_score,
+            items: container.build[..(n_values - 1)]
+                .iter()
+                .map(|item| item.short_name)
+                .collect(),
+            cum_utils: container
+                .cum_utils
+                .iter()
+                .map(|utils| format!("{utils:?}"))
+                .collect(),
+            golds: container.golds[..n_values].to_vec(),
+            dps: container.dps[..n_values].to_vec(),
+            def: container.def[..n_values].to_vec(),
+            ms: container.ms[..n_values].to_vec(),
+            ttk: container.ttk[..n_values].to_vec(),
+        })
+        .collect()
+}
+
+/// Returns the builds generation results in JSON format.
+/// Builds are exported in the given order (they should be sorted beforehand).
+pub fn builds_to_json(
+    builds: &[BuildContainer],
+    champ_properties: &UnitProperties,
+    settings: &BuildsGenerationSettings,
+) -> Result<String, String> {
+    let export: BuildsExport = BuildsExport {
+        champion: champ_properties.name,
+        settings: SettingsPreset::from_settings(settings, champ_properties),
+        builds: get_builds_exports(builds, settings.weights),
+    };
+    serde_json::to_string_pretty(&export)
+        .map_err(|error| format!("Failed to serialize builds to JSON: {error}"))
+}
+
+/// Quotes a CSV field if needed.
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// Returns the builds generation results in CSV format (one row per build).
+/// The settings used are written at the top of the file as comment lines starting with '#'.
+/// Builds are exported in the given order (they should be sorted beforehand).
+pub fn builds_to_csv(
+    builds: &[BuildContainer],
+    champ_properties: &UnitProperties,
+    settings: &BuildsGenerationSettings,
+) -> Result<String, String> {
+    let mut csv: String = String::new();
+
+    //settings as comments
+    let settings_toml: String =
+        toml::to_string(&SettingsPreset::from_settings(settings, champ_properties))
+            .map_err(|error| format!("Failed to serialize settings: {error}"))?;
+    for line in settings_toml.lines().filter(|line| !line.is_empty()) {
+        csv.push_str("# ");
+        csv.push_str(line);
+        csv.push('\n');
+    }
+
+    //header
+    let exports: Vec<BuildExport> = get_builds_exports(builds, settings.weights);
+    let n_values: usize = exports.first().map_or(0, |export| export.golds.len());
+    csv.push_str("rank,avg_score");
+    for item_slot in 1..n_values {
+        write!(csv, ",item_{item_slot}").expect("Failed to write to String");
+    }
+    csv.push_str(",cum_utils");
+    for name in ["golds", "dps", "def", "ms", "ttk"] {
+        for item_slot in 0..n_values {
+            write!(csv, ",{name}_{item_slot}").expect("Failed to write to String");
+        }
+    }
+    csv.push('\n');
+
+    //rows
+    for export in exports {
+        write!(csv, "{},{}", export.rank, export.avg_score).expect("Failed to write to String");
+        for item_idx in 0..(n_values - 1) {
+            csv.push(',');
+            csv.push_str(&csv_field(export.items.get(item_idx).unwrap_or(&"")));
+        }
+        csv.push(',');
+        csv.push_str(&csv_field(&export.cum_utils.join(" ")));
+        for values in [
+            &export.golds,
+            &export.dps,
+            &export.def,
+            &export.ms,
+            &export.ttk,
+        ] {
+            for value in values {
+                write!(csv, ",{value}").expect("Failed to write to String");
+            }
+        }
+        csv.push('\n');
+    }
+    Ok(csv)
+}
+
+/// Format of the exported builds file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    Json,
+    Csv,
+}
+
+impl ExportFormat {
+    /// Returns the format corresponding to the extension of the given path, if any.
+    #[must_use]
+    pub fn from_path(path: &Path) -> Option<Self> {
+        match path
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .map(str::to_lowercase)
+            .as_deref()
+        {
+            Some("json") => Some(Self::Json),
+            Some("csv") => Some(Self::Csv),
+            _ => None,
+        }
+    }
+}
+
+/// Writes the builds generation results to a file in the given format (creates the parent directories if needed).
+/// Builds are exported in the given order (they should be sorted beforehand).
+pub fn export_builds(
+    path: &Path,
+    format: ExportFormat,
+    builds: &[BuildContainer],
+    champ_properties: &UnitProperties,
+    settings: &BuildsGenerationSettings,
+) -> Result<(), String> {
+    let content: String = match format {
+        ExportFormat::Json => builds_to_json(builds, champ_properties, settings)?,
+        ExportFormat::Csv => builds_to_csv(builds, champ_properties, settings)?,
+    };
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|error| {
+            format!("Failed to create directory '{}': {error}", parent.display())
+        })?;
+    }
+    fs::write(path, content)
+        .map_err(|error| format!("Failed to write '{}': {error}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::game_data::units_data::{items_data::Item, Unit, MAX_UNIT_ITEMS};
+
+    use enumset::enum_set;
+
+    #[test]
+    pub fn test_builds_to_csv() {
+        let champ_properties: &UnitProperties = &Unit::ASHE_PROPERTIES;
+        let settings: BuildsGenerationSettings =
+            BuildsGenerationSettings::default_by_champion(champ_properties);
+        let mut container: BuildContainer = BuildContainer {
+            build: Default::default(),
+            cum_utils: enum_set!(ItemUtils::AntiHealShield | ItemUtils::Special),
+            golds: [STARTING_GOLDS; MAX_UNIT_ITEMS + 1],
+            dps: [100.; MAX_UNIT_ITEMS + 1],
+            def: [1000.; MAX_UNIT_ITEMS + 1],
+            ms: [300.; MAX_UNIT_ITEMS + 1],
+            ttk: [10.; MAX_UNIT_ITEMS + 1],
+        };
+        container.build[0] = &Item::INFINITY_EDGE;
+        container.golds[1] = STARTING_GOLDS + Item::INFINITY_EDGE.cost;
+
+        let csv: String =
+            builds_to_csv(&[container], champ_properties, &settings).expect("Failed to export");
+        let mut rows = csv.lines().filter(|line| !line.starts_with('#'));
+        let header: &str = rows.next().expect("Missing CSV header");
+        let row: &str = rows.next().expect("Missing CSV row");
+        assert!(rows.next().is_none());
+        assert_eq!(header.split(',').count(), row.split(',').count());
+        assert!(row.starts_with("1,"));
+        assert!(row.contains(",IE,AntiHealShield Special,"));
+    }
+}
@@ -1,30 +1,31 @@
 use crate::builds_analyzer::sort_builds_by_score;
-use crate::RuneKeystone;
 
 use super::game_data::*;
 
 use items_data::*;
+use patches::*;
 use runes_data::*;
+use summoner_spells_data::*;
 use units_data::*;
 
 use enumset::{enum_set, EnumSet};
-use indicatif::{ProgressBar, ProgressFinish, ProgressIterator, ProgressStyle};
 use rayon::prelude::*;
-use rustc_hash::{FxBuildHasher, FxHashMap};
+use rustc_hash::{FxBuildHasher, FxHashMap, FxHashSet};
 
 use core::fmt;
 use core::iter::zip;
 use core::num::NonZeroUsize;
-use core::time::Duration;
+use core::str::FromStr;
+use std::sync::{Mutex, PoisonError};
 
 /// Meaningless to go above this value (in seconds).
-pub(crate) const MAX_FIGHT_DURATION: f32 = 60.;
+pub const MAX_FIGHT_DURATION: f32 = 60.;
 /// Value (in seconds) under which results may become inaccurate and that is not recommended to use.
-pub(crate) const LOW_FIGHT_DURATION_VALUE_WARNING: f32 = 2.;
+pub const LOW_FIGHT_DURATION_VALUE_WARNING: f32 = 2.;
 /// Value under which results may become inaccurate and that is not recommended to use.
-pub(crate) const LOW_SEARCH_THRESHOLD_VALUE_WARNING: f32 = 0.05;
+pub const LOW_SEARCH_THRESHOLD_VALUE_WARNING: f32 = 0.05;
 /// Value above which computation times may become very long and that is not recommended to use.
-pub(crate) const HIGH_SEARCH_THRESHOLD_VALUE_WARNING: f32 = 0.25;
+pub const HIGH_SEARCH_THRESHOLD_VALUE_WARNING: f32 = 0.25;
 
 //optimizer dummy, used as a shared, read only target to compute dmg from during the optimisation process
 //here we want every stats to be close to those of a real champion (unlike in game dummy)
@@ -35,6 +36,10 @@ const OPTIMIZER_DUMMY_SKILL_ORDER: SkillOrder = SkillOrder::const_default(); //d
 #[allow(clippy::cast_precision_loss)]
 const MAX_UNIT_LVL_F32: f32 = MAX_UNIT_LVL as f32; //`MAX_UNIT_LVL_F32` is well within f32 precision range
 
+/// Lvl of the champion with an empty build and minimum lvl at each item slot when comparing builds
+/// (builds are compared from the end of the laning phase, earlier lvls are only used by `find_best_purchase_path`).
+const BUILDS_GENERATION_MIN_LVL: u8 = 6;
+
 /// Using Ahri stats for squishy dummy.
 pub const SQUISHY_OPTIMIZER_DUMMY_PROPERTIES: UnitProperties = UnitProperties {
     name: "squishy (e.g. Ahri)",
@@ -42,6 +47,7 @@ pub const SQUISHY_OPTIMIZER_DUMMY_PROPERTIES: UnitProperties = UnitProperties {
     as_ratio: 0.625,
     windup_percent: 0.20,
     windup_modifier: 1.,
+    projectile_speed: 0.,
     base_stats: UnitStats {
         hp: 590.,
         mana: 418.,
@@ -61,6 +67,7 @@ pub const SQUISHY_OPTIMIZER_DUMMY_PROPERTIES: UnitProperties = UnitProperties {
         crit_dmg: Unit::BASE_CRIT_DMG,
         ms_flat: 330.,
         ms_percent: 0.,
+        attack_range: 0.,
         lethality: 0.,
         armor_pen_percent: 0.,
         magic_pen_flat: 0.,
@@ -96,6 +103,7 @@ pub const SQUISHY_OPTIMIZER_DUMMY_PROPERTIES: UnitProperties = UnitProperties {
         crit_dmg: 0.,
         ms_flat: 0.,
         ms_percent: 0.,
+        attack_range: 0.,
         lethality: 0.,
         armor_pen_percent: 0.,
         magic_pen_flat: 0.,
@@ -135,6 +143,7 @@ pub const SQUISHY_OPTIMIZER_DUMMY_PROPERTIES: UnitProperties = UnitProperties {
     fight_scenarios: &[(null_simulate_fight, "null")],
     defaults: UnitDefaults {
         runes_pages: OPTIMIZER_DUMMY_RUNES_PAGE,
+        summoner_spells: SummonerSpells::const_default(),
         skill_order: OPTIMIZER_DUMMY_SKILL_ORDER,
         legendary_items_pool: &ALL_LEGENDARY_ITEMS,
         boots_pool: &ALL_BOOTS,
@@ -149,6 +158,7 @@ pub const BRUISER_OPTIMIZER_DUMMY_PROPERTIES: UnitProperties = UnitProperties {
     as_ratio: 0.625,
     windup_percent: 0.16667,
     windup_modifier: 1.,
+    projectile_speed: 0.,
     base_stats: UnitStats {
         hp: 630.,
         mana: 263., //using darius mana
@@ -168,6 +178,7 @@ pub const BRUISER_OPTIMIZER_DUMMY_PROPERTIES: UnitProperties = UnitProperties {
         crit_dmg: Unit::BASE_CRIT_DMG,
         ms_flat: 340.,
         ms_percent: 0.,
+        attack_range: 0.,
         lethality: 0.,
         armor_pen_percent: 0.,
         magic_pen_flat: 0.,
@@ -207,6 +218,7 @@ pub const BRUISER_OPTIMIZER_DUMMY_PROPERTIES: UnitProperties = UnitProperties {
         crit_dmg: 0.,
         ms_flat: 0.,
         ms_percent: 0.,
+        attack_range: 0.,
         lethality: 0.,
         armor_pen_percent: 0.,
         magic_pen_flat: 0.,
@@ -246,6 +258,7 @@ pub const BRUISER_OPTIMIZER_DUMMY_PROPERTIES: UnitProperties = UnitProperties {
     fight_scenarios: &[(null_simulate_fight, "null")],
     defaults: UnitDefaults {
         runes_pages: OPTIMIZER_DUMMY_RUNES_PAGE,
+        summoner_spells: SummonerSpells::const_default(),
         skill_order: OPTIMIZER_DUMMY_SKILL_ORDER,
         legendary_items_pool: &ALL_LEGENDARY_ITEMS,
         boots_pool: &ALL_BOOTS,
@@ -260,6 +273,7 @@ pub const TANKY_OPTIMIZER_DUMMY_PROPERTIES: UnitProperties = UnitProperties {
     as_ratio: 0.625,
     windup_percent: 0.21875,
     windup_modifier: 1.,
+    projectile_speed: 0.,
     base_stats: UnitStats {
         hp: 660.,
         mana: 341.,
@@ -279,6 +293,7 @@ pub const TANKY_OPTIMIZER_DUMMY_PROPERTIES: UnitProperties = UnitProperties {
         crit_dmg: Unit::BASE_CRIT_DMG,
         ms_flat: 335.,
         ms_percent: 0.,
+        attack_range: 0.,
         lethality: 0.,
         armor_pen_percent: 0.,
         magic_pen_flat: 0.,
@@ -321,6 +336,7 @@ pub const TANKY_OPTIMIZER_DUMMY_PROPERTIES: UnitProperties = UnitProperties {
         crit_dmg: 0.,
         ms_flat: 0.,
         ms_percent: 0.,
+        attack_range: 0.,
         lethality: 0.,
         armor_pen_percent: 0.,
         magic_pen_flat: 0.,
@@ -360,6 +376,7 @@ pub const TANKY_OPTIMIZER_DUMMY_PROPERTIES: UnitProperties = UnitProperties {
     fight_scenarios: &[(null_simulate_fight, "null")],
     defaults: UnitDefaults {
         runes_pages: OPTIMIZER_DUMMY_RUNES_PAGE,
+        summoner_spells: SummonerSpells::const_default(),
         skill_order: OPTIMIZER_DUMMY_SKILL_ORDER,
         legendary_items_pool: &ALL_LEGENDARY_ITEMS,
         boots_pool: &ALL_BOOTS,
@@ -367,12 +384,342 @@ pub const TANKY_OPTIMIZER_DUMMY_PROPERTIES: UnitProperties = UnitProperties {
     },
 };
 
-pub(crate) const TARGET_OPTIONS: [&UnitProperties; 3] = [
+pub const TARGET_OPTIONS: [&UnitProperties; 3] = [
     &SQUISHY_OPTIMIZER_DUMMY_PROPERTIES,
     &BRUISER_OPTIMIZER_DUMMY_PROPERTIES,
     &TANKY_OPTIMIZER_DUMMY_PROPERTIES,
 ];
 
+/// Returns the target option matching the given name, if any.
+/// The name can either be the full target name or its first word (e.g. "squishy", "tank"), case insensitive.
+#[must_use]
+pub fn find_target_by_name(name: &str) -> Option<&'static UnitProperties> {
+    let name: String = name.trim().to_lowercase();
+    TARGET_OPTIONS.iter().copied().find(|properties| {
+        properties.name.to_lowercase() == name
+            || properties
+                .name
+                .split_whitespace()
+                .next()
+                .is_some_and(|first_word| first_word.to_lowercase() == name)
+    })
+}
+
+/// Name of the targets with custom stats (see `TargetDefinition::custom`).
+pub const CUSTOM_TARGET_NAME: &str = "custom target";
+
+/// Move speed given to custom targets created from the command line or the interactive interface.
+pub const CUSTOM_TARGET_DEFAULT_MS: f32 = 335.;
+
+/// Lvl of the fight simulations used to get the dmg profile of a target (see `TargetDefinition::dmg_profile`).
+const DMG_PROFILE_LVL: u8 = 16;
+
+/// Kind of an enemy unit used as a target during the builds generation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TargetKind {
+    /// One of the optimizer dummies in `TARGET_OPTIONS` (their items stats are included in their growth stats).
+    Dummy,
+    /// A champion with a build and a runes page.
+    Champion,
+    /// A unit with custom base and growth stats.
+    Custom,
+}
+
+/// Properties of the custom targets already created, to avoid leaking them again when the same stats are used several times.
+static CUSTOM_TARGETS_PROPERTIES: Mutex<Vec<&'static UnitProperties>> = Mutex::new(Vec::new());
+
+/// Enemy unit used as a target during the builds generation.
+#[derive(Debug, Clone, Copy)]
+pub struct TargetDefinition {
+    pub properties: &'static UnitProperties,
+    pub build: Build,
+    pub runes_page: RunesPage,
+    kind: TargetKind,
+}
+
+impl TargetDefinition {
+    /// Creates a target from one of the optimizer dummies in `TARGET_OPTIONS`.
+    #[must_use]
+    pub fn dummy(properties: &'static UnitProperties) -> Self {
+        Self {
+            properties,
+            build: Build::default(),
+            runes_page: OPTIMIZER_DUMMY_RUNES_PAGE,
+            kind: TargetKind::Dummy,
+        }
+    }
+
+    /// Creates a target from a champion with the given build and runes page.
+    #[must_use]
+    pub fn champion(
+        properties: &'static UnitProperties,
+        build: Build,
+        runes_page: RunesPage,
+    ) -> Self {
+        Self {
+            properties,
+            build,
+            runes_page,
+            kind: TargetKind::Champion,
+        }
+    }
+
+    /// Creates a target with custom stats (base stats at lvl 1 and growth stats per lvl, like champions stats).
+    /// The other properties are those of the squishy dummy. The properties are leaked to be used
+    /// for the rest of the program, they are leaked only once for the same stats (see `CUSTOM_TARGETS_PROPERTIES`).
+    #[must_use]
+    pub fn custom(base_stats: UnitStats, growth_stats: UnitStats) -> Self {
+        let mut custom_targets_properties = CUSTOM_TARGETS_PROPERTIES
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        let properties: &'static UnitProperties = if let Some(&properties) =
+            custom_targets_properties.iter().find(|properties| {
+                properties.base_stats == base_stats && properties.growth_stats == growth_stats
+            }) {
+            properties
+        } else {
+            let properties: &'static UnitProperties = Box::leak(Box::new(UnitProperties {
+                name: CUSTOM_TARGET_NAME,
+                base_stats,
+                growth_stats,
+                ..SQUISHY_OPTIMIZER_DUMMY_PROPERTIES
+            }));
+            custom_targets_properties.push(properties);
+            properties
+        };
+        Self {
+            properties,
+            build: Build::default(),
+            runes_page: OPTIMIZER_DUMMY_RUNES_PAGE,
+            kind: TargetKind::Custom,
+        }
+    }
+
+    /// Returns the kind of the target.
+    #[must_use]
+    pub fn kind(&self) -> TargetKind {
+        self.kind
+    }
+
+    /// Parses a target in one of the following forms (case insensitive):
+    /// - a dummy name from `TARGET_OPTIONS` (see `find_target_by_name`),
+    /// - `CHAMPION[:ITEM+ITEM+...]`, a champion from the given game data set with its default runes page and the given items,
+    /// - `custom:HP/ARMOR/MR[/HP_PER_LVL/ARMOR_PER_LVL/MR_PER_LVL]`, a unit with custom stats.
+    pub fn parse(input: &str, game_data: &GameDataSet) -> Result<Self, String> {
+        let (name, details): (&str, Option<&str>) = match input.split_once(':') {
+            Some((name, details)) => (name.trim(), Some(details)),
+            None => (input.trim(), None),
+        };
+
+        if name.eq_ignore_ascii_case("custom") {
+            return Self::parse_custom(details.unwrap_or_default());
+        }
+
+        if let Some(properties) = find_target_by_name(name) {
+            if details.is_some() {
+                return Err(format!("cannot give items to the dummy target '{name}'"));
+            }
+            return Ok(Self::dummy(properties));
+        }
+
+        let properties: &'static UnitProperties = game_data
+            .champions()
+            .iter()
+            .find(|properties| properties.name.eq_ignore_ascii_case(name))
+            .copied()
+            .ok_or_else(|| format!("'{input}' is not a recognized target"))?;
+        let mut build: Build = Build::default();
+        if let Some(details) = details.filter(|details| !details.trim().is_empty()) {
+            let item_names: Vec<&str> = details.split('+').map(str::trim).collect();
+            if item_names.len() > MAX_UNIT_ITEMS {
+                return Err(format!(
+                    "target build must have at most {MAX_UNIT_ITEMS} items (got {})",
+                    item_names.len()
+                ));
+            }
+            for (slot, item_name) in zip(build.iter_mut(), item_names) {
+                *slot = game_data
+                    .items_database
+                    .find_item_by_name(item_name)
+                    .ok_or_else(|| format!("'{item_name}' is not a recognized item"))?;
+            }
+        }
+        Ok(Self::champion(
+            properties,
+            build,
+            properties.defaults.runes_pages,
+        ))
+    }
+
+    fn parse_custom(input: &str) -> Result<Self, String> {
+        let values: Vec<f32> = input
+            .split('/')
+            .map(|value| {
+                value.trim().parse::<f32>().map_err(|error| {
+                    format!("'{value}' is not a valid custom target stat value: {error}")
+                })
+            })
+            .collect::<Result<Vec<f32>, String>>()?;
+        let (base, growth): ([f32; 3], [f32; 3]) = match values[..] {
+            [hp, armor, mr] => ([hp, armor, mr], [0.; 3]),
+            [hp, armor, mr, hp_per_lvl, armor_per_lvl, mr_per_lvl] => {
+                ([hp, armor, mr], [hp_per_lvl, armor_per_lvl, mr_per_lvl])
+            }
+            _ => {
+                return Err(format!(
+                    "expected 3 or 6 custom target stats separated by '/' (got '{input}')"
+                ))
+            }
+        };
+        Ok(Self::custom(
+            UnitStats {
+                hp: base[0],
+                armor: base[1],
+                mr: base[2],
+                ms_flat: CUSTOM_TARGET_DEFAULT_MS,
+                ..Default::default()
+            },
+            UnitStats {
+                hp: growth[0],
+                armor: growth[1],
+                mr: growth[2],
+                ..Default::default()
+            },
+        ))
+    }
+
+    /// Replaces the champion and the items of the target by their version in the given game data set.
+    /// Items that are not in the data set are removed, the champion is kept if it is not in the data set.
+    pub fn use_game_data(&mut self, game_data: &GameDataSet) {
+        if self.kind() != TargetKind::Champion {
+            return;
+        }
+        if let Some(properties) = game_data.get_champion(self.properties) {
+            self.properties = properties;
+        }
+        for item in self.build.iter_mut() {
+            *item = game_data
+                .items_database
+                .get_item(item)
+                .unwrap_or(&Item::NULL_ITEM);
+        }
+    }
+
+    /// Checks that the target can be used with the given game data set.
+    pub fn check_validity(&self, game_data: &GameDataSet) -> Result<(), String> {
+        match self.kind() {
+            TargetKind::Dummy => {}
+            TargetKind::Champion => {
+                if game_data.get_champion(self.properties).is_none() {
+                    return Err(format!(
+                        "{} is not available in patch {}",
+                        self.properties.name, game_data.patch
+                    ));
+                }
+                if let Some(item) = self.build.iter().find(|&&item| {
+                    *item != Item::NULL_ITEM && game_data.items_database.get_item(item).is_none()
+                }) {
+                    return Err(format!(
+                        "{} is not available in patch {}",
+                        item.full_name, game_data.patch
+                    ));
+                }
+                self.build.check_validity()?;
+                self.runes_page.check_validity()?;
+            }
+            TargetKind::Custom => {
+                let (base, growth): (&UnitStats, &UnitStats) =
+                    (&self.properties.base_stats, &self.properties.growth_stats);
+                if !base.hp.is_finite() || base.hp <= 0. {
+                    return Err(format!(
+                        "Custom target hp must be greater than 0 (got {})",
+                        base.hp
+                    ));
+                }
+                if [base.armor, base.mr, growth.hp, growth.armor, growth.mr]
+                    .into_iter()
+                    .any(|value| !value.is_finite())
+                {
+                    return Err("Custom target stats must be finite".to_string());
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the physical and magic dmg dealt by the target during a fight simulation against the squishy dummy
+    /// (at lvl `DMG_PROFILE_LVL`, with its build), used to estimate the type of dmg it deals.
+    /// Returns None for dummies and custom targets since they have no abilities to simulate.
+    #[must_use]
+    pub fn dmg_profile(&self) -> Option<(f32, f32)> {
+        if self.kind() != TargetKind::Champion {
+            return None;
+        }
+        let mut unit: Unit = self.create_unit(DMG_PROFILE_LVL).ok()?;
+        let dummy: Unit = Self::dummy(&SQUISHY_OPTIMIZER_DUMMY_PROPERTIES)
+            .create_unit(DMG_PROFILE_LVL)
+            .ok()?;
+        unit.simulate_fight(dummy.get_stats(), 0, DEFAULT_FIGHT_DURATION);
+        let PartDmg(phys_dmg, magic_dmg, _) = unit.get_dmg_done();
+        Some((phys_dmg, magic_dmg))
+    }
+
+    /// Creates the target unit at the given lvl (initialized for a fight).
+    pub fn create_unit(&self, lvl: u8) -> Result<Unit, String> {
+        let mut unit: Unit = Unit::new(
+            self.properties,
+            self.runes_page,
+            self.properties.defaults.skill_order.clone(),
+            lvl,
+            self.build,
+        )?;
+        unit.init_fight();
+        Ok(unit)
+    }
+}
+
+impl fmt::Display for TargetDefinition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.kind() {
+            TargetKind::Dummy => f.write_str(self.properties.name),
+            TargetKind::Champion => {
+                let items: Vec<&str> = self
+                    .build
+                    .iter()
+                    .filter(|&&item| *item != Item::NULL_ITEM)
+                    .map(|item| item.short_name)
+                    .collect();
+                write!(f, "{} [{}]", self.properties.name, items.join(", "))
+            }
+            TargetKind::Custom => {
+                let (base, growth): (&UnitStats, &UnitStats) =
+                    (&self.properties.base_stats, &self.properties.growth_stats);
+                write!(
+                    f,
+                    "{CUSTOM_TARGET_NAME} ({} hp, {} armor, {} mr, +{}/+{}/+{} per lvl)",
+                    base.hp, base.armor, base.mr, growth.hp, growth.armor, growth.mr
+                )
+            }
+        }
+    }
+}
+
+/// Maximum number of enemies in an enemy team composition (a full team).
+pub const MAX_ENEMY_TEAM_SIZE: usize = 5;
+
+/// Enemy of an enemy team composition, with the relative weight of the champion's focus on it.
+#[derive(Debug, Clone, Copy)]
+pub struct EnemyTeamMember {
+    pub target: TargetDefinition,
+    pub focus_weight: f32,
+}
+
+impl fmt::Display for EnemyTeamMember {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} (focus {})", self.target, self.focus_weight)
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ItemSlot {
     Slot(usize),
@@ -380,6 +727,21 @@ pub enum ItemSlot {
     None,
 }
 
+impl FromStr for ItemSlot {
+    type Err = String;
+
+    /// Parses an item slot number, 'any' or 'none' (case insensitive).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "any" => Ok(Self::Any),
+            "none" => Ok(Self::None),
+            number => number.parse::<usize>().map(Self::Slot).map_err(|error| {
+                format!("'{s}' is not a valid item slot (expected an integer, 'any' or 'none'): {error}")
+            }),
+        }
+    }
+}
+
 impl fmt::Display for ItemSlot {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -392,11 +754,20 @@ impl fmt::Display for ItemSlot {
 
 #[derive(Debug, Clone)]
 pub struct BuildsGenerationSettings {
-    pub target_properties: &'static UnitProperties,
+    pub game_data: &'static GameDataSet, //champion and every item in the settings must come from this data set (patch)
+    pub target: TargetDefinition,
     pub fight_scenario_number: NonZeroUsize,
+    pub fight_script: Option<FightScript>, //if some, used instead of the fight scenario of the champion
     pub fight_duration: f32,
     pub phys_dmg_received_percent: f32,
+    pub incoming_dmg: Option<IncomingDmgProfile>, //if some, defense is the time survived against this dmg instead of the effective hp
+    pub target_flee_percent: f32, //percentage of time the target spends moving away from the champion (0 for a stationary target)
+    pub teamfight_targets: Vec<TargetDefinition>, //if not empty, the champion fights these enemy units instead of the single target
+    pub enemy_team: Vec<EnemyTeamMember>, //if not empty, the champion fights each enemy separately (scores weighted by focus) instead of the single target
     pub runes_page: RunesPage,
+    pub summoner_spells: SummonerSpells,
+    pub skill_order: Option<MaxOrder>, //if some, used instead of the default skill order of the champion
+    pub economy: EconomyProfile, //golds and xp income of the player, used to estimate the champion lvl at each item slot
     pub n_items: usize,
     pub mandatory_items: Build,
     pub boots_slot: ItemSlot,
@@ -405,7 +776,7 @@ pub struct BuildsGenerationSettings {
     pub boots_pool: Vec<&'static Item>,
     pub supp_items_pool: Vec<&'static Item>,
     pub allow_manaflow_first_item: bool, //only effective if manaflow items in items pool, overridden by mandatory items
-    pub weights: (f32, f32, f32),
+    pub weights: (f32, f32, f32, f32), //dps, defense, mobility and time to kill (the time to kill is ignored if its weight is 0)
     pub search_threshold: f32,
 }
 
@@ -413,29 +784,40 @@ const DEFAULT_FIGHT_DURATION: f32 = 6.0;
 impl Default for BuildsGenerationSettings {
     fn default() -> Self {
         BuildsGenerationSettings {
-            target_properties: &SQUISHY_OPTIMIZER_DUMMY_PROPERTIES,
+            game_data: GameDataSet::builtin(),
+            target: TargetDefinition::dummy(&SQUISHY_OPTIMIZER_DUMMY_PROPERTIES),
             fight_scenario_number: NonZeroUsize::new(1).unwrap(),
+            fight_script: None,
             fight_duration: DEFAULT_FIGHT_DURATION,
             phys_dmg_received_percent: 0.60,
+            incoming_dmg: None,
+            target_flee_percent: 0.,
+            teamfight_targets: Vec::new(),
+            enemy_team: Vec::new(),
             runes_page: RunesPage::default(),
+            summoner_spells: SummonerSpells::default(),
+            skill_order: None,
+            economy: EconomyProfile::default(),
             n_items: 4,
             mandatory_items: Build::default(),
             boots_slot: ItemSlot::Slot(2),
             supp_item_slot: ItemSlot::None,
-            legendary_items_pool: Vec::from(ALL_LEGENDARY_ITEMS),
-            boots_pool: Vec::from(ALL_BOOTS),
-            supp_items_pool: Vec::from(ALL_SUPP_ITEMS),
+            legendary_items_pool: Vec::from(ItemsDatabase::builtin().legendary_items()),
+            boots_pool: Vec::from(ItemsDatabase::builtin().boots()),
+            supp_items_pool: Vec::from(ItemsDatabase::builtin().supp_items()),
             allow_manaflow_first_item: false, //may change this to true, idk
-            weights: (1., 0.25, 0.5),
+            weights: (1., 0.25, 0.5, 0.),
             search_threshold: 0.15,
         }
     }
 }
 
 impl BuildsGenerationSettings {
+    #[must_use]
     pub fn default_by_champion(properties: &UnitProperties) -> Self {
         let settings: BuildsGenerationSettings = BuildsGenerationSettings {
             runes_page: properties.defaults.runes_pages,
+            summoner_spells: properties.defaults.summoner_spells,
             legendary_items_pool: Vec::from(properties.defaults.legendary_items_pool),
             boots_pool: Vec::from(properties.defaults.boots_pool),
             supp_items_pool: Vec::from(properties.defaults.supp_items_pool),
@@ -466,17 +848,102 @@ impl BuildsGenerationSettings {
         settings
     }
 
-    pub fn check_settings(&self, champ_properties: &UnitProperties) -> Result<(), String> {
-        if !TARGET_OPTIONS
+    /// Replaces the items of the settings (mandatory items, items pools and targets builds) by their version in the given game data set
+    /// and use this data set (patch) for the builds generation. Items that are not in the data set are removed.
+    pub fn use_game_data(&mut self, game_data: &'static GameDataSet) {
+        self.game_data = game_data;
+        self.target.use_game_data(game_data);
+        for target in &mut self.teamfight_targets {
+            target.use_game_data(game_data);
+        }
+        for enemy in &mut self.enemy_team {
+            enemy.target.use_game_data(game_data);
+        }
+        let items_database: &ItemsDatabase = game_data.items_database;
+        for item in self.mandatory_items.iter_mut() {
+            *item = items_database.get_item(item).unwrap_or(&Item::NULL_ITEM);
+        }
+        self.legendary_items_pool = items_database.get_items(&self.legendary_items_pool);
+        self.boots_pool = items_database.get_items(&self.boots_pool);
+        self.supp_items_pool = items_database.get_items(&self.supp_items_pool);
+    }
+
+    /// Returns the percentage of physical dmg received used for the builds generation.
+    /// With an enemy team, it is derived from the dmg dealt by the enemy champions (see `TargetDefinition::dmg_profile`,
+    /// enemies without dmg profile are ignored). Otherwise (or without enemy champion), returns `phys_dmg_received_percent`.
+    #[must_use]
+    pub fn effective_phys_dmg_received_percent(&self) -> f32 {
+        let (phys_dmg, magic_dmg): (f32, f32) = self
+            .enemy_team
             .iter()
-            .any(|&properties| *self.target_properties == *properties)
-        {
+            .filter_map(|enemy| enemy.target.dmg_profile())
+            .fold((0., 0.), |(phys_acc, magic_acc), (phys_dmg, magic_dmg)| {
+                (phys_acc + phys_dmg, magic_acc + magic_dmg)
+            });
+        if phys_dmg + magic_dmg > 0. {
+            phys_dmg / (phys_dmg + magic_dmg)
+        } else {
+            self.phys_dmg_received_percent
+        }
+    }
+
+    pub fn check_settings(&self, champ_properties: &UnitProperties) -> Result<(), String> {
+        if self.game_data.get_champion(champ_properties).is_none() {
             return Err(format!(
-                "'{}' is not a recognized target",
-                self.target_properties.name
+                "{} is not available in patch {}",
+                champ_properties.name, self.game_data.patch
             ));
         }
 
+        if let Err(error_msg) = self.target.check_validity(self.game_data) {
+            return Err(format!("Invalid target: {error_msg}"));
+        }
+
+        if self.teamfight_targets.len() > MAX_TEAMFIGHT_TARGETS {
+            return Err(format!(
+                "Number of teamfight targets must be at most {MAX_TEAMFIGHT_TARGETS} (got {})",
+                self.teamfight_targets.len()
+            ));
+        }
+        for target in &self.teamfight_targets {
+            if let Err(error_msg) = target.check_validity(self.game_data) {
+                return Err(format!("Invalid teamfight target: {error_msg}"));
+            }
+        }
+
+        if self.enemy_team.len() > MAX_ENEMY_TEAM_SIZE {
+            return Err(format!(
+                "Number of enemies must be at most {MAX_ENEMY_TEAM_SIZE} (got {})",
+                self.enemy_team.len()
+            ));
+        }
+        if !self.enemy_team.is_empty() && !self.teamfight_targets.is_empty() {
+            return Err(
+                "Cannot use teamfight targets and an enemy team at the same time".to_string(),
+            );
+        }
+        for enemy in &self.enemy_team {
+            if let Err(error_msg) = enemy.target.check_validity(self.game_data) {
+                return Err(format!("Invalid enemy: {error_msg}"));
+            }
+            if !enemy.focus_weight.is_finite() || enemy.focus_weight < 0. {
+                return Err(format!(
+                    "Enemies focus weights must be positive (got {})",
+                    enemy.focus_weight
+                ));
+            }
+        }
+        if !self.enemy_team.is_empty()
+            && self
+                .enemy_team
+                .iter()
+                .map(|enemy| enemy.focus_weight)
+                .sum::<f32>()
+                <= 0.
+        {
+            return Err("At least one enemy must have a non-zero focus weight".to_string());
+        }
+
         if self.fight_scenario_number.get() > champ_properties.fight_scenarios.len() {
             return Err(format!(
                 "Fight scenario number for {} must be lower than {} (got {})",
@@ -504,10 +971,39 @@ impl BuildsGenerationSettings {
             ));
         }
 
+        if let Some(profile) = &self.incoming_dmg {
+            if let Err(error_msg) = profile.check_validity() {
+                return Err(format!("Invalid incoming dmg: {error_msg}"));
+            }
+        }
+
+        if !self.target_flee_percent.is_finite() || !(0.0..1.0).contains(&self.target_flee_percent)
+        {
+            return Err(format!(
+                "Percentage of time the target flees must be greater than 0% and under 100% (excluded) (got {}%)",
+                100. * self.target_flee_percent
+            ));
+        }
+
+        if let Err(error_msg) = self.economy.check_validity() {
+            return Err(format!("Invalid economy profile: {error_msg}"));
+        }
+
         if let Err(error_msg) = self.runes_page.check_validity() {
             return Err(format!("Invalid runes page: {error_msg}"));
         }
 
+        if let Err(error_msg) = self.summoner_spells.check_validity() {
+            return Err(format!("Invalid summoner spells: {error_msg}"));
+        }
+
+        if self.skill_order.is_some() && *champ_properties == Unit::APHELIOS_PROPERTIES {
+            return Err(format!(
+                "{} skill order cannot be changed (his abilities are not lvled up like other champions)",
+                champ_properties.name
+            ));
+        }
+
         if !(1..=MAX_UNIT_ITEMS).contains(&self.n_items) {
             return Err(format!(
                 "Number of items per build must be between 1 and {MAX_UNIT_ITEMS} (got {})",
@@ -515,6 +1011,20 @@ impl BuildsGenerationSettings {
             ));
         }
 
+        if let Some(item) = self
+            .mandatory_items
+            .iter()
+            .chain(self.legendary_items_pool.iter())
+            .chain(self.boots_pool.iter())
+            .chain(self.supp_items_pool.iter())
+            .find(|item| self.game_data.items_database.get_item(item).is_none())
+        {
+            return Err(format!(
+                "'{}' is not in the items database used ({})",
+                item.full_name, self.game_data.items_database.source
+            ));
+        }
+
         if let Err(error_msg) = self.mandatory_items.check_validity() {
             return Err(format!(
                 "{} is an invalid combination of items: {error_msg}",
@@ -620,6 +1130,18 @@ impl BuildsGenerationSettings {
         {
             return Err("Items pools cannot contain `NULL_ITEM`".to_string());
         }
+        if self
+            .legendary_items_pool
+            .iter()
+            .chain(self.boots_pool.iter())
+            .chain(self.supp_items_pool.iter())
+            .any(|item| !item.is_finished())
+        {
+            return Err(
+                "Items pools cannot contain component items, starter items or consumables"
+                    .to_string(),
+            );
+        }
         if let Some(item) = crate::find_dupes_in_slice(&mut self.legendary_items_pool.clone()) {
             return Err(format!("Duplicates in legendary items pool: {:#}", item));
         }
@@ -635,16 +1157,22 @@ impl BuildsGenerationSettings {
         if !self.weights.0.is_finite()
             || !self.weights.1.is_finite()
             || !self.weights.2.is_finite()
+            || !self.weights.3.is_finite()
             || self.weights.0 < 0.
             || self.weights.1 < 0.
             || self.weights.2 < 0.
+            || self.weights.3 < 0.
         {
             return Err(format!(
-                "Weights must be finite and positive (got 'DPS {}, defense {}, mobility {}')",
-                self.weights.0, self.weights.1, self.weights.2,
+                "Weights must be finite and positive (got 'DPS {}, defense {}, mobility {}, time to kill {}')",
+                self.weights.0, self.weights.1, self.weights.2, self.weights.3,
             ));
         }
-        if (self.weights.0 == 0.) && (self.weights.1 == 0.) && (self.weights.2 == 0.) {
+        if (self.weights.0 == 0.)
+            && (self.weights.1 == 0.)
+            && (self.weights.2 == 0.)
+            && (self.weights.3 == 0.)
+        {
             return Err("At least one weight must be non-zero".to_string());
         }
 
@@ -667,6 +1195,7 @@ pub struct BuildContainer {
     pub dps: [f32; MAX_UNIT_ITEMS + 1],   //starting dps + 1 value per item
     pub def: [f32; MAX_UNIT_ITEMS + 1],   //starting defense + 1 value per item
     pub ms: [f32; MAX_UNIT_ITEMS + 1],    //starting ms + 1 value per item
+    pub ttk: [f32; MAX_UNIT_ITEMS + 1],   //starting time to kill + 1 value per item
 }
 
 /// Returns the average of the curve formed by the given points.
@@ -704,12 +1233,14 @@ fn gold_weighted_average(values: &[f32], golds: &[f32], max_golds: f32) -> f32 {
 impl BuildContainer {
     /// Returns the build score at the given item count.
     #[allow(dead_code)]
-    pub fn get_item_slot_score(&self, item_slot: usize, weights: (f32, f32, f32)) -> f32 {
-        let normalized_weights: (f32, f32, f32) = get_normalized_weights(weights);
+    #[must_use]
+    pub fn get_item_slot_score(&self, item_slot: usize, weights: (f32, f32, f32, f32)) -> f32 {
+        let normalized_weights: (f32, f32, f32, f32) = get_normalized_weights(weights);
         score_formula_with_normalized_weights(
             self.dps[item_slot],
             self.def[item_slot],
             self.ms[item_slot],
+            self.ttk[item_slot],
             normalized_weights,
         )
     }
@@ -720,19 +1251,26 @@ impl BuildContainer {
     pub(crate) fn _get_item_slot_score_with_normalized_weights(
         &self,
         item_slot: usize,
-        normalized_weights: (f32, f32, f32),
+        normalized_weights: (f32, f32, f32, f32),
     ) -> f32 {
         score_formula_with_normalized_weights(
             self.dps[item_slot],
             self.def[item_slot],
             self.ms[item_slot],
+            self.ttk[item_slot],
             normalized_weights,
         )
     }
 
     /// Returns the build average score over the requested item slots.
     #[allow(dead_code)]
-    pub fn get_avg_score(&self, n_items: usize, max_golds: f32, weights: (f32, f32, f32)) -> f32 {
+    #[must_use]
+    pub fn get_avg_score(
+        &self,
+        n_items: usize,
+        max_golds: f32,
+        weights: (f32, f32, f32, f32),
+    ) -> f32 {
         self._get_avg_score_with_normalized_weights(
             n_items,
             max_golds,
@@ -746,7 +1284,7 @@ impl BuildContainer {
         &self,
         n_items: usize,
         max_golds: f32,
-        normalized_weights: (f32, f32, f32),
+        normalized_weights: (f32, f32, f32, f32),
     ) -> f32 {
         //sanity check
         assert!(
@@ -762,15 +1300,25 @@ impl BuildContainer {
     }
 }
 
+/// Minimum time to kill (in seconds) used in the score formula.
+const MIN_SCORE_TTK: f32 = 1e-4;
+
 #[inline]
+#[must_use]
 pub fn get_normalized_weights(
-    (dps_value_weight, def_weight, ms_weight): (f32, f32, f32),
-) -> (f32, f32, f32) {
-    let sum: f32 = dps_value_weight + def_weight + ms_weight;
-    (dps_value_weight / sum, def_weight / sum, ms_weight / sum)
+    (dps_value_weight, def_weight, ms_weight, ttk_weight): (f32, f32, f32, f32),
+) -> (f32, f32, f32, f32) {
+    let sum: f32 = dps_value_weight + def_weight + ms_weight + ttk_weight;
+    (
+        dps_value_weight / sum,
+        def_weight / sum,
+        ms_weight / sum,
+        ttk_weight / sum,
+    )
 }
 
-/// Formula for the the score of a build.
+/// Formula for the the score of a build (a lower time to kill gives a higher score).
+/// The time to kill is clamped to `MIN_SCORE_TTK` so that an instant kill doesn't give an infinite score.
 /// `weights` must be >= 0 and normalized (their sum must be 1.0) for the formula to be correct
 /// (these requirements are not checked when calling this function for performance reasons).
 #[inline]
@@ -778,9 +1326,13 @@ fn score_formula_with_normalized_weights(
     dps: f32,
     def: f32,
     ms: f32,
-    (norm_dps_value_weight, norm_def_weight, norm_ms_weight): (f32, f32, f32),
+    ttk: f32,
+    (norm_dps_value_weight, norm_def_weight, norm_ms_weight, norm_ttk_weight): (f32, f32, f32, f32),
 ) -> f32 {
-    dps.powf(norm_dps_value_weight) * def.powf(norm_def_weight) * ms.powf(norm_ms_weight)
+    dps.powf(norm_dps_value_weight)
+        * def.powf(norm_def_weight)
+        * ms.powf(norm_ms_weight)
+        * f32::max(MIN_SCORE_TTK, ttk).powf(-norm_ttk_weight)
 }
 
 /// Generate the next 'layer' of builds from current builds, returns None if next layer is empty (never returns an empty Vec).
@@ -789,7 +1341,7 @@ fn generate_build_layer(
     current_builds: Vec<BuildContainer>,
     pool: &[&'static Item],
     layer_to_fill_idx: usize,
-    normalized_weights: (f32, f32, f32),
+    normalized_weights: (f32, f32, f32, f32),
 ) -> Option<Vec<BuildContainer>> {
     let mut new_builds: Vec<BuildContainer> = Vec::with_capacity(current_builds.len()); //new_builds will probably have at least this size
     let mut hashes: FxHashMap<BuildHash, usize> =
@@ -851,23 +1403,132 @@ fn compute_chunk_size(n_elements: usize, thread_count: NonZeroUsize) -> usize {
     usize::max(1, n_elements.div_ceil(thread_count.get()))
 }
 
-fn get_scores_from_sim_results(champ: &Unit, phys_dmg_received_percent: f32) -> (f32, f32, f32) {
+/// Sets the lvl of the teamfight targets and gives their stats to the champion (does nothing if there is no teamfight target).
+/// Champion and targets used in the fight simulations of the builds generation.
+struct SimulationUnits {
+    champ: Unit,
+    /// Enemies of the enemy team or a single target (during a teamfight, the first enemy unit is the main target).
+    targets: Vec<Unit>,
+    focus_weights: Vec<f32>, //normalized, indexed like `targets`
+    teamfight_targets: Vec<Unit>,
+}
+
+impl SimulationUnits {
+    /// Creates the champion and its targets at the given lvl according to the settings.
+    /// `champ_properties` must come from the game data of the settings.
+    fn new(
+        champ_properties: &'static UnitProperties,
+        settings: &BuildsGenerationSettings,
+        phys_dmg_received_percent: f32,
+        lvl: u8,
+    ) -> Result<Self, String> {
+        let mut champ: Unit =
+            Unit::from_properties_defaults(champ_properties, lvl, Build::default())
+                .expect("Failed to create unit");
+        if let Some(max_order) = settings.skill_order {
+            champ.set_skill_order(max_order.to_skill_order())?;
+        }
+        champ.set_runes(settings.runes_page)?;
+        champ.set_summoner_spells(settings.summoner_spells)?;
+        champ.set_incoming_dmg(settings.incoming_dmg, phys_dmg_received_percent);
+        champ.set_target_flee_percent(settings.target_flee_percent)?;
+        champ.set_economy(settings.economy)?;
+
+        let (targets, focus_weights): (Vec<Unit>, Vec<f32>) = if settings.enemy_team.is_empty() {
+            (
+                vec![settings
+                    .teamfight_targets
+                    .first()
+                    .unwrap_or(&settings.target)
+                    .create_unit(lvl)?],
+                vec![1.],
+            )
+        } else {
+            let tot_focus_weight: f32 = settings
+                .enemy_team
+                .iter()
+                .map(|enemy| enemy.focus_weight)
+                .sum();
+            (
+                settings
+                    .enemy_team
+                    .iter()
+                    .map(|enemy| enemy.target.create_unit(lvl))
+                    .collect::<Result<Vec<Unit>, String>>()?,
+                settings
+                    .enemy_team
+                    .iter()
+                    .map(|enemy| enemy.focus_weight / tot_focus_weight)
+                    .collect(),
+            )
+        };
+        let teamfight_targets: Vec<Unit> = settings
+            .teamfight_targets
+            .iter()
+            .map(|target| target.create_unit(lvl))
+            .collect::<Result<Vec<Unit>, String>>()?;
+
+        Ok(Self {
+            champ,
+            targets,
+            focus_weights,
+            teamfight_targets,
+        })
+    }
+
+    /// Sets the lvl of the champion and of every target, returns the stats of the targets with their focus weight.
+    fn set_lvl(&mut self, lvl: u8) -> Vec<(UnitStats, f32)> {
+        self.champ.set_lvl(lvl).expect("Failed to set lvl"); //no need to init (automatically done later when simulating fights)
+        for target in &mut self.targets {
+            target.set_lvl(lvl).expect("Failed to set lvl");
+            target.init_fight();
+        }
+        if !self.teamfight_targets.is_empty() {
+            let teamfight_targets_stats: Vec<UnitStats> = self
+                .teamfight_targets
+                .iter_mut()
+                .map(|target| {
+                    target.set_lvl(lvl).expect("Failed to set lvl");
+                    target.init_fight();
+                    target.get_stats().clone()
+                })
+                .collect();
+            self.champ
+                .set_teamfight_targets(&teamfight_targets_stats)
+                .expect("Failed to set teamfight targets");
+        }
+        zip(&self.targets, &self.focus_weights)
+            .map(|(target, &focus_weight)| (target.get_stats().clone(), focus_weight))
+            .collect()
+    }
+}
+
+fn get_scores_from_sim_results(
+    champ: &Unit,
+    target_stats: &UnitStats,
+    phys_dmg_received_percent: f32,
+) -> (f32, f32, f32, f32) {
     let actual_time: f32 = champ.get_time(); //take champ.time instead of fight_duration in scores calculations, since simulation can be slighlty extended
 
     let dps: f32 = champ.get_dmg_done().as_sum() / actual_time; //average dps of the unit over the fight simulation
 
-    let effective_hp: f32 = (champ.get_stats().hp
-        + champ.get_single_use_heals_shields()
-        + DEFAULT_FIGHT_DURATION * champ.get_periodic_heals_shields() / actual_time)
-        / (phys_dmg_received_percent * resistance_formula(champ.get_stats().armor)
-            + (1. - phys_dmg_received_percent) * resistance_formula(champ.get_stats().mr));
+    //time survived if the unit received dmg during the simulation, effective hp otherwise
+    let defense: f32 = champ.get_time_survived().unwrap_or_else(|| {
+        (champ.get_stats().hp
+            + champ.get_single_use_heals_shields()
+            + DEFAULT_FIGHT_DURATION * champ.get_periodic_heals_shields() / actual_time)
+            / (phys_dmg_received_percent * resistance_formula(champ.get_stats().armor)
+                + (1. - phys_dmg_received_percent) * resistance_formula(champ.get_stats().mr))
+    });
 
     let move_speed: f32 = champ.get_units_travelled() / actual_time; //average move speed of the unit over the fight simulation
 
-    (dps, effective_hp, move_speed)
+    let time_to_kill: f32 = champ.get_time_to_kill(target_stats);
+
+    (dps, defense, move_speed, time_to_kill)
 }
 
-/// Number of pareto scores to consider. Must be consistent with the number of elements in the `ParetoPoint` type.
+/// Number of pareto scores to consider (without the time to kill). Must be consistent with the number of elements in the `ParetoPoint` type.
 /// f32 because only used in f32 calculations.
 const N_PARETO_SCORES: f32 = 7.;
 struct ParetoSpacePoint {
@@ -876,13 +1537,14 @@ struct ParetoSpacePoint {
     dps: f32,
     def: f32,
     ms: f32,
+    ttk: f32, //only considered as a pareto score if its weight is non-zero
 }
 
 impl ParetoSpacePoint {
     /// Returns true if self has reasons to be kept against another point, false otherwise.
     /// This doesn't use the usual definition of pareto efficiency but a variation to keep points
     /// that are close to the pareto front as well (up to a given limit, `discard_percent`).
-    fn is_pareto_efficient(&self, other: &Self, discard_percent: f32) -> bool {
+    fn is_pareto_efficient(&self, other: &Self, discard_percent: f32, use_ttk: bool) -> bool {
         //Points with dps, defense and ms close to the pareto front are kept because they
         //can be subject to some little variance between different simulations and scenarios.
         !((self.utils & !other.utils).is_empty())
@@ -890,59 +1552,51 @@ impl ParetoSpacePoint {
             || self.dps > discard_percent * other.dps
             || self.def > discard_percent * other.def
             || self.ms > discard_percent * other.ms
+            || (use_ttk && discard_percent * self.ttk < other.ttk)
     }
 
+    /// Simulates fights against each target (stats and focus weight, focus weights must sum to 1)
+    /// and returns the scores averaged according to the focus weights.
     fn from_fight_simulation(
         build: &Build,
         champ: &mut Unit,
-        target_stats: &UnitStats,
+        targets: &[(UnitStats, f32)],
+        phys_dmg_received_percent: f32,
         settings: &BuildsGenerationSettings,
     ) -> Self {
         champ.set_build_unchecked(*build); //assumes builds have been cheched prior (when generating combinations)
         let mut avg_dps: f32 = 0.;
         let mut avg_def: f32 = 0.;
         let mut avg_ms: f32 = 0.;
+        let mut avg_ttk: f32 = 0.;
 
         //to avoid combinations of items that are local optimums for the given fight_duration,
         //we simulate for 3 fight durations scattered across a normal distribution around the original fight_duration
         //the final scores are calculated from the weighted sum of each simulation result (weight according to the normal distribution)
         let std_dev: f32 = 0.15 * settings.fight_duration; //chosen arbitrarily, but it works
 
-        //weights for a value at 1.25 std_dev from the mean
-        champ.simulate_fight(
-            target_stats,
-            settings.fight_scenario_number.get() - 1,
-            settings.fight_duration - 1.25 * std_dev,
-        );
-        let (dps, def, ms): (f32, f32, f32) =
-            get_scores_from_sim_results(champ, settings.phys_dmg_received_percent);
-        avg_dps += 0.25 * dps;
-        avg_def += 0.25 * def;
-        avg_ms += 0.25 * ms;
-
-        //weights for a value at the mean
-        champ.simulate_fight(
-            target_stats,
-            settings.fight_scenario_number.get() - 1,
-            settings.fight_duration,
-        );
-        let (dps, def, ms): (f32, f32, f32) =
-            get_scores_from_sim_results(champ, settings.phys_dmg_received_percent);
-        avg_dps += 0.50 * dps;
-        avg_def += 0.50 * def;
-        avg_ms += 0.50 * ms;
-
-        //weights for a value at 1.25 std_dev from the mean
-        champ.simulate_fight(
-            target_stats,
-            settings.fight_scenario_number.get() - 1,
-            settings.fight_duration + 1.25 * std_dev,
-        );
-        let (dps, def, ms): (f32, f32, f32) =
-            get_scores_from_sim_results(champ, settings.phys_dmg_received_percent);
-        avg_dps += 0.25 * dps;
-        avg_def += 0.25 * def;
-        avg_ms += 0.25 * ms;
+        //weights for values at -1.25 std_dev, the mean and +1.25 std_dev
+        for (n_std_dev, duration_weight) in [(-1.25, 0.25), (0., 0.50), (1.25, 0.25)] {
+            for (target_stats, focus_weight) in targets {
+                let fight_duration: f32 = settings.fight_duration + n_std_dev * std_dev;
+                if let Some(script) = &settings.fight_script {
+                    champ.simulate_scripted_fight(target_stats, script, fight_duration);
+                } else {
+                    champ.simulate_fight(
+                        target_stats,
+                        settings.fight_scenario_number.get() - 1,
+                        fight_duration,
+                    );
+                }
+                let (dps, def, ms, ttk): (f32, f32, f32, f32) =
+                    get_scores_from_sim_results(champ, target_stats, phys_dmg_received_percent);
+                let weight: f32 = duration_weight * focus_weight;
+                avg_dps += weight * dps;
+                avg_def += weight * def;
+                avg_ms += weight * ms;
+                avg_ttk += weight * ttk;
+            }
+        }
 
         Self {
             utils: build
@@ -954,6 +1608,7 @@ impl ParetoSpacePoint {
             dps: avg_dps,
             def: avg_def,
             ms: avg_ms,
+            ttk: avg_ttk,
         }
     }
 }
@@ -961,13 +1616,20 @@ impl ParetoSpacePoint {
 fn simulate_chunk_of_builds(
     chunk: &[BuildContainer],
     champ: &mut Unit,
-    target_stats: &UnitStats,
+    targets: &[(UnitStats, f32)],
+    phys_dmg_received_percent: f32,
     settings: &BuildsGenerationSettings,
 ) -> Vec<ParetoSpacePoint> {
     chunk
         .iter()
         .map(|container| {
-            ParetoSpacePoint::from_fight_simulation(&container.build, champ, target_stats, settings)
+            ParetoSpacePoint::from_fight_simulation(
+                &container.build,
+                champ,
+                targets,
+                phys_dmg_received_percent,
+                settings,
+            )
         })
         .collect()
 }
@@ -978,10 +1640,11 @@ fn pareto_compare_chunk_to_point(
     chunk: &[ParetoSpacePoint],
     ref_point: &ParetoSpacePoint,
     discard_percent: f32,
+    use_ttk: bool,
 ) -> Vec<bool> {
     chunk
         .iter()
-        .map(|chunk_point| chunk_point.is_pareto_efficient(ref_point, discard_percent))
+        .map(|chunk_point| chunk_point.is_pareto_efficient(ref_point, discard_percent, use_ttk))
         .collect()
 }
 
@@ -992,6 +1655,7 @@ fn pareto_compare_chunk_to_point(
 fn pareto_front_multithread(
     points: &mut Vec<ParetoSpacePoint>,
     discard_percent: f32,
+    use_ttk: bool,
     thread_count: NonZeroUsize,
 ) -> Vec<bool> {
     let input_len: usize = points.len();
@@ -1008,7 +1672,7 @@ fn pareto_front_multithread(
         pareto_mask = points
             .par_chunks(chunk_size)
             .flat_map_iter(|chunk| {
-                pareto_compare_chunk_to_point(chunk, current_point, discard_percent)
+                pareto_compare_chunk_to_point(chunk, current_point, discard_percent, use_ttk)
             })
             .collect();
         pareto_mask[idx] = true; //keep self
@@ -1036,50 +1700,68 @@ fn pareto_front_multithread(
     pareto_mask
 }
 
-/// From number of items, returns the associated unit lvl.
+/// From number of items, returns the associated unit lvl according to the boots & support item slots
+/// and the economy profile of the settings.
 #[must_use]
-fn lvl_from_number_of_items(n_items: usize, boots_slot: ItemSlot, supp_item_slot: ItemSlot) -> u8 {
+fn lvl_from_number_of_items(n_items: usize, settings: &BuildsGenerationSettings) -> u8 {
+    let xp_per_legendary_item: f32 = settings.economy.xp_per_golds(AVG_LEGENDARY_ITEM_COST);
+    let xp_per_boots_item: f32 = settings.economy.xp_per_golds(AVG_BOOTS_COST);
+    let xp_per_supp_item: f32 = settings.economy.xp_per_golds(AVG_SUPP_ITEM_COST);
+
     #[allow(clippy::cast_precision_loss)] //`MAX_UNIT_ITEMS` is well within f32 precision range
-    let xp_per_non_special_item: f32 = match (boots_slot, supp_item_slot) {
+    let xp_per_non_special_item: f32 = match (settings.boots_slot, settings.supp_item_slot) {
         (ItemSlot::Any, ItemSlot::Any) => {
-            XP_PER_LEGENDARY_ITEM * ((MAX_UNIT_ITEMS - 2) as f32) / (MAX_UNIT_ITEMS as f32)
-                + XP_PER_BOOTS_ITEM / (MAX_UNIT_ITEMS as f32)
-                + XP_PER_SUPP_ITEM / (MAX_UNIT_ITEMS as f32)
+            xp_per_legendary_item * ((MAX_UNIT_ITEMS - 2) as f32) / (MAX_UNIT_ITEMS as f32)
+                + xp_per_boots_item / (MAX_UNIT_ITEMS as f32)
+                + xp_per_supp_item / (MAX_UNIT_ITEMS as f32)
         }
         (ItemSlot::Any, _) => {
-            XP_PER_LEGENDARY_ITEM * ((MAX_UNIT_ITEMS - 1) as f32) / (MAX_UNIT_ITEMS as f32)
-                + XP_PER_BOOTS_ITEM / (MAX_UNIT_ITEMS as f32)
+            xp_per_legendary_item * ((MAX_UNIT_ITEMS - 1) as f32) / (MAX_UNIT_ITEMS as f32)
+                + xp_per_boots_item / (MAX_UNIT_ITEMS as f32)
         }
         (_, ItemSlot::Any) => {
-            XP_PER_LEGENDARY_ITEM * ((MAX_UNIT_ITEMS - 1) as f32) / (MAX_UNIT_ITEMS as f32)
-                + XP_PER_SUPP_ITEM / (MAX_UNIT_ITEMS as f32)
+            xp_per_legendary_item * ((MAX_UNIT_ITEMS - 1) as f32) / (MAX_UNIT_ITEMS as f32)
+                + xp_per_supp_item / (MAX_UNIT_ITEMS as f32)
         }
-        (_, _) => XP_PER_LEGENDARY_ITEM,
+        (_, _) => xp_per_legendary_item,
     };
 
-    let boots_slot: usize = if let ItemSlot::Slot(boots_slot_number) = boots_slot {
+    let boots_slot: usize = if let ItemSlot::Slot(boots_slot_number) = settings.boots_slot {
         boots_slot_number
     } else {
         0
     };
-    let supp_item_slot: usize = if let ItemSlot::Slot(supp_item_slot_number) = supp_item_slot {
-        supp_item_slot_number
-    } else {
-        0
-    };
+    let supp_item_slot: usize =
+        if let ItemSlot::Slot(supp_item_slot_number) = settings.supp_item_slot {
+            supp_item_slot_number
+        } else {
+            0
+        };
 
     let mut cum_xp: f32 = 0.;
     for i in 1..=n_items {
         if i == boots_slot {
-            cum_xp += XP_PER_BOOTS_ITEM;
+            cum_xp += xp_per_boots_item;
         } else if i == supp_item_slot {
-            cum_xp += XP_PER_SUPP_ITEM;
+            cum_xp += xp_per_supp_item;
         } else {
             cum_xp += xp_per_non_special_item;
         }
     }
 
-    let mut lvl: u8 = MIN_UNIT_LVL; //lvl cannot be below `MIN_UNIT_LVL`, so start at this value
+    lvl_from_cum_xp(cum_xp, BUILDS_GENERATION_MIN_LVL)
+}
+
+/// From game time (in minutes), returns the associated unit lvl according to the given economy profile.
+#[must_use]
+fn lvl_from_game_time(economy: &EconomyProfile, game_time: f32) -> u8 {
+    lvl_from_cum_xp(economy.xp_per_min() * game_time, MIN_UNIT_LVL)
+}
+
+/// From cumulated experience, returns the associated unit lvl (not below `min_lvl`).
+#[must_use]
+fn lvl_from_cum_xp(cum_xp: f32, min_lvl: u8) -> u8 {
+    let mut lvl: u8 = min_lvl; //lvl cannot be below `min_lvl`, so start at this value
     while usize::from(lvl - 1) < MAX_UNIT_LVL - 1
         && cum_xp >= CUM_XP_NEEDED_FOR_LVL_UP_BY_LVL[usize::from(lvl - 1)]
     {
@@ -1088,62 +1770,77 @@ fn lvl_from_number_of_items(n_items: usize, boots_slot: ItemSlot, supp_item_slot
     lvl
 }
 
+/// Receives progress events from the builds generation process (`find_best_builds`).
+/// Every method has an empty default implementation, implement only the events you need.
+///
+/// Events are always sent from the thread that called `find_best_builds`.
+pub trait BuildsGenerationObserver {
+    /// Called once at the start of the generation, after settings have been checked.
+    fn on_generation_started(&mut self, _champ_properties: &UnitProperties, _n_items: usize) {}
+
+    /// Called when the generation of builds for a new item slot starts (item slots start at 1).
+    fn on_layer_started(&mut self, _item_slot: usize) {}
+
+    /// Called when every candidate build of the current item slot has been simulated.
+    fn on_builds_simulated(&mut self, _item_slot: usize, _n_builds: usize) {}
+
+    /// Called after pruning the builds of the current item slot.
+    /// `n_pruned_by_gold_value` builds were discarded for their low score per gold,
+    /// `n_pruned_by_pareto` builds were discarded because they are too far from the pareto front,
+    /// `n_remaining` builds are kept for the next item slot.
+    fn on_builds_pruned(
+        &mut self,
+        _item_slot: usize,
+        _n_pruned_by_gold_value: usize,
+        _n_pruned_by_pareto: usize,
+        _n_remaining: usize,
+    ) {
+    }
+
+    /// Called when the generation of builds for an item slot is done.
+    fn on_layer_finished(&mut self, _item_slot: usize) {}
+
+    /// Called once when the generation is done successfully, with the number of builds returned.
+    fn on_generation_finished(&mut self, _n_builds: usize) {}
+}
+
+/// Observer that ignores every event.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SilentObserver;
+
+impl BuildsGenerationObserver for SilentObserver {}
+
 /// Returns a Vec containing the best builds generated for the given champion.
 /// If the generation process fails, return an Err with the corresponding error message.
 ///
-/// Use the silent argument to control if the function shows a progress bar to stdout or not.
+/// The progress of the generation is reported to the given observer.
 pub fn find_best_builds(
     champ_properties: &'static UnitProperties,
     settings: &BuildsGenerationSettings,
-    silent: bool,
+    observer: &mut dyn BuildsGenerationObserver,
 ) -> Result<Vec<BuildContainer>, String> {
     //check input arguments
     settings.check_settings(champ_properties)?;
-
-    //create unit
-    let mut champ: Unit = Unit::from_properties_defaults(champ_properties, 6, Build::default())
-        .expect("Failed to create unit");
-    champ.set_runes(settings.runes_page)?;
+    //use the version of the champion from the patch of the settings
+    let champ_properties: &'static UnitProperties = settings
+        .game_data
+        .get_champion(champ_properties)
+        .expect("Champion should be in the settings game data after checking settings");
+
+    //create champion and targets
+    let lvl: u8 = BUILDS_GENERATION_MIN_LVL; //lvl for the empty build scores
+    let phys_dmg_received_percent: f32 = settings.effective_phys_dmg_received_percent();
+    let mut units: SimulationUnits =
+        SimulationUnits::new(champ_properties, settings, phys_dmg_received_percent, lvl)?;
 
     //get number of available threads
     let thread_count: NonZeroUsize =
         std::thread::available_parallelism().expect("Failed to get amount of available threads");
 
-    //start progress bar
-    let progress_bar: ProgressBar = if silent {
-        ProgressBar::hidden()
-    } else {
-        let bar: ProgressBar = ProgressBar::new(settings.n_items as u64)
-            .with_style(
-                ProgressStyle::with_template(
-                    "{msg} {spinner} [{elapsed_precise}] {bar} {pos}/{len} items",
-                )
-                .expect("Failed to create progress bar style"),
-            )
-            .with_message(format!(
-                "Generating best builds for {}",
-                champ_properties.name
-            ))
-            .with_finish(ProgressFinish::AbandonWithMessage(
-                format!("Done generating builds for {}", champ_properties.name).into(),
-            ));
-        bar.enable_steady_tick(Duration::from_millis(200));
-        bar
-    };
-
-    //create target dummy
-    let lvl: u8 = 6; //use lvl 6 for the empty build scores
-    let mut target: Unit = Unit::new(
-        settings.target_properties,
-        OPTIMIZER_DUMMY_RUNES_PAGE,
-        OPTIMIZER_DUMMY_SKILL_ORDER,
-        lvl,
-        Build::default(),
-    )
-    .expect("Failed to create target dummy");
+    observer.on_generation_started(champ_properties, settings.n_items);
 
     //create empty build base scores
-    champ.set_lvl(lvl).expect("Failed to set lvl");
+    let targets_stats: Vec<(UnitStats, f32)> = units.set_lvl(lvl);
     let mut init_build: BuildContainer = BuildContainer {
         build: Build::default(),
         cum_utils: enum_set!(),
@@ -1151,20 +1848,23 @@ pub fn find_best_builds(
         dps: [0.; MAX_UNIT_ITEMS + 1],
         def: [0.; MAX_UNIT_ITEMS + 1],
         ms: [0.; MAX_UNIT_ITEMS + 1],
+        ttk: [0.; MAX_UNIT_ITEMS + 1],
     };
     let init_point: ParetoSpacePoint = ParetoSpacePoint::from_fight_simulation(
         &init_build.build,
-        &mut champ,
-        target.get_stats(),
+        &mut units.champ,
+        &targets_stats,
+        phys_dmg_received_percent,
         settings,
     );
     init_build.dps[0] = init_point.dps;
     init_build.def[0] = init_point.def;
     init_build.ms[0] = init_point.ms;
+    init_build.ttk[0] = init_point.ttk;
     //no need to change other fields
 
     //initialize best builds generation
-    let normalized_weights: (f32, f32, f32) = get_normalized_weights(settings.weights);
+    let normalized_weights: (f32, f32, f32, f32) = get_normalized_weights(settings.weights);
     //treat boots/support item as legendary items if no slot specified
     let mut legendary_items: &[&Item] = &settings.legendary_items_pool;
     let mut extended_legendary_items_buffer: Vec<&Item>;
@@ -1177,34 +1877,30 @@ pub fn find_best_builds(
         legendary_items = &extended_legendary_items_buffer;
     }
     let discard_percent: f32 = 1. - settings.search_threshold;
+    let use_ttk: bool = settings.weights.3 > 0.; //time to kill is only a pareto score if it is part of the score
+    let n_pareto_scores: f32 = if use_ttk {
+        N_PARETO_SCORES + 1.
+    } else {
+        N_PARETO_SCORES
+    };
     let mut best_builds: Vec<BuildContainer> = vec![init_build];
     //start iterating on each item slot
-    for item_idx in (0..settings.n_items).progress_with(progress_bar) {
+    for item_idx in 0..settings.n_items {
         let item_slot: usize = item_idx + 1;
+        observer.on_layer_started(item_slot);
 
         //set champion & dummy lvl
-        let lvl: u8 =
-            lvl_from_number_of_items(item_slot, settings.boots_slot, settings.supp_item_slot);
-        champ.set_lvl(lvl).expect("Failed to set lvl"); //no need to init (automatically done later when simulating fights)
-        target.set_lvl(lvl).expect("Failed to set lvl");
-        target.init_fight();
+        let lvl: u8 = lvl_from_number_of_items(item_slot, settings);
+        let targets_stats: Vec<(UnitStats, f32)> = units.set_lvl(lvl);
 
         //set item pool
         let mut pool: &[&Item] = &[settings.mandatory_items[item_idx]]; //need to assign temporary value outside of if else brackets
         let pool_without_manaflow_buffer: Vec<&Item>;
         if *settings.mandatory_items[item_idx] == Item::NULL_ITEM {
-            pool = if let ItemSlot::Slot(boots_slot_number) = settings.boots_slot {
-                if item_slot == boots_slot_number {
-                    &settings.boots_pool
-                } else {
-                    legendary_items
-                }
-            } else if let ItemSlot::Slot(supp_item_slot_number) = settings.supp_item_slot {
-                if item_slot == supp_item_slot_number {
-                    &settings.supp_items_pool
-                } else {
-                    legendary_items
-                }
+            pool = if settings.boots_slot == ItemSlot::Slot(item_slot) {
+                &settings.boots_pool
+            } else if settings.supp_item_slot == ItemSlot::Slot(item_slot) {
+                &settings.supp_items_pool
             } else {
                 legendary_items
             };
@@ -1235,11 +1931,19 @@ pub fn find_best_builds(
         let mut pareto_space_points: Vec<ParetoSpacePoint> = best_builds
             .par_chunks(chunk_size)
             .flat_map_iter(|chunk| {
-                simulate_chunk_of_builds(chunk, &mut champ.clone(), target.get_stats(), settings)
+                simulate_chunk_of_builds(
+                    chunk,
+                    &mut units.champ.clone(),
+                    &targets_stats,
+                    phys_dmg_received_percent,
+                    settings,
+                )
             })
             .collect();
+        observer.on_builds_simulated(item_slot, pareto_space_points.len());
 
         //remove low gold value builds
+        let n_simulated_builds: usize = pareto_space_points.len();
         let max_gold_value: f32 = pareto_space_points
             .iter()
             .map(|scores| {
@@ -1247,8 +1951,9 @@ pub fn find_best_builds(
                     scores.dps / scores.golds,
                     scores.def,
                     scores.ms,
+                    scores.ttk * scores.golds,
                     normalized_weights,
-                ) //we divide dps by golds because if dps is perfectly correlated with golds, we want def and ms to be the only deciding factors
+                ) //we divide dps (and multiply time to kill) by golds because if they are perfectly correlated with golds, we want def and ms to be the only deciding factors
             })
             .max_by(|a, b| a.partial_cmp(b).expect("Failed to compare floats"))
             .unwrap(); //points will never be empty (generate_build_layer will return an Err first)
@@ -1259,6 +1964,7 @@ pub fn find_best_builds(
                 scores.dps / scores.golds,
                 scores.def,
                 scores.ms,
+                scores.ttk * scores.golds,
                 normalized_weights,
             ) < discard_percent * max_gold_value
             {
@@ -1269,18 +1975,28 @@ pub fn find_best_builds(
             }
         }
 
+        let n_pruned_by_gold_value: usize = n_simulated_builds - pareto_space_points.len();
+
         //keep pareto efficient builds
+        let n_builds_before_pareto: usize = best_builds.len();
         let pareto_mask: Vec<bool> = pareto_front_multithread(
             &mut pareto_space_points,
             if item_slot == settings.n_items {
                 1.
             } else {
-                discard_percent.powf(1. / N_PARETO_SCORES) //heuristic criteria for `N_PARETO_SCORES` dimensions
+                discard_percent.powf(1. / n_pareto_scores) //heuristic criteria for `n_pareto_scores` dimensions
             },
+            use_ttk,
             thread_count,
         );
         let mut to_keep = pareto_mask.into_iter();
         best_builds.retain(|_| to_keep.next().unwrap()); //will never panic as to_keep has the same length
+        observer.on_builds_pruned(
+            item_slot,
+            n_pruned_by_gold_value,
+            n_builds_before_pareto - best_builds.len(),
+            best_builds.len(),
+        );
 
         //fill remaining build containers
         for (container, scores) in zip(best_builds.iter_mut(), pareto_space_points.iter()) {
@@ -1289,13 +2005,45 @@ pub fn find_best_builds(
             container.dps[item_slot] = scores.dps;
             container.def[item_slot] = scores.def;
             container.ms[item_slot] = scores.ms;
+            container.ttk[item_slot] = scores.ttk;
         }
+        observer.on_layer_finished(item_slot);
     }
 
     //return builds
+    observer.on_generation_finished(best_builds.len());
     Ok(best_builds)
 }
 
+/// Returns the average score of the best builds generated with the given settings
+/// (`settings.weights` must be normalized and `settings.n_items` is the number of items the score is computed at).
+fn avg_best_builds_score(
+    champ_properties: &'static UnitProperties,
+    settings: &BuildsGenerationSettings,
+) -> Result<f32, String> {
+    let n_items: usize = settings.n_items;
+    let mut best_builds: Vec<BuildContainer> =
+        find_best_builds(champ_properties, settings, &mut SilentObserver)?;
+    sort_builds_by_score(&mut best_builds, settings.weights);
+
+    let max_golds: f32 = best_builds
+        .iter()
+        .map(|build| build.golds[n_items])
+        .max_by(|a, b| a.partial_cmp(b).expect("Failed to compare floats"))
+        .unwrap_or(STARTING_GOLDS);
+    let n_to_take: usize = usize::min(5, best_builds.len()); //in case too few generated builds
+
+    #[allow(clippy::cast_precision_loss)] //`n_to_take` is well within f32 precision range
+    Ok((best_builds
+        .iter()
+        .take(n_to_take)
+        .map(|container| {
+            container._get_avg_score_with_normalized_weights(n_items, max_golds, settings.weights)
+        })
+        .sum::<f32>())
+        / (n_to_take as f32))
+}
+
 /// Returns a `Vec<(&'static RuneKeystone, f32)>` containing the best runes keystones at the requested number of items with their corresponding score.
 /// The keystones are in order (first element == keystone with the best score).
 pub fn find_best_runes_keystones(
@@ -1315,32 +2063,8 @@ pub fn find_best_runes_keystones(
 
     let mut best_keystones: Vec<(&'static RuneKeystone, f32)> = Vec::new();
     for &keystone in runes_data::ALL_RUNES_KEYSTONES.iter() {
-        test_settings.runes_page.keystone = keystone;
-        let mut best_builds: Vec<BuildContainer> =
-            find_best_builds(champ_properties, &test_settings, true)?;
-        sort_builds_by_score(&mut best_builds, test_settings.weights);
-
-        let max_golds: f32 = best_builds
-            .iter()
-            .map(|build| build.golds[n_items])
-            .max_by(|a, b| a.partial_cmp(b).expect("Failed to compare floats"))
-            .unwrap_or(STARTING_GOLDS);
-        let n_to_take: usize = usize::min(5, best_builds.len()); //in case too few generated builds
-
-        #[allow(clippy::cast_precision_loss)] //`n_to_take` is well within f32 precision range
-        let avg_score: f32 = (best_builds
-            .iter()
-            .take(n_to_take)
-            .map(|container| {
-                container._get_avg_score_with_normalized_weights(
-                    n_items,
-                    max_golds,
-                    test_settings.weights,
-                )
-            })
-            .sum::<f32>())
-            / (n_to_take as f32);
-
+        test_settings.runes_page.set_keystone(keystone);
+        let avg_score: f32 = avg_best_builds_score(champ_properties, &test_settings)?;
         best_keystones.push((keystone, avg_score));
     }
     //sort in reverse order
@@ -1349,6 +2073,564 @@ pub fn find_best_runes_keystones(
     Ok(best_keystones)
 }
 
+/// Returns every runes page (every runes keystone with every combination of runes shards) with its score, sorted from best to worst.
+/// The score of a runes page is the average score of the best builds generated with it (like in `find_best_runes_keystones`),
+/// averaged over the requested numbers of items. The runes page of the settings is always part of the results.
+/// The minor runes of the settings runes page are kept, adapted to the tree of each keystone (see `RunesPage::set_keystone`).
+pub fn find_best_runes_pages(
+    champ_properties: &'static UnitProperties,
+    settings: &BuildsGenerationSettings,
+    items_counts: &[usize],
+) -> Result<Vec<(RunesPage, f32)>, String> {
+    //sanity check
+    if items_counts.is_empty() {
+        return Err("At least one number of items is needed to compare runes pages".to_string());
+    }
+
+    let mut runes_pages: Vec<RunesPage> = Vec::new();
+    for &keystone in runes_data::ALL_RUNES_KEYSTONES.iter() {
+        let mut runes_page: RunesPage = settings.runes_page;
+        runes_page.set_keystone(keystone);
+        for shard1 in RuneShard::ALL {
+            for shard2 in RuneShard::ALL {
+                for shard3 in RuneShard::ALL {
+                    runes_pages.push(RunesPage {
+                        shard1,
+                        shard2,
+                        shard3,
+                        ..runes_page
+                    });
+                }
+            }
+        }
+    }
+    if !runes_pages.contains(&settings.runes_page) {
+        runes_pages.push(settings.runes_page); //e.g. empty keystone
+    }
+
+    let mut test_settings: BuildsGenerationSettings = settings.clone();
+    test_settings.weights = get_normalized_weights(test_settings.weights);
+
+    let mut best_runes_pages: Vec<(RunesPage, f32)> = Vec::with_capacity(runes_pages.len());
+    for runes_page in runes_pages {
+        test_settings.runes_page = runes_page;
+        let mut tot_score: f32 = 0.;
+        for &n_items in items_counts {
+            test_settings.n_items = n_items;
+            tot_score += avg_best_builds_score(champ_properties, &test_settings)?;
+        }
+        #[allow(clippy::cast_precision_loss)] //number of items counts is small
+        best_runes_pages.push((runes_page, tot_score / items_counts.len() as f32));
+    }
+    //sort in reverse order
+    best_runes_pages
+        .sort_unstable_by(|p1, p2| (p2.1).partial_cmp(&p1.1).expect("Failed to compare floats"));
+    Ok(best_runes_pages)
+}
+
+/// Returns every pair of summoner spells with its score, sorted from best to worst.
+/// The score of a pair is the average score of the best builds generated with it (like in `find_best_runes_pages`),
+/// averaged over the requested numbers of items. The summoner spells of the settings are always part of the results.
+pub fn find_best_summoner_spells(
+    champ_properties: &'static UnitProperties,
+    settings: &BuildsGenerationSettings,
+    items_counts: &[usize],
+) -> Result<Vec<(SummonerSpells, f32)>, String> {
+    //sanity check
+    if items_counts.is_empty() {
+        return Err(
+            "At least one number of items is needed to compare summoner spells".to_string(),
+        );
+    }
+
+    let mut summoner_spells_pairs: Vec<SummonerSpells> = SummonerSpells::all_pairs();
+    let [spell1, spell2] = settings.summoner_spells.spells;
+    if let Some(pair) = summoner_spells_pairs
+        .iter_mut()
+        .find(|pair| pair.spells == [spell2, spell1])
+    {
+        *pair = settings.summoner_spells; //keep the order of the settings
+    } else if !summoner_spells_pairs.contains(&settings.summoner_spells) {
+        summoner_spells_pairs.push(settings.summoner_spells); //e.g. empty summoner spell
+    }
+
+    let mut test_settings: BuildsGenerationSettings = settings.clone();
+    test_settings.weights = get_normalized_weights(test_settings.weights);
+
+    let mut best_summoner_spells: Vec<(SummonerSpells, f32)> =
+        Vec::with_capacity(summoner_spells_pairs.len());
+    for summoner_spells in summoner_spells_pairs {
+        test_settings.summoner_spells = summoner_spells;
+        let mut tot_score: f32 = 0.;
+        for &n_items in items_counts {
+            test_settings.n_items = n_items;
+            tot_score += avg_best_builds_score(champ_properties, &test_settings)?;
+        }
+        #[allow(clippy::cast_precision_loss)] //number of items counts is small
+        best_summoner_spells.push((summoner_spells, tot_score / items_counts.len() as f32));
+    }
+    //sort in reverse order
+    best_summoner_spells
+        .sort_unstable_by(|s1, s2| (s2.1).partial_cmp(&s1.1).expect("Failed to compare floats"));
+    Ok(best_summoner_spells)
+}
+
+/// Returns every max order (see `MaxOrder`) with the average score of the given build when using it, sorted from best to worst.
+/// The build is scored like during the builds generation (same fight simulations and score formula, at each item slot of the build),
+/// the skill order of the settings is ignored.
+pub fn find_best_skill_orders(
+    champ_properties: &'static UnitProperties,
+    settings: &BuildsGenerationSettings,
+    build: &Build,
+) -> Result<Vec<(MaxOrder, f32)>, String> {
+    //check input arguments
+    settings.check_settings(champ_properties)?;
+    let champ_properties: &'static UnitProperties = settings
+        .game_data
+        .get_champion(champ_properties)
+        .expect("Champion should be in the settings game data after checking settings");
+    if *champ_properties == Unit::APHELIOS_PROPERTIES {
+        return Err(format!(
+            "{} skill order cannot be changed (his abilities are not lvled up like other champions)",
+            champ_properties.name
+        ));
+    }
+    build.check_validity()?;
+    let items: Vec<&'static Item> = build
+        .iter()
+        .copied()
+        .filter(|item| **item != Item::NULL_ITEM)
+        .collect();
+    if items.is_empty() {
+        return Err("Build must have at least 1 item to compare skill orders".to_string());
+    }
+    let n_items: usize = items.len();
+
+    let mut test_settings: BuildsGenerationSettings = settings.clone();
+    let normalized_weights: (f32, f32, f32, f32) = get_normalized_weights(settings.weights);
+    let phys_dmg_received_percent: f32 = settings.effective_phys_dmg_received_percent();
+
+    let mut best_skill_orders: Vec<(MaxOrder, f32)> = Vec::new();
+    for max_order in MaxOrder::all() {
+        test_settings.skill_order = Some(max_order);
+        let mut units: SimulationUnits = SimulationUnits::new(
+            champ_properties,
+            &test_settings,
+            phys_dmg_received_percent,
+            BUILDS_GENERATION_MIN_LVL,
+        )?;
+
+        //simulate the build at each item slot (like during the builds generation)
+        let mut container: BuildContainer = BuildContainer {
+            build: Build::default(),
+            cum_utils: enum_set!(),
+            golds: [STARTING_GOLDS; MAX_UNIT_ITEMS + 1],
+            dps: [0.; MAX_UNIT_ITEMS + 1],
+            def: [0.; MAX_UNIT_ITEMS + 1],
+            ms: [0.; MAX_UNIT_ITEMS + 1],
+            ttk: [0.; MAX_UNIT_ITEMS + 1],
+        };
+        for item_slot in 0..=n_items {
+            let lvl: u8 = if item_slot == 0 {
+                BUILDS_GENERATION_MIN_LVL //lvl for the empty build scores
+            } else {
+                lvl_from_number_of_items(item_slot, settings)
+            };
+            let targets_stats: Vec<(UnitStats, f32)> = units.set_lvl(lvl);
+            container.build[..item_slot].copy_from_slice(&items[..item_slot]);
+            let scores: ParetoSpacePoint = ParetoSpacePoint::from_fight_simulation(
+                &container.build,
+                &mut units.champ,
+                &targets_stats,
+                phys_dmg_received_percent,
+                &test_settings,
+            );
+            if item_slot != 0 {
+                container.golds[item_slot] = scores.golds;
+            }
+            container.dps[item_slot] = scores.dps;
+            container.def[item_slot] = scores.def;
+            container.ms[item_slot] = scores.ms;
+            container.ttk[item_slot] = scores.ttk;
+        }
+
+        let avg_score: f32 = container._get_avg_score_with_normalized_weights(
+            n_items,
+            container.golds[n_items],
+            normalized_weights,
+        );
+        best_skill_orders.push((max_order, avg_score));
+    }
+    //sort in reverse order
+    best_skill_orders
+        .sort_unstable_by(|o1, o2| (o2.1).partial_cmp(&o1.1).expect("Failed to compare floats"));
+    Ok(best_skill_orders)
+}
+
+/// Number of purchase paths kept after each purchase when searching for the best purchase path.
+const PURCHASE_PATH_BEAM_WIDTH: usize = 32;
+
+/// Items bought over the course of a game, returned by `find_best_purchase_path`.
+#[derive(Debug, Clone)]
+pub struct PurchasePath {
+    /// Game time (in minutes) of each purchase: the start of the game, then each recall.
+    pub times: Vec<f32>,
+    /// Items bought at each purchase time, in purchase order (may be empty if nothing is bought).
+    pub purchases: Vec<Vec<&'static Item>>,
+    /// Inventory held after each purchase (until the next one).
+    pub inventories: Vec<Build>,
+    /// Golds left unspent after each purchase.
+    pub unspent_golds: Vec<f32>,
+    /// Score of each inventory (at the lvl of the corresponding game time).
+    pub scores: Vec<f32>,
+    /// Average score over the game time, until the end of the timeline.
+    pub avg_score: f32,
+}
+
+/// Purchase path being built during the search of the best purchase path.
+#[derive(Debug, Clone)]
+struct PurchasePathNode {
+    inventory: Build,
+    purchases: Vec<Vec<&'static Item>>,
+    inventories: Vec<Build>,
+    scores: Vec<f32>,
+    /// Integral of the score over the game time, up to the next purchase time.
+    cum_score: f32,
+}
+
+/// Returns the total amount of golds earned at the given game time (in minutes) according to the given economy profile.
+#[inline]
+#[must_use]
+fn golds_at_game_time(economy: &EconomyProfile, game_time: f32) -> f32 {
+    STARTING_GOLDS + economy.golds_per_min() * game_time
+}
+
+/// Returns the inventory after buying the given item, the components of its recipe held in the inventory
+/// being used for the recipe (like `Build::upgrade_cost`). Returns None if there is no room left for the item.
+#[must_use]
+fn inventory_after_purchase(inventory: &Build, item: &'static Item) -> Option<Build> {
+    let mut items: Vec<&'static Item> = inventory
+        .iter()
+        .copied()
+        .filter(|held_item| **held_item != Item::NULL_ITEM)
+        .collect();
+    for component in item.components() {
+        if let Some(idx) = items.iter().position(|held_item| held_item == component) {
+            items.remove(idx);
+        }
+    }
+    if items.len() >= MAX_UNIT_ITEMS {
+        return None;
+    }
+    items.push(item);
+
+    let mut new_inventory: Build = Build::default();
+    new_inventory[..items.len()].copy_from_slice(&items);
+    Some(new_inventory)
+}
+
+/// Returns the items that are worth buying on top of the given inventory to progress towards the target items:
+/// the target items not held yet and the components of their recipes that are not held yet.
+#[must_use]
+fn purchase_candidates(inventory: &Build, target_items: &[&'static Item]) -> Vec<&'static Item> {
+    let remaining_items: Vec<&'static Item> = target_items
+        .iter()
+        .filter(|item| !inventory.contains(item))
+        .copied()
+        .collect();
+
+    let mut needed_components: Vec<&'static Item> = remaining_items
+        .iter()
+        .flat_map(|item| item.components())
+        .copied()
+        .collect();
+    for held_component in inventory.iter().filter(|item| item.is_component()) {
+        if let Some(idx) = needed_components
+            .iter()
+            .position(|component| component == held_component)
+        {
+            needed_components.swap_remove(idx);
+        }
+    }
+    needed_components.sort_unstable_by_key(|component| component.full_name);
+    needed_components.dedup();
+
+    remaining_items
+        .into_iter()
+        .chain(needed_components)
+        .collect()
+}
+
+/// Adds every inventory that can be reached from the given inventory with the available golds
+/// (and the items bought to reach it) to `reachable`, skipping inventories already in `seen`.
+fn add_reachable_inventories(
+    inventory: &Build,
+    bought: &mut Vec<&'static Item>,
+    total_golds: f32,
+    target_items: &[&'static Item],
+    seen: &mut FxHashSet<BuildHash>,
+    reachable: &mut Vec<(Build, Vec<&'static Item>)>,
+) {
+    //golds spent only depend on the inventory since components are refunded in the cost of the items using them
+    let golds: f32 = total_golds - inventory.cost();
+    for item in purchase_candidates(inventory, target_items) {
+        if inventory.upgrade_cost(item) > golds {
+            continue;
+        }
+        let Some(new_inventory) = inventory_after_purchase(inventory, item) else {
+            continue;
+        };
+        if seen.insert(new_inventory.get_hash()) {
+            bought.push(item);
+            reachable.push((new_inventory, bought.clone()));
+            add_reachable_inventories(
+                &new_inventory,
+                bought,
+                total_golds,
+                target_items,
+                seen,
+                reachable,
+            );
+            bought.pop();
+        }
+    }
+}
+
+/// Finds the components and finished items to buy at the start of the game and at each recall
+/// to progress towards the given build, maximizing the score averaged over the game time.
+///
+/// The golds available at each purchase follow the golds income of the settings economy profile (starting from `STARTING_GOLDS`),
+/// the champion lvl follows the experience from farming at the same rate.
+/// Unlike `gold_weighted_average` that interpolates scores between item slots,
+/// inventories are held as is between purchases, so the score over time is a step function.
+///
+/// `recall_times` are the game times (in minutes) of the recalls, in strictly increasing order.
+/// The timeline ends after the last recall, one average interval between purchases later.
+/// Finished items of the build can be bought in any order, their recipes components
+/// take inventory slots until they are used.
+pub fn find_best_purchase_path(
+    champ_properties: &'static UnitProperties,
+    settings: &BuildsGenerationSettings,
+    build: &Build,
+    recall_times: &[f32],
+) -> Result<PurchasePath, String> {
+    //check input arguments
+    settings.check_settings(champ_properties)?;
+    let champ_properties: &'static UnitProperties = settings
+        .game_data
+        .get_champion(champ_properties)
+        .expect("Champion should be in the settings game data after checking settings");
+    build.check_validity()?;
+    let target_items: Vec<&'static Item> = build
+        .iter()
+        .copied()
+        .filter(|item| **item != Item::NULL_ITEM)
+        .collect();
+    if target_items.is_empty() {
+        return Err("Build must have at least 1 item to find a purchase path".to_string());
+    }
+    if let Some(item) = target_items.iter().find(|item| !item.is_finished()) {
+        return Err(format!(
+            "Build must only contain finished items to find a purchase path (got '{}')",
+            item.full_name
+        ));
+    }
+    if recall_times.is_empty() {
+        return Err("At least 1 recall time is needed to find a purchase path".to_string());
+    }
+    if recall_times
+        .iter()
+        .any(|recall_time| !recall_time.is_finite() || *recall_time <= 0.)
+    {
+        return Err("Recall times must be strictly positive".to_string());
+    }
+    if recall_times.windows(2).any(|window| window[0] >= window[1]) {
+        return Err("Recall times must be in strictly increasing order".to_string());
+    }
+
+    //purchase times are the start of the game then each recall
+    let mut times: Vec<f32> = Vec::with_capacity(recall_times.len() + 1);
+    times.push(0.);
+    times.extend_from_slice(recall_times);
+    let last_time: f32 = recall_times[recall_times.len() - 1];
+    #[allow(clippy::cast_precision_loss)] //number of recalls is well within f32 precision range
+    let end_time: f32 = last_time + last_time / (recall_times.len() as f32);
+
+    let normalized_weights: (f32, f32, f32, f32) = get_normalized_weights(settings.weights);
+    let phys_dmg_received_percent: f32 = settings.effective_phys_dmg_received_percent();
+    let mut units: SimulationUnits = SimulationUnits::new(
+        champ_properties,
+        settings,
+        phys_dmg_received_percent,
+        MIN_UNIT_LVL,
+    )?;
+
+    let mut nodes: Vec<PurchasePathNode> = vec![PurchasePathNode {
+        inventory: Build::default(),
+        purchases: Vec::with_capacity(times.len()),
+        inventories: Vec::with_capacity(times.len()),
+        scores: Vec::with_capacity(times.len()),
+        cum_score: 0.,
+    }];
+    for (idx, &time) in times.iter().enumerate() {
+        let total_golds: f32 = golds_at_game_time(&settings.economy, time);
+        let duration: f32 = times.get(idx + 1).unwrap_or(&end_time) - time;
+        let targets_stats: Vec<(UnitStats, f32)> =
+            units.set_lvl(lvl_from_game_time(&settings.economy, time));
+
+        //inventories reachable at this purchase time, only keeping the best path to each of them
+        let mut best_paths: FxHashMap<BuildHash, PurchasePathNode> = FxHashMap::default();
+        let mut inventories_scores: FxHashMap<BuildHash, f32> = FxHashMap::default();
+        for node in nodes {
+            let mut seen: FxHashSet<BuildHash> = FxHashSet::default();
+            let mut reachable: Vec<(Build, Vec<&'static Item>)> = Vec::new();
+            seen.insert(node.inventory.get_hash());
+            reachable.push((node.inventory, Vec::new()));
+            add_reachable_inventories(
+                &node.inventory,
+                &mut Vec::new(),
+                total_golds,
+                &target_items,
+                &mut seen,
+                &mut reachable,
+            );
+
+            for (inventory, bought) in reachable {
+                let hash: BuildHash = inventory.get_hash();
+                let score: f32 = *inventories_scores.entry(hash).or_insert_with(|| {
+                    let scores: ParetoSpacePoint = ParetoSpacePoint::from_fight_simulation(
+                        &inventory,
+                        &mut units.champ,
+                        &targets_stats,
+                        phys_dmg_received_percent,
+                        settings,
+                    );
+                    score_formula_with_normalized_weights(
+                        scores.dps,
+                        scores.def,
+                        scores.ms,
+                        scores.ttk,
+                        normalized_weights,
+                    )
+                });
+                let cum_score: f32 = node.cum_score + score * duration;
+                if best_paths
+                    .get(&hash)
+                    .is_some_and(|best_path| best_path.cum_score >= cum_score)
+                {
+                    continue;
+                }
+                let mut new_node: PurchasePathNode = node.clone();
+                new_node.inventory = inventory;
+                new_node.purchases.push(bought);
+                new_node.inventories.push(inventory);
+                new_node.scores.push(score);
+                new_node.cum_score = cum_score;
+                best_paths.insert(hash, new_node);
+            }
+        }
+
+        nodes = best_paths.into_values().collect();
+        //sort in reverse order
+        nodes.sort_unstable_by(|n1, n2| {
+            (n2.cum_score)
+                .partial_cmp(&n1.cum_score)
+                .expect("Failed to compare floats")
+        });
+        nodes.truncate(PURCHASE_PATH_BEAM_WIDTH);
+    }
+
+    //nodes are never empty since the inventory without purchase is always reachable
+    let best_node: PurchasePathNode = nodes.swap_remove(0);
+    let unspent_golds: Vec<f32> = zip(&times, &best_node.inventories)
+        .map(|(time, inventory)| golds_at_game_time(&settings.economy, *time) - inventory.cost())
+        .collect();
+    Ok(PurchasePath {
+        times,
+        purchases: best_node.purchases,
+        inventories: best_node.inventories,
+        unspent_golds,
+        scores: best_node.scores,
+        avg_score: best_node.cum_score / end_time,
+    })
+}
+
+/// Compares every starting items choice at the given lvl (below the lvls of the builds generation),
+/// returns them with their score, from best to worst.
+///
+/// A starting items choice is a starter item, with as many health potions as the remaining starting golds allow.
+pub fn find_best_starting_items(
+    champ_properties: &'static UnitProperties,
+    settings: &BuildsGenerationSettings,
+    lvl: u8,
+) -> Result<Vec<(Build, f32)>, String> {
+    //check input arguments
+    settings.check_settings(champ_properties)?;
+    let champ_properties: &'static UnitProperties = settings
+        .game_data
+        .get_champion(champ_properties)
+        .expect("Champion should be in the settings game data after checking settings");
+    if !(MIN_UNIT_LVL..BUILDS_GENERATION_MIN_LVL).contains(&lvl) {
+        return Err(format!(
+            "Lvl to compare starting items must be between {MIN_UNIT_LVL} and {} (got {lvl})",
+            BUILDS_GENERATION_MIN_LVL - 1
+        ));
+    }
+
+    let items_database: &ItemsDatabase = settings.game_data.items_database;
+    let health_potion: Option<&'static Item> = items_database.get_item(&Item::HEALTH_POTION);
+    let mut starting_builds: Vec<Build> = Vec::with_capacity(items_database.starter_items().len());
+    for &starter_item in items_database.starter_items() {
+        let mut build: Build = Build::default();
+        build[0] = starter_item;
+        if let Some(health_potion) = health_potion {
+            let mut golds: f32 = STARTING_GOLDS - starter_item.cost;
+            for item_slot in 1..MAX_UNIT_ITEMS {
+                if health_potion.cost > golds {
+                    break;
+                }
+                build[item_slot] = health_potion;
+                golds -= health_potion.cost;
+            }
+        }
+        starting_builds.push(build);
+    }
+    if starting_builds.is_empty() {
+        return Err("Items database has no starter item to compare".to_string());
+    }
+
+    let normalized_weights: (f32, f32, f32, f32) = get_normalized_weights(settings.weights);
+    let phys_dmg_received_percent: f32 = settings.effective_phys_dmg_received_percent();
+    let mut units: SimulationUnits =
+        SimulationUnits::new(champ_properties, settings, phys_dmg_received_percent, lvl)?;
+    let targets_stats: Vec<(UnitStats, f32)> = units.set_lvl(lvl);
+
+    let mut best_starting_items: Vec<(Build, f32)> = starting_builds
+        .into_iter()
+        .map(|build| {
+            let scores: ParetoSpacePoint = ParetoSpacePoint::from_fight_simulation(
+                &build,
+                &mut units.champ,
+                &targets_stats,
+                phys_dmg_received_percent,
+                settings,
+            );
+            let score: f32 = score_formula_with_normalized_weights(
+                scores.dps,
+                scores.def,
+                scores.ms,
+                scores.ttk,
+                normalized_weights,
+            );
+            (build, score)
+        })
+        .collect();
+    //sort in reverse order
+    best_starting_items
+        .sort_unstable_by(|b1, b2| (b2.1).partial_cmp(&b1.1).expect("Failed to compare floats"));
+    Ok(best_starting_items)
+}
+
 #[cfg(test)]
 mod tests {
     #[cfg(test)]
@@ -1368,4 +2650,369 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    pub fn test_lvl_from_economy() {
+        let mut settings: BuildsGenerationSettings = BuildsGenerationSettings::default();
+        let default_lvls: Vec<u8> = (1..=MAX_UNIT_ITEMS)
+            .map(|n_items| lvl_from_number_of_items(n_items, &settings))
+            .collect();
+        assert!(default_lvls.windows(2).all(|window| window[0] <= window[1]));
+
+        for name in ["low-farm", "shared-xp"] {
+            settings.economy = EconomyProfile::from_name(name).unwrap();
+            for (n_items, default_lvl) in (1..=MAX_UNIT_ITEMS).zip(&default_lvls) {
+                assert!(lvl_from_number_of_items(n_items, &settings) <= *default_lvl);
+            }
+        }
+        assert!(
+            lvl_from_number_of_items(3, &settings) < default_lvls[2],
+            "shared xp should lower the lvl at 3 items"
+        );
+    }
+
+    /// Observer recording every event it receives, in order.
+    #[derive(Debug, Default)]
+    struct RecordingObserver {
+        events: Vec<ObserverEvent>,
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    enum ObserverEvent {
+        GenerationStarted(usize),
+        LayerStarted(usize),
+        BuildsSimulated(usize, usize),
+        BuildsPruned(usize, usize, usize, usize),
+        LayerFinished(usize),
+        GenerationFinished(usize),
+    }
+
+    impl BuildsGenerationObserver for RecordingObserver {
+        fn on_generation_started(&mut self, _champ_properties: &UnitProperties, n_items: usize) {
+            self.events.push(ObserverEvent::GenerationStarted(n_items));
+        }
+
+        fn on_layer_started(&mut self, item_slot: usize) {
+            self.events.push(ObserverEvent::LayerStarted(item_slot));
+        }
+
+        fn on_builds_simulated(&mut self, item_slot: usize, n_builds: usize) {
+            self.events
+                .push(ObserverEvent::BuildsSimulated(item_slot, n_builds));
+        }
+
+        fn on_builds_pruned(
+            &mut self,
+            item_slot: usize,
+            n_pruned_by_gold_value: usize,
+            n_pruned_by_pareto: usize,
+            n_remaining: usize,
+        ) {
+            self.events.push(ObserverEvent::BuildsPruned(
+                item_slot,
+                n_pruned_by_gold_value,
+                n_pruned_by_pareto,
+                n_remaining,
+            ));
+        }
+
+        fn on_layer_finished(&mut self, item_slot: usize) {
+            self.events.push(ObserverEvent::LayerFinished(item_slot));
+        }
+
+        fn on_generation_finished(&mut self, n_builds: usize) {
+            self.events
+                .push(ObserverEvent::GenerationFinished(n_builds));
+        }
+    }
+
+    #[test]
+    pub fn test_builds_generation_observer() {
+        let mut settings: BuildsGenerationSettings =
+            BuildsGenerationSettings::default_by_champion(&Unit::ASHE_PROPERTIES);
+        settings.n_items = 2;
+        settings.boots_slot = ItemSlot::None;
+        settings.legendary_items_pool = vec![
+            &Item::INFINITY_EDGE,
+            &Item::KRAKEN_SLAYER,
+            &Item::LORD_DOMINIKS_REGARDS,
+            &Item::RUNAANS_HURRICANE,
+        ];
+        let mut observer: RecordingObserver = RecordingObserver::default();
+        let builds: Vec<BuildContainer> =
+            find_best_builds(&Unit::ASHE_PROPERTIES, &settings, &mut observer)
+                .expect("Failed to generate builds");
+
+        //1 start event, 4 events per item slot and 1 finish event
+        let events: &[ObserverEvent] = &observer.events;
+        assert_eq!(events.len(), 2 + 4 * settings.n_items);
+        assert_eq!(
+            events[0],
+            ObserverEvent::GenerationStarted(settings.n_items)
+        );
+        let mut n_remaining_previous_layer: usize = 1; //empty build
+        for (layer, layer_events) in events[1..events.len() - 1].chunks(4).enumerate() {
+            let item_slot: usize = layer + 1;
+            assert_eq!(layer_events[0], ObserverEvent::LayerStarted(item_slot));
+            let ObserverEvent::BuildsSimulated(slot, n_simulated) = layer_events[1] else {
+                panic!("Expected builds simulated event, got {:?}", layer_events[1]);
+            };
+            assert_eq!(slot, item_slot);
+            assert!(n_simulated >= n_remaining_previous_layer);
+            let ObserverEvent::BuildsPruned(slot, n_gold_value, n_pareto, n_remaining) =
+                layer_events[2]
+            else {
+                panic!("Expected builds pruned event, got {:?}", layer_events[2]);
+            };
+            assert_eq!(slot, item_slot);
+            assert_eq!(n_gold_value + n_pareto + n_remaining, n_simulated);
+            assert!(n_remaining > 0);
+            assert_eq!(layer_events[3], ObserverEvent::LayerFinished(item_slot));
+            n_remaining_previous_layer = n_remaining;
+        }
+        assert_eq!(
+            events[events.len() - 1],
+            ObserverEvent::GenerationFinished(builds.len())
+        );
+        assert_eq!(builds.len(), n_remaining_previous_layer);
+    }
+
+    #[test]
+    pub fn test_score_formula_instant_kill() {
+        let weights: (f32, f32, f32, f32) = get_normalized_weights((1., 1., 1., 1.));
+        let instant_kill_score: f32 =
+            score_formula_with_normalized_weights(1000., 2000., 400., 0., weights);
+        assert!(instant_kill_score.is_finite() && instant_kill_score > 0.);
+        assert!(
+            instant_kill_score
+                >= score_formula_with_normalized_weights(1000., 2000., 400., 5., weights)
+        );
+        assert!(
+            gold_weighted_average(&[instant_kill_score, 1.], &[1000., 2000.], 3000.).is_finite()
+        );
+    }
+
+    #[test]
+    pub fn test_best_runes_pages() {
+        let mut settings: BuildsGenerationSettings =
+            BuildsGenerationSettings::default_by_champion(&Unit::ASHE_PROPERTIES);
+        settings.boots_slot = ItemSlot::None;
+        settings.legendary_items_pool = vec![&Item::INFINITY_EDGE, &Item::KRAKEN_SLAYER];
+        let best_runes_pages: Vec<(RunesPage, f32)> =
+            find_best_runes_pages(&Unit::ASHE_PROPERTIES, &settings, &[1])
+                .expect("Failed to compare runes pages");
+
+        //every keystone with every combination of runes shards
+        let n_shards_combinations: usize = RuneShard::ALL.len().pow(3);
+        assert_eq!(
+            best_runes_pages.len(),
+            runes_data::ALL_RUNES_KEYSTONES.len() * n_shards_combinations
+        );
+        for keystone in runes_data::ALL_RUNES_KEYSTONES {
+            for shards in RuneShard::ALL
+                .into_iter()
+                .flat_map(|shard1| RuneShard::ALL.map(|shard2| (shard1, shard2)))
+                .flat_map(|(shard1, shard2)| RuneShard::ALL.map(|shard3| (shard1, shard2, shard3)))
+            {
+                assert!(best_runes_pages.iter().any(|(runes_page, _)| {
+                    runes_page.keystone == keystone
+                        && (runes_page.shard1, runes_page.shard2, runes_page.shard3) == shards
+                }));
+            }
+        }
+        assert!(best_runes_pages
+            .iter()
+            .any(|(runes_page, _)| *runes_page == settings.runes_page));
+        assert!(best_runes_pages
+            .windows(2)
+            .all(|window| window[0].1 >= window[1].1));
+
+        //the best runes page has the best score of every runes page scored individually
+        let mut test_settings: BuildsGenerationSettings = settings.clone();
+        test_settings.weights = get_normalized_weights(test_settings.weights);
+        test_settings.n_items = 1;
+        let exhaustive_best_score: f32 = best_runes_pages
+            .iter()
+            .map(|&(runes_page, _)| {
+                test_settings.runes_page = runes_page;
+                avg_best_builds_score(&Unit::ASHE_PROPERTIES, &test_settings)
+                    .expect("Failed to score runes page")
+            })
+            .fold(f32::MIN, f32::max);
+        assert_eq!(best_runes_pages[0].1, exhaustive_best_score);
+    }
+
+    #[test]
+    pub fn test_target_definition_parse() {
+        let game_data: &GameDataSet = BuildsGenerationSettings::default().game_data;
+        let parse = |input: &str| -> Result<TargetDefinition, String> {
+            TargetDefinition::parse(input, game_data)
+                .and_then(|target| target.check_validity(game_data).map(|()| target))
+        };
+
+        //dummies, by full name or first word
+        let target: TargetDefinition = parse(" Tank ").expect("Failed to parse valid target");
+        assert_eq!(target.kind(), TargetKind::Dummy);
+        assert_eq!(*target.properties, TANKY_OPTIMIZER_DUMMY_PROPERTIES);
+        let target: TargetDefinition =
+            parse("squishy (e.g. Ahri)").expect("Failed to parse valid target");
+        assert_eq!(*target.properties, SQUISHY_OPTIMIZER_DUMMY_PROPERTIES);
+
+        //champions, with or without items
+        let target: TargetDefinition = parse("Varus").expect("Failed to parse valid target");
+        assert_eq!(target.kind(), TargetKind::Champion);
+        assert!(target.build.iter().all(|&item| *item == Item::NULL_ITEM));
+        let target: TargetDefinition =
+            parse("varus: IE + Berserkers").expect("Failed to parse valid target");
+        assert_eq!(target.kind(), TargetKind::Champion);
+        assert_eq!(target.properties.name, "Varus");
+        assert_eq!(
+            target
+                .build
+                .iter()
+                .filter(|&&item| *item != Item::NULL_ITEM)
+                .count(),
+            2
+        );
+
+        //custom targets, with or without growth stats
+        let target: TargetDefinition =
+            parse("custom:2000/100/80").expect("Failed to parse valid target");
+        assert_eq!(target.kind(), TargetKind::Custom);
+        assert_eq!(
+            (
+                target.properties.base_stats.hp,
+                target.properties.base_stats.armor,
+                target.properties.base_stats.mr
+            ),
+            (2000., 100., 80.)
+        );
+        assert_eq!(target.properties.growth_stats.hp, 0.);
+        let target: TargetDefinition =
+            parse("Custom: 600/30/30/100/4/2").expect("Failed to parse valid target");
+        assert_eq!(
+            (
+                target.properties.growth_stats.hp,
+                target.properties.growth_stats.armor,
+                target.properties.growth_stats.mr
+            ),
+            (100., 4., 2.)
+        );
+
+        //the same custom stats reuse the same properties
+        let other_target: TargetDefinition =
+            parse("custom:600/30/30/100/4/2").expect("Failed to parse valid target");
+        assert!(core::ptr::eq(target.properties, other_target.properties));
+
+        //the kind doesn't depend on the name of the target
+        let properties: &'static UnitProperties = Box::leak(Box::new(UnitProperties {
+            name: CUSTOM_TARGET_NAME,
+            ..Unit::VARUS_PROPERTIES
+        }));
+        let target: TargetDefinition = TargetDefinition::champion(
+            properties,
+            Build::default(),
+            properties.defaults.runes_pages,
+        );
+        assert_eq!(target.kind(), TargetKind::Champion);
+
+        //invalid targets
+        assert!(parse("tank:IE").is_err()); //items on a dummy
+        assert!(parse("varus:IE+not an item").is_err()); //unknown item
+        assert!(parse("varus:IE+IE+IE+IE+IE+IE+IE").is_err()); //too many items
+        assert!(parse("not a champion").is_err());
+        assert!(parse("custom:2000/100").is_err()); //wrong number of values
+        assert!(parse("custom:2000/100/80/10").is_err());
+        assert!(parse("custom").is_err());
+        assert!(parse("custom:2000/a lot/80").is_err());
+        assert!(parse("custom:0/100/80").is_err()); //non-positive hp
+        assert!(parse("custom:-500/100/80").is_err());
+    }
+
+    #[test]
+    pub fn test_enemy_team_phys_dmg_received_percent() {
+        let mut settings: BuildsGenerationSettings = BuildsGenerationSettings {
+            enemy_team: vec![EnemyTeamMember {
+                target: TargetDefinition::dummy(&TANKY_OPTIMIZER_DUMMY_PROPERTIES),
+                focus_weight: 1.,
+            }],
+            ..Default::default()
+        };
+        //no enemy champion, uses the manual percentage
+        assert_eq!(
+            settings.effective_phys_dmg_received_percent(),
+            settings.phys_dmg_received_percent
+        );
+
+        settings.enemy_team.push(EnemyTeamMember {
+            target: TargetDefinition::parse("varus:IE+Berserkers", settings.game_data)
+                .expect("Failed to parse valid target"),
+            focus_weight: 1.,
+        });
+        let phys_dmg_received_percent: f32 = settings.effective_phys_dmg_received_percent();
+        assert!(phys_dmg_received_percent > 0.5 && phys_dmg_received_percent <= 1.);
+    }
+
+    #[test]
+    pub fn test_starting_items() {
+        let settings: BuildsGenerationSettings =
+            BuildsGenerationSettings::default_by_champion(&Unit::ASHE_PROPERTIES);
+        for lvl in MIN_UNIT_LVL..BUILDS_GENERATION_MIN_LVL {
+            let best_starting_items: Vec<(Build, f32)> =
+                find_best_starting_items(&Unit::ASHE_PROPERTIES, &settings, lvl)
+                    .expect("Failed to compare starting items");
+            assert_eq!(best_starting_items.len(), ALL_STARTER_ITEMS.len());
+            for (build, score) in &best_starting_items {
+                assert!(build.check_validity().is_ok());
+                assert!(build.cost() <= STARTING_GOLDS);
+                assert!(*score > 0.);
+            }
+        }
+        assert!(find_best_starting_items(
+            &Unit::ASHE_PROPERTIES,
+            &settings,
+            BUILDS_GENERATION_MIN_LVL
+        )
+        .is_err());
+    }
+
+    #[test]
+    pub fn test_purchase_path() {
+        let settings: BuildsGenerationSettings =
+            BuildsGenerationSettings::default_by_champion(&Unit::ASHE_PROPERTIES);
+        let mut build: Build = Build::default();
+        build[0] = &Item::YUN_TAL_WILDARROWS;
+        build[1] = &Item::INFINITY_EDGE;
+        let recall_times: [f32; 3] = [5., 10., 20.];
+
+        let purchase_path: PurchasePath =
+            find_best_purchase_path(&Unit::ASHE_PROPERTIES, &settings, &build, &recall_times)
+                .expect("Failed to find a purchase path");
+        assert_eq!(purchase_path.times, [0., 5., 10., 20.]);
+        assert_eq!(purchase_path.inventories.len(), purchase_path.times.len());
+        for (inventory, unspent_golds) in
+            zip(&purchase_path.inventories, &purchase_path.unspent_golds)
+        {
+            assert!(*unspent_golds >= 0.);
+            //only finished items of the build and components of their recipes can be bought
+            assert!(inventory.iter().all(|item| **item == Item::NULL_ITEM
+                || build.contains(item)
+                || build
+                    .iter()
+                    .any(|build_item| build_item.components().contains(item))));
+        }
+        //golds at 20min are enough to complete both items
+        let last_inventory: &Build =
+            &purchase_path.inventories[purchase_path.inventories.len() - 1];
+        assert!(build[..2].iter().all(|item| last_inventory.contains(item)));
+
+        assert!(
+            find_best_purchase_path(&Unit::ASHE_PROPERTIES, &settings, &build, &[10., 5.]).is_err()
+        );
+        build[2] = &Item::BF_SWORD;
+        assert!(
+            find_best_purchase_path(&Unit::ASHE_PROPERTIES, &settings, &build, &recall_times)
+                .is_err()
+        );
+    }
 }
@@ -0,0 +1,948 @@
+use super::*;
+
+use std::fs;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+/// Exit code returned when the builds generation failed.
+const GENERATION_FAILURE_EXIT_CODE: u8 = 1;
+/// Exit code returned when the results could not be exported.
+const EXPORT_FAILURE_EXIT_CODE: u8 = 3;
+/// Exit code returned when the command line arguments are invalid (unknown flag, invalid value, invalid settings, ...).
+const INVALID_ARGS_EXIT_CODE: u8 = 2;
+
+const ARGS_HELP_MSG: &str =
+    "Usage: lol_best_adc_build_finder [OPTIONS]\n\
+     Launches the interactive interface when no option is given.\n\
+     Otherwise, generates the best builds for the given champion with the given settings\n\
+     (unspecified settings are set to their default values for the champion).\n\
+     \n\
+     Options:\n  \
+     -c, --champion <NAME>           champion to find the best builds for (required if not in the preset)\n      \
+     --preset <PATH>                 load settings from a preset file (other options override it)\n      \
+     --save-preset <PATH>            save the resulting settings to a preset file\n      \
+     --patch <PATCH>                 game patch data to use (from data/patches/<PATCH>, default: compiled-in patch\n                                     \
+     or the patch of the preset)\n      \
+     --items-data <PATH>             load items from an items data file (default: data/items.toml if it exists)\n      \
+     --import-snapshot <DIR>         override champions/items values with a local Data Dragon snapshot\n                                     \
+     (directory containing champion.json and item.json) and print the differences\n      \
+     --write-items-data <PATH>       write the items data used to a file and exit (useful as a template)\n  \
+     -t, --target <TARGET>           target used to compute DPS: squishy, bruiser, tank, a champion with a build\n                                     \
+     (CHAMPION:ITEM+ITEM+..., e.g. varus:jaksho+randuins) or custom stats\n                                     \
+     (custom:HP/ARMOR/MR[/HP_PER_LVL/ARMOR_PER_LVL/MR_PER_LVL])\n  \
+     -s, --scenario <NUMBER>         fight scenario number (starts at 1)\n      \
+     --script <SCRIPT>               fight script used instead of the fight scenario, as a priority list of statements\n                                     \
+     separated by ';' (e.g. \"use actives; r once; q if ready; aa if ready; walk otherwise\")\n      \
+     --script-file <PATH>            read the fight script from a file (one statement per line or separated by ';')\n      \
+     --no-script                     use the fight scenario (e.g. to override a preset)\n  \
+     -d, --duration <SECONDS>        fight duration\n      \
+     --phys-dmg-received <PERCENT>   percentage of physical dmg received\n      \
+     --incoming-dmg <DPS,BURST[,T]>  receive dmg during the fight (pre-mitigation dmg per lvl: sustained dps, burst\n                                     \
+     dmg and burst time), defense is then the time survived instead of the effective hp\n      \
+     --true-dmg-received <PERCENT>   percentage of true dmg received (enables incoming dmg)\n      \
+     --grievous-wounds               reduce the heals received by grievous wounds (enables incoming dmg)\n      \
+     --no-incoming-dmg               do not receive dmg during the fight (e.g. to override a preset)\n      \
+     --target-flee <PERCENT>         percentage of time the target moves away from the champion (default: 0,\n                                     \
+     stationary target), the champion must then walk back in range to basic attack\n      \
+     --teamfight <TARGETS>           fight several enemy units instead of a single target (comma separated targets,\n                                     \
+     e.g. tank,bruiser,squishy), the time to kill is then the time to kill all of them\n      \
+     --no-teamfight                  fight a single target (e.g. to override a preset)\n      \
+     --enemy-team <ENEMIES>          optimize against an enemy team composition (up to 5 comma separated targets,\n                                     \
+     each with an optional focus weight, e.g. varus:IE+Berserkers@2,tank), DPS is scored against each enemy\n                                     \
+     and the percentage of physical dmg received is derived from the enemy champions\n      \
+     --no-enemy-team                 fight a single target (e.g. to override a preset)\n      \
+     --keystone <NAME>               rune keystone (full name or short name)\n      \
+     --primary-runes <R1,R2,R3>      minor runes of the keystone tree, one per row (full name or short name,\n                                     \
+     'none' for an empty slot, e.g. pom,alacrity,\"coup de grace\")\n      \
+     --secondary-runes <R1,R2>       minor runes of the secondary tree, from two different rows (e.g. tob,\"ultimate hunter\")\n      \
+     --shards <S1,S2,S3>             rune shards (left, middle or right for each)\n      \
+     --best-runes-page <N_ITEMS>     find the best runes page (keystone and shards) at the given numbers of items\n                                     \
+     (comma separated, e.g. 2,4) and use it for the builds generation\n      \
+     --summoner-spells <S1,S2>       summoner spells (full name or short name, 'none' for an empty slot,\n                                     \
+     e.g. flash,heal)\n      \
+     --best-summoner-spells <N_ITEMS>\n                                     \
+     find the best summoner spells at the given numbers of items (comma separated,\n                                     \
+     e.g. 2,4) and use them for the builds generation\n      \
+     --skill-order <ORDER>           order in which basic abilities are maxed (e.g. w>q>e), add ':late' to take\n                                     \
+     the first point of the last ability at lvl 5 instead of lvl 3 (e.g. q>w>e:late)\n      \
+     --default-skill-order           use the default skill order of the champion (e.g. to override a preset)\n      \
+     --compare-skill-orders          also print the score of the best build with each skill order\n      \
+     --compare-starting-items <LVL>  also print the score of each starting items choice (starter item and health\n                                     \
+     potions) at the given early lvl (1 to 5)\n      \
+     --purchase-path <MINUTES>       also print the best purchase path towards the best build for the given recall\n                                     \
+     times (comma separated game times in minutes, e.g. 4,9,14,20)\n      \
+     --economy <PROFILE>             golds and xp income of the player, used to estimate the champion lvl at each item\n                                     \
+     slot: default, low-farm (6 cs/min), high-farm (12 cs/min) or shared-xp (lane xp shared)\n      \
+     --cs-per-min <NUMBER>           cs/min of the player (overrides the value of the economy profile)\n      \
+     --time-between-fights <SECONDS>\n                                     \
+     average time between fights, used to weight effects with long cooldowns\n  \
+     -n, --items <NUMBER>            number of items per build\n  \
+     -m, --mandatory <SLOT=ITEM>     impose an item at the given slot (can be repeated or comma separated)\n      \
+     --boots-slot <SLOT>             boots slot (item slot, 'any' or 'none')\n      \
+     --supp-item-slot <SLOT>         support item slot (item slot, 'any' or 'none')\n      \
+     --allow-manaflow-first-item     allow manaflow items in first slot\n  \
+     -w, --weights <DPS,DEF,MS[,TTK]>\n                                     \
+     weights for DPS, defense, mobility and time to kill (default time to kill weight: 0)\n      \
+     --threshold <PERCENT>           search threshold percentage\n  \
+     -p, --print <NUMBER>            number of builds to print\n  \
+     -q, --quiet                     do not show the progress bar\n      \
+     --export-json <PATH>            export the results to a JSON file\n      \
+     --export-csv <PATH>             export the results to a CSV file\n  \
+     -h, --help                      show this help message\n\
+     \n\
+     Exit codes:\n  \
+     0 - success\n  \
+     1 - builds generation failed\n  \
+     2 - invalid arguments or settings\n  \
+     3 - failed to export the results";
+
+/// Builds generation request parsed from command line arguments.
+#[derive(Debug)]
+struct ArgsRequest {
+    champ_properties: &'static UnitProperties,
+    settings: BuildsGenerationSettings,
+    n_to_print: NonZeroUsize,
+    silent: bool,
+    runes_pages_items_counts: Option<Vec<usize>>, //if some, the best runes page is searched before the builds generation
+    summoner_spells_items_counts: Option<Vec<usize>>, //if some, the best summoner spells are searched before the builds generation (after the runes page)
+    compare_skill_orders: bool,
+    starting_items_lvl: Option<u8>, //if some, starting items are compared at this lvl after the builds generation
+    recall_times: Option<Vec<f32>>, //if some, the best purchase path towards the best build is searched after the builds generation
+    exports: Vec<(PathBuf, ExportFormat)>,
+}
+
+/// Result of command line arguments parsing.
+#[derive(Debug)]
+enum ParsedArgs {
+    Help,
+    WriteItemsData(&'static ItemsDatabase, PathBuf),
+    Request(Box<ArgsRequest>),
+}
+
+/// Generates the best builds according to the given command line arguments (without the program name),
+/// prints the results and returns the exit code of the program.
+pub fn launch_from_args(args: &[String]) -> ExitCode {
+    let mut request: ArgsRequest = match parse_args(args) {
+        Ok(ParsedArgs::Help) => {
+            println!("{ARGS_HELP_MSG}");
+            return ExitCode::SUCCESS;
+        }
+        Ok(ParsedArgs::WriteItemsData(items_database, path)) => {
+            if let Err(error_msg) = items_database.save(&path) {
+                eprintln!("Error: failed to write items data: {error_msg}");
+                return ExitCode::from(EXPORT_FAILURE_EXIT_CODE);
+            }
+            println!(
+                "{} items written to '{}'",
+                items_database.n_items(),
+                path.display()
+            );
+            return ExitCode::SUCCESS;
+        }
+        Ok(ParsedArgs::Request(request)) => *request,
+        Err(error_msg) => {
+            eprintln!("Error: {error_msg}\n(use --help to show available options)");
+            return ExitCode::from(INVALID_ARGS_EXIT_CODE);
+        }
+    };
+
+    if let Some(items_counts) = &request.runes_pages_items_counts {
+        match find_best_runes_pages(request.champ_properties, &request.settings, items_counts) {
+            Ok(best_runes_pages) => {
+                print_runes_pages_scores(&best_runes_pages, &request.settings.runes_page);
+                request.settings.runes_page = best_runes_pages[0].0; //should never go out of bounds since the current runes page is always included
+            }
+            Err(error_msg) => {
+                eprintln!("Error: failed to find the best runes page: {error_msg}");
+                return ExitCode::from(GENERATION_FAILURE_EXIT_CODE);
+            }
+        }
+    }
+
+    if let Some(items_counts) = &request.summoner_spells_items_counts {
+        match find_best_summoner_spells(request.champ_properties, &request.settings, items_counts) {
+            Ok(best_summoner_spells) => {
+                print_summoner_spells_scores(
+                    &best_summoner_spells,
+                    &request.settings.summoner_spells,
+                );
+                request.settings.summoner_spells = best_summoner_spells[0].0; //should never go out of bounds since the current summoner spells are always included
+            }
+            Err(error_msg) => {
+                eprintln!("Error: failed to find the best summoner spells: {error_msg}");
+                return ExitCode::from(GENERATION_FAILURE_EXIT_CODE);
+            }
+        }
+    }
+
+    let generation_result: Result<Vec<BuildContainer>, String> = if request.silent {
+        find_best_builds(
+            request.champ_properties,
+            &request.settings,
+            &mut SilentObserver,
+        )
+    } else {
+        find_best_builds(
+            request.champ_properties,
+            &request.settings,
+            &mut ProgressBarObserver::default(),
+        )
+    };
+    let mut pareto_builds: Vec<BuildContainer> = match generation_result {
+        Ok(pareto_builds) => pareto_builds,
+        Err(error_msg) => {
+            eprintln!("Error: failed to generate builds: {error_msg}");
+            return ExitCode::from(GENERATION_FAILURE_EXIT_CODE);
+        }
+    };
+    sort_builds_by_score(&mut pareto_builds, request.settings.weights);
+
+    for (path, format) in &request.exports {
+        if let Err(error_msg) = export_builds(
+            path,
+            *format,
+            &pareto_builds,
+            request.champ_properties,
+            &request.settings,
+        ) {
+            eprintln!("Error: failed to export results: {error_msg}");
+            return ExitCode::from(EXPORT_FAILURE_EXIT_CODE);
+        }
+    }
+
+    println!(" ");
+    print_builds_scores(
+        &pareto_builds,
+        request.champ_properties.name,
+        request.settings.weights,
+        request.n_to_print,
+        enum_set!(),
+    );
+
+    if request.compare_skill_orders {
+        //pareto builds are never empty after a successful generation
+        let best_build: &Build = &pareto_builds[0].build;
+        match find_best_skill_orders(request.champ_properties, &request.settings, best_build) {
+            Ok(best_skill_orders) => print_skill_orders_scores(&best_skill_orders, best_build),
+            Err(error_msg) => {
+                eprintln!("Error: failed to compare skill orders: {error_msg}");
+                return ExitCode::from(GENERATION_FAILURE_EXIT_CODE);
+            }
+        }
+    }
+
+    if let Some(lvl) = request.starting_items_lvl {
+        match find_best_starting_items(request.champ_properties, &request.settings, lvl) {
+            Ok(best_starting_items) => print_starting_items_scores(&best_starting_items, lvl),
+            Err(error_msg) => {
+                eprintln!("Error: failed to compare starting items: {error_msg}");
+                return ExitCode::from(GENERATION_FAILURE_EXIT_CODE);
+            }
+        }
+    }
+
+    if let Some(recall_times) = &request.recall_times {
+        //pareto builds are never empty after a successful generation
+        match find_best_purchase_path(
+            request.champ_properties,
+            &request.settings,
+            &pareto_builds[0].build,
+            recall_times,
+        ) {
+            Ok(purchase_path) => print_purchase_path(&purchase_path),
+            Err(error_msg) => {
+                eprintln!("Error: failed to find a purchase path: {error_msg}");
+                return ExitCode::from(GENERATION_FAILURE_EXIT_CODE);
+            }
+        }
+    }
+    ExitCode::SUCCESS
+}
+
+/// Flags that don't take a value.
+const VALUELESS_FLAGS: [&str; 12] = [
+    "-h",
+    "--help",
+    "-q",
+    "--quiet",
+    "--allow-manaflow-first-item",
+    "--grievous-wounds",
+    "--no-incoming-dmg",
+    "--no-teamfight",
+    "--no-enemy-team",
+    "--no-script",
+    "--default-skill-order",
+    "--compare-skill-orders",
+];
+
+/// Splits the arguments into (flag, value) pairs.
+/// Values can be given either as the next argument or with the `--flag=value` syntax.
+fn split_flags_values(args: &[String]) -> Result<Vec<(&str, Option<&str>)>, String> {
+    let mut pairs: Vec<(&str, Option<&str>)> = Vec::with_capacity(args.len());
+    let mut args_iter = args.iter();
+    while let Some(arg) = args_iter.next() {
+        if !arg.starts_with('-') {
+            return Err(format!("unexpected argument '{arg}'"));
+        }
+        if let Some((flag, value)) = arg.split_once('=') {
+            pairs.push((flag, Some(value)));
+        } else if VALUELESS_FLAGS.contains(&arg.as_str()) {
+            pairs.push((arg, None));
+        } else {
+            pairs.push((arg, args_iter.next().map(String::as_str)));
+        }
+    }
+    Ok(pairs)
+}
+
+/// Returns the value associated with a flag or an Err if there is none.
+fn expect_value<'a>(flag: &str, value: Option<&'a str>) -> Result<&'a str, String> {
+    value.ok_or_else(|| format!("missing value for '{flag}'"))
+}
+
+fn parse_usize(flag: &str, value: &str) -> Result<usize, String> {
+    value
+        .trim()
+        .parse::<usize>()
+        .map_err(|error| format!("invalid integer '{value}' for '{flag}': {error}"))
+}
+
+fn parse_f32(flag: &str, value: &str) -> Result<f32, String> {
+    value
+        .trim()
+        .parse::<f32>()
+        .map_err(|error| format!("invalid number '{value}' for '{flag}': {error}"))
+}
+
+fn parse_champion(name: &str) -> Result<&'static UnitProperties, String> {
+    Unit::ALL_CHAMPIONS
+        .iter()
+        .find(|properties| properties.name.eq_ignore_ascii_case(name.trim()))
+        .copied()
+        .ok_or_else(|| format!("'{name}' is not an available champion"))
+}
+
+/// Matches a target either by its number or parses it (see `TargetDefinition::parse`).
+fn parse_target(input: &str, game_data: &GameDataSet) -> Result<TargetDefinition, String> {
+    if let Ok(number) = input.trim().parse::<usize>() {
+        if (1..=TARGET_OPTIONS.len()).contains(&number) {
+            return Ok(TargetDefinition::dummy(TARGET_OPTIONS[number - 1]));
+        }
+    }
+    TargetDefinition::parse(input, game_data)
+}
+
+fn parse_fight_script(input: &str) -> Result<FightScript, String> {
+    input
+        .parse::<FightScript>()
+        .map_err(|error_msg| format!("invalid fight script: {error_msg}"))
+}
+
+/// Parses minor runes names separated by commas (`none` for an empty slot).
+fn parse_minor_runes(input: &str) -> Result<Vec<&'static MinorRune>, String> {
+    input
+        .split(',')
+        .map(|name| {
+            MinorRune::find_by_name(name)
+                .ok_or_else(|| format!("'{}' is not a recognized minor rune", name.trim()))
+        })
+        .collect()
+}
+
+/// Parses summoner spells names separated by commas (`none` for an empty slot).
+fn parse_summoner_spells(input: &str) -> Result<Vec<&'static SummonerSpell>, String> {
+    input
+        .split(',')
+        .map(|name| {
+            SummonerSpell::find_by_name(name)
+                .ok_or_else(|| format!("'{}' is not a recognized summoner spell", name.trim()))
+        })
+        .collect()
+}
+
+/// Parses numbers of items separated by commas (e.g. `2,4`), each must be a valid number of items per build.
+pub(super) fn parse_items_counts(input: &str) -> Result<Vec<usize>, String> {
+    input
+        .split(',')
+        .map(|n_items| {
+            let n_items: usize = parse_usize("number of items", n_items.trim())?;
+            if (1..=MAX_UNIT_ITEMS).contains(&n_items) {
+                Ok(n_items)
+            } else {
+                Err(format!(
+                    "number of items must be between 1 and {MAX_UNIT_ITEMS} (got {n_items})"
+                ))
+            }
+        })
+        .collect()
+}
+
+/// Parses game times in minutes separated by commas (e.g. `4,9.5,14`).
+pub(super) fn parse_recall_times(input: &str) -> Result<Vec<f32>, String> {
+    input
+        .split(',')
+        .map(|time| parse_f32("recall time", time.trim()))
+        .collect()
+}
+
+/// Parses an enemy team in the form `TARGET[@FOCUS_WEIGHT],...` (focus weight of 1 if not specified).
+pub(super) fn parse_enemy_team(
+    input: &str,
+    game_data: &GameDataSet,
+) -> Result<Vec<EnemyTeamMember>, String> {
+    input
+        .split(',')
+        .map(|enemy| {
+            let (target, focus_weight): (&str, f32) = match enemy.rsplit_once('@') {
+                Some((target, focus_weight)) => (target, parse_f32("focus weight", focus_weight)?),
+                None => (enemy, 1.),
+            };
+            Ok(EnemyTeamMember {
+                target: parse_target(target, game_data)?,
+                focus_weight,
+            })
+        })
+        .collect()
+}
+
+/// Parses mandatory items in the form `SLOT=ITEM[,SLOT=ITEM...]` and sets them in the given build.
+fn parse_mandatory_items(
+    input: &str,
+    items_database: &ItemsDatabase,
+    mandatory_items: &mut Build,
+) -> Result<(), String> {
+    for slot_item in input.split(',') {
+        let (slot, item_name) = slot_item
+            .split_once('=')
+            .ok_or_else(|| format!("invalid mandatory item '{slot_item}' (expected SLOT=ITEM)"))?;
+
+        let slot: usize = parse_usize("mandatory item slot", slot)?;
+        if !(1..=MAX_UNIT_ITEMS).contains(&slot) {
+            return Err(format!(
+                "mandatory item slot must be between 1 and {MAX_UNIT_ITEMS} (got {slot})"
+            ));
+        }
+        mandatory_items[slot - 1] =
+            find_item_in_pools(item_name.trim(), items_database, EnumSet::all())
+                .ok_or_else(|| format!("'{}' is not a recognized item", item_name.trim()))?;
+    }
+    Ok(())
+}
+
+/// Parses incoming dmg in the form `SUSTAINED_DPS,BURST_DMG[,BURST_TIME]` and sets them in the given profile.
+fn parse_incoming_dmg(input: &str, profile: &mut IncomingDmgProfile) -> Result<(), String> {
+    let values: Vec<f32> = input
+        .split(',')
+        .map(|value| parse_f32("--incoming-dmg", value))
+        .collect::<Result<Vec<f32>, String>>()?;
+    match values[..] {
+        [sustained_dps_per_lvl, burst_dmg_per_lvl] => {
+            profile.sustained_dps_per_lvl = sustained_dps_per_lvl;
+            profile.burst_dmg_per_lvl = burst_dmg_per_lvl;
+        }
+        [sustained_dps_per_lvl, burst_dmg_per_lvl, burst_time] => {
+            profile.sustained_dps_per_lvl = sustained_dps_per_lvl;
+            profile.burst_dmg_per_lvl = burst_dmg_per_lvl;
+            profile.burst_time = burst_time;
+        }
+        _ => {
+            return Err(format!(
+                "expected 2 or 3 values separated by commas for '--incoming-dmg' (got '{input}')"
+            ))
+        }
+    }
+    Ok(())
+}
+
+fn parse_weights(input: &str) -> Result<(f32, f32, f32, f32), String> {
+    let weights: Vec<f32> = input
+        .split(',')
+        .map(|weight| parse_f32("--weights", weight))
+        .collect::<Result<Vec<f32>, String>>()?;
+    match weights[..] {
+        [dps_weight, def_weight, ms_weight] => Ok((dps_weight, def_weight, ms_weight, 0.)),
+        [dps_weight, def_weight, ms_weight, ttk_weight] => {
+            Ok((dps_weight, def_weight, ms_weight, ttk_weight))
+        }
+        _ => Err(format!(
+            "expected 3 or 4 weights separated by commas (got '{input}')"
+        )),
+    }
+}
+
+/// Parses the command line arguments (without the program name) into a builds generation request.
+/// The returned settings are checked and valid for the requested champion.
+fn parse_args(args: &[String]) -> Result<ParsedArgs, String> {
+    let pairs: Vec<(&str, Option<&str>)> = split_flags_values(args)?;
+
+    if pairs
+        .iter()
+        .any(|(flag, _)| matches!(*flag, "-h" | "--help"))
+    {
+        return Ok(ParsedArgs::Help);
+    }
+
+    //the preset, the game data and the champion must be known first as other settings values depend on them
+    let preset: Option<SettingsPreset> = match pairs.iter().find(|(flag, _)| *flag == "--preset") {
+        Some(&(flag, value)) => Some(SettingsPreset::load(Path::new(expect_value(flag, value)?))?),
+        None => None,
+    };
+    let patch: Option<&str> = match pairs.iter().find(|(flag, _)| *flag == "--patch") {
+        Some(&(flag, value)) => Some(expect_value(flag, value)?),
+        None => preset.as_ref().and_then(|preset| preset.patch.as_deref()),
+    };
+    let items_data_path: Option<&Path> =
+        match pairs.iter().find(|(flag, _)| *flag == "--items-data") {
+            Some(&(flag, value)) => Some(Path::new(expect_value(flag, value)?)),
+            None => None,
+        };
+    let mut game_data: &'static GameDataSet = load_game_data(patch, items_data_path)?;
+    if let Some(&(flag, value)) = pairs.iter().find(|(flag, _)| *flag == "--import-snapshot") {
+        let import: SnapshotImport = import_snapshot(
+            Path::new(expect_value(flag, value)?),
+            game_data.champions(),
+            game_data.items_database,
+        )?;
+        println!("{}", import.report);
+        game_data = GameDataSet::new(&game_data.patch, import.champions, import.items_database);
+    }
+    if let Some(&(flag, value)) = pairs.iter().find(|(flag, _)| *flag == "--write-items-data") {
+        return Ok(ParsedArgs::WriteItemsData(
+            game_data.items_database,
+            PathBuf::from(expect_value(flag, value)?),
+        ));
+    }
+
+    let champ_properties: &'static UnitProperties = match pairs
+        .iter()
+        .find(|(flag, _)| matches!(*flag, "-c" | "--champion"))
+    {
+        Some(&(flag, value)) => parse_champion(expect_value(flag, value)?)?,
+        None => match preset.as_ref().and_then(|preset| preset.champion.as_ref()) {
+            Some(champ_name) => parse_champion(champ_name)?,
+            None => return Err("missing required argument '--champion'".to_string()),
+        },
+    };
+    let champ_properties: &'static UnitProperties =
+        game_data.get_champion(champ_properties).ok_or_else(|| {
+            format!(
+                "{} is not available in patch {}",
+                champ_properties.name, game_data.patch
+            )
+        })?;
+
+    let mut settings: BuildsGenerationSettings = if let Some(preset) = &preset {
+        preset
+            .to_settings(champ_properties, game_data)
+            .map_err(|error_msg| format!("failed to load preset: {error_msg}"))?
+    } else {
+        let mut settings: BuildsGenerationSettings =
+            BuildsGenerationSettings::default_by_champion(champ_properties);
+        settings.use_game_data(game_data);
+        settings
+    };
+    //the economy profile is set first so that its values can be overridden regardless of the flags order
+    if let Some(&(flag, value)) = pairs.iter().find(|(flag, _)| *flag == "--economy") {
+        let name: &str = expect_value(flag, value)?;
+        settings.economy = EconomyProfile::from_name(name.trim()).ok_or_else(|| {
+            format!(
+                "'{name}' is not a recognized economy profile (expected one of: {})",
+                EconomyProfile::PROFILES_NAMES.join(", ")
+            )
+        })?;
+    }
+    let mut save_preset_path: Option<&str> = None;
+    let mut exports: Vec<(PathBuf, ExportFormat)> = Vec::new();
+    let mut n_to_print: NonZeroUsize = NonZeroUsize::new(DEFAULT_N_PRINTED_BUILDS)
+        .expect("Failed to create NonZeroUsize from DEFAULT_N_PRINTED_BUILDS");
+    let mut silent: bool = false;
+    let mut runes_pages_items_counts: Option<Vec<usize>> = None;
+    let mut summoner_spells_items_counts: Option<Vec<usize>> = None;
+    let mut compare_skill_orders: bool = false;
+    let mut starting_items_lvl: Option<u8> = None;
+    let mut recall_times: Option<Vec<f32>> = None;
+
+    for (flag, value) in pairs {
+        match flag {
+            "-c" | "--champion" | "--preset" | "--patch" | "--items-data" | "--import-snapshot"
+            | "--economy" => {
+                //already handled
+            }
+            "--save-preset" => save_preset_path = Some(expect_value(flag, value)?),
+            "-t" | "--target" => {
+                settings.target = parse_target(expect_value(flag, value)?, game_data)?;
+            }
+            "-s" | "--scenario" => {
+                settings.fight_scenario_number =
+                    NonZeroUsize::new(parse_usize(flag, expect_value(flag, value)?)?)
+                        .ok_or_else(|| "fight scenario number must be non-zero".to_string())?;
+            }
+            "--script" => {
+                settings.fight_script = Some(parse_fight_script(expect_value(flag, value)?)?);
+            }
+            "--script-file" => {
+                let path: &str = expect_value(flag, value)?;
+                let content: String = fs::read_to_string(path)
+                    .map_err(|error| format!("failed to read '{path}': {error}"))?;
+                settings.fight_script = Some(parse_fight_script(&content)?);
+            }
+            "--no-script" => settings.fight_script = None,
+            "-d" | "--duration" => {
+                settings.fight_duration = parse_f32(flag, expect_value(flag, value)?)?;
+            }
+            "--phys-dmg-received" => {
+                settings.phys_dmg_received_percent =
+                    parse_f32(flag, expect_value(flag, value)?)? / 100.;
+            }
+            "--incoming-dmg" => parse_incoming_dmg(
+                expect_value(flag, value)?,
+                settings.incoming_dmg.get_or_insert_with(Default::default),
+            )?,
+            "--true-dmg-received" => {
+                settings
+                    .incoming_dmg
+                    .get_or_insert_with(Default::default)
+                    .true_dmg_percent = parse_f32(flag, expect_value(flag, value)?)? / 100.;
+            }
+            "--grievous-wounds" => {
+                settings
+                    .incoming_dmg
+                    .get_or_insert_with(Default::default)
+                    .grievous_wounds = true;
+            }
+            "--no-incoming-dmg" => settings.incoming_dmg = None,
+            "--target-flee" => {
+                settings.target_flee_percent = parse_f32(flag, expect_value(flag, value)?)? / 100.;
+            }
+            "--cs-per-min" => {
+                settings.economy.cs_per_min = parse_f32(flag, expect_value(flag, value)?)?;
+            }
+            "--time-between-fights" => {
+                settings.economy.time_between_fights = parse_f32(flag, expect_value(flag, value)?)?;
+            }
+            "--teamfight" => {
+                settings.teamfight_targets = expect_value(flag, value)?
+                    .split(',')
+                    .map(|target| parse_target(target, game_data))
+                    .collect::<Result<Vec<TargetDefinition>, String>>()?;
+            }
+            "--no-teamfight" => settings.teamfight_targets.clear(),
+            "--enemy-team" => {
+                settings.enemy_team = parse_enemy_team(expect_value(flag, value)?, game_data)?;
+            }
+            "--no-enemy-team" => settings.enemy_team.clear(),
+            "--keystone" => {
+                let name: &str = expect_value(flag, value)?;
+                settings.runes_page.set_keystone(
+                    RuneKeystone::find_by_name(name)
+                        .ok_or_else(|| format!("'{name}' is not a recognized rune keystone"))?,
+                );
+            }
+            "--primary-runes" => {
+                let runes: Vec<&MinorRune> = parse_minor_runes(expect_value(flag, value)?)?;
+                settings.runes_page.primary_runes = runes[..]
+                    .try_into()
+                    .map_err(|_| "expected 3 primary runes separated by commas".to_string())?;
+            }
+            "--secondary-runes" => {
+                let runes: Vec<&MinorRune> = parse_minor_runes(expect_value(flag, value)?)?;
+                settings.runes_page.secondary_runes = runes[..]
+                    .try_into()
+                    .map_err(|_| "expected 2 secondary runes separated by commas".to_string())?;
+            }
+            "--shards" => {
+                let shards: Vec<RuneShard> = expect_value(flag, value)?
+                    .split(',')
+                    .map(str::parse::<RuneShard>)
+                    .collect::<Result<Vec<RuneShard>, String>>()?;
+                let [shard1, shard2, shard3] = shards[..] else {
+                    return Err("expected 3 rune shards separated by commas".to_string());
+                };
+                settings.runes_page.shard1 = shard1;
+                settings.runes_page.shard2 = shard2;
+                settings.runes_page.shard3 = shard3;
+            }
+            "--summoner-spells" => {
+                let spells: Vec<&SummonerSpell> =
+                    parse_summoner_spells(expect_value(flag, value)?)?;
+                settings.summoner_spells.spells = spells[..]
+                    .try_into()
+                    .map_err(|_| "expected 2 summoner spells separated by commas".to_string())?;
+            }
+            "--best-summoner-spells" => {
+                summoner_spells_items_counts =
+                    Some(parse_items_counts(expect_value(flag, value)?)?);
+            }
+            "--skill-order" => {
+                settings.skill_order = Some(expect_value(flag, value)?.parse::<MaxOrder>()?);
+            }
+            "--default-skill-order" => settings.skill_order = None,
+            "--compare-skill-orders" => compare_skill_orders = true,
+            "--compare-starting-items" => {
+                let lvl: usize = parse_usize(flag, expect_value(flag, value)?)?;
+                starting_items_lvl = Some(
+                    u8::try_from(lvl).map_err(|_| format!("invalid lvl for '{flag}': {lvl}"))?,
+                );
+            }
+            "--purchase-path" => {
+                recall_times = Some(parse_recall_times(expect_value(flag, value)?)?);
+            }
+            "--best-runes-page" => {
+                runes_pages_items_counts = Some(parse_items_counts(expect_value(flag, value)?)?);
+            }
+            "-n" | "--items" => settings.n_items = parse_usize(flag, expect_value(flag, value)?)?,
+            "-m" | "--mandatory" => {
+                parse_mandatory_items(
+                    expect_value(flag, value)?,
+                    game_data.items_database,
+                    &mut settings.mandatory_items,
+                )?;
+            }
+            "--boots-slot" => {
+                settings.boots_slot = expect_value(flag, value)?.parse::<ItemSlot>()?;
+            }
+            "--supp-item-slot" => {
+                settings.supp_item_slot = expect_value(flag, value)?.parse::<ItemSlot>()?;
+            }
+            "--allow-manaflow-first-item" => settings.allow_manaflow_first_item = true,
+            "-w" | "--weights" => settings.weights = parse_weights(expect_value(flag, value)?)?,
+            "--threshold" => {
+                settings.search_threshold = parse_f32(flag, expect_value(flag, value)?)? / 100.;
+            }
+            "-p" | "--print" => {
+                n_to_print = NonZeroUsize::new(parse_usize(flag, expect_value(flag, value)?)?)
+                    .ok_or_else(|| "number of builds to print must be non-zero".to_string())?;
+            }
+            "-q" | "--quiet" => silent = true,
+            "--export-json" => {
+                exports.push((
+                    PathBuf::from(expect_value(flag, value)?),
+                    ExportFormat::Json,
+                ));
+            }
+            "--export-csv" => {
+                exports.push((PathBuf::from(expect_value(flag, value)?), ExportFormat::Csv));
+            }
+            _ => return Err(format!("unknown option '{flag}'")),
+        }
+    }
+
+    settings
+        .check_settings(champ_properties)
+        .map_err(|error_msg| format!("invalid settings: {error_msg}"))?;
+
+    if let Some(path) = save_preset_path {
+        save_settings_preset(Path::new(path), &settings, champ_properties)?;
+    }
+
+    Ok(ParsedArgs::Request(Box::new(ArgsRequest {
+        champ_properties,
+        settings,
+        n_to_print,
+        silent,
+        runes_pages_items_counts,
+        summoner_spells_items_counts,
+        compare_skill_orders,
+        starting_items_lvl,
+        recall_times,
+        exports,
+    })))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn to_args(line: &str) -> Vec<String> {
+        line.split_whitespace().map(String::from).collect()
+    }
+
+    #[test]
+    pub fn test_parse_args() {
+        let ParsedArgs::Request(request) = parse_args(&to_args(
+            "--champion draven --target tank --items 5 --scenario 2 --weights 1,0.25,0.5 --mandatory 1=IE",
+        ))
+        .expect("Failed to parse valid arguments") else {
+            panic!("Expected a builds generation request");
+        };
+
+        assert_eq!(*request.champ_properties, Unit::DRAVEN_PROPERTIES);
+        assert_eq!(
+            *request.settings.target.properties,
+            TANKY_OPTIMIZER_DUMMY_PROPERTIES
+        );
+        assert_eq!(request.settings.n_items, 5);
+        assert_eq!(request.settings.fight_scenario_number.get(), 2);
+        assert_eq!(request.settings.weights, (1., 0.25, 0.5, 0.));
+        assert_eq!(*request.settings.mandatory_items[0], Item::INFINITY_EDGE);
+
+        //champion and custom targets
+        let ParsedArgs::Request(request) = parse_args(&to_args(
+            "--champion ashe --target varus:jaksho+randuins --teamfight tank,custom:2000/80/50",
+        ))
+        .expect("Failed to parse valid arguments") else {
+            panic!("Expected a builds generation request");
+        };
+        assert_eq!(request.settings.target.kind(), TargetKind::Champion);
+        assert_eq!(*request.settings.target.properties, Unit::VARUS_PROPERTIES);
+        assert_eq!(request.settings.target.build.item_count(), 2);
+        assert_eq!(
+            request.settings.teamfight_targets[1].kind(),
+            TargetKind::Custom
+        );
+        assert!(parse_args(&to_args("--champion ashe --target varus:not_an_item")).is_err());
+
+        //enemy team
+        let ParsedArgs::Request(request) = parse_args(&to_args(
+            "--champion ashe --enemy-team varus:jaksho@2,custom:2000/80/50,squishy@0.5",
+        ))
+        .expect("Failed to parse valid arguments") else {
+            panic!("Expected a builds generation request");
+        };
+        let focus_weights: Vec<f32> = request
+            .settings
+            .enemy_team
+            .iter()
+            .map(|enemy| enemy.focus_weight)
+            .collect();
+        assert_eq!(focus_weights, [2., 1., 0.5]);
+        assert!(parse_args(&to_args("--champion ashe --enemy-team tank@-1")).is_err());
+
+        //fight script
+        let ParsedArgs::Request(request) =
+            parse_args(&to_args("--champion ashe --script=w;aa;walk"))
+                .expect("Failed to parse valid arguments")
+        else {
+            panic!("Expected a builds generation request");
+        };
+        assert_eq!(
+            request
+                .settings
+                .fight_script
+                .map(|script| script.to_string()),
+            Some("w if ready; aa if ready; walk otherwise".to_string())
+        );
+        assert!(parse_args(&to_args("--champion ashe --script=walk_backwards")).is_err());
+
+        //skill order
+        let ParsedArgs::Request(request) = parse_args(&to_args(
+            "--champion ashe --skill-order w>q>e:late --compare-skill-orders",
+        ))
+        .expect("Failed to parse valid arguments") else {
+            panic!("Expected a builds generation request");
+        };
+        assert_eq!(
+            request.settings.skill_order.map(|order| order.to_string()),
+            Some("W>Q>E:late".to_string())
+        );
+        assert!(request.compare_skill_orders);
+        assert_eq!(request.runes_pages_items_counts, None);
+        assert_eq!(request.recall_times, None);
+
+        //purchase path
+        let ParsedArgs::Request(request) =
+            parse_args(&to_args("--champion ashe --purchase-path 4,9.5,14"))
+                .expect("Failed to parse valid arguments")
+        else {
+            panic!("Expected a builds generation request");
+        };
+        assert_eq!(request.recall_times, Some(vec![4., 9.5, 14.]));
+        assert_eq!(request.starting_items_lvl, None);
+        assert!(parse_args(&to_args("--champion ashe --purchase-path 4,soon")).is_err());
+
+        //starting items
+        let ParsedArgs::Request(request) = parse_args(&to_args(
+            "--champion ashe --compare-starting-items 2 -m 6=potion",
+        ))
+        .expect("Failed to parse valid arguments") else {
+            panic!("Expected a builds generation request");
+        };
+        assert_eq!(request.starting_items_lvl, Some(2));
+        assert_eq!(request.settings.mandatory_items[5], &Item::HEALTH_POTION);
+
+        //economy (the profile values can be overridden regardless of the flags order)
+        let ParsedArgs::Request(request) = parse_args(&to_args(
+            "--champion ashe --cs-per-min 8 --economy shared-xp --time-between-fights 120",
+        ))
+        .expect("Failed to parse valid arguments") else {
+            panic!("Expected a builds generation request");
+        };
+        assert_eq!(
+            request.settings.economy,
+            EconomyProfile {
+                cs_per_min: 8.,
+                time_between_fights: 120.,
+                ..EconomyProfile::from_name("shared-xp").unwrap()
+            }
+        );
+        assert!(parse_args(&to_args("--champion ashe --economy jungle")).is_err());
+        assert!(parse_args(&to_args("--champion ashe --time-between-fights 0")).is_err());
+
+        //runes pages
+        let ParsedArgs::Request(request) =
+            parse_args(&to_args("--champion ashe --best-runes-page 2,4"))
+                .expect("Failed to parse valid arguments")
+        else {
+            panic!("Expected a builds generation request");
+        };
+        assert_eq!(request.runes_pages_items_counts, Some(vec![2, 4]));
+        assert!(parse_args(&to_args("--champion ashe --best-runes-page 2,7")).is_err());
+
+        //summoner spells
+        let ParsedArgs::Request(request) = parse_args(&to_args(
+            "--champion ashe --summoner-spells flash,barrier --best-summoner-spells 3",
+        ))
+        .expect("Failed to parse valid arguments") else {
+            panic!("Expected a builds generation request");
+        };
+        assert_eq!(
+            request.settings.summoner_spells.spells,
+            [&SummonerSpell::FLASH, &SummonerSpell::BARRIER]
+        );
+        assert_eq!(request.summoner_spells_items_counts, Some(vec![3]));
+        assert!(parse_args(&to_args("--champion ashe --summoner-spells flash")).is_err());
+        assert!(parse_args(&to_args("--champion ashe --summoner-spells heal,heal")).is_err()); //same summoner spell twice
+        assert!(parse_args(&to_args("--champion ashe --summoner-spells flash,smite")).is_err());
+
+        //minor runes
+        let ParsedArgs::Request(request) = parse_args(&to_args(
+            "--champion ashe --primary-runes pom,haste,none --secondary-runes transcendence,scorch",
+        ))
+        .expect("Failed to parse valid arguments") else {
+            panic!("Expected a builds generation request");
+        };
+        assert_eq!(
+            request.settings.runes_page.primary_runes,
+            [
+                &MinorRune::PRESENCE_OF_MIND,
+                &MinorRune::LEGEND_HASTE,
+                &MinorRune::EMPTY_MINOR_RUNE
+            ]
+        );
+        assert_eq!(
+            request.settings.runes_page.secondary_runes,
+            [&MinorRune::TRANSCENDENCE, &MinorRune::SCORCH]
+        );
+        assert!(parse_args(&to_args("--champion ashe --primary-runes pom,haste")).is_err());
+        assert!(parse_args(&to_args("--champion ashe --secondary-runes pom,tob")).is_err()); //same tree as the keystone
+        assert!(parse_args(&to_args("--champion ashe --secondary-runes tob,scorch")).is_err()); //different trees
+        assert!(parse_args(&to_args("--champion ashe --skill-order q>w>w")).is_err());
+        assert!(parse_args(&to_args("--champion aphelios --skill-order q>w>e")).is_err());
+        assert!(parse_args(&to_args(
+            "--champion ashe --enemy-team tank --teamfight tank"
+        ))
+        .is_err());
+        assert!(parse_args(&to_args("--champion ashe --target custom:0/80/50")).is_err()); //hp must be > 0
+
+        //invalid arguments
+        assert!(parse_args(&to_args("--items 5")).is_err()); //missing champion
+        assert!(parse_args(&to_args("--champion ashe --unknown 1")).is_err());
+        assert!(parse_args(&to_args("--champion ashe --mandatory 1=not_an_item")).is_err());
+        assert!(parse_args(&to_args("--champion ashe --items 0")).is_err()); //invalid settings
+        assert!(parse_args(&to_args("--champion ashe --scenario 2")).is_err()); //ashe has only 1 fight scenario
+        assert!(matches!(
+            parse_args(&to_args("--help")),
+            Ok(ParsedArgs::Help)
+        ));
+    }
+}
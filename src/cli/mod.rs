@@ -0,0 +1,2869 @@
+mod args;
+mod progress_bar;
+
+pub use args::launch_from_args;
+
+use args::{parse_enemy_team, parse_items_counts, parse_recall_times};
+use progress_bar::ProgressBarObserver;
+
+use super::builds_analyzer::*;
+use super::champion_optimizer::*;
+use super::game_data::*;
+use super::presets::*;
+
+use items_data::*;
+use patches::*;
+use runes_data::*;
+use snapshot_import::*;
+use summoner_spells_data::*;
+use units_data::*;
+
+use constcat::concat;
+use enumset::{enum_set, EnumSet, EnumSetType};
+
+use core::num::NonZeroUsize;
+use core::ops::RangeBounds;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use io::Write;
+
+/// Number of builds to be printed by default when displaying results.
+const DEFAULT_N_PRINTED_BUILDS: usize = 18;
+/// Directory where results are exported by default.
+const DEFAULT_EXPORTS_DIR: &str = "exports";
+/// Number of items used when automatically finding the best runes.
+const N_ITEMS_WHEN_FINDING_BEST_RUNES: usize = 2;
+/// Number of items used when automatically finding the best summoner spells.
+const N_ITEMS_WHEN_FINDING_BEST_SUMMONER_SPELLS: usize = 2;
+/// Number of runes pages printed when searching for the best runes page (the current runes page is always printed).
+const N_PRINTED_RUNES_PAGES: usize = 10;
+
+const WELCOME_HELP_MSG: &str = "At any time, you can enter:\n\
+                                back/b - to go back to the previous menu.\n\
+                                help   - to show help info on the current menu.\n\
+                                home   - to return to the champion selection page (this page).\n\
+                                exit   - to exit the program.";
+
+/// Returns the items database at the given path, or the one at `DEFAULT_ITEMS_DATA_PATH` if no path is given and the file exists.
+/// Otherwise, returns the compiled-in items database.
+fn load_items_database(path: Option<&Path>) -> Result<&'static ItemsDatabase, String> {
+    match path {
+        Some(path) => ItemsDatabase::load(path),
+        None if Path::new(DEFAULT_ITEMS_DATA_PATH).is_file() => {
+            ItemsDatabase::load(Path::new(DEFAULT_ITEMS_DATA_PATH))
+        }
+        None => Ok(ItemsDatabase::builtin()),
+    }
+}
+
+/// Returns the game data set of the given patch (see `load_patch`), or the compiled-in patch if no patch is given.
+/// The items of the data set are replaced by those of the given items data file if any,
+/// or by those of the file at `DEFAULT_ITEMS_DATA_PATH` (if it exists) for the compiled-in patch.
+fn load_game_data(
+    patch: Option<&str>,
+    items_data_path: Option<&Path>,
+) -> Result<&'static GameDataSet, String> {
+    let game_data: &'static GameDataSet = match patch {
+        Some(patch) => load_patch(Path::new(DEFAULT_PATCHES_DIR), patch)?,
+        None => GameDataSet::builtin(),
+    };
+    if items_data_path.is_none() && !std::ptr::eq(game_data, GameDataSet::builtin()) {
+        return Ok(game_data);
+    }
+    let items_database: &'static ItemsDatabase = load_items_database(items_data_path)?;
+    if std::ptr::eq(items_database, game_data.items_database) {
+        Ok(game_data)
+    } else {
+        Ok(game_data.with_items_database(items_database))
+    }
+}
+
+pub fn launch_interface() {
+    let game_data: &'static GameDataSet = match load_game_data(None, None) {
+        Ok(game_data) => game_data,
+        Err(error_msg) => {
+            println!("Failed to load items data: {error_msg}\nUsing built-in items instead.\n");
+            GameDataSet::builtin()
+        }
+    };
+
+    println!(
+        "---------------------------------------------------\n\
+         ---\\ LoL best ADC build finder - patch {:2}.{:2} \\-----\n\
+         ----\\ Champions implemented: {:3}              \\----\n\
+         -----\\ Items in database: {:3}                  \\---\n\
+         ---------------------------------------------------\n\
+         Items data: {}\n\
+         Available patches: {} (can be changed in the builds generation settings)\n\
+         {WELCOME_HELP_MSG}",
+        PATCH_NUMBER_MAJOR,
+        PATCH_NUMBER_MINOR,
+        Unit::ALL_CHAMPIONS.len(),
+        game_data.items_database.n_items(),
+        game_data.items_database.source,
+        available_patches(Path::new(DEFAULT_PATCHES_DIR)).join(", "),
+    );
+
+    let champ_names: Vec<&str> = Unit::ALL_CHAMPIONS
+        .iter()
+        .map(|champ_properties| champ_properties.name)
+        .collect();
+
+    let mut greetings_msg: String = String::from("\nAvailable champions: ");
+    greetings_msg.push_str(champ_names[0]);
+    for name in champ_names[1..].iter() {
+        greetings_msg.push_str(", ");
+        greetings_msg.push_str(name);
+    }
+
+    loop {
+        let properties: &UnitProperties = match get_user_matching_input(
+            &greetings_msg,
+            "\nEnter the champion for which you want to find the best builds",
+            "\nPlease enter a valid champion name (among those available)",
+            WELCOME_HELP_MSG,
+            champ_names.iter().copied(),
+            false, //safety of a later expect() depends on this argument to be false
+        ) {
+            Ok(index) => {
+                Unit::ALL_CHAMPIONS[index.expect("Expected an input from user, but received none")]
+            }
+
+            Err(UserCommand::Back) => {
+                println!("Cannot go further back");
+                continue;
+            }
+            Err(UserCommand::Home) => continue, //already home
+            Err(UserCommand::Exit) => break,
+        };
+
+        if let Err(UserCommand::Exit) = builds_generation_screen(properties, game_data) {
+            break;
+        }
+    }
+}
+
+#[derive(Debug)]
+/// Represents user command that are possible to trigger anywhere in the cli
+/// and that must be transmitted through different functions.
+enum UserCommand {
+    Back,
+    Home,
+    Exit,
+}
+
+/// Get the user input, returns it in a String with its case preserved.
+/// doesn't catch user commands (go back, exit, etc) and returns the String directly.
+///
+/// Returns None if stdin is closed (-> must exit the program).
+#[must_use]
+fn get_user_case_sensitive_raw_input(input_line: &str) -> Option<String> {
+    print!("{input_line} ");
+    io::stdout().flush().expect("Failed to flush stdout");
+
+    let mut buffer: String = String::new();
+    io::stdin()
+        .read_line(&mut buffer)
+        .expect("Failed to read user input from stdin");
+
+    if buffer.is_empty() {
+        None //means stdin is closed (otherwise buffer would contain newline characters)
+    } else {
+        Some(buffer.trim().to_string())
+    }
+}
+
+/// Get the user input, returns it in a lowercase String.
+/// doesn't catch user commands (go back, exit, etc) and returns the String directly.
+///
+/// Returns None if stdin is closed (-> must exit the program).
+#[must_use]
+fn get_user_raw_input(input_line: &str) -> Option<String> {
+    get_user_case_sensitive_raw_input(input_line).map(|input| input.to_lowercase())
+}
+
+/// Get the user input, returns it in a lowercase String.
+/// Catches user commands (go back, exit, etc) and may return an Err with the specific variant to handle.
+fn get_user_input(input_line: &str, help_msg: &str) -> Result<String, UserCommand> {
+    get_user_case_sensitive_input(input_line, help_msg).map(|input| input.to_lowercase())
+}
+
+/// Get the user input, returns it in a String with its case preserved.
+/// Catches user commands (go back, exit, etc) and may return an Err with the specific variant to handle.
+fn get_user_case_sensitive_input(input_line: &str, help_msg: &str) -> Result<String, UserCommand> {
+    let mut line: String = String::from(input_line);
+    line.push(':');
+    loop {
+        let input: String = get_user_case_sensitive_raw_input(&line).ok_or(UserCommand::Exit)?;
+
+        match input.to_lowercase().as_str() {
+            "help" | "?" => println!(
+                "\n---[ HELP ]---\n\
+                   {}",
+                if help_msg.is_empty() {
+                    "No help message available"
+                } else {
+                    help_msg
+                }
+            ),
+            "back" | "b" => return Err(UserCommand::Back),
+            "home" => return Err(UserCommand::Home),
+            "exit" => {
+                if confirm_exit()? {
+                    return Err(UserCommand::Exit);
+                }
+            }
+            _ => return Ok(input),
+        }
+    }
+}
+
+/// Prompt the user to confirm the exit of the program and return the result in an Ok(bool).
+/// Returns `Err(UserCommand::Exit)` if stdin is closed.
+fn confirm_exit() -> Result<bool, UserCommand> {
+    loop {
+        let input: String = get_user_raw_input("Confirm exit? (y/n):").ok_or(UserCommand::Exit)?;
+        match input.as_str() {
+            "yes" | "y" | "" => return Ok(true),
+            "no" | "n" => return Ok(false),
+            _ => {
+                println!("'{input}' is not a recognized input, type 'y'/'n' to confirm or deny");
+            }
+        }
+    }
+}
+
+/// Matches the user input with the provided &str in the `match_strs` iterator and returns the corresponding index.
+/// This function will either:
+///  - loop until the user provides a valid input and returns it.
+///  - return Err with the specific variant if the user performs one of the persistent command.
+///
+/// This will return None only if `allow_no_input` is true and the user enters no input.
+/// `invalid_msg` must fit into one line.
+fn get_user_matching_input<'a>(
+    greetings_msg: &str,
+    input_line: &str,
+    invalid_line: &str,
+    help_msg: &str,
+    match_strs: impl IntoIterator<Item = &'a str>,
+    allow_no_input: bool,
+) -> Result<Option<usize>, UserCommand> {
+    if !(greetings_msg.is_empty()) {
+        println!("{greetings_msg}");
+    }
+
+    //ensure to match lowercase inputs with lowercase strings
+    let match_strings: Vec<String> = match_strs.into_iter().map(str::to_lowercase).collect();
+
+    loop {
+        let input: String = get_user_input(input_line, help_msg)?;
+
+        if input.is_empty() {
+            if allow_no_input {
+                return Ok(None);
+            } else {
+                println!("{invalid_line}");
+            }
+        } else if let Some(index) = match_strings.iter().position(|s| *s == input) {
+            return Ok(Some(index));
+        } else {
+            println!("'{input}' is not a recognized input, {invalid_line}");
+        }
+    }
+}
+
+/// Matches the user input with the provided choices and returns the corresponding choice number.
+/// This function will either:
+///  - loop until the user provides a valid input and return `Ok(number)`.
+///  - return Err with the specific variant if the user performs one of the persistent command.
+///
+/// Choice count starts at 1, so the number returned is the index of the corresponding choice + 1,
+/// This will return `None` only if `allow_no_input` is true and the user enters no input.
+fn get_user_choice<'a>(
+    greetings_msg: &str,
+    input_line: &str,
+    help_msg: &str,
+    choices: impl IntoIterator<Item = &'a str>,
+    allow_no_input: bool,
+) -> Result<Option<usize>, UserCommand> {
+    //create String that displays every choice to the user
+    let mut choices_iter = choices.into_iter();
+    let mut greetings_with_choices_msg: String = String::from(greetings_msg);
+
+    //need to handle first choice slightly differently (depending if `greetings_msg` is empty or not)
+    if !(greetings_msg.is_empty()) {
+        greetings_with_choices_msg.push('\n');
+    }
+    let mut counter: usize = 1; //display choices numbers starting from 1
+    greetings_with_choices_msg.push_str(&counter.to_string());
+    greetings_with_choices_msg.push_str(") ");
+    greetings_with_choices_msg.push_str(
+        choices_iter
+            .next()
+            .expect("Choices given to user are empty"),
+    );
+    for choice_str in choices_iter {
+        counter += 1;
+        greetings_with_choices_msg.push('\n');
+        greetings_with_choices_msg.push_str(&counter.to_string()); //use after increasing counter because we display choices numbers starting from 1
+        greetings_with_choices_msg.push_str(") ");
+        greetings_with_choices_msg.push_str(choice_str);
+    }
+
+    get_user_usize(
+        &greetings_with_choices_msg,
+        input_line,
+        help_msg,
+        1..=counter,
+        allow_no_input,
+    )
+}
+
+/// Prompts the user to enter a positive integer and returns it.
+/// This will return None only if `allow_no_input` is true and the user enters no input.
+fn get_user_usize(
+    greetings_msg: &str,
+    input_line: &str,
+    help_msg: &str,
+    range: impl RangeBounds<usize>,
+    allow_no_input: bool,
+) -> Result<Option<usize>, UserCommand> {
+    if !(greetings_msg.is_empty()) {
+        println!("{greetings_msg}");
+    }
+
+    loop {
+        let input: String = get_user_input(input_line, help_msg)?;
+
+        if input.is_empty() {
+            if allow_no_input {
+                return Ok(None);
+            } else {
+                println!("Please enter a valid integer");
+                continue;
+            }
+        }
+        let number: usize = match input.parse::<usize>() {
+            Ok(number) => number,
+            Err(error) => {
+                println!("'{input}' is not a valid integer: {}", error);
+                continue;
+            }
+        };
+        if !range.contains(&number) {
+            println!(
+                "{} is outside of range: {:?} to {:?}",
+                number,
+                range.start_bound(),
+                range.end_bound()
+            );
+            continue;
+        }
+        return Ok(Some(number));
+    }
+}
+
+/// Prompts the user to enter a (float) number and returns it.
+/// This will return None only if `allow_no_input` is true and the user enters no input.
+fn get_user_f32(
+    greetings_msg: &str,
+    input_line: &str,
+    help_msg: &str,
+    allow_no_input: bool,
+) -> Result<Option<f32>, UserCommand> {
+    if !(greetings_msg.is_empty()) {
+        println!("{greetings_msg}");
+    }
+
+    loop {
+        let input: String = get_user_input(input_line, help_msg)?;
+
+        if input.is_empty() {
+            if allow_no_input {
+                return Ok(None);
+            } else {
+                println!("Please enter a valid number");
+                continue;
+            }
+        }
+        match input.parse::<f32>() {
+            Ok(number) => return Ok(Some(number)),
+            Err(error) => println!("'{input}' is not a valid number: {}", error),
+        }
+    }
+}
+
+#[derive(EnumSetType, Debug)]
+enum ItemPoolType {
+    Legendary,
+    Boots,
+    Support,
+    Component,
+    Starter,
+    Consumable,
+}
+
+impl ItemPoolType {
+    /// Returns the items of the database that belong to this pool.
+    fn reference_pool(self, items_database: &ItemsDatabase) -> &[&'static Item] {
+        match self {
+            ItemPoolType::Legendary => items_database.legendary_items(),
+            ItemPoolType::Boots => items_database.boots(),
+            ItemPoolType::Support => items_database.supp_items(),
+            ItemPoolType::Component => items_database.component_items(),
+            ItemPoolType::Starter => items_database.starter_items(),
+            ItemPoolType::Consumable => items_database.consumables(),
+        }
+    }
+}
+
+/// Returns the items of the database that belong to the requested pools.
+fn get_items_from_pool_types(
+    items_database: &ItemsDatabase,
+    item_pool_types: EnumSet<ItemPoolType>,
+) -> Vec<&'static Item> {
+    item_pool_types
+        .iter()
+        .flat_map(|pool_type| pool_type.reference_pool(items_database).iter().copied())
+        .collect()
+}
+
+/// Returns the item matching the given name (full name or short name) among the requested pools of the database, if any.
+/// An empty name corresponds to `Item::NULL_ITEM`.
+fn find_item_in_pools(
+    name: &str,
+    items_database: &ItemsDatabase,
+    item_pool_types: EnumSet<ItemPoolType>,
+) -> Option<&'static Item> {
+    if sanitize_item_name(name).is_empty() {
+        return Some(&Item::NULL_ITEM);
+    }
+    find_item_by_name(
+        name,
+        &get_items_from_pool_types(items_database, item_pool_types),
+    )
+}
+
+/// Prompts the user to enter an item name and returns the corresponding item.
+fn get_user_item(
+    greetings_msg: &str,
+    input_line: &str,
+    items_database: &ItemsDatabase,
+    item_pool_types: EnumSet<ItemPoolType>,
+) -> Result<&'static Item, UserCommand> {
+    assert!(
+        !item_pool_types.is_empty(),
+        "Cannot choose an item from an empty pool"
+    );
+
+    if !(greetings_msg.is_empty()) {
+        println!("{greetings_msg}");
+    }
+
+    loop {
+        let input: String = get_user_input(
+            input_line,
+            "Enter an item name (type 'list' to show available items)",
+        )?;
+        if let Some(item) = find_item_in_pools(&input, items_database, item_pool_types) {
+            return Ok(item);
+        } else if sanitize_item_name(&input) == "list" {
+            //print list of items
+            if item_pool_types.contains(ItemPoolType::Legendary) {
+                println!("\nLegendary items in database:");
+                for item in items_database.legendary_items() {
+                    println!("- {item:#}");
+                }
+            }
+            if item_pool_types.contains(ItemPoolType::Boots) {
+                println!("\nBoots in database:");
+                for item in items_database.boots() {
+                    println!("- {item:#}");
+                }
+            }
+            if item_pool_types.contains(ItemPoolType::Support) {
+                println!("\nSupport items in database:");
+                for item in items_database.supp_items() {
+                    println!("- {item:#}");
+                }
+            }
+            if item_pool_types.contains(ItemPoolType::Component) {
+                println!("\nComponent items in database:");
+                for item in items_database.component_items() {
+                    println!("- {item:#}");
+                }
+            }
+            if item_pool_types.contains(ItemPoolType::Starter) {
+                println!("\nStarter items in database:");
+                for item in items_database.starter_items() {
+                    println!("- {item:#}");
+                }
+            }
+            if item_pool_types.contains(ItemPoolType::Consumable) {
+                println!("\nConsumables in database:");
+                for item in items_database.consumables() {
+                    println!("- {item:#}");
+                }
+            }
+        } else {
+            println!("'{input}' is not a recognized item (type 'list' to show available items)");
+        }
+    }
+}
+
+/// Handle the whole build generation with the user.
+/// This function never returns `Err(UserCommand::back)` because cannot go further back.
+fn builds_generation_screen(
+    champ_properties: &'static UnitProperties,
+    game_data: &'static GameDataSet,
+) -> Result<(), UserCommand> {
+    //create build generation settings
+    let mut settings: BuildsGenerationSettings =
+        BuildsGenerationSettings::default_by_champion(champ_properties);
+    settings.use_game_data(game_data);
+
+    loop {
+        //set build generation settings
+        match confirm_builds_generation_settings(&mut settings, champ_properties) {
+            Ok(()) => (),
+            Err(UserCommand::Back) => return Ok(()),
+            Err(command) => return Err(command),
+        };
+
+        //compute best builds
+        println!(" ");
+        let mut pareto_builds: Vec<BuildContainer> = match find_best_builds(
+            champ_properties,
+            &settings,
+            &mut ProgressBarObserver::default(),
+        ) {
+            Ok(pareto_builds) => pareto_builds,
+            Err(error_msg) => {
+                get_user_raw_input(&format!(
+                        "\nFailed to generate builds: {error_msg} (press enter to return to settings screen)"
+                    )).ok_or(UserCommand::Exit)?;
+                continue;
+            }
+        };
+        sort_builds_by_score(&mut pareto_builds, settings.weights);
+
+        //results screen
+        let mut n_to_print: NonZeroUsize = NonZeroUsize::new(DEFAULT_N_PRINTED_BUILDS)
+            .expect("Failed to create NonZeroUsize from DEFAULT_N_PRINTED_BUILDS");
+        let mut must_have_utils: EnumSet<ItemUtils> = enum_set!();
+        loop {
+            println!(" ");
+            print_builds_scores(
+                &pareto_builds,
+                champ_properties.name,
+                settings.weights,
+                n_to_print,
+                must_have_utils,
+            );
+
+            //prompt for what's next
+            let choice: usize = match get_user_choice(
+                " ",
+                "\nSelect an action (press enter to return to champion selection)",
+                "How to interpret the columns from left to right:\n \
+                    - score: the overall score of the build\n \
+                    - !h/s : if the build has anti heal/shield utility\n \
+                    - surv : if the build has survivability utility (e.g. zhonyas stasis, edge of night spell shield, ...)\n \
+                    - spec : if the build has special utility (e.g. RFC bonus range, black cleaver armor reduction, ...)\n \
+                    - build: the build in item order (with the score at each item slot in brackets)",
+                [
+                    &format!(
+                        "only show builds with anti heal/shield utility: {}",
+                        must_have_utils.contains(ItemUtils::AntiHealShield)
+                    ),
+                    &format!(
+                        "only show builds with survivability utility: {}",
+                        must_have_utils.contains(ItemUtils::Survivability)
+                    ),
+                    &format!(
+                        "only show builds with special utility: {}",
+                        must_have_utils.contains(ItemUtils::Special)
+                    ),
+                    "choose the number of builds to show",
+                    "export results to a file (JSON or CSV)",
+                    "compare skill orders on the best build (the best one replaces the current setting)",
+                    "find the best purchase path towards the best build",
+                    "compare starting items at an early lvl",
+                    "return to build generation settings",
+                ],
+                true,
+            ) {
+                Ok(Some(choice)) => choice,
+                Ok(None) => return Ok(()),
+                Err(UserCommand::Back) => break,
+                Err(command) => return Err(command),
+            };
+
+            match choice {
+                1 => must_have_utils ^= enum_set!(ItemUtils::AntiHealShield),
+                2 => must_have_utils ^= enum_set!(ItemUtils::Survivability),
+                3 => must_have_utils ^= enum_set!(ItemUtils::Special),
+                4 => match get_user_usize(
+                    "",
+                    "\nEnter the number of builds to show",
+                    "",
+                    1..,   //safety of a later unwrap depends on this range to exclude 0
+                    false, //safety of a later expect() depends on this argument to be false
+                ) {
+                    Ok(n) => {
+                        n_to_print = NonZeroUsize::new(
+                            n.expect("Expected an input from user, but received none"),
+                        )
+                        .unwrap(); //should never panic as we prevent choice from being 0 above
+                    }
+                    Err(UserCommand::Back) => (),
+                    Err(command) => return Err(command),
+                },
+                5 => export_results(&pareto_builds, champ_properties, &settings)?,
+                6 => {
+                    //pareto builds are never empty after a successful generation
+                    let best_build: &Build = &pareto_builds[0].build;
+                    match find_best_skill_orders(champ_properties, &settings, best_build) {
+                        Ok(best_skill_orders) => {
+                            print_skill_orders_scores(&best_skill_orders, best_build);
+                            settings.skill_order = Some(best_skill_orders[0].0); //should never go out of bounds since `MaxOrder::all` is never empty
+                            println!("{} has replaced the previous skill order setting (regenerate builds to use it).", best_skill_orders[0].0);
+                        }
+                        Err(error_msg) => println!("\nFailed to compare skill orders: {error_msg}"),
+                    }
+                    get_user_raw_input("press enter to return to results screen")
+                        .ok_or(UserCommand::Exit)?;
+                }
+                7 => {
+                    //pareto builds are never empty after a successful generation
+                    find_purchase_path(champ_properties, &settings, &pareto_builds[0].build)?;
+                }
+                8 => {
+                    match get_user_usize(
+                        "",
+                        "\nEnter the lvl to compare starting items at",
+                        STARTING_ITEMS_HELP_MSG,
+                        usize::from(MIN_UNIT_LVL)..=5,
+                        false,
+                    ) {
+                        Ok(lvl) => {
+                            let lvl: u8 = u8::try_from(
+                                lvl.expect("Expected an input from user, but received none"),
+                            )
+                            .expect("Lvl should fit in u8 after range check");
+                            match find_best_starting_items(champ_properties, &settings, lvl) {
+                                Ok(best_starting_items) => {
+                                    print_starting_items_scores(&best_starting_items, lvl);
+                                }
+                                Err(error_msg) => {
+                                    println!("\nFailed to compare starting items: {error_msg}");
+                                }
+                            }
+                            get_user_raw_input("press enter to return to results screen")
+                                .ok_or(UserCommand::Exit)?;
+                        }
+                        Err(UserCommand::Back) => (),
+                        Err(command) => return Err(command),
+                    }
+                }
+                9 => break,
+                _ => unreachable!("Unhandled user input"),
+            }
+        }
+    }
+}
+
+const STARTING_ITEMS_HELP_MSG: &str =
+    "Each starter item is compared with the health potions that can be bought with the remaining starting golds.\n\
+     Fights are simulated at the given lvl against targets of the same lvl, abilities that are not lvled up yet\n\
+     according to the skill order are not used.";
+
+/// Prints the starting items choices with their score.
+fn print_starting_items_scores(best_starting_items: &[(Build, f32)], lvl: u8) {
+    println!("\nStarting items at lvl {lvl}, from best to worst:");
+    for (build, score) in best_starting_items {
+        println!(
+            " - {} (score: {score:.0})",
+            build
+                .iter()
+                .filter(|&&item| *item != Item::NULL_ITEM)
+                .map(|item| item.short_name)
+                .collect::<Vec<&str>>()
+                .join(", ")
+        );
+    }
+}
+
+const PURCHASE_PATH_HELP_MSG: &str =
+    "Recall times are the game times (in minutes) at which the champion goes back to buy items, separated by commas.\n\
+     Golds and lvl at each recall follow the golds and experience income from farming.\n\
+     At the start of the game and at each recall, the components and finished items of the build to buy are chosen\n\
+     to maximize the score averaged over the game time (finished items can be bought in any order).";
+
+/// Default recall times (in minutes) proposed when finding the best purchase path.
+const DEFAULT_RECALL_TIMES: &str = "4,9,14,20,26";
+
+/// This function never returns `Err(UserCommand::back)`.
+fn find_purchase_path(
+    champ_properties: &'static UnitProperties,
+    settings: &BuildsGenerationSettings,
+    build: &Build,
+) -> Result<(), UserCommand> {
+    loop {
+        let input: String = match get_user_input(
+            &format!(
+                "\nEnter the recall times in minutes separated by commas (press enter for {DEFAULT_RECALL_TIMES})"
+            ),
+            PURCHASE_PATH_HELP_MSG,
+        ) {
+            Ok(input) => input,
+            Err(UserCommand::Back) => return Ok(()),
+            Err(command) => return Err(command),
+        };
+        let input: &str = if input.is_empty() {
+            DEFAULT_RECALL_TIMES
+        } else {
+            &input
+        };
+
+        match parse_recall_times(input).and_then(|recall_times| {
+            find_best_purchase_path(champ_properties, settings, build, &recall_times)
+        }) {
+            Ok(purchase_path) => {
+                print_purchase_path(&purchase_path);
+                get_user_raw_input("press enter to return to results screen")
+                    .ok_or(UserCommand::Exit)?;
+                return Ok(());
+            }
+            Err(error_msg) => println!("Failed to find a purchase path: {error_msg}"),
+        }
+    }
+}
+
+/// Prints the items bought and the inventory held at each purchase time of the purchase path.
+fn print_purchase_path(purchase_path: &PurchasePath) {
+    println!(
+        "\nBest purchase path (average score over the game: {:.0}):",
+        purchase_path.avg_score
+    );
+    for (idx, time) in purchase_path.times.iter().enumerate() {
+        let bought: &[&Item] = &purchase_path.purchases[idx];
+        let bought: String = if bought.is_empty() {
+            "nothing".to_string()
+        } else {
+            bought
+                .iter()
+                .map(|item| item.short_name)
+                .collect::<Vec<&str>>()
+                .join(", ")
+        };
+        println!(
+            " - {time:>5.1}min: buy {bought} -> [{}] ({:.0} golds left, score: {:.0})",
+            purchase_path.inventories[idx]
+                .iter()
+                .filter(|&&item| *item != Item::NULL_ITEM)
+                .map(|item| item.short_name)
+                .collect::<Vec<&str>>()
+                .join(", "),
+            purchase_path.unspent_golds[idx],
+            purchase_path.scores[idx]
+        );
+    }
+}
+
+const EXPORT_HELP_MSG: &str =
+    "Builds are exported in the order shown, with the settings used, the average score of each build\n\
+     and the values of golds, DPS, defense and mobility at each item slot.\n\
+     The format is chosen from the file extension ('.json' or '.csv').";
+
+/// This function never returns `Err(UserCommand::back)`.
+fn export_results(
+    builds: &[BuildContainer],
+    champ_properties: &UnitProperties,
+    settings: &BuildsGenerationSettings,
+) -> Result<(), UserCommand> {
+    loop {
+        let path: PathBuf = match get_user_path(
+            "\nEnter the path of the file to export results to",
+            EXPORT_HELP_MSG,
+            Path::new(DEFAULT_EXPORTS_DIR)
+                .join(champ_properties.name.to_lowercase())
+                .with_extension("json"),
+        ) {
+            Ok(path) => path,
+            Err(UserCommand::Back) => return Ok(()),
+            Err(command) => return Err(command),
+        };
+
+        let Some(format) = ExportFormat::from_path(&path) else {
+            println!("Unrecognized file extension (expected '.json' or '.csv')");
+            continue;
+        };
+        match export_builds(&path, format, builds, champ_properties, settings) {
+            Ok(()) => println!("\nResults have been exported to '{}'.", path.display()),
+            Err(error_msg) => println!("\nFailed to export results: {error_msg}"),
+        }
+        return Ok(());
+    }
+}
+
+const BUILDS_GENERATION_SETTINGS_HELP_MSG: &str = concat!(
+    "Meaning of these settings:\n\
+    -1) target:\n",
+    TARGET_HELP_MSG,
+    "\n\n-2) fight scenario:\n",
+    FIGHT_SCENARIO_HELP_MSG,
+    "\n\n-3) fight duration:\n",
+    FIGHT_DURATION_HELP_MSG,
+    "\n\n-4) percentage of physical damage received:\n",
+    PHYS_DMG_RECEIVED_PERCENT_HELP_MSG,
+    "\n\n-5) go to incoming damage settings:\n",
+    INCOMING_DMG_HELP_MSG,
+    "\n\n-6) percentage of time the target flees:\n",
+    TARGET_FLEE_PERCENT_HELP_MSG,
+    "\n\n-7) teamfight targets:\n",
+    TEAMFIGHT_TARGETS_HELP_MSG,
+    "\n\n-8) enemy team:\n",
+    ENEMY_TEAM_HELP_MSG,
+    "\n\n-9) go to runes settings:\n\
+         change rune keystone, minor runes and rune shards.",
+    "\n\n-10) summoner spells:\n",
+    SUMMONER_SPELLS_HELP_MSG,
+    "\n\n-11) skill order:\n",
+    SKILL_ORDER_HELP_MSG,
+    "\n\n-12) go to items settings:\n\
+         manage items rules (such as when boots must be purchased, which items are allowed, etc.)",
+    "\n\n-13) weights:\n\
+         4 values, for DPS, defense, mobility and time to kill. These vales are used to weight\n\
+         the relative importance of DPS, defense, mobility and time to kill the target of the champion\n\
+         in a single score value given to a build. The weights are relative to each other,\n\
+         i.e. DPS 3, defense 2, mobility 1 is the same as DPS 1, defense 0.67, mobility 0.33.\n\
+         The time to kill is only considered if its weight is non-zero (it then also becomes\n\
+         an objective of the pareto front, which increases the number of builds kept).",
+    "\n\n-14) search threshold:\n",
+    SEARCH_THRESHOLD_HELP_MSG,
+    "\n\n-15) game patch:\n",
+    PATCH_HELP_MSG,
+    "\n\n-16) save settings to a preset file:\n",
+    PRESET_HELP_MSG,
+    "\n\n-17) load settings from a preset file:\n",
+    PRESET_HELP_MSG,
+);
+
+/// Show the build generation settings, prompt the user for any change and returns the settings when done.
+fn confirm_builds_generation_settings(
+    settings: &mut BuildsGenerationSettings,
+    champ_properties: &'static UnitProperties,
+) -> Result<(), UserCommand> {
+    loop {
+        let choice: usize = match get_user_choice(
+            format!(
+                "\nBuild generation for {} will be launched with these settings:",
+                champ_properties.name
+            )
+            .as_str(),
+            "\nSelect a setting to change (press enter to confirm current settings)",
+            BUILDS_GENERATION_SETTINGS_HELP_MSG,
+            [
+                format!("target: {}", settings.target).as_str(),
+                if let Some(script) = &settings.fight_script {
+                    format!("fight scenario: custom fight script ({script})")
+                } else {
+                    format!(
+                        "fight scenario: {}",
+                        champ_properties
+                            .fight_scenarios_names()
+                            .nth(settings.fight_scenario_number.get() - 1)
+                            .unwrap_or_default()
+                    )
+                }
+                .as_str(),
+                format!(
+                    "fight duration: {}s{}",
+                    settings.fight_duration,
+                    if settings.fight_duration < LOW_FIGHT_DURATION_VALUE_WARNING {
+                        format!(
+                        " (/!\\ set to a low value (<{}s), this can lead to inaccurate results)",
+                        LOW_FIGHT_DURATION_VALUE_WARNING
+                    )
+                    } else {
+                        "".to_string()
+                    }
+                )
+                .as_str(),
+                format!(
+                    "percentage of physical damage received (by {}): {:.0}%{}",
+                    champ_properties.name,
+                    100. * settings.effective_phys_dmg_received_percent(),
+                    if settings.enemy_team.is_empty() {
+                        ""
+                    } else {
+                        " (derived from the enemy team)"
+                    }
+                )
+                .as_str(),
+                format!(
+                    "go to incoming damage settings (current: {}) ->",
+                    settings
+                        .incoming_dmg
+                        .map_or_else(|| "none".to_string(), |profile| profile.to_string())
+                )
+                .as_str(),
+                format!(
+                    "percentage of time the target flees: {:.0}%",
+                    100. * settings.target_flee_percent,
+                )
+                .as_str(),
+                format!(
+                    "teamfight targets: {}",
+                    if settings.teamfight_targets.is_empty() {
+                        "none (single target)".to_string()
+                    } else {
+                        settings
+                            .teamfight_targets
+                            .iter()
+                            .map(TargetDefinition::to_string)
+                            .collect::<Vec<String>>()
+                            .join(", ")
+                    }
+                )
+                .as_str(),
+                format!(
+                    "enemy team: {}",
+                    if settings.enemy_team.is_empty() {
+                        "none (single target)".to_string()
+                    } else {
+                        settings
+                            .enemy_team
+                            .iter()
+                            .map(EnemyTeamMember::to_string)
+                            .collect::<Vec<String>>()
+                            .join(", ")
+                    }
+                )
+                .as_str(),
+                format!(
+                    "go to runes settings (current keystone: {}) ->",
+                    settings.runes_page.keystone
+                )
+                .as_str(),
+                format!("summoner spells: {}", settings.summoner_spells).as_str(),
+                format!(
+                    "skill order: {}",
+                    settings.skill_order.map_or_else(
+                        || "default skill order of the champion".to_string(),
+                        |max_order| max_order.to_string()
+                    )
+                )
+                .as_str(),
+                format!(
+                    "go to economy settings (current: {}) ->",
+                    settings.economy
+                )
+                .as_str(),
+                "go to items settings ->",
+                format!(
+                    "weights: DPS {:.2}, defense {:.2}, mobility {:.2}, time to kill {:.2}",
+                    settings.weights.0,
+                    settings.weights.1,
+                    settings.weights.2,
+                    settings.weights.3
+                )
+                .as_str(),
+                format!(
+                    "search threshold: {:.0}%{}",
+                    100. * settings.search_threshold,
+                    if settings.search_threshold < LOW_SEARCH_THRESHOLD_VALUE_WARNING {
+                        format!(
+                        " (/!\\ set to a low value (<{:.0}%), this can lead to inaccurate results)",
+                        100. * LOW_SEARCH_THRESHOLD_VALUE_WARNING
+                    )
+                    } else if settings.search_threshold > HIGH_SEARCH_THRESHOLD_VALUE_WARNING {
+                        format!(
+                        " (/!\\ set to a high value (>{:.0}%), this can result in long computation time)",
+                        100. * HIGH_SEARCH_THRESHOLD_VALUE_WARNING
+                    )
+                    } else {
+                        "".to_string()
+                    }
+                ).as_str(),
+                format!(
+                    "game patch: {} (items data: {})",
+                    settings.game_data.patch, settings.game_data.items_database.source
+                )
+                .as_str(),
+                "save settings to a preset file",
+                "load settings from a preset file",
+                "reset all settings to default",
+            ],
+            true,
+        )? {
+            Some(choice) => choice,
+            None => return Ok(()),
+        };
+
+        match choice {
+            1 => {
+                //target
+                change_target(settings, champ_properties)?;
+            }
+            2 => {
+                //fight_scenario
+                change_fight_scenario_number(settings, champ_properties)?;
+            }
+            3 => {
+                //fight_duration
+                change_fight_duration(settings, champ_properties)?;
+            }
+            4 => {
+                //phys_dmg_received_percent
+                change_phys_dmg_received_percent(settings, champ_properties)?;
+            }
+            5 => {
+                //incoming dmg
+                incoming_dmg_settings_screen(settings, champ_properties)?;
+            }
+            6 => {
+                //target_flee_percent
+                change_target_flee_percent(settings, champ_properties)?;
+            }
+            7 => {
+                //teamfight_targets
+                change_teamfight_targets(settings, champ_properties)?;
+            }
+            8 => {
+                //enemy_team
+                change_enemy_team(settings, champ_properties)?;
+            }
+            9 => {
+                //change runes
+                runes_settings_screen(settings, champ_properties)?;
+            }
+            10 => {
+                //summoner spells
+                change_summoner_spells(settings, champ_properties)?;
+            }
+            11 => {
+                //skill order
+                change_skill_order(settings, champ_properties)?;
+            }
+            12 => {
+                //economy
+                economy_settings_screen(settings, champ_properties)?;
+            }
+            13 => {
+                //items settings
+                items_settings_screen(settings, champ_properties)?;
+            }
+            14 => {
+                //weights
+                change_weights(settings, champ_properties)?;
+            }
+            15 => {
+                //search_threshold
+                change_search_threshold(settings, champ_properties)?;
+            }
+            16 => {
+                //patch
+                change_patch(settings, champ_properties)?;
+            }
+            17 => {
+                //save preset
+                save_preset(settings, champ_properties)?;
+            }
+            18 => {
+                //load preset
+                load_preset(settings, champ_properties)?;
+            }
+            19 => {
+                //reset all settings to default (keeping the same patch)
+                let game_data: &'static GameDataSet = settings.game_data;
+                *settings = BuildsGenerationSettings::default_by_champion(champ_properties);
+                settings.use_game_data(game_data);
+                println!("\nAll settings have been reset to default.");
+            }
+            _ => unreachable!("Unhandled user input"),
+        }
+    }
+}
+
+const PATCH_HELP_MSG: &str =
+    "Game data (champions and items values) used for the builds generation.\n\
+     Other patches are read from their directory in 'data/patches' (e.g. 'data/patches/14.23'),\n\
+     containing an items data file ('items.toml') and/or a Data Dragon snapshot ('champion.json' and 'item.json').\n\
+     Items that are not available in the selected patch are removed from the settings.";
+
+/// This function never returns `Err(UserCommand::back)`.
+fn change_patch(
+    settings: &mut BuildsGenerationSettings,
+    champ_properties: &UnitProperties,
+) -> Result<(), UserCommand> {
+    let patches: Vec<String> = available_patches(Path::new(DEFAULT_PATCHES_DIR));
+    loop {
+        let index: usize = match get_user_matching_input(
+            &format!("\nAvailable patches: {}", patches.join(", ")),
+            "\nEnter the patch to use",
+            "\nPlease enter a valid patch (among those available)",
+            PATCH_HELP_MSG,
+            patches.iter().map(String::as_str),
+            false, //safety of a later expect() depends on this argument to be false
+        ) {
+            Ok(index) => index.expect("Expected an input from user, but received none"),
+            Err(UserCommand::Back) => return Ok(()),
+            Err(command) => return Err(command),
+        };
+
+        let game_data: &'static GameDataSet = match load_game_data(Some(&patches[index]), None) {
+            Ok(game_data) => game_data,
+            Err(error_msg) => {
+                println!("Failed to load patch: {error_msg}");
+                continue;
+            }
+        };
+        let old_settings: BuildsGenerationSettings = settings.clone(); //backup before checking validity
+        settings.use_game_data(game_data);
+
+        if let Err(error_msg) = settings.check_settings(champ_properties) {
+            println!("Failed to change patch: {error_msg}");
+            *settings = old_settings; //restore valid settings
+        } else {
+            return Ok(());
+        }
+    }
+}
+
+const PRESET_HELP_MSG: &str =
+    "Presets are human-editable TOML files containing builds generation settings.\n\
+     Items are referenced by their short name, the target and the rune keystone by their name.\n\
+     Settings missing from a preset are set to their default values when loading it.";
+
+/// Returns the default path of the preset file for the given champion.
+fn default_preset_path(champ_properties: &UnitProperties) -> PathBuf {
+    Path::new(DEFAULT_PRESETS_DIR)
+        .join(champ_properties.name.to_lowercase())
+        .with_extension(PRESET_FILE_EXTENSION)
+}
+
+/// Prompts the user to enter a file path, returns `default_path` if the user enters no input.
+fn get_user_path(
+    input_line: &str,
+    help_msg: &str,
+    default_path: PathBuf,
+) -> Result<PathBuf, UserCommand> {
+    let input: String = get_user_case_sensitive_input(
+        &format!(
+            "{input_line} (press enter for '{}')",
+            default_path.display()
+        ),
+        help_msg,
+    )?;
+    if input.is_empty() {
+        Ok(default_path)
+    } else {
+        Ok(PathBuf::from(input))
+    }
+}
+
+/// This function never returns `Err(UserCommand::back)`.
+fn save_preset(
+    settings: &BuildsGenerationSettings,
+    champ_properties: &UnitProperties,
+) -> Result<(), UserCommand> {
+    let path: PathBuf = match get_user_path(
+        "\nEnter the path of the preset file to save",
+        PRESET_HELP_MSG,
+        default_preset_path(champ_properties),
+    ) {
+        Ok(path) => path,
+        Err(UserCommand::Back) => return Ok(()),
+        Err(command) => return Err(command),
+    };
+
+    match save_settings_preset(&path, settings, champ_properties) {
+        Ok(()) => println!("\nSettings have been saved to '{}'.", path.display()),
+        Err(error_msg) => println!("\nFailed to save preset: {error_msg}"),
+    }
+    Ok(())
+}
+
+/// This function never returns `Err(UserCommand::back)`.
+fn load_preset(
+    settings: &mut BuildsGenerationSettings,
+    champ_properties: &UnitProperties,
+) -> Result<(), UserCommand> {
+    loop {
+        let path: PathBuf = match get_user_path(
+            "\nEnter the path of the preset file to load",
+            PRESET_HELP_MSG,
+            default_preset_path(champ_properties),
+        ) {
+            Ok(path) => path,
+            Err(UserCommand::Back) => return Ok(()),
+            Err(command) => return Err(command),
+        };
+
+        let preset: SettingsPreset = match SettingsPreset::load(&path) {
+            Ok(preset) => preset,
+            Err(error_msg) => {
+                println!("Failed to load preset: {error_msg}");
+                continue;
+            }
+        };
+        //use the patch of the preset if it differs from the current one
+        let game_data: &'static GameDataSet = match preset
+            .patch
+            .as_deref()
+            .filter(|&patch| patch != settings.game_data.patch)
+        {
+            Some(patch) => match load_game_data(Some(patch), None) {
+                Ok(game_data) => game_data,
+                Err(error_msg) => {
+                    println!(
+                        "\nNote: failed to load the patch of this preset ({error_msg}), using patch {} instead.",
+                        settings.game_data.patch
+                    );
+                    settings.game_data
+                }
+            },
+            None => settings.game_data,
+        };
+        match preset.to_settings(champ_properties, game_data) {
+            Ok(loaded_settings) => {
+                *settings = loaded_settings;
+                if let Some(champ_name) = preset
+                    .champion
+                    .as_ref()
+                    .filter(|name| !name.eq_ignore_ascii_case(champ_properties.name))
+                {
+                    println!(
+                        "\nNote: this preset was saved for {champ_name}, not {}.",
+                        champ_properties.name
+                    );
+                }
+                println!("\nSettings have been loaded from '{}'.", path.display());
+                return Ok(());
+            }
+            Err(error_msg) => println!("Failed to load preset: {error_msg}"),
+        }
+    }
+}
+
+const TARGET_HELP_MSG: &str =
+"The selected target resistances will be used to compute the champion's DPS.\n\
+Besides the dummies (whose items stats are included in their stats), the target can be a champion\n\
+with a build (CHAMPION:ITEM+ITEM+..., e.g. Varus:Jaksho+Randuins, with the champion's default runes)\n\
+or a unit with custom stats (custom:HP/ARMOR/MR[/HP_PER_LVL/ARMOR_PER_LVL/MR_PER_LVL]).";
+
+/// Format of targets entered by the user (see `TargetDefinition::parse`).
+const TARGET_INPUT_FORMAT: &str =
+    "CHAMPION:ITEM+ITEM+... or custom:HP/ARMOR/MR[/HP_PER_LVL/ARMOR_PER_LVL/MR_PER_LVL]";
+
+/// This function never returns `Err(UserCommand::back)`.
+fn change_target(
+    settings: &mut BuildsGenerationSettings,
+    champ_properties: &UnitProperties,
+) -> Result<(), UserCommand> {
+    loop {
+        let choice: usize = match get_user_choice(
+            "\nAvailable targets:",
+            "\nSelect a target",
+            TARGET_HELP_MSG,
+            TARGET_OPTIONS
+                .iter()
+                .map(|properties| properties.name)
+                .chain(core::iter::once("champion with a build or custom stats")),
+            false,
+        ) {
+            Ok(Some(choice)) => choice,
+            Ok(None) => return Ok(()), //should never get here because `allow_no_input` is false
+            Err(UserCommand::Back) => return Ok(()),
+            Err(command) => return Err(command),
+        };
+
+        let target: TargetDefinition = if let Some(&properties) = TARGET_OPTIONS.get(choice - 1) {
+            TargetDefinition::dummy(properties)
+        } else {
+            let input: String = match get_user_input(
+                &format!("\nEnter the target ({TARGET_INPUT_FORMAT})"),
+                TARGET_HELP_MSG,
+            ) {
+                Ok(input) => input,
+                Err(UserCommand::Back) => continue,
+                Err(command) => return Err(command),
+            };
+            match TargetDefinition::parse(&input, settings.game_data) {
+                Ok(target) => target,
+                Err(error_msg) => {
+                    println!("Failed to set target: {error_msg}");
+                    continue;
+                }
+            }
+        };
+
+        let old_target: TargetDefinition = core::mem::replace(&mut settings.target, target); //backup before checking validity
+
+        if let Err(error_msg) = settings.check_settings(champ_properties) {
+            println!("Failed to set target: {error_msg}");
+            settings.target = old_target; //restore valid value
+        } else {
+            return Ok(());
+        }
+    }
+}
+
+const FIGHT_SCENARIO_HELP_MSG: &str =
+"Each generated build will go through a fight simulation according to the selected scenario in order\n\
+to evaluate its performance. Therefore, the builds found will perform best for the selected scenario.\n\
+A custom fight script can be used instead, as a priority list of statements separated by ';'\n\
+(e.g. \"use actives; r once; q if ready; aa if ready; w if ready; walk otherwise\"). During the fight,\n\
+the first statement whose condition is met is executed, then the list is evaluated again from the start.\n\
+Actions: q, w, e, r, aa (basic attack), use actives, walk and weighted r (r damage weighted by its availability).\n\
+Conditions: if ready (default), once, otherwise (always, waiting for the cooldown if needed) and at end (weighted r).";
+
+/// This function never returns `Err(UserCommand::back)`.
+fn change_fight_scenario_number(
+    settings: &mut BuildsGenerationSettings,
+    champ_properties: &UnitProperties,
+) -> Result<(), UserCommand> {
+    loop {
+        let number: usize = match get_user_choice(
+            &format!(
+                "\nAvailable fight scenarios for {} are:",
+                champ_properties.name
+            ),
+            &format!("\nSelect a fight scenario for {}", champ_properties.name),
+            FIGHT_SCENARIO_HELP_MSG,
+            champ_properties
+                .fight_scenarios_names()
+                .chain(core::iter::once("custom fight script")),
+            false,
+        ) {
+            Ok(Some(choice)) => choice,
+            Ok(None) => return Ok(()), //should never get here because `allow_no_input` is false
+            Err(UserCommand::Back) => return Ok(()),
+            Err(command) => return Err(command),
+        };
+
+        let old_fight_scenario_number: NonZeroUsize = settings.fight_scenario_number; //backup before checking validity
+        let old_fight_script: Option<FightScript> = settings.fight_script.take();
+        if number > champ_properties.fight_scenarios_names().count() {
+            let input: String = match get_user_input(
+                "\nEnter the fight script (statements separated by ';')",
+                FIGHT_SCENARIO_HELP_MSG,
+            ) {
+                Ok(input) => input,
+                Err(UserCommand::Back) => {
+                    settings.fight_script = old_fight_script;
+                    continue;
+                }
+                Err(command) => {
+                    settings.fight_script = old_fight_script;
+                    return Err(command);
+                }
+            };
+            match input.parse::<FightScript>() {
+                Ok(script) => settings.fight_script = Some(script),
+                Err(error_msg) => {
+                    println!("Failed to set fight script: {error_msg}");
+                    settings.fight_script = old_fight_script;
+                    continue;
+                }
+            }
+        } else {
+            settings.fight_scenario_number =
+                NonZeroUsize::new(number).expect("Fight scenario number must be non-zero");
+        }
+
+        if let Err(error_msg) = settings.check_settings(champ_properties) {
+            println!("Failed to set fight scenario: {error_msg}");
+            settings.fight_scenario_number = old_fight_scenario_number; //restore valid values
+            settings.fight_script = old_fight_script;
+        } else {
+            return Ok(());
+        }
+    }
+}
+
+const FIGHT_DURATION_HELP_MSG: &str =
+"Every build will be evaluated based on a fight simulation of the selected duration (in seconds).";
+
+/// This function never returns `Err(UserCommand::back)`.
+fn change_fight_duration(
+    settings: &mut BuildsGenerationSettings,
+    champ_properties: &UnitProperties,
+) -> Result<(), UserCommand> {
+    loop {
+        let number: f32 = match get_user_f32(
+            "",
+            "\nEnter a fight duration",
+            FIGHT_DURATION_HELP_MSG,
+            false,
+        ) {
+            Ok(Some(number)) => number,
+            Ok(None) => return Ok(()), //should never get here because `allow_no_input` is false
+            Err(UserCommand::Back) => return Ok(()),
+            Err(command) => return Err(command),
+        };
+
+        let old_fight_duration: f32 = settings.fight_duration; //backup before checking validity
+        settings.fight_duration = number;
+
+        if let Err(error_msg) = settings.check_settings(champ_properties) {
+            println!("Failed to set fight duration: {error_msg}");
+            settings.fight_duration = old_fight_duration; //restore valid value
+        } else {
+            return Ok(());
+        }
+    }
+}
+
+const PHYS_DMG_RECEIVED_PERCENT_HELP_MSG: &str =
+"The selected percentage of physical dmg received will be considered when evaluating the defensive value\n\
+of different builds. The percentage of magic dmg received is deducted from this (assuming no true dmg received).";
+
+/// This function never returns `Err(UserCommand::back)`.
+fn change_phys_dmg_received_percent(
+    settings: &mut BuildsGenerationSettings,
+    champ_properties: &UnitProperties,
+) -> Result<(), UserCommand> {
+    loop {
+        let number: f32 = match get_user_f32(
+            "",
+            format!(
+                "\nEnter the percentage of physical dmg received by {}",
+                champ_properties.name
+            )
+            .as_str(),
+            PHYS_DMG_RECEIVED_PERCENT_HELP_MSG,
+            false,
+        ) {
+            Ok(Some(number)) => number,
+            Ok(None) => return Ok(()), //should never get here because `allow_no_input` is false
+            Err(UserCommand::Back) => return Ok(()),
+            Err(command) => return Err(command),
+        };
+
+        let old_phys_dmg_received_percent: f32 = settings.phys_dmg_received_percent; //backup before checking validity
+        settings.phys_dmg_received_percent = number / 100.;
+
+        if let Err(error_msg) = settings.check_settings(champ_properties) {
+            println!("Failed to set percentage of physical dmg received: {error_msg}");
+            settings.phys_dmg_received_percent = old_phys_dmg_received_percent; //restore valid value
+        } else {
+            return Ok(());
+        }
+    }
+}
+
+const TARGET_FLEE_PERCENT_HELP_MSG: &str =
+"Percentage of time the target spends moving away from the champion during the fight (0% for a stationary target).\n\
+When the target is out of range, the champion must walk back in range before basic attacking,\n\
+so attack range and move speed affect the number of basic attacks landed.";
+
+/// This function never returns `Err(UserCommand::back)`.
+fn change_target_flee_percent(
+    settings: &mut BuildsGenerationSettings,
+    champ_properties: &UnitProperties,
+) -> Result<(), UserCommand> {
+    loop {
+        let number: f32 = match get_user_f32(
+            "",
+            "\nEnter the percentage of time the target flees",
+            TARGET_FLEE_PERCENT_HELP_MSG,
+            false,
+        ) {
+            Ok(Some(number)) => number,
+            Ok(None) => return Ok(()), //should never get here because `allow_no_input` is false
+            Err(UserCommand::Back) => return Ok(()),
+            Err(command) => return Err(command),
+        };
+
+        let old_target_flee_percent: f32 = settings.target_flee_percent; //backup before checking validity
+        settings.target_flee_percent = number / 100.;
+
+        if let Err(error_msg) = settings.check_settings(champ_properties) {
+            println!("Failed to set percentage of time the target flees: {error_msg}");
+            settings.target_flee_percent = old_target_flee_percent; //restore valid value
+        } else {
+            return Ok(());
+        }
+    }
+}
+
+const TEAMFIGHT_TARGETS_HELP_MSG: &str =
+"Enemy units fought by the champion instead of a single target (up to 5, e.g. tank, bruiser, squishy).\n\
+Targets can also be champions with a build or units with custom stats (same format as the target setting).\n\
+Each enemy unit has its own stats, hp and debuffs, damage on multiple targets is dealt to the other enemy units\n\
+and the champion switches to the next enemy unit when its target dies (debuffs on the dead target are lost).\n\
+The time to kill is then the time to kill every enemy unit.";
+
+/// This function never returns `Err(UserCommand::back)`.
+fn change_teamfight_targets(
+    settings: &mut BuildsGenerationSettings,
+    champ_properties: &UnitProperties,
+) -> Result<(), UserCommand> {
+    loop {
+        let input: String = match get_user_input(
+            &format!(
+                "\nAvailable targets: {}, {TARGET_INPUT_FORMAT}\nEnter the teamfight targets separated by commas (press enter for a single target)",
+                TARGET_OPTIONS
+                    .iter()
+                    .map(|properties| properties.name)
+                    .collect::<Vec<&str>>()
+                    .join(", ")
+            ),
+            TEAMFIGHT_TARGETS_HELP_MSG,
+        ) {
+            Ok(input) => input,
+            Err(UserCommand::Back) => return Ok(()),
+            Err(command) => return Err(command),
+        };
+
+        let teamfight_targets: Vec<TargetDefinition> = if input.trim().is_empty() {
+            Vec::new()
+        } else {
+            match input
+                .split(',')
+                .map(|target| TargetDefinition::parse(target, settings.game_data))
+                .collect::<Result<Vec<TargetDefinition>, String>>()
+            {
+                Ok(targets) => targets,
+                Err(error_msg) => {
+                    println!("Failed to set teamfight targets: {error_msg}");
+                    continue;
+                }
+            }
+        };
+
+        let old_teamfight_targets: Vec<TargetDefinition> =
+            core::mem::replace(&mut settings.teamfight_targets, teamfight_targets); //backup before checking validity
+
+        if let Err(error_msg) = settings.check_settings(champ_properties) {
+            println!("Failed to set teamfight targets: {error_msg}");
+            settings.teamfight_targets = old_teamfight_targets; //restore valid value
+        } else {
+            return Ok(());
+        }
+    }
+}
+
+const ENEMY_TEAM_HELP_MSG: &str =
+"Enemy team composition to optimize against instead of a single target (up to 5 enemies, same format as\n\
+the target setting, each followed by an optional focus weight, e.g. Varus:IE+Berserkers@2, tank, squishy@0.5).\n\
+The champion fights each enemy separately and the scores are averaged according to the focus weights\n\
+(relative to each other). The percentage of physical damage received is derived from the damage dealt\n\
+by the enemy champions (dummies and custom targets are ignored). Cannot be used with teamfight targets.";
+
+/// This function never returns `Err(UserCommand::back)`.
+fn change_enemy_team(
+    settings: &mut BuildsGenerationSettings,
+    champ_properties: &UnitProperties,
+) -> Result<(), UserCommand> {
+    loop {
+        let input: String = match get_user_input(
+            &format!(
+                "\nAvailable targets: {}, {TARGET_INPUT_FORMAT}\nEnter the enemies separated by commas, each with an optional @FOCUS_WEIGHT (press enter for a single target)",
+                TARGET_OPTIONS
+                    .iter()
+                    .map(|properties| properties.name)
+                    .collect::<Vec<&str>>()
+                    .join(", ")
+            ),
+            ENEMY_TEAM_HELP_MSG,
+        ) {
+            Ok(input) => input,
+            Err(UserCommand::Back) => return Ok(()),
+            Err(command) => return Err(command),
+        };
+
+        let enemy_team: Vec<EnemyTeamMember> = if input.trim().is_empty() {
+            Vec::new()
+        } else {
+            match parse_enemy_team(&input, settings.game_data) {
+                Ok(enemy_team) => enemy_team,
+                Err(error_msg) => {
+                    println!("Failed to set enemy team: {error_msg}");
+                    continue;
+                }
+            }
+        };
+
+        let old_enemy_team: Vec<EnemyTeamMember> =
+            core::mem::replace(&mut settings.enemy_team, enemy_team); //backup before checking validity
+
+        if let Err(error_msg) = settings.check_settings(champ_properties) {
+            println!("Failed to set enemy team: {error_msg}");
+            settings.enemy_team = old_enemy_team; //restore valid value
+        } else {
+            return Ok(());
+        }
+    }
+}
+
+const INCOMING_DMG_HELP_MSG: &str =
+"When enabled, the champion receives damage during the fight simulation and its hp is tracked,\n\
+the defense of a build is then the time survived instead of its effective hp (heals, shields and\n\
+stasis are valued according to when they happen). The damage received is split between physical\n\
+and magic damage according to the percentage of physical damage received (true damage excluded).\n\
+Damage values are given per champion lvl (pre-mitigation).";
+
+/// Returns the incoming dmg profile of the settings, or the default profile if the champion receives no dmg.
+fn incoming_dmg_or_default(settings: &BuildsGenerationSettings) -> IncomingDmgProfile {
+    settings.incoming_dmg.unwrap_or_default()
+}
+
+fn incoming_dmg_settings_screen(
+    settings: &mut BuildsGenerationSettings,
+    champ_properties: &UnitProperties,
+) -> Result<(), UserCommand> {
+    loop {
+        let profile: IncomingDmgProfile = incoming_dmg_or_default(settings);
+        let choice: usize = match get_user_choice(
+            "\nIncoming damage settings (changing a value enables incoming damage):",
+            "\nSelect a setting to change (press enter to confirm current settings)",
+            INCOMING_DMG_HELP_MSG,
+            [
+                format!(
+                    "receive damage during the fight: {}",
+                    settings.incoming_dmg.is_some()
+                )
+                .as_str(),
+                format!(
+                    "sustained damage per second per lvl: {}",
+                    profile.sustained_dps_per_lvl
+                )
+                .as_str(),
+                format!("burst damage per lvl: {}", profile.burst_dmg_per_lvl).as_str(),
+                format!("burst time: {}s", profile.burst_time).as_str(),
+                format!(
+                    "percentage of true damage received: {:.0}%",
+                    100. * profile.true_dmg_percent
+                )
+                .as_str(),
+                format!("grievous wounds: {}", profile.grievous_wounds).as_str(),
+            ],
+            true,
+        ) {
+            Ok(Some(choice)) => choice,
+            Ok(None) => return Ok(()),
+            Err(UserCommand::Back) => return Ok(()),
+            Err(command) => return Err(command),
+        };
+
+        match choice {
+            1 => {
+                settings.incoming_dmg = match settings.incoming_dmg {
+                    Some(_) => None,
+                    None => Some(IncomingDmgProfile::default()),
+                };
+            }
+            2 => change_incoming_dmg_value(
+                settings,
+                champ_properties,
+                "\nEnter the sustained damage received per second per lvl",
+                |profile, number| profile.sustained_dps_per_lvl = number,
+            )?,
+            3 => change_incoming_dmg_value(
+                settings,
+                champ_properties,
+                "\nEnter the burst damage received per lvl",
+                |profile, number| profile.burst_dmg_per_lvl = number,
+            )?,
+            4 => change_incoming_dmg_value(
+                settings,
+                champ_properties,
+                "\nEnter the time at which the burst is received (in seconds)",
+                |profile, number| profile.burst_time = number,
+            )?,
+            5 => change_incoming_dmg_value(
+                settings,
+                champ_properties,
+                "\nEnter the percentage of true damage received",
+                |profile, number| profile.true_dmg_percent = number / 100.,
+            )?,
+            6 => {
+                let mut profile: IncomingDmgProfile = incoming_dmg_or_default(settings);
+                profile.grievous_wounds = !profile.grievous_wounds;
+                settings.incoming_dmg = Some(profile);
+            }
+            _ => unreachable!("Unhandled user input"),
+        }
+    }
+}
+
+/// This function never returns `Err(UserCommand::back)`.
+fn change_incoming_dmg_value(
+    settings: &mut BuildsGenerationSettings,
+    champ_properties: &UnitProperties,
+    input_line: &str,
+    set_value: fn(&mut IncomingDmgProfile, f32),
+) -> Result<(), UserCommand> {
+    loop {
+        let number: f32 = match get_user_f32("", input_line, INCOMING_DMG_HELP_MSG, false) {
+            Ok(Some(number)) => number,
+            Ok(None) => return Ok(()), //should never get here because `allow_no_input` is false
+            Err(UserCommand::Back) => return Ok(()),
+            Err(command) => return Err(command),
+        };
+
+        let old_incoming_dmg: Option<IncomingDmgProfile> = settings.incoming_dmg; //backup before checking validity
+        let mut profile: IncomingDmgProfile = incoming_dmg_or_default(settings);
+        set_value(&mut profile, number);
+        settings.incoming_dmg = Some(profile);
+
+        if let Err(error_msg) = settings.check_settings(champ_properties) {
+            println!("Failed to set incoming damage: {error_msg}");
+            settings.incoming_dmg = old_incoming_dmg; //restore valid value
+        } else {
+            return Ok(());
+        }
+    }
+}
+
+const ECONOMY_HELP_MSG: &str =
+"The economy profile is the golds and experience income of the player, it is used to estimate\n\
+the champion lvl at each item slot (less experience per golds means a lower lvl for the same items)\n\
+and the golds available at each recall of a purchase path. We approximate that the golds income\n\
+only comes from cs and passive golds generation, so the cs/min should be a bit inflated to take\n\
+other sources of golds into account (kills, towers, ...). The experience per cs should be lowered\n\
+if the experience is shared with a lane partner. The time between fights is used to weight\n\
+effects with long cooldowns (items actives, lifelines, ultimates, ...) by their availability.";
+
+fn economy_settings_screen(
+    settings: &mut BuildsGenerationSettings,
+    champ_properties: &UnitProperties,
+) -> Result<(), UserCommand> {
+    loop {
+        let choice: usize = match get_user_choice(
+            "\nEconomy settings:",
+            "\nSelect a setting to change (press enter to confirm current settings)",
+            ECONOMY_HELP_MSG,
+            [
+                format!(
+                    "load a predefined profile ({})",
+                    EconomyProfile::PROFILES_NAMES.join(", ")
+                )
+                .as_str(),
+                format!("cs/min: {}", settings.economy.cs_per_min).as_str(),
+                format!("golds per cs: {:.2}", settings.economy.golds_per_cs).as_str(),
+                format!("experience per cs: {:.2}", settings.economy.xp_per_cs).as_str(),
+                format!(
+                    "passive golds generation per minute: {}",
+                    settings.economy.passive_golds_per_min
+                )
+                .as_str(),
+                format!(
+                    "time between fights: {}s",
+                    settings.economy.time_between_fights
+                )
+                .as_str(),
+            ],
+            true,
+        ) {
+            Ok(Some(choice)) => choice,
+            Ok(None) => return Ok(()),
+            Err(UserCommand::Back) => return Ok(()),
+            Err(command) => return Err(command),
+        };
+
+        match choice {
+            1 => {
+                let index: usize = match get_user_matching_input(
+                    "",
+                    "\nEnter the name of the profile to load",
+                    "\nPlease enter a valid profile name",
+                    ECONOMY_HELP_MSG,
+                    EconomyProfile::PROFILES_NAMES,
+                    false, //safety of a later expect() depends on this argument to be false
+                ) {
+                    Ok(index) => index.expect("Expected an input from user, but received none"),
+                    Err(UserCommand::Back) => continue,
+                    Err(command) => return Err(command),
+                };
+                settings.economy = EconomyProfile::from_name(EconomyProfile::PROFILES_NAMES[index])
+                    .expect("Failed to find predefined economy profile");
+            }
+            2 => change_economy_value(
+                settings,
+                champ_properties,
+                "\nEnter the cs/min of the player",
+                |economy, number| economy.cs_per_min = number,
+            )?,
+            3 => change_economy_value(
+                settings,
+                champ_properties,
+                "\nEnter the average golds per cs",
+                |economy, number| economy.golds_per_cs = number,
+            )?,
+            4 => change_economy_value(
+                settings,
+                champ_properties,
+                "\nEnter the average experience per cs",
+                |economy, number| economy.xp_per_cs = number,
+            )?,
+            5 => change_economy_value(
+                settings,
+                champ_properties,
+                "\nEnter the passive golds generation per minute",
+                |economy, number| economy.passive_golds_per_min = number,
+            )?,
+            6 => change_economy_value(
+                settings,
+                champ_properties,
+                "\nEnter the average time between fights (in seconds)",
+                |economy, number| economy.time_between_fights = number,
+            )?,
+            _ => unreachable!("Unhandled user input"),
+        }
+    }
+}
+
+/// This function never returns `Err(UserCommand::back)`.
+fn change_economy_value(
+    settings: &mut BuildsGenerationSettings,
+    champ_properties: &UnitProperties,
+    input_line: &str,
+    set_value: fn(&mut EconomyProfile, f32),
+) -> Result<(), UserCommand> {
+    loop {
+        let number: f32 = match get_user_f32("", input_line, ECONOMY_HELP_MSG, false) {
+            Ok(Some(number)) => number,
+            Ok(None) => return Ok(()), //should never get here because `allow_no_input` is false
+            Err(UserCommand::Back) => return Ok(()),
+            Err(command) => return Err(command),
+        };
+
+        let old_economy: EconomyProfile = settings.economy; //backup before checking validity
+        set_value(&mut settings.economy, number);
+
+        if let Err(error_msg) = settings.check_settings(champ_properties) {
+            println!("Failed to set economy profile value: {error_msg}");
+            settings.economy = old_economy; //restore valid value
+        } else {
+            return Ok(());
+        }
+    }
+}
+
+fn runes_settings_screen(
+    settings: &mut BuildsGenerationSettings,
+    champ_properties: &'static UnitProperties,
+) -> Result<(), UserCommand> {
+    loop {
+        let choice: usize = match get_user_choice(
+            "\nRunes settings:\n\
+            (/!\\ runes shard = runes bonus stats, not the runes slots under the keystone)",
+            "\nSelect a setting to change (press enter to confirm current runes)",
+            "",
+            [
+                format!("rune shard 1: {:?}", settings.runes_page.shard1).as_str(),
+                format!("rune shard 2: {:?}", settings.runes_page.shard2).as_str(),
+                format!("rune shard 3: {:?}", settings.runes_page.shard3).as_str(),
+                format!("rune keystone: {:#}", settings.runes_page.keystone).as_str(),
+                format!(
+                    "primary runes: {}",
+                    minor_runes_names(&settings.runes_page.primary_runes)
+                )
+                .as_str(),
+                format!(
+                    "secondary runes: {}",
+                    minor_runes_names(&settings.runes_page.secondary_runes)
+                )
+                .as_str(),
+                "automatically find the best runes keystones",
+                "automatically find the best runes page (keystone and shards)",
+                "reset to default runes page",
+            ],
+            true,
+        ) {
+            Ok(Some(choice)) => choice,
+            Ok(None) => return Ok(()),
+            Err(UserCommand::Back) => return Ok(()),
+            Err(command) => return Err(command),
+        };
+
+        match choice {
+            1 => {
+                //set rune shard 1
+                change_rune_shard(settings, champ_properties, 1)?;
+            }
+            2 => {
+                //set rune shard 2
+                change_rune_shard(settings, champ_properties, 2)?;
+            }
+            3 => {
+                //set rune shard 3
+                change_rune_shard(settings, champ_properties, 3)?;
+            }
+            4 => {
+                //change rune keystone
+                change_rune_keystone(settings, champ_properties)?;
+            }
+            5 => {
+                //change primary runes
+                change_primary_runes(settings, champ_properties)?;
+            }
+            6 => {
+                //change secondary runes
+                change_secondary_runes(settings, champ_properties)?;
+            }
+            7 => {
+                //find best runes keystones
+                let best_keystones: Vec<(&RuneKeystone, f32)> = match find_best_runes_keystones(
+                    champ_properties,
+                    settings,
+                    N_ITEMS_WHEN_FINDING_BEST_RUNES,
+                ) {
+                    Ok(best_keystones) => best_keystones,
+                    Err(error_msg) => {
+                        get_user_raw_input(&format!(
+                            "\nFailed to find best runes keystones: {error_msg} (press enter to return to runes settings screen)"
+                        )).ok_or(UserCommand::Exit)?;
+                        continue;
+                    }
+                };
+                settings.runes_page.set_keystone(best_keystones[0].0); //should never go out of bounds since `runes_data::ALL_RUNES_KEYSTONES` should never be empty
+
+                //print best runes keystone screen
+                println!("\nBest runes keystone in order:");
+                println!(
+                    " - {:#} (score: {:.0}) - has replaced the previous setting",
+                    best_keystones[0].0, best_keystones[0].1
+                );
+                for k in best_keystones[1..].iter() {
+                    println!(" - {:#} (score: {:.0})", k.0, k.1);
+                }
+
+                get_user_raw_input("press enter to return to runes settings screen")
+                    .ok_or(UserCommand::Exit)?;
+            }
+            8 => {
+                //find best runes page
+                let items_counts: Vec<usize> = match get_user_input(
+                    &format!("\nEnter the numbers of items to compare runes pages at, separated by commas (press enter for {N_ITEMS_WHEN_FINDING_BEST_RUNES})"),
+                    RUNES_PAGES_SEARCH_HELP_MSG,
+                ) {
+                    Ok(input) if input.is_empty() => vec![N_ITEMS_WHEN_FINDING_BEST_RUNES],
+                    Ok(input) => match parse_items_counts(&input) {
+                        Ok(items_counts) => items_counts,
+                        Err(error_msg) => {
+                            println!("Invalid input: {error_msg}");
+                            continue;
+                        }
+                    },
+                    Err(UserCommand::Back) => continue,
+                    Err(command) => return Err(command),
+                };
+                println!("Comparing runes pages, this may take a while...");
+                let best_runes_pages: Vec<(RunesPage, f32)> = match find_best_runes_pages(
+                    champ_properties,
+                    settings,
+                    &items_counts,
+                ) {
+                    Ok(best_runes_pages) => best_runes_pages,
+                    Err(error_msg) => {
+                        get_user_raw_input(&format!(
+                                "\nFailed to find best runes page: {error_msg} (press enter to return to runes settings screen)"
+                            )).ok_or(UserCommand::Exit)?;
+                        continue;
+                    }
+                };
+                print_runes_pages_scores(&best_runes_pages, &settings.runes_page);
+                settings.runes_page = best_runes_pages[0].0; //should never go out of bounds since the current runes page is always included
+                println!(
+                    "{} has replaced the previous runes page.",
+                    settings.runes_page
+                );
+
+                get_user_raw_input("press enter to return to runes settings screen")
+                    .ok_or(UserCommand::Exit)?;
+            }
+            9 => {
+                //reset to default runes page
+                settings.runes_page = champ_properties.defaults.runes_pages;
+                println!("\nRunes page has been reset to default.");
+            }
+            _ => unreachable!("Unhandled user input"),
+        }
+    }
+}
+
+const RUNES_PAGES_SEARCH_HELP_MSG: &str =
+"Every combination of rune keystone and rune shards is compared by generating builds with it (minor runes are kept,\n\
+adapted to the keystone tree when it changes)\n\
+at each of the given numbers of items (the score of a runes page is averaged over these numbers of items).\n\
+The more numbers of items, the longer it takes.";
+
+/// Prints the best runes pages with their score gap to the current runes page.
+fn print_runes_pages_scores(best_runes_pages: &[(RunesPage, f32)], current_runes_page: &RunesPage) {
+    let current_score: Option<f32> = best_runes_pages
+        .iter()
+        .find(|(runes_page, _)| runes_page == current_runes_page)
+        .map(|(_, score)| *score);
+    let print_runes_page = |rank: usize, runes_page: &RunesPage, score: f32| {
+        println!(
+            " {rank:>3}) {runes_page} (score: {score:.0}{}){}",
+            current_score.map_or_else(String::new, |current_score| format!(
+                ", {:+.1}% vs current",
+                100. * (score - current_score) / current_score
+            )),
+            if runes_page == current_runes_page {
+                " <- current"
+            } else {
+                ""
+            }
+        );
+    };
+
+    println!("\nBest runes pages in order:");
+    for (idx, (runes_page, score)) in best_runes_pages
+        .iter()
+        .enumerate()
+        .take(N_PRINTED_RUNES_PAGES)
+    {
+        print_runes_page(idx + 1, runes_page, *score);
+    }
+    if let Some((idx, (runes_page, score))) = best_runes_pages
+        .iter()
+        .enumerate()
+        .skip(N_PRINTED_RUNES_PAGES)
+        .find(|(_, (runes_page, _))| runes_page == current_runes_page)
+    {
+        println!("  ...");
+        print_runes_page(idx + 1, runes_page, *score);
+    }
+}
+
+fn change_rune_keystone(
+    settings: &mut BuildsGenerationSettings,
+    champ_properties: &UnitProperties,
+) -> Result<(), UserCommand> {
+    let keystone_choices: Vec<&RuneKeystone> = [
+        &runes_data::ALL_RUNES_KEYSTONES[..],
+        &[&RuneKeystone::EMPTY_RUNE_KEYSTONE][..],
+    ]
+    .concat();
+
+    loop {
+        let choice: usize = match get_user_choice(
+            "\nAvailable rune keystones:",
+            "\nSelect a rune keystone",
+            "",
+            keystone_choices.iter().map(|keystone| keystone.full_name),
+            false,
+        ) {
+            Ok(Some(choice)) => choice,
+            Ok(None) => return Ok(()), //should never get here because `allow_no_input` is false
+            Err(UserCommand::Back) => return Ok(()),
+            Err(command) => return Err(command),
+        };
+
+        let old_runes: RunesPage = settings.runes_page; //backup before checking validity
+        settings
+            .runes_page
+            .set_keystone(keystone_choices[choice - 1]);
+
+        if let Err(error_msg) = settings.check_settings(champ_properties) {
+            println!("Failed to set rune shard: {error_msg}");
+            settings.runes_page = old_runes; //restore valid value
+        } else {
+            return Ok(());
+        }
+    }
+}
+
+/// Returns the full names of the given minor runes separated by commas ("none" for empty minor runes).
+fn minor_runes_names(runes: &[&MinorRune]) -> String {
+    runes
+        .iter()
+        .map(|&rune| {
+            if *rune == MinorRune::EMPTY_MINOR_RUNE {
+                "none"
+            } else {
+                rune.full_name
+            }
+        })
+        .collect::<Vec<&str>>()
+        .join(", ")
+}
+
+/// Asks the user to choose a minor rune among the given ones (or no rune).
+/// Returns Ok(None) if the user went back.
+fn choose_minor_rune(
+    header: &str,
+    runes_choices: &[&'static MinorRune],
+) -> Result<Option<&'static MinorRune>, UserCommand> {
+    match get_user_choice(
+        header,
+        "\nSelect a minor rune",
+        "",
+        runes_choices
+            .iter()
+            .map(|rune| rune.full_name)
+            .chain(["none"]),
+        false,
+    ) {
+        Ok(Some(choice)) => Ok(Some(
+            runes_choices
+                .get(choice - 1)
+                .copied()
+                .unwrap_or(&MinorRune::EMPTY_MINOR_RUNE),
+        )),
+        Ok(None) => Ok(None), //should never get here because `allow_no_input` is false
+        Err(UserCommand::Back) => Ok(None),
+        Err(command) => Err(command),
+    }
+}
+
+fn change_primary_runes(
+    settings: &mut BuildsGenerationSettings,
+    champ_properties: &UnitProperties,
+) -> Result<(), UserCommand> {
+    loop {
+        let old_runes: RunesPage = settings.runes_page; //backup before checking validity
+        for row in 1..=3 {
+            let runes_choices: Vec<&MinorRune> = runes_data::ALL_MINOR_RUNES
+                .iter()
+                .copied()
+                .filter(|rune| rune.tree == settings.runes_page.keystone.tree && rune.row == row)
+                .collect();
+            let Some(rune) = choose_minor_rune(
+                &format!("\nAvailable primary runes (row {row} of the keystone tree):"),
+                &runes_choices,
+            )?
+            else {
+                settings.runes_page = old_runes; //restore previous runes
+                return Ok(());
+            };
+            settings.runes_page.primary_runes[usize::from(row - 1)] = rune;
+        }
+
+        if let Err(error_msg) = settings.check_settings(champ_properties) {
+            println!("Failed to set primary runes: {error_msg}");
+            settings.runes_page = old_runes; //restore valid value
+        } else {
+            return Ok(());
+        }
+    }
+}
+
+fn change_secondary_runes(
+    settings: &mut BuildsGenerationSettings,
+    champ_properties: &UnitProperties,
+) -> Result<(), UserCommand> {
+    loop {
+        let old_runes: RunesPage = settings.runes_page; //backup before checking validity
+        for idx in 0..settings.runes_page.secondary_runes.len() {
+            //second rune from the tree of the first one
+            let tree: Option<RuneTree> = if idx == 0 {
+                None
+            } else {
+                settings.runes_page.secondary_runes[0].tree
+            };
+            let runes_choices: Vec<&MinorRune> = runes_data::ALL_MINOR_RUNES
+                .iter()
+                .copied()
+                .filter(|rune| {
+                    rune.tree != settings.runes_page.keystone.tree
+                        && tree.is_none_or(|tree| rune.tree == Some(tree))
+                })
+                .collect();
+            let Some(rune) = choose_minor_rune(
+                &format!("\nAvailable secondary runes (rune {}):", idx + 1),
+                &runes_choices,
+            )?
+            else {
+                settings.runes_page = old_runes; //restore previous runes
+                return Ok(());
+            };
+            settings.runes_page.secondary_runes[idx] = rune;
+        }
+
+        if let Err(error_msg) = settings.check_settings(champ_properties) {
+            println!("Failed to set secondary runes: {error_msg}");
+            settings.runes_page = old_runes; //restore valid value
+        } else {
+            return Ok(());
+        }
+    }
+}
+
+fn change_rune_shard(
+    settings: &mut BuildsGenerationSettings,
+    champ_properties: &UnitProperties,
+    shard_number: usize,
+) -> Result<(), UserCommand> {
+    loop {
+        let choice: RuneShard = match get_user_choice(
+            "\nAvailable rune shards:",
+            "\nSelect a rune shard",
+            "",
+            ["Left", "Middle", "Right"],
+            false,
+        ) {
+            Ok(Some(1)) => RuneShard::Left,
+            Ok(Some(2)) => RuneShard::Middle,
+            Ok(Some(3)) => RuneShard::Right,
+            Ok(Some(_)) => unreachable!("Unhandled shard number"),
+            Ok(None) => return Ok(()), //should never get here because `allow_no_input` is false
+            Err(UserCommand::Back) => return Ok(()),
+            Err(command) => return Err(command),
+        };
+
+        let old_runes: RunesPage = settings.runes_page; //backup before checking validity
+        match shard_number {
+            1 => settings.runes_page.shard1 = choice,
+            2 => settings.runes_page.shard2 = choice,
+            3 => settings.runes_page.shard3 = choice,
+            _ => unreachable!("Unhandled shard number"),
+        }
+
+        if let Err(error_msg) = settings.check_settings(champ_properties) {
+            println!("Failed to set rune shard: {error_msg}");
+            settings.runes_page = old_runes; //restore valid value
+        } else {
+            return Ok(());
+        }
+    }
+}
+
+const SKILL_ORDER_HELP_MSG: &str =
+"Order in which the champion maxes its basic abilities (the ultimate is leveled up at lvl 6, 11 and 16).\n\
+With 'early', the first point of each basic ability is taken at lvl 1, 2 and 3. With 'late' (':late' suffix),\n\
+the last ability of the max order only gets its first point at lvl 5.\n\
+The best skill order for a build can be found from the results screen, after generating builds.";
+
+/// Prints the score of a build with each max order, in the given order.
+fn print_skill_orders_scores(best_skill_orders: &[(MaxOrder, f32)], build: &Build) {
+    println!(
+        "\nSkill orders for build {}, from best to worst:",
+        build
+            .iter()
+            .filter(|&&item| *item != Item::NULL_ITEM)
+            .map(|item| item.short_name)
+            .collect::<Vec<&str>>()
+            .join(", ")
+    );
+    for (max_order, score) in best_skill_orders {
+        println!(" - {max_order} (score: {score:.0})");
+    }
+}
+
+const SUMMONER_SPELLS_HELP_MSG: &str =
+"Summoner spells of the champion, their actives are used at the start of the fight with the items actives.\n\
+Since summoner spells have long cooldowns, their effects are weighted by their availability during a game.\n\
+The best summoner spells can be found by generating builds with each pair of summoner spells (takes a while).";
+
+/// Prints the summoner spells pairs with their score gap to the current summoner spells.
+fn print_summoner_spells_scores(
+    best_summoner_spells: &[(SummonerSpells, f32)],
+    current_summoner_spells: &SummonerSpells,
+) {
+    let current_score: Option<f32> = best_summoner_spells
+        .iter()
+        .find(|(summoner_spells, _)| summoner_spells == current_summoner_spells)
+        .map(|(_, score)| *score);
+
+    println!("\nBest summoner spells in order:");
+    for (idx, (summoner_spells, score)) in best_summoner_spells.iter().enumerate() {
+        println!(
+            " {:>3}) {summoner_spells} (score: {score:.0}{}){}",
+            idx + 1,
+            current_score.map_or_else(String::new, |current_score| format!(
+                ", {:+.1}% vs current",
+                100. * (score - current_score) / current_score
+            )),
+            if summoner_spells == current_summoner_spells {
+                " <- current"
+            } else {
+                ""
+            }
+        );
+    }
+}
+
+/// This function never returns `Err(UserCommand::back)`.
+fn change_summoner_spells(
+    settings: &mut BuildsGenerationSettings,
+    champ_properties: &'static UnitProperties,
+) -> Result<(), UserCommand> {
+    let summoner_spells_pairs: Vec<SummonerSpells> = SummonerSpells::all_pairs();
+    let choices: Vec<String> =
+        core::iter::once("default summoner spells of the champion".to_string())
+            .chain(summoner_spells_pairs.iter().map(SummonerSpells::to_string))
+            .chain(core::iter::once(
+                "automatically find the best summoner spells".to_string(),
+            ))
+            .collect();
+
+    loop {
+        let choice: usize = match get_user_choice(
+            "\nAvailable summoner spells:",
+            "\nSelect summoner spells",
+            SUMMONER_SPELLS_HELP_MSG,
+            choices.iter().map(String::as_str),
+            false,
+        ) {
+            Ok(Some(choice)) => choice,
+            Ok(None) => return Ok(()), //should never get here because `allow_no_input` is false
+            Err(UserCommand::Back) => return Ok(()),
+            Err(command) => return Err(command),
+        };
+
+        if choice == choices.len() {
+            //find best summoner spells
+            println!("Comparing summoner spells, this may take a while...");
+            let best_summoner_spells: Vec<(SummonerSpells, f32)> = match find_best_summoner_spells(
+                champ_properties,
+                settings,
+                &[N_ITEMS_WHEN_FINDING_BEST_SUMMONER_SPELLS],
+            ) {
+                Ok(best_summoner_spells) => best_summoner_spells,
+                Err(error_msg) => {
+                    println!("Failed to find best summoner spells: {error_msg}");
+                    continue;
+                }
+            };
+            print_summoner_spells_scores(&best_summoner_spells, &settings.summoner_spells);
+            settings.summoner_spells = best_summoner_spells[0].0; //should never go out of bounds since the current summoner spells are always included
+            println!(
+                "{} has replaced the previous summoner spells.",
+                settings.summoner_spells
+            );
+            get_user_raw_input("press enter to return to settings screen")
+                .ok_or(UserCommand::Exit)?;
+            return Ok(());
+        }
+
+        let old_summoner_spells: SummonerSpells = settings.summoner_spells; //backup before checking validity
+        settings.summoner_spells = choice
+            .checked_sub(2)
+            .map_or(champ_properties.defaults.summoner_spells, |idx| {
+                summoner_spells_pairs[idx]
+            });
+
+        if let Err(error_msg) = settings.check_settings(champ_properties) {
+            println!("Failed to set summoner spells: {error_msg}");
+            settings.summoner_spells = old_summoner_spells; //restore valid value
+        } else {
+            return Ok(());
+        }
+    }
+}
+
+/// This function never returns `Err(UserCommand::back)`.
+fn change_skill_order(
+    settings: &mut BuildsGenerationSettings,
+    champ_properties: &UnitProperties,
+) -> Result<(), UserCommand> {
+    let max_orders: Vec<MaxOrder> = MaxOrder::all();
+    let choices: Vec<String> = core::iter::once("default skill order of the champion".to_string())
+        .chain(max_orders.iter().map(|max_order| {
+            format!(
+                "{max_order} ({} {})",
+                max_order.priority[2],
+                if max_order.early_last_ability {
+                    "early"
+                } else {
+                    "late"
+                }
+            )
+        }))
+        .collect();
+
+    loop {
+        let choice: usize = match get_user_choice(
+            "\nAvailable skill orders:",
+            "\nSelect a skill order",
+            SKILL_ORDER_HELP_MSG,
+            choices.iter().map(String::as_str),
+            false,
+        ) {
+            Ok(Some(choice)) => choice,
+            Ok(None) => return Ok(()), //should never get here because `allow_no_input` is false
+            Err(UserCommand::Back) => return Ok(()),
+            Err(command) => return Err(command),
+        };
+
+        let old_skill_order: Option<MaxOrder> = settings.skill_order; //backup before checking validity
+        settings.skill_order = choice.checked_sub(2).map(|idx| max_orders[idx]);
+
+        if let Err(error_msg) = settings.check_settings(champ_properties) {
+            println!("Failed to set skill order: {error_msg}");
+            settings.skill_order = old_skill_order; //restore valid value
+        } else {
+            return Ok(());
+        }
+    }
+}
+
+const ITEMS_POOLS_SETTINGS_HELP_MSG: &str = concat!(
+    "Meaning of these settings:\n\
+    -1) number of items per build:\n",
+    N_ITEMS_HELP_MSG,
+    "\n\n-2) mandatory items:\n",
+    MANDATORY_ITEMS_HELP_MSG,
+    "\n\n-3) boots slot:\n",
+    BOOTS_SLOT_HELP_MSG,
+    "\n\n-4) support item slot:\n",
+    SUPP_ITEM_SLOT_HELP_MSG,
+    "\n\n-5) change allowed legendary items",
+    "\n\n-6) change allowed boots",
+    "\n\n-7) change allowed support items",
+    "\n\n-8) allow manaflow items in first slot:\n",
+    ALLOW_MANAFLOW_FIRST_ITEM_HELP_MSG,
+);
+
+fn items_settings_screen(
+    settings: &mut BuildsGenerationSettings,
+    champ_properties: &UnitProperties,
+) -> Result<(), UserCommand> {
+    loop {
+        let choice: usize = match get_user_choice(
+            "\nItem settings:",
+            "\nSelect a setting to change (press enter to confirm current settings)",
+            ITEMS_POOLS_SETTINGS_HELP_MSG,
+            [
+                format!("number of items per build: {}", settings.n_items).as_str(),
+                format!("mandatory items: {}", settings.mandatory_items).as_str(),
+                format!("boots slot: {}", settings.boots_slot).as_str(),
+                format!("support item slot: {}", settings.supp_item_slot).as_str(),
+                "change allowed legendary items ->",
+                "change allowed boots ->",
+                "change allowed support items ->",
+                format!(
+                    "allow manaflow items in first slot: {}",
+                    settings.allow_manaflow_first_item
+                )
+                .as_str(),
+                "reset to default items settings",
+            ],
+            true,
+        ) {
+            Ok(Some(choice)) => choice,
+            Ok(None) => return Ok(()),
+            Err(UserCommand::Back) => return Ok(()),
+            Err(command) => return Err(command),
+        };
+
+        match choice {
+            1 => {
+                //n_items
+                change_n_items(settings, champ_properties)?;
+            }
+            2 => {
+                //mandatory_items
+                change_mandatory_items(settings, champ_properties)?;
+            }
+            3 => {
+                //boots_slot
+                change_boots_slot(settings, champ_properties)?;
+            }
+            4 => {
+                //support_item_slot
+                change_supp_item_slot(settings, champ_properties)?;
+            }
+            5 => {
+                //change legendary items pool
+                change_items_pool(
+                    ItemPoolType::Legendary,
+                    settings.game_data.items_database,
+                    &mut settings.legendary_items_pool,
+                )?;
+            }
+            6 => {
+                //change boots pool
+                change_items_pool(
+                    ItemPoolType::Boots,
+                    settings.game_data.items_database,
+                    &mut settings.boots_pool,
+                )?;
+            }
+            7 => {
+                //change support items pool
+                change_items_pool(
+                    ItemPoolType::Support,
+                    settings.game_data.items_database,
+                    &mut settings.supp_items_pool,
+                )?;
+            }
+            8 => {
+                //flip allow_manaflow_first_item
+                settings.allow_manaflow_first_item = !settings.allow_manaflow_first_item;
+            }
+            9 => {
+                //reset to default items settings
+                let mut default: BuildsGenerationSettings =
+                    BuildsGenerationSettings::default_by_champion(champ_properties);
+                default.use_game_data(settings.game_data);
+
+                settings.n_items = default.n_items;
+                settings.mandatory_items = default.mandatory_items;
+                settings.boots_slot = default.boots_slot;
+                settings.supp_item_slot = default.supp_item_slot;
+                settings.legendary_items_pool = default.legendary_items_pool;
+                settings.boots_pool = default.boots_pool;
+                settings.supp_items_pool = default.supp_items_pool;
+
+                println!("\nItem settings have been reset to default.");
+            }
+            _ => unreachable!("Unhandled user input"),
+        }
+    }
+}
+
+const N_ITEMS_HELP_MSG: &str = "Generated builds will have the selected number of items.";
+
+/// This function never returns `Err(UserCommand::back)`.
+fn change_n_items(
+    settings: &mut BuildsGenerationSettings,
+    champ_properties: &UnitProperties,
+) -> Result<(), UserCommand> {
+    loop {
+        let n_items: usize = match get_user_usize(
+            "",
+            "\nEnter a number of item per build",
+            N_ITEMS_HELP_MSG,
+            1..=MAX_UNIT_ITEMS,
+            false,
+        ) {
+            Ok(Some(n_items)) => n_items,
+            Ok(None) => return Ok(()), //should never get here because `allow_no_input` is false
+            Err(UserCommand::Back) => return Ok(()),
+            Err(command) => return Err(command),
+        };
+
+        let old_n_items: usize = settings.n_items; //backup before checking validity
+        settings.n_items = n_items;
+
+        if let Err(error_msg) = settings.check_settings(champ_properties) {
+            println!("Failed to set number of items per build: {error_msg}");
+            settings.n_items = old_n_items; //restore valid value
+        } else {
+            return Ok(());
+        }
+    }
+}
+
+const MANDATORY_ITEMS_HELP_MSG: &str =
+    "Every generated build will have the selected items at the specified slots.";
+
+/// This function never returns `Err(UserCommand::back)`.
+fn change_mandatory_items(
+    settings: &mut BuildsGenerationSettings,
+    champ_properties: &UnitProperties,
+) -> Result<(), UserCommand> {
+    //get item index first
+    loop {
+        let item_slot: usize = match get_user_usize(
+            "",
+            format!(
+                "\nCurrent mandatory items are: {}\n\
+                Enter an item slot where you want to impose an item (press enter to confirm current items)",
+                settings.mandatory_items
+            )
+            .as_str(),
+            MANDATORY_ITEMS_HELP_MSG,
+            1..=MAX_UNIT_ITEMS,
+            true,
+        ) {
+            Ok(Some(item_slot)) => item_slot,
+            Ok(None) => return Ok(()),
+            Err(UserCommand::Back) => return Ok(()),
+            Err(command) => return Err(command),
+        };
+        let item_idx: usize = item_slot - 1;
+
+        //get item and put it in mandatory items if valid
+        loop {
+            let item: &Item = match get_user_item(
+                "",
+                &format!("\nEnter an item to impose at slot {item_slot} (press enter for none)"),
+                settings.game_data.items_database,
+                EnumSet::all(),
+            ) {
+                Ok(item) => item,
+                Err(UserCommand::Back) => break,
+                Err(command) => return Err(command),
+            };
+
+            let old_item: &Item = settings.mandatory_items[item_idx]; //backup before checking validity
+            settings.mandatory_items[item_idx] = item;
+
+            if let Err(error_msg) = settings.check_settings(champ_properties) {
+                println!("Failed to set mandatory items: {error_msg}");
+                settings.mandatory_items[item_idx] = old_item; //restore valid value
+            } else {
+                break;
+            }
+        }
+    }
+}
+
+const BOOTS_SLOT_HELP_MSG: &str = "Every generated build will have boots at the selected slot.\n\
+                                   If set to 'Any', you let the optimizer decide which slot is best\n\
+                                   (it may even not pick any depending on your other settings).\n\
+                                   If set to 'None', boots are disallowed.";
+
+fn get_item_slot(help_msg: &str) -> Result<ItemSlot, UserCommand> {
+    loop {
+        let input: String = get_user_input("\nEnter an item slot or 'any' or 'none'", help_msg)?;
+
+        if input.is_empty() {
+            println!("Please enter a valid item slot");
+            continue;
+        }
+        match input.as_str() {
+            "any" => return Ok(ItemSlot::Any),
+            "none" => return Ok(ItemSlot::None),
+            input => {
+                let number: usize = match input.parse::<usize>() {
+                    Ok(number) => number,
+                    Err(error) => {
+                        println!("'{input}' is not a valid integer: {}", error);
+                        continue;
+                    }
+                };
+                if !(1..=MAX_UNIT_ITEMS).contains(&number) {
+                    println!(
+                        "Item slot must be between 1 and {MAX_UNIT_ITEMS} (got {})",
+                        number,
+                    );
+                    continue;
+                }
+                return Ok(ItemSlot::Slot(number));
+            }
+        }
+    }
+}
+
+/// This function never returns `Err(UserCommand::back)`.
+fn change_boots_slot(
+    settings: &mut BuildsGenerationSettings,
+    champ_properties: &UnitProperties,
+) -> Result<(), UserCommand> {
+    loop {
+        let boots_slot: ItemSlot = match get_item_slot(BOOTS_SLOT_HELP_MSG) {
+            Ok(boots_slot) => boots_slot,
+            Err(UserCommand::Back) => return Ok(()),
+            Err(command) => return Err(command),
+        };
+
+        let old_boots_slot: ItemSlot = settings.boots_slot; //backup before checking validity
+        settings.boots_slot = boots_slot;
+
+        if let Err(error_msg) = settings.check_settings(champ_properties) {
+            println!("Failed to set boots slot: {error_msg}");
+            settings.boots_slot = old_boots_slot; //restore valid value
+        } else {
+            return Ok(());
+        }
+    }
+}
+
+const SUPP_ITEM_SLOT_HELP_MSG: &str =
+    "Every generated build will have a support item at the selected slot.\n\
+     If set to 'Any', you let the optimizer decide which slot is best\n\
+     (it may even not pick any depending on your other settings).\n\
+     If set to 'None', support items are disallowed.";
+
+/// This function never returns `Err(UserCommand::back)`.
+fn change_supp_item_slot(
+    settings: &mut BuildsGenerationSettings,
+    champ_properties: &UnitProperties,
+) -> Result<(), UserCommand> {
+    loop {
+        let supp_item_slot: ItemSlot = match get_item_slot(SUPP_ITEM_SLOT_HELP_MSG) {
+            Ok(supp_item_slot) => supp_item_slot,
+            Err(UserCommand::Back) => return Ok(()),
+            Err(command) => return Err(command),
+        };
+
+        let old_supp_item_slot: ItemSlot = settings.supp_item_slot; //backup before checking validity
+        settings.supp_item_slot = supp_item_slot;
+
+        if let Err(error_msg) = settings.check_settings(champ_properties) {
+            println!("Failed to set support item slot: {error_msg}");
+            settings.supp_item_slot = old_supp_item_slot; //restore valid value
+        } else {
+            return Ok(());
+        }
+    }
+}
+
+fn change_items_pool(
+    item_pool_types: ItemPoolType,
+    items_database: &ItemsDatabase,
+    pool: &mut Vec<&Item>,
+) -> Result<(), UserCommand> {
+    let reference_pool: &[&Item] = item_pool_types.reference_pool(items_database);
+
+    loop {
+        //print list of items with allowed/disallowed checkbox
+        match item_pool_types {
+            ItemPoolType::Legendary => {
+                println!("\nAllowed legendary items:");
+            }
+            ItemPoolType::Boots => {
+                println!("\nAllowed boots:");
+            }
+            ItemPoolType::Support => {
+                println!("\nAllowed support items:");
+            }
+            ItemPoolType::Component => {
+                println!("\nAllowed component items:");
+            }
+            ItemPoolType::Starter => {
+                println!("\nAllowed starter items:");
+            }
+            ItemPoolType::Consumable => {
+                println!("\nAllowed consumables:");
+            }
+        }
+        for item in reference_pool {
+            println!(
+                "[{}] {item:#}",
+                if pool.contains(item) {
+                    CHECK_MARK_CHAR
+                } else {
+                    UNCHECKED_MARK_CHAR
+                }
+            );
+        }
+
+        //get item
+        let item: &Item = match get_user_item(
+            "",
+            "\nEnter an item to switch its allowance status (press enter to confirm current settings)",
+            items_database,
+            enum_set!(item_pool_types)
+        ) {
+            Ok(item) => item,
+            Err(UserCommand::Back) => return Ok(()),
+            Err(command) => return Err(command),
+        };
+        if *item == Item::NULL_ITEM {
+            return Ok(());
+        }
+
+        //switch allowance status
+        if let Some(index) = pool.iter().position(|&x| *x == *item) {
+            pool.swap_remove(index);
+        } else {
+            pool.push(item);
+        }
+    }
+}
+
+const ALLOW_MANAFLOW_FIRST_ITEM_HELP_MSG: &str = "If manaflow items are allowed as first item.\n\
+                                                  Only effective if there are manaflow items allowed in items pools.\n\
+                                                  This setting is overridden by mandatory items.";
+
+const SEARCH_THRESHOLD_HELP_MSG: &str =
+    "Controls the percentage of builds explored among the possibilities during the generation process.\n\
+     Higher value -> a higher number of badly performing builds are explored,\n\
+     may find better scaling builds but increases the computation time and memory usage.\n\
+     Lower value -> a lower number of badly performing builds are explored,\n\
+     may find worse scaling builds but decreases the computation time and memory usage.\n\
+     A search treshold percentage under 15-20% is generally sufficient to find most of the relevant builds.";
+
+#[allow(clippy::type_complexity)]
+fn get_user_weights() -> Result<(Option<f32>, Option<f32>, Option<f32>, Option<f32>), UserCommand> {
+    //get dps weight
+    let dps_weight: Option<f32> = get_user_f32("",
+             "\nEnter the DPS weight (press enter to keep the previous value)",
+             "The DPS weight is used to measure the importance of the champion's DPS when calculating the gold value of a build.\n\
+             The absolute value of the weight is not relevant, what is important is its value relative to other weights.",
+             true)?;
+
+    //get defense weight
+    let def_weight: Option<f32> = get_user_f32("",
+             "\nEnter the defense weight (press enter to keep the previous value)",
+             "The defense weight is used to measure the importance of the champion's defensive stats, heals and hields when calculating the gold value of a build.\n\
+             The absolute value of the weight is not relevant, what is important is its value relative to other weights.",
+             true)?;
+
+    //get ms weight
+    let ms_weight: Option<f32> = get_user_f32("",
+             "\nEnter the mobility weight (press enter to keep the previous value)",
+             "The mobility weight is used to measure the importance of the champion's mobility when calculating the gold value of a build.\n\
+             The absolute value of the weight is not relevant, what is important is its value relative to other weights.",
+             true)?;
+
+    //get time to kill weight
+    let ttk_weight: Option<f32> = get_user_f32("",
+             "\nEnter the time to kill weight (press enter to keep the previous value)",
+             "The time to kill weight is used to measure the importance of the time needed by the champion to kill the target when calculating the gold value of a build\n\
+             (0 to ignore it). Unlike the DPS, it values burst dmg and executes. \
+             The absolute value of the weight is not relevant, what is important is its value relative to other weights.",
+             true)?;
+
+    Ok((dps_weight, def_weight, ms_weight, ttk_weight))
+}
+
+/// This function never returns `Err(UserCommand::back)`.
+fn change_weights(
+    settings: &mut BuildsGenerationSettings,
+    champ_properties: &UnitProperties,
+) -> Result<(), UserCommand> {
+    loop {
+        let input_weights: (Option<f32>, Option<f32>, Option<f32>, Option<f32>) =
+            match get_user_weights() {
+                Ok(input_weights) => input_weights,
+                Err(UserCommand::Back) => return Ok(()),
+                Err(command) => return Err(command),
+            };
+
+        let old_weights: (f32, f32, f32, f32) = settings.weights; //backup before checking validity
+        if let Some(dps_weight) = input_weights.0 {
+            settings.weights.0 = dps_weight;
+        }
+        if let Some(def_weight) = input_weights.1 {
+            settings.weights.1 = def_weight;
+        }
+        if let Some(ms_weight) = input_weights.2 {
+            settings.weights.2 = ms_weight;
+        }
+        if let Some(ttk_weight) = input_weights.3 {
+            settings.weights.3 = ttk_weight;
+        }
+
+        if let Err(error_msg) = settings.check_settings(champ_properties) {
+            println!("Failed to set weights: {error_msg}");
+            settings.weights = old_weights; //restore valid value
+        } else {
+            return Ok(());
+        }
+    }
+}
+
+/// This function never returns `Err(UserCommand::back)`.
+fn change_search_threshold(
+    settings: &mut BuildsGenerationSettings,
+    champ_properties: &UnitProperties,
+) -> Result<(), UserCommand> {
+    loop {
+        let number: f32 = match get_user_f32(
+            "",
+            "\nEnter the search threshold percentage",
+            SEARCH_THRESHOLD_HELP_MSG,
+            false,
+        ) {
+            Ok(Some(number)) => number,
+            Ok(None) => return Ok(()), //should never get here because `allow_no_input` is false
+            Err(UserCommand::Back) => return Ok(()),
+            Err(command) => return Err(command),
+        };
+
+        let old_search_threshold: f32 = settings.search_threshold; //backup before checking validity
+        settings.search_threshold = number / 100.;
+
+        if let Err(error_msg) = settings.check_settings(champ_properties) {
+            println!("Failed to set search threshold: {error_msg}");
+            settings.search_threshold = old_search_threshold; //restore valid value
+        } else {
+            return Ok(());
+        }
+    }
+}
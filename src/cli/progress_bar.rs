@@ -0,0 +1,53 @@
+use super::*;
+
+use indicatif::{ProgressBar, ProgressFinish, ProgressStyle};
+
+use core::time::Duration;
+
+/// Observer showing the progress of the builds generation with a progress bar.
+#[derive(Debug, Default)]
+pub(super) struct ProgressBarObserver {
+    bar: Option<ProgressBar>,
+}
+
+impl BuildsGenerationObserver for ProgressBarObserver {
+    fn on_generation_started(&mut self, champ_properties: &UnitProperties, n_items: usize) {
+        let bar: ProgressBar = ProgressBar::new(n_items as u64)
+            .with_style(
+                ProgressStyle::with_template(
+                    "{msg} {spinner} [{elapsed_precise}] {bar} {pos}/{len} items",
+                )
+                .expect("Failed to create progress bar style"),
+            )
+            .with_message(format!(
+                "Generating best builds for {}",
+                champ_properties.name
+            ))
+            .with_finish(ProgressFinish::AbandonWithMessage(
+                format!("Done generating builds for {}", champ_properties.name).into(),
+            ));
+        bar.enable_steady_tick(Duration::from_millis(200));
+        self.bar = Some(bar);
+    }
+
+    fn on_layer_finished(&mut self, _item_slot: usize) {
+        if let Some(bar) = &self.bar {
+            bar.inc(1);
+        }
+    }
+
+    fn on_generation_finished(&mut self, _n_builds: usize) {
+        if let Some(bar) = self.bar.take() {
+            bar.finish_using_style();
+        }
+    }
+}
+
+impl Drop for ProgressBarObserver {
+    /// Stops the progress bar if the generation failed before finishing.
+    fn drop(&mut self) {
+        if let Some(bar) = self.bar.take() {
+            bar.abandon();
+        }
+    }
+}
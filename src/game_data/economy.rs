@@ -0,0 +1,176 @@
+use super::*;
+
+use serde::{Deserialize, Serialize};
+
+/// Percentage of the minions experience received by each champion when two champions share a lane
+/// (the experience is split with a bonus, see the game experience sharing rules).
+const SHARED_XP_PERCENT: f32 = 0.652;
+
+/// Golds income and experience gain of the player of the champion we want to optimize.
+/// Used to estimate the champion lvl at each item slot (and the golds available over the game),
+/// and to weight effects with long cooldowns by their availability between fights.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct EconomyProfile {
+    /// CS/min of the player, a bit inflated to take other sources of golds into account (kills, towers, ...).
+    pub cs_per_min: f32,
+    /// Average golds per minion killed.
+    pub golds_per_cs: f32,
+    /// Average experience per minion killed (lower if the experience is shared with a lane partner).
+    pub xp_per_cs: f32,
+    /// Passive golds generation per minute.
+    pub passive_golds_per_min: f32,
+    /// Average time in seconds between fights (used to weight items actives with different cooldowns).
+    pub time_between_fights: f32,
+}
+
+impl Default for EconomyProfile {
+    fn default() -> Self {
+        Self {
+            cs_per_min: CS_PER_MIN,
+            golds_per_cs: AVG_GOLDS_PER_CS,
+            xp_per_cs: AVG_XP_PER_CS,
+            passive_golds_per_min: PASSIVE_GOLDS_GEN_PER_MIN,
+            time_between_fights: TIME_BETWEEN_FIGHTS,
+        }
+    }
+}
+
+impl fmt::Display for EconomyProfile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} cs/min ({:.1} golds and {:.1} xp per cs), {} passive golds/min, {}s between fights",
+            self.cs_per_min,
+            self.golds_per_cs,
+            self.xp_per_cs,
+            self.passive_golds_per_min,
+            self.time_between_fights
+        )
+    }
+}
+
+impl EconomyProfile {
+    /// Names of the predefined economy profiles (see `EconomyProfile::from_name`).
+    pub const PROFILES_NAMES: [&'static str; 4] = ["default", "low-farm", "high-farm", "shared-xp"];
+
+    /// Returns the predefined economy profile with the given name (case insensitive), if any:
+    /// - "default": solo laner farming 10 cs/min (inflated to take kills, towers, ... into account).
+    /// - "low-farm": solo laner farming 6 cs/min.
+    /// - "high-farm": solo laner farming 12 cs/min.
+    /// - "shared-xp": 10 cs/min but the experience is shared with a lane partner (bot lane).
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.to_lowercase().as_str() {
+            "default" => Some(Self::default()),
+            "low-farm" => Some(Self {
+                cs_per_min: 6.,
+                ..Default::default()
+            }),
+            "high-farm" => Some(Self {
+                cs_per_min: 12.,
+                ..Default::default()
+            }),
+            "shared-xp" => Some(Self {
+                xp_per_cs: SHARED_XP_PERCENT * AVG_XP_PER_CS,
+                ..Default::default()
+            }),
+            _ => None,
+        }
+    }
+
+    /// Returns an Err with the corresponding error message if the profile values are invalid.
+    pub fn check_validity(&self) -> Result<(), String> {
+        if !self.cs_per_min.is_finite() || self.cs_per_min < 0. {
+            return Err(format!("CS/min must be positive (got {})", self.cs_per_min));
+        }
+        if !self.golds_per_cs.is_finite() || self.golds_per_cs < 0. {
+            return Err(format!(
+                "Golds per cs must be positive (got {})",
+                self.golds_per_cs
+            ));
+        }
+        if !self.xp_per_cs.is_finite() || self.xp_per_cs < 0. {
+            return Err(format!(
+                "Experience per cs must be positive (got {})",
+                self.xp_per_cs
+            ));
+        }
+        if !self.passive_golds_per_min.is_finite() || self.passive_golds_per_min < 0. {
+            return Err(format!(
+                "Passive golds generation per minute must be positive (got {})",
+                self.passive_golds_per_min
+            ));
+        }
+        if self.golds_per_min() <= 0. {
+            return Err("Total golds income per minute must be greater than 0".to_string());
+        }
+        if !self.time_between_fights.is_finite() || self.time_between_fights <= 0. {
+            return Err(format!(
+                "Time between fights must be greater than 0s (got {}s)",
+                self.time_between_fights
+            ));
+        }
+        Ok(())
+    }
+
+    /// Returns the total amount of golds income per minute considering farm + passive generation.
+    #[must_use]
+    #[inline]
+    pub fn golds_per_min(&self) -> f32 {
+        self.golds_per_cs * self.cs_per_min + self.passive_golds_per_min
+    }
+
+    /// Returns the amount of experience gained per minute farming.
+    #[must_use]
+    #[inline]
+    pub fn xp_per_min(&self) -> f32 {
+        self.xp_per_cs * self.cs_per_min
+    }
+
+    /// Returns the amount of experience gained farming while earning the given amount of golds.
+    /// We approximate that the gold income is only from cs golds and passive golds generation.
+    #[must_use]
+    #[inline]
+    pub fn xp_per_golds(&self, golds: f32) -> f32 {
+        self.xp_per_min() * golds / self.golds_per_min()
+    }
+
+    /// Returns the availability coef of a passive/active effect according to its cooldown.
+    /// It should be used on effects that have cooldowns way longer than the fight simulation.
+    /// The function receives the real cooldown of the effect, already reduced by haste.
+    #[must_use]
+    #[inline]
+    pub(crate) fn effect_availability_formula(&self, real_cooldown: f32) -> f32 {
+        self.time_between_fights / (self.time_between_fights + real_cooldown)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_economy_profiles() {
+        for name in EconomyProfile::PROFILES_NAMES {
+            let profile: EconomyProfile =
+                EconomyProfile::from_name(name).expect("Failed to find economy profile");
+            assert!(profile.check_validity().is_ok(), "{name}");
+        }
+        assert!(EconomyProfile::from_name("unknown").is_none());
+
+        let default: EconomyProfile = EconomyProfile::default();
+        let low_farm: EconomyProfile = EconomyProfile::from_name("low-farm").unwrap();
+        let shared_xp: EconomyProfile = EconomyProfile::from_name("shared-xp").unwrap();
+        assert!(low_farm.golds_per_min() < default.golds_per_min());
+        //less cs means a bigger part of the income comes from passive golds, so less experience per golds spent
+        assert!(low_farm.xp_per_golds(1000.) < default.xp_per_golds(1000.));
+        assert!(shared_xp.xp_per_golds(1000.) < default.xp_per_golds(1000.));
+
+        let invalid: EconomyProfile = EconomyProfile {
+            time_between_fights: 0.,
+            ..Default::default()
+        };
+        assert!(invalid.check_validity().is_err());
+    }
+}
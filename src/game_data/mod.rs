@@ -1,5 +1,10 @@
+mod economy;
+pub mod patches;
+pub mod snapshot_import;
 pub mod units_data;
 
+pub use economy::EconomyProfile;
+
 use core::{fmt, ops};
 
 //patch number
@@ -22,9 +27,9 @@ pub const ARMOR_GOLD_VALUE: f32 = 20.;
 pub const MR_GOLD_VALUE: f32 = 20.;
 /// Starting golds on summoners rift.
 pub const STARTING_GOLDS: f32 = 500.;
-/// Passive gold generation per minute on summoners rift.
+/// Passive gold generation per minute on summoners rift (default value of `EconomyProfile`).
 pub const PASSIVE_GOLDS_GEN_PER_MIN: f32 = 122.4;
-/// CS/min of the player of the champion we want to optimize,
+/// Default CS/min of the player of the champion we want to optimize (see `EconomyProfile`),
 /// a bit inflated (~+25%) to take other sources of golds into account (kills, towers, ...).
 pub const CS_PER_MIN: f32 = 10.;
 const GOLDS_PER_MELEE_CS: f32 = 21.;
@@ -43,8 +48,6 @@ pub const AVG_GOLDS_PER_CS: f32 = 15. / 30.
         / (6. + 1. / 2.)
     + 5. / 30. * (3. * GOLDS_PER_MELEE_CS + 3. * GOLDS_PER_CASTER_CS + 1. * AVG_GOLDS_PER_SIEGE_CS)
         / 7.;
-/// Total amount of golds income per minute considering farm + passive generation
-const TOT_GOLDS_PER_MIN: f32 = AVG_GOLDS_PER_CS * CS_PER_MIN + PASSIVE_GOLDS_GEN_PER_MIN;
 const XP_PER_MELEE_CS: f32 = 61.75;
 const XP_PER_CASTER_CS: f32 = 30.4;
 const XP_PER_SIEGE_CS: f32 = 95.;
@@ -60,15 +63,8 @@ pub const AVG_XP_PER_CS: f32 = 15. / 30.
     + 5. / 30. * (3. * XP_PER_MELEE_CS + 3. * XP_PER_CASTER_CS + 1. * XP_PER_SIEGE_CS) / 7.;
 
 //fights simulation parameters:
-/// Average time in seconds we consider between fights (used to weight items actives with different cooldowns).
+/// Default average time in seconds we consider between fights (used to weight items actives with different cooldowns).
 const TIME_BETWEEN_FIGHTS: f32 = 180.;
-/// Returns the availability coef of a passive/active effect according to its cooldown.
-/// It should be used on effects that have cooldowns way longer than the fight simulation.
-/// The function receives the real cooldown of the effect, already reduced by haste.
-#[inline]
-fn effect_availability_formula(real_cooldown: f32) -> f32 {
-    TIME_BETWEEN_FIGHTS / (TIME_BETWEEN_FIGHTS + real_cooldown)
-}
 
 /// Contains a damage value divided in (`phys_dmg`, `magic_dmg`, `true_dmg`).
 #[derive(Debug, Clone, Copy)]
@@ -83,7 +79,6 @@ impl fmt::Display for PartDmg {
 impl ops::Add for PartDmg {
     type Output = Self;
 
-    #[must_use]
     #[inline]
     fn add(self, rhs: Self) -> Self::Output {
         Self(self.0 + rhs.0, self.1 + rhs.1, self.2 + rhs.2)
@@ -93,7 +88,6 @@ impl ops::Add for PartDmg {
 impl ops::Sub for PartDmg {
     type Output = Self;
 
-    #[must_use]
     #[inline]
     fn sub(self, rhs: Self) -> Self::Output {
         Self(self.0 - rhs.0, self.1 - rhs.1, self.2 - rhs.2)
@@ -103,7 +97,6 @@ impl ops::Sub for PartDmg {
 impl ops::Mul<f32> for PartDmg {
     type Output = Self;
 
-    #[must_use]
     #[inline]
     fn mul(self, rhs: f32) -> Self::Output {
         Self(self.0 * rhs, self.1 * rhs, self.2 * rhs)
@@ -113,7 +106,6 @@ impl ops::Mul<f32> for PartDmg {
 impl ops::Mul<PartDmg> for f32 {
     type Output = PartDmg;
 
-    #[must_use]
     #[inline]
     fn mul(self, rhs: PartDmg) -> Self::Output {
         PartDmg(self * rhs.0, self * rhs.1, self * rhs.2)
@@ -123,7 +115,6 @@ impl ops::Mul<PartDmg> for f32 {
 impl ops::Div<f32> for PartDmg {
     type Output = Self;
 
-    #[must_use]
     #[inline]
     fn div(self, rhs: f32) -> Self::Output {
         Self(self.0 / rhs, self.1 / rhs, self.2 / rhs)
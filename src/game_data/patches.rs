@@ -0,0 +1,250 @@
+use super::snapshot_import::*;
+use super::units_data::{items_data::ItemsDatabase, Unit, UnitProperties};
+use super::{PATCH_NUMBER_MAJOR, PATCH_NUMBER_MINOR};
+
+use core::cmp::Ordering;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{LazyLock, Mutex, PoisonError};
+
+/// Directory containing one sub-directory per patch data set (named after the patch, e.g. `data/patches/14.23`).
+pub const DEFAULT_PATCHES_DIR: &str = "data/patches";
+/// Name of the items data file in a patch directory.
+pub const PATCH_ITEMS_FILE: &str = "items.toml";
+
+/// Champions and items data of a game patch.
+///
+/// A patch directory can contain an items data file (`PATCH_ITEMS_FILE`) and/or a Data Dragon snapshot
+/// (`SNAPSHOT_CHAMPIONS_FILE` and `SNAPSHOT_ITEMS_FILE`) applied on top of it.
+/// Data not found in the directory is taken from the compiled-in patch.
+#[derive(Debug)]
+pub struct GameDataSet {
+    /// Name of the patch (e.g. "14.24").
+    pub patch: String,
+    champions: Vec<&'static UnitProperties>,
+    pub items_database: &'static ItemsDatabase,
+}
+
+static BUILTIN_GAME_DATA: LazyLock<GameDataSet> = LazyLock::new(|| GameDataSet {
+    patch: format!("{PATCH_NUMBER_MAJOR}.{PATCH_NUMBER_MINOR}"),
+    champions: Vec::from(Unit::ALL_CHAMPIONS),
+    items_database: ItemsDatabase::builtin(),
+});
+
+/// Patches data sets already loaded from a directory, to avoid leaking them again when they are selected several times.
+static LOADED_PATCHES: Mutex<Vec<(PathBuf, &'static GameDataSet)>> = Mutex::new(Vec::new());
+
+impl GameDataSet {
+    /// Returns the data set of the compiled-in patch (`PATCH_NUMBER_MAJOR`.`PATCH_NUMBER_MINOR`).
+    #[must_use]
+    pub fn builtin() -> &'static Self {
+        &BUILTIN_GAME_DATA
+    }
+
+    /// Creates a data set from the given champions and items, the data set is leaked to get a `&'static GameDataSet`.
+    #[must_use]
+    pub fn new(
+        patch: &str,
+        champions: Vec<&'static UnitProperties>,
+        items_database: &'static ItemsDatabase,
+    ) -> &'static Self {
+        Box::leak(Box::new(Self {
+            patch: patch.to_string(),
+            champions,
+            items_database,
+        }))
+    }
+
+    /// Returns a copy of the data set using the given items database instead.
+    #[must_use]
+    pub fn with_items_database(&self, items_database: &'static ItemsDatabase) -> &'static Self {
+        Self::new(&self.patch, self.champions.clone(), items_database)
+    }
+
+    /// Reads a patch directory (see `GameDataSet`), the patch name is the name of the directory.
+    ///
+    /// Like `ItemsDatabase::load`, the data loaded is leaked so this function is meant to be called a few times at most
+    /// (prefer `load_patch` which doesn't load the same directory twice).
+    pub fn load(dir: &Path) -> Result<&'static Self, String> {
+        let patch: &str = dir
+            .file_name()
+            .and_then(|name| name.to_str())
+            .ok_or_else(|| format!("Invalid patch directory '{}'", dir.display()))?;
+
+        let items_path: PathBuf = dir.join(PATCH_ITEMS_FILE);
+        let has_items_file: bool = items_path.is_file();
+        let has_snapshot: bool =
+            dir.join(SNAPSHOT_CHAMPIONS_FILE).is_file() || dir.join(SNAPSHOT_ITEMS_FILE).is_file();
+        if !has_items_file && !has_snapshot {
+            return Err(format!(
+                "No data found for patch '{patch}' (expected '{PATCH_ITEMS_FILE}' and/or '{SNAPSHOT_CHAMPIONS_FILE}' and '{SNAPSHOT_ITEMS_FILE}' in '{}')",
+                dir.display()
+            ));
+        }
+
+        let items_database: &'static ItemsDatabase = if has_items_file {
+            ItemsDatabase::load(&items_path)?
+        } else {
+            ItemsDatabase::builtin()
+        };
+        if has_snapshot {
+            let import: SnapshotImport =
+                import_snapshot(dir, &Self::builtin().champions, items_database)?;
+            Ok(Self::new(patch, import.champions, import.items_database))
+        } else {
+            Ok(Self::new(
+                patch,
+                Self::builtin().champions.clone(),
+                items_database,
+            ))
+        }
+    }
+
+    /// Returns the champions of the data set.
+    #[must_use]
+    pub fn champions(&self) -> &[&'static UnitProperties] {
+        &self.champions
+    }
+
+    /// Returns the version of the given champion in this data set (champions are matched by name), if any.
+    #[must_use]
+    pub fn get_champion(&self, properties: &UnitProperties) -> Option<&'static UnitProperties> {
+        self.champions
+            .iter()
+            .find(|&&champion| *champion == *properties)
+            .copied()
+    }
+}
+
+/// Compares patch names by their numbers (e.g. "14.9" < "14.10"), non-numeric parts are compared as strings.
+#[must_use]
+pub fn compare_patches(patch1: &str, patch2: &str) -> Ordering {
+    let mut parts1 = patch1.split('.');
+    let mut parts2 = patch2.split('.');
+    loop {
+        match (parts1.next(), parts2.next()) {
+            (None, None) => return Ordering::Equal,
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(part1), Some(part2)) => {
+                let ordering: Ordering = match (part1.parse::<u32>(), part2.parse::<u32>()) {
+                    (Ok(n1), Ok(n2)) => n1.cmp(&n2),
+                    _ => part1.cmp(part2),
+                };
+                if ordering != Ordering::Equal {
+                    return ordering;
+                }
+            }
+        }
+    }
+}
+
+/// Returns the names of the patches available (the compiled-in patch and the sub-directories of `patches_dir`),
+/// from the oldest to the most recent.
+#[must_use]
+pub fn available_patches(patches_dir: &Path) -> Vec<String> {
+    let mut patches: Vec<String> = vec![GameDataSet::builtin().patch.clone()];
+    if let Ok(entries) = fs::read_dir(patches_dir) {
+        for entry in entries.flatten() {
+            if !entry.path().is_dir() {
+                continue;
+            }
+            if let Some(name) = entry.file_name().to_str() {
+                if !patches.iter().any(|patch| patch == name) {
+                    patches.push(name.to_string());
+                }
+            }
+        }
+    }
+    patches.sort_unstable_by(|patch1, patch2| compare_patches(patch1, patch2));
+    patches
+}
+
+/// Returns the data set of the given patch, read from its directory in `patches_dir`.
+/// The compiled-in patch is returned if the requested patch is the compiled-in one and has no directory.
+///
+/// A patch directory is only read once, later calls return the same data set.
+pub fn load_patch(patches_dir: &Path, patch: &str) -> Result<&'static GameDataSet, String> {
+    let patch: &str = patch.trim();
+    if patch.is_empty() || patch.contains(['/', '\\']) || patch.starts_with('.') {
+        return Err(format!("'{patch}' is not a valid patch name"));
+    }
+
+    let dir: PathBuf = patches_dir.join(patch);
+    if !dir.is_dir() {
+        if patch == GameDataSet::builtin().patch {
+            return Ok(GameDataSet::builtin());
+        }
+        return Err(format!(
+            "Patch '{patch}' is not available (available patches: {})",
+            available_patches(patches_dir).join(", ")
+        ));
+    }
+
+    let mut loaded_patches = LOADED_PATCHES
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner);
+    if let Some(&(_, game_data)) = loaded_patches.iter().find(|(path, _)| *path == dir) {
+        return Ok(game_data);
+    }
+    let game_data: &'static GameDataSet = GameDataSet::load(&dir)?;
+    loaded_patches.push((dir, game_data));
+    Ok(game_data)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::game_data::{units_data::items_data::Item, F32_TOL};
+
+    #[test]
+    pub fn test_load_patch_fixture() {
+        //patches directory containing the Data Dragon snapshot fixture as patch 14.23
+        let patches_dir: PathBuf =
+            std::env::temp_dir().join(format!("lol_build_finder_patches_{}", std::process::id()));
+        let patch_dir: PathBuf = patches_dir.join("14.23");
+        fs::create_dir_all(&patch_dir).expect("Failed to create patch directory");
+        let snapshot_dir: PathBuf =
+            Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/ddragon_snapshot");
+        for file in [SNAPSHOT_CHAMPIONS_FILE, SNAPSHOT_ITEMS_FILE] {
+            fs::copy(snapshot_dir.join(file), patch_dir.join(file))
+                .expect("Failed to copy snapshot fixture");
+        }
+
+        let patches: Vec<String> = available_patches(&patches_dir);
+        assert!(patches.contains(&GameDataSet::builtin().patch));
+        assert!(patches.contains(&"14.23".to_string()));
+
+        let game_data: &GameDataSet =
+            load_patch(&patches_dir, "14.23").expect("Failed to load patch fixture");
+        assert_eq!(game_data.patch, "14.23");
+        //loading the same patch again returns the same data set
+        assert!(std::ptr::eq(
+            game_data,
+            load_patch(&patches_dir, "14.23").expect("Failed to load patch fixture")
+        ));
+
+        //values from the snapshot
+        let ashe: &UnitProperties = game_data
+            .get_champion(&Unit::ASHE_PROPERTIES)
+            .expect("Failed to find champion in patch");
+        assert!((ashe.base_stats.hp - 640.).abs() < F32_TOL);
+        let infinity_edge: &Item = game_data
+            .items_database
+            .find_item_by_name("infinity edge")
+            .expect("Failed to find item in patch");
+        assert!((infinity_edge.cost - 3450.).abs() < F32_TOL);
+
+        //the compiled-in patch doesn't need a directory
+        assert!(std::ptr::eq(
+            load_patch(&patches_dir, &GameDataSet::builtin().patch)
+                .expect("Failed to load compiled-in patch"),
+            GameDataSet::builtin()
+        ));
+        assert!(load_patch(&patches_dir, "1.0").is_err());
+        assert!(load_patch(&patches_dir, "../patches").is_err());
+
+        fs::remove_dir_all(&patches_dir).expect("Failed to remove patches directory");
+    }
+}
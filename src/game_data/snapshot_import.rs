@@ -0,0 +1,422 @@
+use super::units_data::{
+    items_data::{sanitize_item_name, Item, ItemsDatabase},
+    UnitProperties, UnitStats,
+};
+
+use serde::Deserialize;
+
+use core::fmt;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+/// Name of the champions file in a Data Dragon snapshot directory.
+pub const SNAPSHOT_CHAMPIONS_FILE: &str = "champion.json";
+/// Name of the items file in a Data Dragon snapshot directory.
+pub const SNAPSHOT_ITEMS_FILE: &str = "item.json";
+/// Summoner's rift map id in Data Dragon files (used to choose between items with the same name).
+const SUMMONERS_RIFT_MAP_ID: &str = "11";
+
+//Data Dragon files layout, fields that are not needed are ignored.
+#[derive(Debug, Deserialize)]
+struct SnapshotChampions {
+    data: BTreeMap<String, SnapshotChampion>,
+}
+
+#[derive(Debug, Deserialize)]
+struct SnapshotChampion {
+    name: String,
+    stats: BTreeMap<String, f32>,
+}
+
+#[derive(Debug, Deserialize)]
+struct SnapshotItems {
+    data: BTreeMap<String, SnapshotItem>,
+}
+
+#[derive(Debug, Deserialize)]
+struct SnapshotItem {
+    name: String,
+    gold: SnapshotGold,
+    #[serde(default)]
+    stats: BTreeMap<String, f32>,
+    #[serde(default)]
+    maps: BTreeMap<String, bool>,
+}
+
+#[derive(Debug, Deserialize)]
+struct SnapshotGold {
+    total: f32,
+}
+
+/// Champion stats imported from the snapshot: (snapshot key, stat name, getter of the stat in `UnitStats`, value multiplier).
+/// `base_stats` and `growth_stats` are listed separately because Data Dragon uses different keys for them.
+type StatMapping = (
+    &'static str,
+    &'static str,
+    fn(&mut UnitStats) -> &mut f32,
+    f32,
+);
+
+const CHAMPION_BASE_STATS_MAPPING: [StatMapping; 8] = [
+    ("hp", "hp", |stats| &mut stats.hp, 1.),
+    ("mp", "mana", |stats| &mut stats.mana, 1.),
+    ("attackdamage", "base_ad", |stats| &mut stats.base_ad, 1.),
+    ("armor", "armor", |stats| &mut stats.armor, 1.),
+    ("spellblock", "mr", |stats| &mut stats.mr, 1.),
+    ("attackspeed", "base_as", |stats| &mut stats.base_as, 1.),
+    ("movespeed", "ms_flat", |stats| &mut stats.ms_flat, 1.),
+    (
+        "attackrange",
+        "attack_range",
+        |stats| &mut stats.attack_range,
+        1.,
+    ),
+];
+
+const CHAMPION_GROWTH_STATS_MAPPING: [StatMapping; 6] = [
+    ("hpperlevel", "hp", |stats| &mut stats.hp, 1.),
+    ("mpperlevel", "mana", |stats| &mut stats.mana, 1.),
+    (
+        "attackdamageperlevel",
+        "base_ad",
+        |stats| &mut stats.base_ad,
+        1.,
+    ),
+    ("armorperlevel", "armor", |stats| &mut stats.armor, 1.),
+    ("spellblockperlevel", "mr", |stats| &mut stats.mr, 1.),
+    //attack speed growth is given in % in Data Dragon
+    (
+        "attackspeedperlevel",
+        "bonus_as",
+        |stats| &mut stats.bonus_as,
+        0.01,
+    ),
+];
+
+/// Item stats imported from the snapshot. Stats that are not listed (lethality, ability haste, ...)
+/// are not available in Data Dragon files and keep their current value.
+const ITEM_STATS_MAPPING: [StatMapping; 11] = [
+    ("FlatHPPoolMod", "hp", |stats| &mut stats.hp, 1.),
+    ("FlatMPPoolMod", "mana", |stats| &mut stats.mana, 1.),
+    (
+        "FlatPhysicalDamageMod",
+        "bonus_ad",
+        |stats| &mut stats.bonus_ad,
+        1.,
+    ),
+    (
+        "FlatMagicDamageMod",
+        "ap_flat",
+        |stats| &mut stats.ap_flat,
+        1.,
+    ),
+    ("FlatArmorMod", "armor", |stats| &mut stats.armor, 1.),
+    ("FlatSpellBlockMod", "mr", |stats| &mut stats.mr, 1.),
+    (
+        "PercentAttackSpeedMod",
+        "bonus_as",
+        |stats| &mut stats.bonus_as,
+        1.,
+    ),
+    (
+        "FlatCritChanceMod",
+        "crit_chance",
+        |stats| &mut stats.crit_chance,
+        1.,
+    ),
+    (
+        "FlatMovementSpeedMod",
+        "ms_flat",
+        |stats| &mut stats.ms_flat,
+        1.,
+    ),
+    (
+        "PercentMovementSpeedMod",
+        "ms_percent",
+        |stats| &mut stats.ms_percent,
+        1.,
+    ),
+    (
+        "PercentLifeStealMod",
+        "life_steal",
+        |stats| &mut stats.life_steal,
+        1.,
+    ),
+];
+
+/// Tolerance under which imported values are considered equal to the current ones.
+const IMPORT_DIFF_TOL: f32 = 1e-4;
+
+/// Value that differs between the current data and the snapshot.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ValueDiff {
+    /// Name of the champion or item the value belongs to.
+    pub owner: String,
+    pub field: String,
+    pub old_value: f32,
+    pub new_value: f32,
+}
+
+/// Every difference found while importing a snapshot, along with the champions/items that were not found in it.
+#[derive(Debug, Default)]
+pub struct ImportReport {
+    pub diffs: Vec<ValueDiff>,
+    pub missing_champions: Vec<&'static str>,
+    pub missing_items: Vec<&'static str>,
+}
+
+impl fmt::Display for ImportReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.diffs.is_empty() {
+            writeln!(f, "No value differs from the snapshot")?;
+        } else {
+            writeln!(f, "{} values differ from the snapshot:", self.diffs.len())?;
+            for diff in &self.diffs {
+                writeln!(
+                    f,
+                    "- {} {}: {} -> {}",
+                    diff.owner, diff.field, diff.old_value, diff.new_value
+                )?;
+            }
+        }
+        if !self.missing_champions.is_empty() {
+            writeln!(
+                f,
+                "Champions not found in the snapshot (unchanged): {}",
+                self.missing_champions.join(", ")
+            )?;
+        }
+        if !self.missing_items.is_empty() {
+            writeln!(
+                f,
+                "Items not found in the snapshot (unchanged): {}",
+                self.missing_items.join(", ")
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Champions and items with their values overridden by a snapshot.
+#[derive(Debug)]
+pub struct SnapshotImport {
+    pub champions: Vec<&'static UnitProperties>,
+    pub items_database: &'static ItemsDatabase,
+    pub report: ImportReport,
+}
+
+impl SnapshotImport {
+    /// Returns the imported version of the given champion (matched by name), if any.
+    #[must_use]
+    pub fn get_champion(&self, properties: &UnitProperties) -> Option<&'static UnitProperties> {
+        self.champions
+            .iter()
+            .find(|&&champion| *champion == *properties)
+            .copied()
+    }
+}
+
+/// Applies the stats found in the snapshot to `stats` according to the given mapping and records the differences.
+/// If `missing_is_zero` is true, stats absent from the snapshot are set to 0 (Data Dragon omits null item stats).
+fn apply_stats(
+    snapshot_stats: &BTreeMap<String, f32>,
+    mapping: &[StatMapping],
+    missing_is_zero: bool,
+    stats: &mut UnitStats,
+    (owner, prefix): (&str, &str),
+    diffs: &mut Vec<ValueDiff>,
+) {
+    for (key, stat_name, getter, multiplier) in mapping {
+        let new_value: f32 = match snapshot_stats.get(*key) {
+            Some(value) => value * multiplier,
+            None if missing_is_zero => 0.,
+            None => continue,
+        };
+        let stat: &mut f32 = getter(stats);
+        if (*stat - new_value).abs() > IMPORT_DIFF_TOL {
+            diffs.push(ValueDiff {
+                owner: owner.to_string(),
+                field: format!("{prefix}{stat_name}"),
+                old_value: *stat,
+                new_value,
+            });
+            *stat = new_value;
+        }
+    }
+}
+
+/// Overrides champions base and growth stats, items cost and items stats with the values of a Data Dragon snapshot.
+/// `champions_json` and `items_json` are the contents of the snapshot `champion.json` and `item.json` files,
+/// `snapshot_name` is only used to identify the resulting items database.
+///
+/// Champions and items are matched by name, those that are not in the snapshot are kept unchanged.
+/// Imported champions and items are leaked to get `&'static` references, so this is meant to be called a few times at most.
+pub fn import_snapshot_from_str(
+    champions_json: &str,
+    items_json: &str,
+    snapshot_name: &str,
+    champions: &[&'static UnitProperties],
+    items_database: &ItemsDatabase,
+) -> Result<SnapshotImport, String> {
+    let snapshot_champions: SnapshotChampions = serde_json::from_str(champions_json)
+        .map_err(|error| format!("Failed to parse snapshot champions: {error}"))?;
+    let snapshot_items: SnapshotItems = serde_json::from_str(items_json)
+        .map_err(|error| format!("Failed to parse snapshot items: {error}"))?;
+
+    let mut report: ImportReport = ImportReport::default();
+
+    let mut imported_champions: Vec<&'static UnitProperties> = Vec::with_capacity(champions.len());
+    for &properties in champions {
+        let Some(snapshot_champion) = snapshot_champions.data.values().find(|champion| {
+            sanitize_item_name(&champion.name) == sanitize_item_name(properties.name)
+        }) else {
+            report.missing_champions.push(properties.name);
+            imported_champions.push(properties);
+            continue;
+        };
+
+        let mut imported: UnitProperties = properties.clone();
+        apply_stats(
+            &snapshot_champion.stats,
+            &CHAMPION_BASE_STATS_MAPPING,
+            false,
+            &mut imported.base_stats,
+            (properties.name, "base "),
+            &mut report.diffs,
+        );
+        apply_stats(
+            &snapshot_champion.stats,
+            &CHAMPION_GROWTH_STATS_MAPPING,
+            false,
+            &mut imported.growth_stats,
+            (properties.name, "growth "),
+            &mut report.diffs,
+        );
+        imported_champions.push(Box::leak(Box::new(imported)));
+    }
+
+    let mut imported_items: Vec<&'static Item> = Vec::with_capacity(items_database.n_items());
+    for item in items_database.all_items() {
+        //items with the same name may exist for different maps, prefer the summoner's rift version
+        let mut candidates: Vec<&SnapshotItem> = snapshot_items
+            .data
+            .values()
+            .filter(|snapshot_item| {
+                sanitize_item_name(&snapshot_item.name) == sanitize_item_name(item.full_name)
+            })
+            .collect();
+        candidates.sort_by_key(|snapshot_item| {
+            !snapshot_item
+                .maps
+                .get(SUMMONERS_RIFT_MAP_ID)
+                .copied()
+                .unwrap_or(true)
+        });
+        let Some(snapshot_item) = candidates.first() else {
+            report.missing_items.push(item.full_name);
+            imported_items.push(item);
+            continue;
+        };
+
+        let mut imported: Item = item.clone();
+        if (imported.cost - snapshot_item.gold.total).abs() > IMPORT_DIFF_TOL {
+            report.diffs.push(ValueDiff {
+                owner: item.full_name.to_string(),
+                field: "cost".to_string(),
+                old_value: imported.cost,
+                new_value: snapshot_item.gold.total,
+            });
+            imported.cost = snapshot_item.gold.total;
+        }
+        apply_stats(
+            &snapshot_item.stats,
+            &ITEM_STATS_MAPPING,
+            true,
+            &mut imported.stats,
+            (item.full_name, ""),
+            &mut report.diffs,
+        );
+        imported_items.push(Box::leak(Box::new(imported)));
+    }
+
+    Ok(SnapshotImport {
+        champions: imported_champions,
+        items_database: ItemsDatabase::from_items(
+            &format!("{} + snapshot '{snapshot_name}'", items_database.source),
+            imported_items,
+        ),
+        report,
+    })
+}
+
+/// Reads a Data Dragon snapshot directory (containing `SNAPSHOT_CHAMPIONS_FILE` and `SNAPSHOT_ITEMS_FILE`)
+/// and overrides the given champions and items values with it (see `import_snapshot_from_str`).
+pub fn import_snapshot(
+    dir: &Path,
+    champions: &[&'static UnitProperties],
+    items_database: &ItemsDatabase,
+) -> Result<SnapshotImport, String> {
+    let read = |file_name: &str| -> Result<String, String> {
+        let path = dir.join(file_name);
+        fs::read_to_string(&path)
+            .map_err(|error| format!("Failed to read '{}': {error}", path.display()))
+    };
+    import_snapshot_from_str(
+        &read(SNAPSHOT_CHAMPIONS_FILE)?,
+        &read(SNAPSHOT_ITEMS_FILE)?,
+        &dir.display().to_string(),
+        champions,
+        items_database,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::game_data::units_data::Unit;
+
+    #[test]
+    pub fn test_import_snapshot_fixture() {
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/ddragon_snapshot");
+        let import: SnapshotImport =
+            import_snapshot(&dir, &Unit::ALL_CHAMPIONS, ItemsDatabase::builtin())
+                .expect("Failed to import snapshot fixture");
+
+        //ashe values in the fixture are modified: hp 610 -> 640, attack speed growth 3.33% -> 3.5%
+        let ashe: &UnitProperties = import
+            .get_champion(&Unit::ASHE_PROPERTIES)
+            .expect("Ashe is missing from imported champions");
+        assert!((ashe.base_stats.hp - 640.).abs() < IMPORT_DIFF_TOL);
+        assert!((ashe.growth_stats.bonus_as - 0.035).abs() < IMPORT_DIFF_TOL);
+        assert!(
+            (ashe.base_stats.mana - Unit::ASHE_PROPERTIES.base_stats.mana).abs() < IMPORT_DIFF_TOL
+        );
+
+        //infinity edge values in the fixture are modified: cost 3600 -> 3450, crit chance unchanged
+        let infinity_edge: &Item = import
+            .items_database
+            .get_item(&Item::INFINITY_EDGE)
+            .expect("Infinity edge is missing from imported items");
+        assert!((infinity_edge.cost - 3450.).abs() < IMPORT_DIFF_TOL);
+        assert!(
+            (infinity_edge.stats.crit_dmg - Item::INFINITY_EDGE.stats.crit_dmg).abs()
+                < IMPORT_DIFF_TOL
+        ); //not in snapshot
+
+        //only the modified values are reported
+        let mut diff_fields: Vec<String> = import
+            .report
+            .diffs
+            .iter()
+            .map(|diff| format!("{} {}", diff.owner, diff.field))
+            .collect();
+        diff_fields.sort_unstable();
+        assert_eq!(
+            diff_fields,
+            ["Ashe base hp", "Ashe growth bonus_as", "Infinity_edge cost"]
+        );
+        assert!(import.report.missing_champions.contains(&"Draven"));
+    }
+}
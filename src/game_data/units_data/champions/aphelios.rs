@@ -2,6 +2,7 @@ use crate::game_data::*;
 
 use items_data::Item;
 use runes_data::*;
+use summoner_spells_data::*;
 use units_data::*;
 
 use enumset::enum_set;
@@ -321,7 +322,10 @@ fn aphelios_r(champ: &mut Unit, target_stats: &UnitStats) -> PartDmg {
         * (APHELIOS_R_CALIBRUM_PHYS_DMG_BY_R_LVL[r_lvl_idx] + 15. + 0.2 * champ.stats.bonus_ad);
 
     //severum, heal weighted 1/5
-    champ.single_use_heals_shields += 1. / 5. * (APHELIOS_R_SEVERUM_HEAL_BY_R_LVL[r_lvl_idx]);
+    champ.add_heal(
+        1. / 5. * (APHELIOS_R_SEVERUM_HEAL_BY_R_LVL[r_lvl_idx]),
+        false,
+    );
 
     //gravitum, root not taken into account
 
@@ -355,22 +359,12 @@ fn aphelios_r(champ: &mut Unit, target_stats: &UnitStats) -> PartDmg {
 fn aphelios_fight_scenario(champ: &mut Unit, target_stats: &UnitStats, fight_duration: f32) {
     while champ.time < fight_duration {
         //priority order: q, basic attack
-        if champ.q_cd == 0. {
+        if champ.q_cd() == 0. {
             champ.q(target_stats);
-        } else if champ.basic_attack_cd == 0. {
+        } else if champ.basic_attack_cd() == 0. {
             champ.basic_attack(target_stats);
         } else {
-            champ.walk(
-                F32_TOL
-                    + [
-                        champ.q_cd,
-                        champ.basic_attack_cd,
-                        f32::max(0., fight_duration - champ.time),
-                    ]
-                    .into_iter()
-                    .min_by(|a, b| a.partial_cmp(b).expect("Failed to compare floats"))
-                    .unwrap(),
-            );
+            champ.walk_until_ready([champ.q_cd(), champ.basic_attack_cd()], fight_duration);
         }
     }
     //add weighted r dmg at the end
@@ -384,7 +378,8 @@ impl Unit {
         as_limit: Unit::DEFAULT_AS_LIMIT,
         as_ratio: APHELIOS_BASE_AS, //if not specified, same as base AS
         windup_percent: 0.15333,
-        windup_modifier: 1., //"mod" next to attack windup, 1 by default
+        windup_modifier: 1.,     //"mod" next to attack windup, 1 by default
+        projectile_speed: 1800., //basic attack projectile speed
         base_stats: UnitStats {
             hp: 600.,
             mana: 348.,
@@ -404,6 +399,7 @@ impl Unit {
             crit_dmg: Unit::BASE_CRIT_DMG,
             ms_flat: 325.,
             ms_percent: 0.,
+            attack_range: 550.,
             lethality: 0.,
             armor_pen_percent: 0.,
             magic_pen_flat: 0.,
@@ -439,6 +435,7 @@ impl Unit {
             crit_dmg: 0.,
             ms_flat: 0.,
             ms_percent: 0.,
+            attack_range: 0.,
             lethality: 0.,
             armor_pen_percent: 0.,
             magic_pen_flat: 0.,
@@ -487,10 +484,19 @@ impl Unit {
         defaults: UnitDefaults {
             runes_pages: RunesPage {
                 keystone: &RuneKeystone::PRESS_THE_ATTACK, //todo: prone to change
+                primary_runes: [
+                    &MinorRune::PRESENCE_OF_MIND,
+                    &MinorRune::LEGEND_ALACRITY,
+                    &MinorRune::COUP_DE_GRACE,
+                ],
+                secondary_runes: [&MinorRune::TASTE_OF_BLOOD, &MinorRune::ULTIMATE_HUNTER],
                 shard1: RuneShard::Middle,
                 shard2: RuneShard::Left,
                 shard3: RuneShard::Left,
             },
+            summoner_spells: SummonerSpells {
+                spells: [&SummonerSpell::FLASH, &SummonerSpell::HEAL],
+            },
             skill_order: SkillOrder {
                 //lvls:
                 //  1  2  3  4  5  6  7  8  9 10 11 12 13 14 15 16 17 18
@@ -583,7 +589,13 @@ impl Unit {
                 //&Item::PLATED_STEELCAPS,
                 //&Item::SORCERERS_SHOES,
             ],
-            supp_items_pool: &[],
+            supp_items_pool: &[
+                &Item::BLOODSONG,
+                &Item::CELESTIAL_OPPOSITION,
+                &Item::DREAM_MAKER,
+                &Item::SOLSTICE_SLEIGH,
+                &Item::ZAZZAKS_REALMSPIKE,
+            ],
         },
     };
 }
@@ -2,6 +2,7 @@ use crate::game_data::*;
 
 use items_data::Item;
 use runes_data::*;
+use summoner_spells_data::*;
 use units_data::*;
 
 use enumset::enum_set;
@@ -82,11 +83,12 @@ const RANGERS_FOCUS_BUFF: TemporaryEffect = TemporaryEffect {
     remove_every_stack: ashe_rangers_focus_disable,
     duration: 6.,
     cooldown: 0.,
+    restore_target_debuff: None,
 };
 
 fn ashe_q(champ: &mut Unit, _target_stats: &UnitStats) -> PartDmg {
     champ.add_temporary_effect(&RANGERS_FOCUS_BUFF, 0.);
-    champ.basic_attack_cd = 0.; //q resets basic attack cd
+    champ.set_basic_attack_cd(0.); //q resets basic attack cd
     PartDmg(0., 0., 0.)
 }
 
@@ -139,31 +141,27 @@ fn ashe_fight_scenario(champ: &mut Unit, target_stats: &UnitStats, fight_duratio
 
     while champ.time < fight_duration {
         //priority order: w, q, basic attack
-        if champ.w_cd == 0. {
+        if champ.w_cd() == 0. {
             champ.w(target_stats);
         } else if champ.effects_stacks[EffectStackId::AsheFocusStacks] == Q_MAX_STACKS {
             //ashe q has no cd
             champ.q(target_stats);
             //basic attack directly after
             //ashe q resets cooldown (patch 14.17) but we keep that here for consistency in case it gets changed
-            if champ.basic_attack_cd != 0. {
-                champ.walk(champ.basic_attack_cd + F32_TOL);
+            if champ.basic_attack_cd() != 0. {
+                champ.walk(champ.basic_attack_cd());
             }
             champ.basic_attack(target_stats);
-        } else if champ.basic_attack_cd == 0. {
+        } else if champ.basic_attack_cd() == 0. {
             champ.basic_attack(target_stats);
         } else {
-            champ.walk(
-                F32_TOL
-                    + [
-                        //ashe q has no cd
-                        champ.w_cd,
-                        champ.basic_attack_cd,
-                        f32::max(0., fight_duration - champ.time),
-                    ]
-                    .into_iter()
-                    .min_by(|a, b| a.partial_cmp(b).expect("Failed to compare floats"))
-                    .unwrap(),
+            champ.walk_until_ready(
+                [
+                    //ashe q has no cd
+                    champ.w_cd(),
+                    champ.basic_attack_cd(),
+                ],
+                fight_duration,
             );
         }
     }
@@ -176,7 +174,8 @@ impl Unit {
         as_limit: Unit::DEFAULT_AS_LIMIT,
         as_ratio: ASHE_BASE_AS, //if not specified, same as base AS
         windup_percent: 0.2193,
-        windup_modifier: 1., //"mod" next to attack windup, 1 by default
+        windup_modifier: 1.,     //"mod" next to attack windup, 1 by default
+        projectile_speed: 2000., //basic attack projectile speed
         base_stats: UnitStats {
             hp: 610.,
             mana: 280.,
@@ -196,6 +195,7 @@ impl Unit {
             crit_dmg: Unit::BASE_CRIT_DMG,
             ms_flat: 325.,
             ms_percent: 0.,
+            attack_range: 600.,
             lethality: 0.,
             armor_pen_percent: 0.,
             magic_pen_flat: 0.,
@@ -231,6 +231,7 @@ impl Unit {
             crit_dmg: 0.,
             ms_flat: 0.,
             ms_percent: 0.,
+            attack_range: 0.,
             lethality: 0.,
             armor_pen_percent: 0.,
             magic_pen_flat: 0.,
@@ -287,10 +288,19 @@ impl Unit {
         defaults: UnitDefaults {
             runes_pages: RunesPage {
                 keystone: &RuneKeystone::LETHAL_TEMPO, //todo: prone to change
+                primary_runes: [
+                    &MinorRune::PRESENCE_OF_MIND,
+                    &MinorRune::LEGEND_ALACRITY,
+                    &MinorRune::CUT_DOWN,
+                ],
+                secondary_runes: [&MinorRune::ABSOLUTE_FOCUS, &MinorRune::GATHERING_STORM],
                 shard1: RuneShard::Middle,
                 shard2: RuneShard::Left,
                 shard3: RuneShard::Left,
             },
+            summoner_spells: SummonerSpells {
+                spells: [&SummonerSpell::FLASH, &SummonerSpell::HEAL],
+            },
             skill_order: SkillOrder {
                 //lvls:
                 //  1  2  3  4  5  6  7  8  9 10 11 12 13 14 15 16 17 18
@@ -383,7 +393,13 @@ impl Unit {
                 //&Item::PLATED_STEELCAPS,
                 //&Item::SORCERERS_SHOES,
             ],
-            supp_items_pool: &[],
+            supp_items_pool: &[
+                &Item::BLOODSONG,
+                &Item::CELESTIAL_OPPOSITION,
+                &Item::DREAM_MAKER,
+                &Item::SOLSTICE_SLEIGH,
+                &Item::ZAZZAKS_REALMSPIKE,
+            ],
         },
     };
 }
@@ -2,6 +2,7 @@ use crate::game_data::*;
 
 use items_data::Item;
 use runes_data::*;
+use summoner_spells_data::*;
 use units_data::*;
 
 use enumset::enum_set;
@@ -129,22 +130,12 @@ fn caitlyn_fight_scenario(champ: &mut Unit, target_stats: &UnitStats, fight_dura
 
     while champ.time < fight_duration {
         //priority order: q, basic attack
-        if champ.q_cd == 0. {
+        if champ.q_cd() == 0. {
             champ.q(target_stats);
-        } else if champ.basic_attack_cd == 0. {
+        } else if champ.basic_attack_cd() == 0. {
             champ.basic_attack(target_stats);
         } else {
-            champ.walk(
-                F32_TOL
-                    + [
-                        champ.q_cd,
-                        champ.basic_attack_cd,
-                        f32::max(0., fight_duration - champ.time),
-                    ]
-                    .into_iter()
-                    .min_by(|a, b| a.partial_cmp(b).expect("Failed to compare floats"))
-                    .unwrap(),
-            );
+            champ.walk_until_ready([champ.q_cd(), champ.basic_attack_cd()], fight_duration);
         }
     }
     //add weighted r dmg at the end
@@ -158,7 +149,8 @@ impl Unit {
         as_limit: Unit::DEFAULT_AS_LIMIT,
         as_ratio: 0.625,
         windup_percent: 0.17708,
-        windup_modifier: 1., //"mod" next to attack windup, 1 by default
+        windup_modifier: 1.,     //"mod" next to attack windup, 1 by default
+        projectile_speed: 2500., //basic attack projectile speed
         base_stats: UnitStats {
             hp: 580.,
             mana: 315.,
@@ -178,6 +170,7 @@ impl Unit {
             crit_dmg: Unit::BASE_CRIT_DMG,
             ms_flat: 325.,
             ms_percent: 0.,
+            attack_range: 650.,
             lethality: 0.,
             armor_pen_percent: 0.,
             magic_pen_flat: 0.,
@@ -213,6 +206,7 @@ impl Unit {
             crit_dmg: 0.,
             ms_flat: 0.,
             ms_percent: 0.,
+            attack_range: 0.,
             lethality: 0.,
             armor_pen_percent: 0.,
             magic_pen_flat: 0.,
@@ -269,10 +263,19 @@ impl Unit {
         defaults: UnitDefaults {
             runes_pages: RunesPage {
                 keystone: &RuneKeystone::FLEET_FOOTWORK, //todo: prone to change (no real good rune for cait rn in 14.20 kek)
+                primary_runes: [
+                    &MinorRune::PRESENCE_OF_MIND,
+                    &MinorRune::LEGEND_BLOODLINE,
+                    &MinorRune::COUP_DE_GRACE,
+                ],
+                secondary_runes: [&MinorRune::ABSOLUTE_FOCUS, &MinorRune::GATHERING_STORM],
                 shard1: RuneShard::Middle,
                 shard2: RuneShard::Left,
                 shard3: RuneShard::Left,
             },
+            summoner_spells: SummonerSpells {
+                spells: [&SummonerSpell::FLASH, &SummonerSpell::HEAL],
+            },
             skill_order: SkillOrder {
                 //lvls:
                 //  1  2  3  4  5  6  7  8  9 10 11 12 13 14 15 16 17 18
@@ -365,7 +368,13 @@ impl Unit {
                 //&Item::PLATED_STEELCAPS,
                 //&Item::SORCERERS_SHOES,
             ],
-            supp_items_pool: &[],
+            supp_items_pool: &[
+                &Item::BLOODSONG,
+                &Item::CELESTIAL_OPPOSITION,
+                &Item::DREAM_MAKER,
+                &Item::SOLSTICE_SLEIGH,
+                &Item::ZAZZAKS_REALMSPIKE,
+            ],
         },
     };
 }
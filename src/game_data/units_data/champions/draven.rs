@@ -2,6 +2,7 @@ use crate::game_data::*;
 
 use items_data::Item;
 use runes_data::*;
+use summoner_spells_data::*;
 use units_data::*;
 
 use enumset::enum_set;
@@ -30,7 +31,7 @@ fn draven_throw_axe(champ: &mut Unit, _availability_coef: f32) {
 fn draven_catch_axe(champ: &mut Unit) {
     champ.effects_stacks[EffectStackId::DravenAxesInAir] -= 1;
     champ.effects_stacks[EffectStackId::DravenAxesInHand] += 1;
-    champ.w_cd = 0.; //catching axe resets w cd
+    champ.set_w_cd(0.); //catching axe resets w cd
 }
 
 //effect for axe n1
@@ -40,6 +41,7 @@ const DRAVEN_THROW_AXE1: TemporaryEffect = TemporaryEffect {
     remove_every_stack: draven_catch_axe, //effect assumes draven catches every axe
     duration: AXE_TIME_SPENT_IN_AIR,
     cooldown: 0.,
+    restore_target_debuff: None,
 };
 
 //effect for axe n2
@@ -49,6 +51,7 @@ const DRAVEN_THROW_AXE2: TemporaryEffect = TemporaryEffect {
     remove_every_stack: draven_catch_axe, //effect assumes draven catches every axe
     duration: AXE_TIME_SPENT_IN_AIR,
     cooldown: 0.,
+    restore_target_debuff: None,
 };
 
 const SPINNING_AXE_PHYS_DMG_BY_Q_LVL: [f32; 5] = [40., 45., 50., 55., 60.];
@@ -67,16 +70,10 @@ fn draven_basic_attack(champ: &mut Unit, target_stats: &UnitStats) -> PartDmg {
         && champ.effects_stacks[EffectStackId::DravenAxesInAir] < 2
     {
         //this code only supports 2 axes in the air maximum, but its fine for most cases anyway
-        if !champ
-            .temporary_effects_durations
-            .contains_key(&DRAVEN_THROW_AXE1)
-        {
+        if !champ.has_temporary_effect(&DRAVEN_THROW_AXE1) {
             champ.add_temporary_effect(&DRAVEN_THROW_AXE1, 0.);
             phys_dmg += draven_q_axe_bonus_dmg(champ);
-        } else if !champ
-            .temporary_effects_durations
-            .contains_key(&DRAVEN_THROW_AXE2)
-        {
+        } else if !champ.has_temporary_effect(&DRAVEN_THROW_AXE2) {
             champ.add_temporary_effect(&DRAVEN_THROW_AXE2, 0.);
             phys_dmg += draven_q_axe_bonus_dmg(champ);
         }
@@ -126,6 +123,7 @@ const DRAVEN_BLOOD_RUSH: TemporaryEffect = TemporaryEffect {
     remove_every_stack: draven_blood_rush_disable,
     duration: 1.5,
     cooldown: 0.,
+    restore_target_debuff: None,
 };
 
 fn draven_w(champ: &mut Unit, _target_stats: &UnitStats) -> PartDmg {
@@ -176,37 +174,39 @@ fn draven_fight_scenario(champ: &mut Unit, target_stats: &UnitStats, fight_durat
     let mut basic_attacks_count: u8 = BASIC_ATTACKS_PER_W - 1;
     while champ.time < fight_duration {
         //priority order: q before basic attacking if less than 2 axes in hand, basic attack if at least one axe and less than 2 axes in air, w every x basic attack
-        if champ.basic_attack_cd == 0. && champ.effects_stacks[EffectStackId::DravenAxesInAir] < 2 {
+        if champ.basic_attack_cd() == 0. && champ.effects_stacks[EffectStackId::DravenAxesInAir] < 2
+        {
             //q before launching basic attack if available
-            if champ.q_cd == 0. && champ.effects_stacks[EffectStackId::DravenAxesInHand] < 2 {
+            if champ.q_cd() == 0. && champ.effects_stacks[EffectStackId::DravenAxesInHand] < 2 {
                 champ.q(target_stats);
             }
             champ.basic_attack(target_stats);
             basic_attacks_count += 1;
-        } else if champ.w_cd == 0. && basic_attacks_count >= BASIC_ATTACKS_PER_W {
+        } else if champ.w_cd() == 0. && basic_attacks_count >= BASIC_ATTACKS_PER_W {
             champ.w(target_stats);
             basic_attacks_count = 0;
         } else {
-            champ.walk(
-                F32_TOL
-                    + [
-                        //cap minimum waiting time by TIME_BETWEEN_CLICKS if forced to wait for the axes in air to launch basic attack
-                        //(waiting repeatedly by small time steps is faster than retrieving axes remaining in air duration)
-                        if champ.effects_stacks[EffectStackId::DravenAxesInAir] < 2 {
-                            champ.basic_attack_cd
-                        } else {
-                            f32::max(champ.basic_attack_cd, TIME_BETWEEN_CLICKS)
-                        },
-                        if basic_attacks_count >= BASIC_ATTACKS_PER_W {
-                            champ.w_cd
-                        } else {
-                            fight_duration - champ.time
-                        },
-                        f32::max(0., fight_duration - champ.time),
-                    ]
-                    .into_iter()
-                    .min_by(|a, b| a.partial_cmp(b).expect("Failed to compare floats"))
-                    .unwrap(),
+            champ.walk_until_ready(
+                [
+                    //if forced to wait for the axes in air to launch basic attack, wait until the first axe is caught
+                    if champ.effects_stacks[EffectStackId::DravenAxesInAir] < 2 {
+                        champ.basic_attack_cd()
+                    } else {
+                        f32::max(
+                            champ.basic_attack_cd(),
+                            f32::min(
+                                champ.get_temporary_effect_remaining_duration(&DRAVEN_THROW_AXE1),
+                                champ.get_temporary_effect_remaining_duration(&DRAVEN_THROW_AXE2),
+                            ),
+                        )
+                    },
+                    if basic_attacks_count >= BASIC_ATTACKS_PER_W {
+                        champ.w_cd()
+                    } else {
+                        fight_duration - champ.time
+                    },
+                ],
+                fight_duration,
             );
         }
     }
@@ -230,7 +230,8 @@ impl Unit {
         as_limit: Unit::DEFAULT_AS_LIMIT,
         as_ratio: DRAVEN_BASE_AS, //if not specified, same as base AS
         windup_percent: 0.15614,
-        windup_modifier: 1., //"mod" next to attack windup, 1 by default
+        windup_modifier: 1.,     //"mod" next to attack windup, 1 by default
+        projectile_speed: 1600., //basic attack projectile speed
         base_stats: UnitStats {
             hp: 675.,
             mana: 361.,
@@ -250,6 +251,7 @@ impl Unit {
             crit_dmg: Unit::BASE_CRIT_DMG,
             ms_flat: 330.,
             ms_percent: 0.,
+            attack_range: 550.,
             lethality: 0.,
             armor_pen_percent: 0.,
             magic_pen_flat: 0.,
@@ -285,6 +287,7 @@ impl Unit {
             crit_dmg: 0.,
             ms_flat: 0.,
             ms_percent: 0.,
+            attack_range: 0.,
             lethality: 0.,
             armor_pen_percent: 0.,
             magic_pen_flat: 0.,
@@ -344,10 +347,19 @@ impl Unit {
         defaults: UnitDefaults {
             runes_pages: RunesPage {
                 keystone: &RuneKeystone::PRESS_THE_ATTACK, //todo: prone to change
+                primary_runes: [
+                    &MinorRune::PRESENCE_OF_MIND,
+                    &MinorRune::LEGEND_ALACRITY,
+                    &MinorRune::COUP_DE_GRACE,
+                ],
+                secondary_runes: [&MinorRune::TASTE_OF_BLOOD, &MinorRune::ULTIMATE_HUNTER],
                 shard1: RuneShard::Left,
                 shard2: RuneShard::Left,
                 shard3: RuneShard::Left,
             },
+            summoner_spells: SummonerSpells {
+                spells: [&SummonerSpell::FLASH, &SummonerSpell::HEAL],
+            },
             skill_order: SkillOrder {
                 //lvls:
                 //  1  2  3  4  5  6  7  8  9 10 11 12 13 14 15 16 17 18
@@ -440,7 +452,13 @@ impl Unit {
                 //&Item::PLATED_STEELCAPS,
                 //&Item::SORCERERS_SHOES,
             ],
-            supp_items_pool: &[],
+            supp_items_pool: &[
+                &Item::BLOODSONG,
+                &Item::CELESTIAL_OPPOSITION,
+                &Item::DREAM_MAKER,
+                &Item::SOLSTICE_SLEIGH,
+                &Item::ZAZZAKS_REALMSPIKE,
+            ],
         },
     };
 }
@@ -2,6 +2,7 @@ use crate::game_data::*;
 
 use items_data::Item;
 use runes_data::*;
+use summoner_spells_data::*;
 use units_data::*;
 
 use enumset::enum_set;
@@ -47,6 +48,7 @@ const EZREAL_RISING_SPELL_FORCE: TemporaryEffect = TemporaryEffect {
     remove_every_stack: ezreal_rising_spell_force_disable,
     duration: 6.,
     cooldown: 0.,
+    restore_target_debuff: None,
 };
 
 const Q_PHYS_DMG_BY_Q_LVL: [f32; 5] = [20., 45., 70., 95., 120.];
@@ -61,10 +63,10 @@ fn ezreal_q(champ: &mut Unit, target_stats: &UnitStats) -> PartDmg {
         Q_PHYS_DMG_BY_Q_LVL[q_lvl_idx] + 1.30 * champ.stats.ad() + 0.15 * champ.stats.ap();
 
     //q hit reduces abilities cooldown
-    champ.q_cd = f32::max(0., champ.q_cd - Q_HIT_PERCENT * Q_CD_REFUND);
-    champ.w_cd = f32::max(0., champ.w_cd - Q_HIT_PERCENT * Q_CD_REFUND);
-    champ.e_cd = f32::max(0., champ.e_cd - Q_HIT_PERCENT * Q_CD_REFUND);
-    champ.r_cd = f32::max(0., champ.r_cd - Q_HIT_PERCENT * Q_CD_REFUND);
+    champ.set_q_cd(f32::max(0., champ.q_cd() - Q_HIT_PERCENT * Q_CD_REFUND));
+    champ.set_w_cd(f32::max(0., champ.w_cd() - Q_HIT_PERCENT * Q_CD_REFUND));
+    champ.set_e_cd(f32::max(0., champ.e_cd() - Q_HIT_PERCENT * Q_CD_REFUND));
+    champ.set_r_cd(f32::max(0., champ.r_cd() - Q_HIT_PERCENT * Q_CD_REFUND));
 
     //add passive stack
     champ.add_temporary_effect(&EZREAL_RISING_SPELL_FORCE, 0.);
@@ -179,24 +181,16 @@ fn ezreal_fight_scenario(champ: &mut Unit, target_stats: &UnitStats, fight_durat
 
     while champ.time < fight_duration {
         //priority order: w, q, basic attack (no e)
-        if champ.w_cd == 0. {
+        if champ.w_cd() == 0. {
             champ.w(target_stats);
-        } else if champ.q_cd == 0. {
+        } else if champ.q_cd() == 0. {
             champ.q(target_stats);
-        } else if champ.basic_attack_cd == 0. {
+        } else if champ.basic_attack_cd() == 0. {
             champ.basic_attack(target_stats);
         } else {
-            champ.walk(
-                F32_TOL
-                    + [
-                        champ.w_cd,
-                        champ.q_cd,
-                        champ.basic_attack_cd,
-                        f32::max(0., fight_duration - champ.time),
-                    ]
-                    .into_iter()
-                    .min_by(|a, b| a.partial_cmp(b).expect("Failed to compare floats"))
-                    .unwrap(),
+            champ.walk_until_ready(
+                [champ.w_cd(), champ.q_cd(), champ.basic_attack_cd()],
+                fight_duration,
             );
         }
     }
@@ -215,22 +209,12 @@ fn ezreal_fight_scenario_abilities_only(
 
     while champ.time < fight_duration {
         //priority order: w, q (no basic attack, no e)
-        if champ.w_cd == 0. {
+        if champ.w_cd() == 0. {
             champ.w(target_stats);
-        } else if champ.q_cd == 0. {
+        } else if champ.q_cd() == 0. {
             champ.q(target_stats);
         } else {
-            champ.walk(
-                F32_TOL
-                    + [
-                        champ.w_cd,
-                        champ.q_cd,
-                        f32::max(0., fight_duration - champ.time),
-                    ]
-                    .into_iter()
-                    .min_by(|a, b| a.partial_cmp(b).expect("Failed to compare floats"))
-                    .unwrap(),
-            );
+            champ.walk_until_ready([champ.w_cd(), champ.q_cd()], fight_duration);
         }
     }
     //add weighted r dmg at the end
@@ -244,7 +228,8 @@ impl Unit {
         as_limit: Unit::DEFAULT_AS_LIMIT,
         as_ratio: EZREAL_BASE_AS,
         windup_percent: 0.18839,
-        windup_modifier: 1., //"mod" next to attack windup, 1 by default
+        windup_modifier: 1.,     //"mod" next to attack windup, 1 by default
+        projectile_speed: 2000., //basic attack projectile speed
         base_stats: UnitStats {
             hp: 600.,
             mana: 375.,
@@ -264,6 +249,7 @@ impl Unit {
             crit_dmg: Unit::BASE_CRIT_DMG,
             ms_flat: 325.,
             ms_percent: 0.,
+            attack_range: 550.,
             lethality: 0.,
             armor_pen_percent: 0.,
             magic_pen_flat: 0.,
@@ -299,6 +285,7 @@ impl Unit {
             crit_dmg: 0.,
             ms_flat: 0.,
             ms_percent: 0.,
+            attack_range: 0.,
             lethality: 0.,
             armor_pen_percent: 0.,
             magic_pen_flat: 0.,
@@ -358,10 +345,19 @@ impl Unit {
         defaults: UnitDefaults {
             runes_pages: RunesPage {
                 keystone: &RuneKeystone::PRESS_THE_ATTACK, //PTA for short fights, conq better for long fights //todo: prone to change
+                primary_runes: [
+                    &MinorRune::PRESENCE_OF_MIND,
+                    &MinorRune::LEGEND_ALACRITY,
+                    &MinorRune::COUP_DE_GRACE,
+                ],
+                secondary_runes: [&MinorRune::ABSOLUTE_FOCUS, &MinorRune::GATHERING_STORM],
                 shard1: RuneShard::Left,
                 shard2: RuneShard::Left,
                 shard3: RuneShard::Left,
             },
+            summoner_spells: SummonerSpells {
+                spells: [&SummonerSpell::FLASH, &SummonerSpell::HEAL],
+            },
             skill_order: SkillOrder {
                 //lvls:
                 //  1  2  3  4  5  6  7  8  9 10 11 12 13 14 15 16 17 18
@@ -454,7 +450,13 @@ impl Unit {
                 //&Item::PLATED_STEELCAPS,
                 &Item::SORCERERS_SHOES,
             ],
-            supp_items_pool: &[],
+            supp_items_pool: &[
+                &Item::BLOODSONG,
+                &Item::CELESTIAL_OPPOSITION,
+                &Item::DREAM_MAKER,
+                &Item::SOLSTICE_SLEIGH,
+                &Item::ZAZZAKS_REALMSPIKE,
+            ],
         },
     };
 }
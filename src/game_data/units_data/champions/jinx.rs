@@ -2,34 +2,48 @@ use crate::game_data::*;
 
 use items_data::Item;
 use runes_data::*;
+use summoner_spells_data::*;
 use units_data::*;
 
 use enumset::enum_set;
 
 //champion parameters (constants):
 const JINX_W_HIT_PERCENT: f32 = 0.75;
-const JINX_R_TARGET_MISSING_HP_PERCENT: f32 = 0.67;
 const JINX_R_AVG_TARGETS: f32 = 1.2;
 const JINX_R_HIT_PERCENT: f32 = 0.85;
 
 const JINX_ROCKET_LAUNCHER_AOE_RADIUS: f32 = 250.;
 const JINX_ROCKET_LAUNCHER_AOE_AVG_TARGETS: f32 =
     basic_attack_aoe_effect_avg_additionnal_targets!(JINX_ROCKET_LAUNCHER_AOE_RADIUS);
+const JINX_ROCKET_LAUNCHER_AOE_MAX_TARGETS: f32 = 4.;
 
 /// Only rocket launcher.
 fn jinx_basic_attack(champ: &mut Unit, target_stats: &UnitStats) -> PartDmg {
     //attack speed already slowed in jinx as ratio
-    let phys_dmg: f32 = (1. + JINX_ROCKET_LAUNCHER_AOE_AVG_TARGETS)
-        * 1.1
-        * champ.stats.ad()
-        * champ.stats.crit_coef();
-    champ.dmg_on_target(
+    let phys_dmg: f32 = 1.1 * champ.stats.ad() * champ.stats.crit_coef();
+    let mut tot_dmg: PartDmg = champ.dmg_on_target(
         target_stats,
         PartDmg(phys_dmg, 0., 0.),
         (1, 1),
         enum_set!(DmgTag::BasicAttack),
-        1., //rocket launcher aoe doesnt trigger on hit on additionnal targets
-    )
+        1.,
+    );
+
+    //rocket launcher aoe, doesnt trigger on hit on additionnal targets
+    let aoe_n_targets: f32 = champ.aoe_additionnal_targets(
+        JINX_ROCKET_LAUNCHER_AOE_AVG_TARGETS,
+        JINX_ROCKET_LAUNCHER_AOE_MAX_TARGETS,
+    );
+    if aoe_n_targets > 0. {
+        tot_dmg += champ.dmg_on_target(
+            target_stats,
+            PartDmg(aoe_n_targets * phys_dmg, 0., 0.),
+            (0, 0),
+            enum_set!(DmgTag::BasicAttack | DmgTag::AroundTarget),
+            aoe_n_targets,
+        );
+    }
+    tot_dmg
 }
 
 fn jinx_q(_champ: &mut Unit, _target_stats: &UnitStats) -> PartDmg {
@@ -69,8 +83,7 @@ fn jinx_r(champ: &mut Unit, target_stats: &UnitStats) -> PartDmg {
     let phys_dmg: f32 = (1. + 0.8 * (JINX_R_AVG_TARGETS - 1.))
         * (0.64 * (JINX_R_PHYS_DMG_BY_R_LVL[r_lvl_idx] + 1.65 * champ.stats.bonus_ad)
             + (JINX_R_MISSING_HP_RATIO_BY_R_LVL[r_lvl_idx]
-                * JINX_R_TARGET_MISSING_HP_PERCENT
-                * target_stats.hp));
+                * (target_stats.hp - champ.get_target_current_hp(target_stats))));
 
     champ.dmg_on_target(
         target_stats,
@@ -84,22 +97,12 @@ fn jinx_r(champ: &mut Unit, target_stats: &UnitStats) -> PartDmg {
 fn jinx_fight_scenario(champ: &mut Unit, target_stats: &UnitStats, fight_duration: f32) {
     while champ.time < fight_duration {
         //priority order: w, basic attack
-        if champ.w_cd == 0. {
+        if champ.w_cd() == 0. {
             champ.w(target_stats);
-        } else if champ.basic_attack_cd == 0. {
+        } else if champ.basic_attack_cd() == 0. {
             champ.basic_attack(target_stats);
         } else {
-            champ.walk(
-                F32_TOL
-                    + [
-                        champ.w_cd,
-                        champ.basic_attack_cd,
-                        f32::max(0., fight_duration - champ.time),
-                    ]
-                    .into_iter()
-                    .min_by(|a, b| a.partial_cmp(b).expect("Failed to compare floats"))
-                    .unwrap(),
-            );
+            champ.walk_until_ready([champ.w_cd(), champ.basic_attack_cd()], fight_duration);
         }
     }
     //add weighted r dmg at the end
@@ -116,19 +119,10 @@ fn jinx_fight_scenario_basic_attacks_only(
 
     while champ.time < fight_duration {
         //priority order: w, basic attack
-        if champ.basic_attack_cd == 0. {
+        if champ.basic_attack_cd() == 0. {
             champ.basic_attack(target_stats);
         } else {
-            champ.walk(
-                F32_TOL
-                    + [
-                        champ.basic_attack_cd,
-                        f32::max(0., fight_duration - champ.time),
-                    ]
-                    .into_iter()
-                    .min_by(|a, b| a.partial_cmp(b).expect("Failed to compare floats"))
-                    .unwrap(),
-            );
+            champ.walk_until_ready([champ.basic_attack_cd()], fight_duration);
         }
     }
     //add weighted r dmg at the end
@@ -142,7 +136,8 @@ impl Unit {
         as_limit: Unit::DEFAULT_AS_LIMIT,
         as_ratio: 0.9 * JINX_BASE_AS, //rocket launcher scales with 90% of bonus as
         windup_percent: 0.16875,
-        windup_modifier: 1., //"mod" next to attack windup, 1 by default
+        windup_modifier: 1.,     //"mod" next to attack windup, 1 by default
+        projectile_speed: 2000., //basic attack projectile speed
         base_stats: UnitStats {
             hp: 630.,
             mana: 260.,
@@ -162,6 +157,7 @@ impl Unit {
             crit_dmg: Unit::BASE_CRIT_DMG,
             ms_flat: 325.,
             ms_percent: 0.,
+            attack_range: 525.,
             lethality: 0.,
             armor_pen_percent: 0.,
             magic_pen_flat: 0.,
@@ -197,6 +193,7 @@ impl Unit {
             crit_dmg: 0.,
             ms_flat: 0.,
             ms_percent: 0.,
+            attack_range: 0.,
             lethality: 0.,
             armor_pen_percent: 0.,
             magic_pen_flat: 0.,
@@ -259,10 +256,19 @@ impl Unit {
         defaults: UnitDefaults {
             runes_pages: RunesPage {
                 keystone: &RuneKeystone::PRESS_THE_ATTACK, //todo: prone to change
+                primary_runes: [
+                    &MinorRune::PRESENCE_OF_MIND,
+                    &MinorRune::LEGEND_ALACRITY,
+                    &MinorRune::COUP_DE_GRACE,
+                ],
+                secondary_runes: [&MinorRune::ABSOLUTE_FOCUS, &MinorRune::GATHERING_STORM],
                 shard1: RuneShard::Middle,
                 shard2: RuneShard::Left,
                 shard3: RuneShard::Left,
             },
+            summoner_spells: SummonerSpells {
+                spells: [&SummonerSpell::FLASH, &SummonerSpell::HEAL],
+            },
             skill_order: SkillOrder {
                 //lvls:
                 //  1  2  3  4  5  6  7  8  9 10 11 12 13 14 15 16 17 18
@@ -355,7 +361,13 @@ impl Unit {
                 //&Item::PLATED_STEELCAPS,
                 //&Item::SORCERERS_SHOES,
             ],
-            supp_items_pool: &[],
+            supp_items_pool: &[
+                &Item::BLOODSONG,
+                &Item::CELESTIAL_OPPOSITION,
+                &Item::DREAM_MAKER,
+                &Item::SOLSTICE_SLEIGH,
+                &Item::ZAZZAKS_REALMSPIKE,
+            ],
         },
     };
 }
@@ -2,14 +2,12 @@ use crate::game_data::*;
 
 use items_data::Item;
 use runes_data::*;
+use summoner_spells_data::*;
 use units_data::*;
 
 use enumset::enum_set;
 
 //champion parameters (constants):
-/// Percentage of target missing hp when second skin 5 stacks procs.
-/// The missing hp taken for the calculation is the value AFTER base dmg from the basic attack/w hits,
-const SECOND_SKIN_TARGET_MISSING_HP_PERCENT: f32 = 0.5;
 const W_HIT_PERCENT: f32 = 0.85;
 
 fn kaisa_init_abilities(champ: &mut Unit) {
@@ -129,8 +127,7 @@ fn kaisa_second_skin(
         //(the idea is that no item should be artificially "buffed", but they can be "nerfed" so when it's picked by the optimiser, it's guaranteed to be good).
         //With the current dmg system I basically have to choose which between guinsoos and runaans work with the second skin passive.
         //I chose guinsoos because its interaction with the second skin passive seems more important than runaan's.
-        magic_dmg += SECOND_SKIN_TARGET_MISSING_HP_PERCENT
-            * target_stats.hp
+        magic_dmg += (target_stats.hp - champ.get_target_current_hp(target_stats))
             * (0.15 + 0.06 / 100. * champ.stats.ap());
     }
 
@@ -139,7 +136,7 @@ fn kaisa_second_skin(
 
 fn kaisa_on_basic_attack_cast(champ: &mut Unit) {
     //basic attack reduce e cd by 0.5 sec
-    champ.e_cd = f32::max(0., champ.e_cd - 0.5);
+    champ.set_e_cd(f32::max(0., champ.e_cd() - 0.5));
 }
 
 /// Assumes single target dmg.
@@ -194,7 +191,9 @@ fn kaisa_w(champ: &mut Unit, target_stats: &UnitStats) -> PartDmg {
 
     if champ.effects_stacks[EffectStackId::KaisaWEvolved] == 1 {
         //if evolved
-        champ.w_cd -= W_HIT_PERCENT * 0.75 * f32::max(0., champ.w_cd - W_TRAVEL_TIME); //account for w travel time (otherwise cd is instantly refunded after casting and that can be op)
+        champ.set_w_cd(
+            champ.w_cd() - W_HIT_PERCENT * 0.75 * f32::max(0., champ.w_cd() - W_TRAVEL_TIME),
+        ); //account for w travel time (otherwise cd is instantly refunded after casting and that can be op)
         second_skin_dmg += kaisa_second_skin(champ, target_stats, 1., false);
     }
 
@@ -230,6 +229,7 @@ const KAISA_SUPERCHARGE_AS: TemporaryEffect = TemporaryEffect {
     remove_every_stack: kaisa_supercharge_as_disable,
     duration: 4.,
     cooldown: 0.,
+    restore_target_debuff: None,
 };
 
 fn kaisa_e(champ: &mut Unit, _target_stats: &UnitStats) -> PartDmg {
@@ -254,9 +254,12 @@ const R_SHIELD_AD_RATIO_BY_R_LVL: [f32; 3] = [0.90, 1.35, 1.8];
 
 fn kaisa_r(champ: &mut Unit, _target_stats: &UnitStats) -> PartDmg {
     let r_lvl_idx: usize = usize::from(champ.r_lvl - 1); //to index ability ratios by lvl
-    champ.single_use_heals_shields += R_SHIELD_BY_R_LVL[r_lvl_idx]
-        + R_SHIELD_AD_RATIO_BY_R_LVL[r_lvl_idx] * champ.stats.ad()
-        + 1.2 * champ.stats.ap();
+    champ.add_shield(
+        R_SHIELD_BY_R_LVL[r_lvl_idx]
+            + R_SHIELD_AD_RATIO_BY_R_LVL[r_lvl_idx] * champ.stats.ad()
+            + 1.2 * champ.stats.ap(),
+        false,
+    );
     champ.units_travelled += 425.; //assumed dash range (max r radius around the ennemy - champion width)
     PartDmg(0., 0., 0.)
 }
@@ -264,27 +267,23 @@ fn kaisa_r(champ: &mut Unit, _target_stats: &UnitStats) -> PartDmg {
 fn kaisa_fight_scenario(champ: &mut Unit, target_stats: &UnitStats, fight_duration: f32) {
     while champ.time < fight_duration {
         //priority order: w, e, q, basic attack
-        if champ.w_cd == 0. {
+        if champ.w_cd() == 0. {
             champ.w(target_stats);
-        } else if champ.e_cd == 0. {
+        } else if champ.e_cd() == 0. {
             champ.e(target_stats);
-        } else if champ.q_cd == 0. {
+        } else if champ.q_cd() == 0. {
             champ.q(target_stats);
-        } else if champ.basic_attack_cd == 0. {
+        } else if champ.basic_attack_cd() == 0. {
             champ.basic_attack(target_stats);
         } else {
-            champ.walk(
-                F32_TOL
-                    + [
-                        champ.q_cd,
-                        champ.w_cd,
-                        champ.e_cd,
-                        champ.basic_attack_cd,
-                        f32::max(0., fight_duration - champ.time),
-                    ]
-                    .into_iter()
-                    .min_by(|a, b| a.partial_cmp(b).expect("Failed to compare floats"))
-                    .unwrap(),
+            champ.walk_until_ready(
+                [
+                    champ.q_cd(),
+                    champ.w_cd(),
+                    champ.e_cd(),
+                    champ.basic_attack_cd(),
+                ],
+                fight_duration,
             );
         }
     }
@@ -299,7 +298,8 @@ impl Unit {
         as_limit: Unit::DEFAULT_AS_LIMIT,
         as_ratio: KAISA_BASE_AS, //if not specified, same as base AS
         windup_percent: 0.16108,
-        windup_modifier: 1., //"mod" next to attack windup, 1 by default
+        windup_modifier: 1.,     //"mod" next to attack windup, 1 by default
+        projectile_speed: 1800., //basic attack projectile speed
         base_stats: UnitStats {
             hp: 640.,
             mana: 345.,
@@ -319,6 +319,7 @@ impl Unit {
             crit_dmg: Unit::BASE_CRIT_DMG,
             ms_flat: 335.,
             ms_percent: 0.,
+            attack_range: 525.,
             lethality: 0.,
             armor_pen_percent: 0.,
             magic_pen_flat: 0.,
@@ -354,6 +355,7 @@ impl Unit {
             crit_dmg: 0.,
             ms_flat: 0.,
             ms_percent: 0.,
+            attack_range: 0.,
             lethality: 0.,
             armor_pen_percent: 0.,
             magic_pen_flat: 0.,
@@ -410,10 +412,19 @@ impl Unit {
         defaults: UnitDefaults {
             runes_pages: RunesPage {
                 keystone: &RuneKeystone::LETHAL_TEMPO, //todo: prone to change
+                primary_runes: [
+                    &MinorRune::PRESENCE_OF_MIND,
+                    &MinorRune::LEGEND_ALACRITY,
+                    &MinorRune::CUT_DOWN,
+                ],
+                secondary_runes: [&MinorRune::TASTE_OF_BLOOD, &MinorRune::ULTIMATE_HUNTER],
                 shard1: RuneShard::Middle,
                 shard2: RuneShard::Left,
                 shard3: RuneShard::Left,
             },
+            summoner_spells: SummonerSpells {
+                spells: [&SummonerSpell::FLASH, &SummonerSpell::HEAL],
+            },
             skill_order: SkillOrder {
                 //lvls:
                 //  1  2  3  4  5  6  7  8  9 10 11 12 13 14 15 16 17 18
@@ -506,7 +517,13 @@ impl Unit {
                 //&Item::PLATED_STEELCAPS,
                 &Item::SORCERERS_SHOES,
             ],
-            supp_items_pool: &[],
+            supp_items_pool: &[
+                &Item::BLOODSONG,
+                &Item::CELESTIAL_OPPOSITION,
+                &Item::DREAM_MAKER,
+                &Item::SOLSTICE_SLEIGH,
+                &Item::ZAZZAKS_REALMSPIKE,
+            ],
         },
     };
 }
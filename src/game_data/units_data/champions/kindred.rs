@@ -1,19 +1,21 @@
-use crate::{game_data::*, AVG_ITEM_COST_WITH_BOOTS};
+use crate::game_data::*;
 
-use items_data::Item;
+use items_data::{Item, AVG_ITEM_COST_WITH_BOOTS};
 use runes_data::*;
+use summoner_spells_data::*;
 use units_data::*;
 
 use enumset::enum_set;
 
 //champion parameters (constants):
 const MARKS_PER_MIN: f32 = 4. / 15.; //assumes 4 marks at 15min
-const MARKS_PER_ITEM: f32 = MARKS_PER_MIN * AVG_ITEM_COST_WITH_BOOTS / TOT_GOLDS_PER_MIN; //use marks per items instead of marks per min to not bias towards expensive items
 const Q_N_TARGETS: f32 = 1.5;
 
 fn kindred_init_abilities(champ: &mut Unit) {
+    let marks_per_item: f32 =
+        MARKS_PER_MIN * AVG_ITEM_COST_WITH_BOOTS / champ.economy.golds_per_min(); //use marks per items instead of marks per min to not bias towards expensive items
     #[allow(clippy::cast_precision_loss)] //`build.item_count()` is well within f32 precision range
-    let marks: f32 = (champ.build.item_count() as f32) * MARKS_PER_ITEM;
+    let marks: f32 = (champ.build.item_count() as f32) * marks_per_item;
     champ.effects_values[EffectValueId::KindredMarks] = marks;
 
     champ.effects_values[EffectValueId::KindredDanceOfArrowsBonusAS] = 0.;
@@ -21,10 +23,8 @@ fn kindred_init_abilities(champ: &mut Unit) {
     champ.effects_values[EffectValueId::KindredHuntersVigorLastTriggerDistance] =
         -(HUNTERS_VIGOR_TRAVEL_REQUIRED + F32_TOL); // to allow for effect at time == 0
 
-    champ.effects_values[EffectValueId::KindredWolfsFrenzyLastHitTime] = 0.;
     champ.effects_values[EffectValueId::KindredWolfsFrenzyLastStartTime] =
         -(WOLFS_FRENZY_DURATION + F32_TOL);
-    champ.effects_values[EffectValueId::KindredWolfsFrenzyMagicDmgPerSec] = 0.;
 
     champ.effects_stacks[EffectStackId::KindredMountingDreadStacks] = 0;
     champ.effects_values[EffectValueId::KindredMountingDreadLastStackTime] =
@@ -50,8 +50,11 @@ fn kindred_on_basic_attack_hit(
         }
         champ.effects_values[EffectValueId::KindredHuntersVigorLastTriggerDistance] =
             champ.units_travelled;
-        champ.periodic_heals_shields += f32::min(1., 1.25 * MEAN_MISSING_HP_PERCENT)
-            * HUNTERS_VIGOR_HEAL_BY_LVL[usize::from(champ.lvl.get() - 1)];
+        champ.add_heal(
+            f32::min(1., 1.25 * MEAN_MISSING_HP_PERCENT)
+                * HUNTERS_VIGOR_HEAL_BY_LVL[usize::from(champ.lvl.get() - 1)],
+            true,
+        );
     }
 
     //mounting dread stacks
@@ -79,7 +82,7 @@ fn kindred_on_basic_attack_hit(
     let hp_crit_treshold: f32 = 0.25 + 0.5 * champ.stats.crit_chance;
     let missing_hp_crit_chance: f32 = hp_crit_treshold * hp_crit_treshold;
     let missing_hp_dmg: f32 = (0.05 + 0.005 * champ.effects_values[EffectValueId::KindredMarks])
-        * (MEAN_MISSING_HP_PERCENT * target_stats.hp)
+        * (target_stats.hp - champ.get_target_current_hp(target_stats))
         * (1. + missing_hp_crit_chance * (0.5 + champ.stats.crit_dmg - Unit::BASE_CRIT_DMG));
 
     PartDmg(base_phys_dmg + missing_hp_dmg, 0., 0.)
@@ -104,6 +107,7 @@ const KINDRED_DANCE_OF_ARROWS_AS: TemporaryEffect = TemporaryEffect {
     remove_every_stack: kindred_dance_of_arrows_disable,
     duration: 4.,
     cooldown: 0.,
+    restore_target_debuff: None,
 };
 
 const Q_PHYS_DMG_BY_Q_LVL: [f32; 5] = [40., 65., 90., 115., 140.];
@@ -120,14 +124,14 @@ fn kindred_q(champ: &mut Unit, target_stats: &UnitStats) -> PartDmg {
     champ.units_travelled += Q_DASH_DISTANCE;
 
     //reset basic attack cd
-    champ.basic_attack_cd = 0.;
+    champ.set_basic_attack_cd(0.);
 
     //reduce q cd if w field is active
     if (champ.time - champ.effects_values[EffectValueId::KindredWolfsFrenzyLastStartTime]
         < WOLFS_FRENZY_DURATION)
-        && (champ.q_cd > Q_STATIC_CD_BY_Q_LVL[q_lvl_idx])
+        && (champ.q_cd() > Q_STATIC_CD_BY_Q_LVL[q_lvl_idx])
     {
-        champ.q_cd = Q_STATIC_CD_BY_Q_LVL[q_lvl_idx];
+        champ.set_q_cd(Q_STATIC_CD_BY_Q_LVL[q_lvl_idx]);
     }
 
     let phys_dmg: f32 =
@@ -171,17 +175,21 @@ fn kindred_w(champ: &mut Unit, target_stats: &UnitStats) -> PartDmg {
     //correct w cd (haste only reduces cooldown after the field)
     let calculated_cooldown: f32 = haste_formula(champ.stats.ability_haste_basic())
         * champ.properties.w.base_cooldown_by_ability_lvl[usize::from(champ.w_lvl - 1)];
-    let time_spent: f32 = calculated_cooldown - champ.w_cd; //no time should have been spent since cooldown was set, but we still compensate just in case
-    champ.w_cd = WOLFS_FRENZY_DURATION
-        + haste_formula(champ.stats.ability_haste_basic())
-            * (champ.properties.w.base_cooldown_by_ability_lvl[usize::from(champ.w_lvl - 1)]
-                - WOLFS_FRENZY_DURATION)
-        - time_spent;
-
-    //reduce q cd
-    let static_q_cd: f32 = Q_STATIC_CD_BY_Q_LVL[usize::from(champ.q_lvl - 1)];
-    if champ.q_cd > static_q_cd {
-        champ.q_cd = static_q_cd;
+    let time_spent: f32 = calculated_cooldown - champ.w_cd(); //no time should have been spent since cooldown was set, but we still compensate just in case
+    champ.set_w_cd(
+        WOLFS_FRENZY_DURATION
+            + haste_formula(champ.stats.ability_haste_basic())
+                * (champ.properties.w.base_cooldown_by_ability_lvl[usize::from(champ.w_lvl - 1)]
+                    - WOLFS_FRENZY_DURATION)
+            - time_spent,
+    );
+
+    //reduce q cd (if q is lvled up)
+    if champ.q_lvl != 0 {
+        let static_q_cd: f32 = Q_STATIC_CD_BY_Q_LVL[usize::from(champ.q_lvl - 1)];
+        if champ.q_cd() > static_q_cd {
+            champ.set_q_cd(static_q_cd);
+        }
     }
 
     let w_lvl_idx: usize = usize::from(champ.w_lvl - 1); //to index ability ratios by lvl
@@ -190,29 +198,24 @@ fn kindred_w(champ: &mut Unit, target_stats: &UnitStats) -> PartDmg {
         + 0.2 * champ.stats.bonus_ad
         + 0.2 * champ.stats.ap()
         + (0.015 + 0.01 * champ.effects_values[EffectValueId::KindredMarks])
-            * ((1. - MEAN_MISSING_HP_PERCENT) * target_stats.hp); //dmg per wolf's hit
-
-    //assumes kindred stays in the field
-    champ.effects_values[EffectValueId::KindredWolfsFrenzyMagicDmgPerSec] =
-        (1. * wolf_as) * wolf_magic_dmg;
-    champ.effects_values[EffectValueId::KindredWolfsFrenzyLastStartTime] = champ.time;
-    PartDmg(0., 0., 0.)
-}
-
-fn kindred_on_any_hit(champ: &mut Unit, _target_stats: &UnitStats) -> PartDmg {
-    //wolf's frenzy attacks over time (haven't found a better way to implement this)
-    if champ.time - champ.effects_values[EffectValueId::KindredWolfsFrenzyLastStartTime]
-        < WOLFS_FRENZY_DURATION
-    {
-        let time_elapsed: f32 =
-            champ.time - champ.effects_values[EffectValueId::KindredWolfsFrenzyLastHitTime];
-        champ.effects_values[EffectValueId::KindredWolfsFrenzyLastHitTime] = champ.time;
-        return PartDmg(
-            0.,
-            time_elapsed * champ.effects_values[EffectValueId::KindredWolfsFrenzyMagicDmgPerSec],
-            0.,
+            * champ.get_target_current_hp(target_stats); //dmg per wolf's hit
+
+    //schedule wolf's attacks over the field duration (assumes kindred stays in the field)
+    //wolf's attacks don't apply kindred on-hit effects
+    let wolf_attack_delay: f32 = 1. / wolf_as;
+    let mut delay: f32 = wolf_attack_delay;
+    while delay <= WOLFS_FRENZY_DURATION {
+        champ.schedule_dmg(
+            delay,
+            target_stats,
+            PartDmg(0., wolf_magic_dmg, 0.),
+            (0, 0),
+            enum_set!(),
+            1.,
         );
+        delay += wolf_attack_delay;
     }
+    champ.effects_values[EffectValueId::KindredWolfsFrenzyLastStartTime] = champ.time;
     PartDmg(0., 0., 0.)
 }
 
@@ -237,27 +240,23 @@ fn kindred_fight_scenario(champ: &mut Unit, target_stats: &UnitStats, fight_dura
 
     while champ.time < fight_duration {
         //priority order: q, w, basic attack, e
-        if champ.q_cd == 0. {
+        if champ.q_cd() == 0. {
             champ.q(target_stats);
-        } else if champ.w_cd == 0. {
+        } else if champ.w_cd() == 0. {
             champ.w(target_stats);
-        } else if champ.basic_attack_cd == 0. {
+        } else if champ.basic_attack_cd() == 0. {
             champ.basic_attack(target_stats);
-        } else if champ.e_cd == 0. {
+        } else if champ.e_cd() == 0. {
             champ.e(target_stats);
         } else {
-            champ.walk(
-                F32_TOL
-                    + [
-                        champ.q_cd,
-                        champ.w_cd,
-                        champ.basic_attack_cd,
-                        champ.e_cd,
-                        f32::max(0., fight_duration - champ.time),
-                    ]
-                    .into_iter()
-                    .min_by(|a, b| a.partial_cmp(b).expect("Failed to compare floats"))
-                    .unwrap(),
+            champ.walk_until_ready(
+                [
+                    champ.q_cd(),
+                    champ.w_cd(),
+                    champ.basic_attack_cd(),
+                    champ.e_cd(),
+                ],
+                fight_duration,
             );
         }
     }
@@ -270,7 +269,8 @@ impl Unit {
         as_limit: Unit::DEFAULT_AS_LIMIT,
         as_ratio: KINDRED_BASE_AS, //if not specified, same as base AS
         windup_percent: 0.17544,
-        windup_modifier: 1., //"mod" next to attack windup, 1 by default
+        windup_modifier: 1.,     //"mod" next to attack windup, 1 by default
+        projectile_speed: 2000., //basic attack projectile speed
         base_stats: UnitStats {
             hp: 595.,
             mana: 300.,
@@ -290,6 +290,7 @@ impl Unit {
             crit_dmg: Unit::BASE_CRIT_DMG,
             ms_flat: 325.,
             ms_percent: 0.,
+            attack_range: 500.,
             lethality: 0.,
             armor_pen_percent: 0.,
             magic_pen_flat: 0.,
@@ -325,6 +326,7 @@ impl Unit {
             crit_dmg: 0.,
             ms_flat: 0.,
             ms_percent: 0.,
+            attack_range: 0.,
             lethality: 0.,
             armor_pen_percent: 0.,
             magic_pen_flat: 0.,
@@ -375,16 +377,25 @@ impl Unit {
             on_phys_hit: None,
             on_magic_hit: None,
             on_true_dmg_hit: None,
-            on_any_hit: Some(kindred_on_any_hit),
+            on_any_hit: None,
         },
         fight_scenarios: &[(kindred_fight_scenario, "all out")],
         defaults: UnitDefaults {
             runes_pages: RunesPage {
                 keystone: &RuneKeystone::PRESS_THE_ATTACK, //todo: prone to change
+                primary_runes: [
+                    &MinorRune::PRESENCE_OF_MIND,
+                    &MinorRune::LEGEND_ALACRITY,
+                    &MinorRune::COUP_DE_GRACE,
+                ],
+                secondary_runes: [&MinorRune::TASTE_OF_BLOOD, &MinorRune::ULTIMATE_HUNTER],
                 shard1: RuneShard::Middle,
                 shard2: RuneShard::Left,
                 shard3: RuneShard::Right,
             },
+            summoner_spells: SummonerSpells {
+                spells: [&SummonerSpell::FLASH, &SummonerSpell::EMPTY_SUMMONER_SPELL], //smite not implemented
+            },
             skill_order: SkillOrder {
                 //lvls:
                 //  1  2  3  4  5  6  7  8  9 10 11 12 13 14 15 16 17 18
@@ -477,7 +488,13 @@ impl Unit {
                 //&Item::PLATED_STEELCAPS,
                 //&Item::SORCERERS_SHOES,
             ],
-            supp_items_pool: &[],
+            supp_items_pool: &[
+                &Item::BLOODSONG,
+                &Item::CELESTIAL_OPPOSITION,
+                &Item::DREAM_MAKER,
+                &Item::SOLSTICE_SLEIGH,
+                &Item::ZAZZAKS_REALMSPIKE,
+            ],
         },
     };
 }
@@ -495,6 +512,6 @@ mod tests {
                 .all(|&cd| cd >= WOLFS_FRENZY_DURATION),
             "Cooldown of Kindred W must be greater than the duration of its field ({}), this is due to how the cooldown reduced by haste is calculated",
             WOLFS_FRENZY_DURATION
-        )
+        );
     }
 }
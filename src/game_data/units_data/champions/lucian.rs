@@ -2,6 +2,7 @@ use crate::game_data::*;
 
 use items_data::Item;
 use runes_data::*;
+use summoner_spells_data::*;
 use units_data::*;
 
 use enumset::enum_set;
@@ -42,7 +43,7 @@ const LIGHTSLINGER_BASIC_ATTACKS_AD_RATIO_BY_LVL: [f32; MAX_UNIT_LVL] = [
 fn lucian_basic_attack(champ: &mut Unit, target_stats: &UnitStats) -> PartDmg {
     if champ.effects_stacks[EffectStackId::LucianLightslingerEmpowered] == 1 {
         champ.effects_stacks[EffectStackId::LucianLightslingerEmpowered] = 0;
-        champ.e_cd = f32::max(0., champ.e_cd - 4.); //double basic attack reduce e_cd by 2sec for each hit
+        champ.set_e_cd(f32::max(0., champ.e_cd() - 4.)); //double basic attack reduce e_cd by 2sec for each hit
 
         //vigilance passive
         let vigilance_dmg: f32 =
@@ -120,6 +121,7 @@ const LUCIAN_ARDENT_BLAZE_MS: TemporaryEffect = TemporaryEffect {
     remove_every_stack: lucian_ardent_blaze_ms_disable,
     duration: 1.,
     cooldown: 0.,
+    restore_target_debuff: None,
 };
 
 const W_MAGIC_DMG_BY_W_LVL: [f32; 5] = [75., 110., 145., 180., 215.];
@@ -173,31 +175,27 @@ fn lucian_fight_scenario_all_out(champ: &mut Unit, target_stats: &UnitStats, fig
         //priority order: empowered basic attack, e, q, w, unempowered basic attack
         if champ.effects_stacks[EffectStackId::LucianLightslingerEmpowered] == 1 {
             //wait for the basic attack cooldown if there is one
-            if champ.basic_attack_cd != 0. {
-                champ.walk(champ.basic_attack_cd + F32_TOL);
+            if champ.basic_attack_cd() != 0. {
+                champ.walk(champ.basic_attack_cd());
             }
             champ.basic_attack(target_stats);
-        } else if champ.e_cd == 0. {
+        } else if champ.e_cd() == 0. {
             champ.e(target_stats);
-        } else if champ.q_cd == 0. {
+        } else if champ.q_cd() == 0. {
             champ.q(target_stats);
-        } else if champ.w_cd == 0. {
+        } else if champ.w_cd() == 0. {
             champ.w(target_stats);
-        } else if champ.basic_attack_cd == 0. {
+        } else if champ.basic_attack_cd() == 0. {
             champ.basic_attack(target_stats);
         } else {
-            champ.walk(
-                F32_TOL
-                    + [
-                        champ.q_cd,
-                        champ.w_cd,
-                        champ.e_cd,
-                        champ.basic_attack_cd,
-                        f32::max(0., fight_duration - champ.time),
-                    ]
-                    .into_iter()
-                    .min_by(|a, b| a.partial_cmp(b).expect("Failed to compare floats"))
-                    .unwrap(),
+            champ.walk_until_ready(
+                [
+                    champ.q_cd(),
+                    champ.w_cd(),
+                    champ.e_cd(),
+                    champ.basic_attack_cd(),
+                ],
+                fight_duration,
             );
         }
     }
@@ -210,29 +208,25 @@ fn lucian_fight_scenario_poke(champ: &mut Unit, target_stats: &UnitStats, fight_
         //priority order: empowered basic attack, e, q, w (no unempowered basic attack)
         if champ.effects_stacks[EffectStackId::LucianLightslingerEmpowered] == 1 {
             //wait for the basic basic_attack cooldown if there is one
-            if champ.basic_attack_cd != 0. {
-                champ.walk(champ.basic_attack_cd + F32_TOL);
+            if champ.basic_attack_cd() != 0. {
+                champ.walk(champ.basic_attack_cd());
             }
             champ.basic_attack(target_stats);
-        } else if champ.e_cd == 0. {
+        } else if champ.e_cd() == 0. {
             champ.e(target_stats);
-        } else if champ.q_cd == 0. {
+        } else if champ.q_cd() == 0. {
             champ.q(target_stats);
-        } else if champ.w_cd == 0. {
+        } else if champ.w_cd() == 0. {
             champ.w(target_stats);
         } else {
-            champ.walk(
-                F32_TOL
-                    + [
-                        champ.q_cd,
-                        champ.w_cd,
-                        champ.e_cd,
-                        champ.basic_attack_cd,
-                        f32::max(0., fight_duration - champ.time),
-                    ]
-                    .into_iter()
-                    .min_by(|a, b| a.partial_cmp(b).expect("Failed to compare floats"))
-                    .unwrap(),
+            champ.walk_until_ready(
+                [
+                    champ.q_cd(),
+                    champ.w_cd(),
+                    champ.e_cd(),
+                    champ.basic_attack_cd(),
+                ],
+                fight_duration,
             );
         }
     }
@@ -247,7 +241,8 @@ impl Unit {
         as_limit: Unit::DEFAULT_AS_LIMIT,
         as_ratio: LUCIAN_BASE_AS, //if not specified, same as base AS
         windup_percent: 0.15,
-        windup_modifier: 1., //"mod" next to attack windup, 1 by default
+        windup_modifier: 1.,     //"mod" next to attack windup, 1 by default
+        projectile_speed: 2800., //basic attack projectile speed
         base_stats: UnitStats {
             hp: 641.,
             mana: 320.,
@@ -267,6 +262,7 @@ impl Unit {
             crit_dmg: Unit::BASE_CRIT_DMG,
             ms_flat: 335.,
             ms_percent: 0.,
+            attack_range: 500.,
             lethality: 0.,
             armor_pen_percent: 0.,
             magic_pen_flat: 0.,
@@ -302,6 +298,7 @@ impl Unit {
             crit_dmg: 0.,
             ms_flat: 0.,
             ms_percent: 0.,
+            attack_range: 0.,
             lethality: 0.,
             armor_pen_percent: 0.,
             magic_pen_flat: 0.,
@@ -364,10 +361,19 @@ impl Unit {
         defaults: UnitDefaults {
             runes_pages: RunesPage {
                 keystone: &RuneKeystone::PRESS_THE_ATTACK, //conq better for long fights, PTA better for poke (14.20) //todo: prone to change
+                primary_runes: [
+                    &MinorRune::PRESENCE_OF_MIND,
+                    &MinorRune::LEGEND_ALACRITY,
+                    &MinorRune::COUP_DE_GRACE,
+                ],
+                secondary_runes: [&MinorRune::TASTE_OF_BLOOD, &MinorRune::ULTIMATE_HUNTER],
                 shard1: RuneShard::Middle,
                 shard2: RuneShard::Left,
                 shard3: RuneShard::Left,
             },
+            summoner_spells: SummonerSpells {
+                spells: [&SummonerSpell::FLASH, &SummonerSpell::HEAL],
+            },
             skill_order: SkillOrder {
                 //lvls:
                 //  1  2  3  4  5  6  7  8  9 10 11 12 13 14 15 16 17 18
@@ -460,7 +466,13 @@ impl Unit {
                 //&Item::PLATED_STEELCAPS,
                 //&Item::SORCERERS_SHOES,
             ],
-            supp_items_pool: &[],
+            supp_items_pool: &[
+                &Item::BLOODSONG,
+                &Item::CELESTIAL_OPPOSITION,
+                &Item::DREAM_MAKER,
+                &Item::SOLSTICE_SLEIGH,
+                &Item::ZAZZAKS_REALMSPIKE,
+            ],
         },
     };
 }
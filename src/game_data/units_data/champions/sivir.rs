@@ -2,6 +2,7 @@ use crate::game_data::*;
 
 use items_data::Item;
 use runes_data::*;
+use summoner_spells_data::*;
 use units_data::*;
 
 use enumset::enum_set;
@@ -11,8 +12,10 @@ const Q_N_TARGETS: f32 = 1.0;
 /// Percentage of the time the q return hit its targets.
 const Q_RETURN_PERCENT: f32 = 0.67;
 /// Number of targets hit by sivir ricochets (adds to the basic attack that launched the ricochet).
-/// Must be less or equal to 9.
+/// Must be less or equal to `W_MAX_RICOCHETS`.
 const W_N_RICOCHETS: f32 = 1.0;
+/// Maximum number of targets hit by sivir ricochets (used during teamfights).
+const W_MAX_RICOCHETS: f32 = 8.;
 
 fn sivir_init_abilities(champ: &mut Unit) {
     champ.effects_values[EffectValueId::SivirRicochetBonusAS] = 0.;
@@ -60,6 +63,7 @@ const SIVIR_FLEET_OF_FOOT: TemporaryEffect = TemporaryEffect {
     remove_every_stack: sivir_fleet_of_foot_disable,
     duration: 1.5,
     cooldown: 0.,
+    restore_target_debuff: None,
 };
 
 fn sivir_basic_attack(champ: &mut Unit, target_stats: &UnitStats) -> PartDmg {
@@ -67,9 +71,9 @@ fn sivir_basic_attack(champ: &mut Unit, target_stats: &UnitStats) -> PartDmg {
 
     //if buffed by r, basic attacks reduces abilities cooldown
     if champ.effects_values[EffectValueId::SivirOnTheHuntMsPercent] != 0. {
-        champ.q_cd = f32::max(0., champ.q_cd - R_ABILITIES_CD_REFUND_TIME);
-        champ.w_cd = f32::max(0., champ.w_cd - R_ABILITIES_CD_REFUND_TIME);
-        champ.e_cd = f32::max(0., champ.e_cd - R_ABILITIES_CD_REFUND_TIME);
+        champ.set_q_cd(f32::max(0., champ.q_cd() - R_ABILITIES_CD_REFUND_TIME));
+        champ.set_w_cd(f32::max(0., champ.w_cd() - R_ABILITIES_CD_REFUND_TIME));
+        champ.set_e_cd(f32::max(0., champ.e_cd() - R_ABILITIES_CD_REFUND_TIME));
     }
 
     //basic attack dmg
@@ -78,7 +82,8 @@ fn sivir_basic_attack(champ: &mut Unit, target_stats: &UnitStats) -> PartDmg {
     //w ricochets dmg, instance of dmg must be done after basic attack
     if champ.effects_values[EffectValueId::SivirRicochetBonusAS] != 0. {
         let w_lvl_idx: usize = usize::from(champ.w_lvl - 1);
-        let ricochet_phys_dmg: f32 = W_N_RICOCHETS
+        let n_ricochets: f32 = champ.aoe_additionnal_targets(W_N_RICOCHETS, W_MAX_RICOCHETS);
+        let ricochet_phys_dmg: f32 = n_ricochets
             * W_AD_RATIO_BY_W_LVL[w_lvl_idx]
             * champ.stats.ad()
             * champ.stats.crit_coef();
@@ -87,8 +92,8 @@ fn sivir_basic_attack(champ: &mut Unit, target_stats: &UnitStats) -> PartDmg {
             target_stats,
             PartDmg(ricochet_phys_dmg, 0., 0.),
             (0, 0), //most abilities effects don't work with sivir ricochets (known exception: shojin), so putting 0 instances cancels their effects -> adapt items pool as a fail safe
-            enum_set!(DmgTag::Ability), //abilities coef (shojin) will still run even with 0 instances
-            1.,
+            enum_set!(DmgTag::Ability | DmgTag::AroundTarget), //abilities coef (shojin) will still run even with 0 instances
+            n_ricochets,
         );
     }
 
@@ -138,6 +143,7 @@ const SIVIR_RICOCHET: TemporaryEffect = TemporaryEffect {
     remove_every_stack: sivir_ricochet_disable,
     duration: 4.,
     cooldown: 0.,
+    restore_target_debuff: None,
 };
 
 const W_AD_RATIO_BY_W_LVL: [f32; 5] = [0.30, 0.35, 0.40, 0.45, 0.50];
@@ -146,7 +152,7 @@ fn sivir_w(champ: &mut Unit, _target_stats: &UnitStats) -> PartDmg {
     champ.add_temporary_effect(&SIVIR_RICOCHET, 0.);
 
     //reset basic attack cd
-    champ.basic_attack_cd = 0.;
+    champ.set_basic_attack_cd(0.);
 
     PartDmg(0., 0., 0.)
 }
@@ -192,6 +198,7 @@ const SIVIR_ON_THE_HUNT_MS_LVL_1: TemporaryEffect = TemporaryEffect {
     remove_every_stack: sivir_on_the_hunt_disable,
     duration: 8.,
     cooldown: Unit::SIVIR_PROPERTIES.r.base_cooldown_by_ability_lvl[0],
+    restore_target_debuff: None,
 };
 
 const SIVIR_ON_THE_HUNT_MS_LVL_2: TemporaryEffect = TemporaryEffect {
@@ -200,6 +207,7 @@ const SIVIR_ON_THE_HUNT_MS_LVL_2: TemporaryEffect = TemporaryEffect {
     remove_every_stack: sivir_on_the_hunt_disable,
     duration: 10.,
     cooldown: Unit::SIVIR_PROPERTIES.r.base_cooldown_by_ability_lvl[1],
+    restore_target_debuff: None,
 };
 
 const SIVIR_ON_THE_HUNT_MS_LVL_3: TemporaryEffect = TemporaryEffect {
@@ -208,6 +216,7 @@ const SIVIR_ON_THE_HUNT_MS_LVL_3: TemporaryEffect = TemporaryEffect {
     remove_every_stack: sivir_on_the_hunt_disable,
     duration: 12.,
     cooldown: Unit::SIVIR_PROPERTIES.r.base_cooldown_by_ability_lvl[2],
+    restore_target_debuff: None,
 };
 
 /// Basic abilities cooldown refunded by each basic attack when under r effect
@@ -241,30 +250,22 @@ fn sivir_fight_scenario(champ: &mut Unit, target_stats: &UnitStats, fight_durati
 
     while champ.time < fight_duration {
         //priority order: q, basic attack, w (w after basic attack so it performs basic attack reset)
-        if champ.q_cd == 0. {
+        if champ.q_cd() == 0. {
             champ.q(target_stats);
-        } else if champ.basic_attack_cd == 0. {
+        } else if champ.basic_attack_cd() == 0. {
             champ.basic_attack(target_stats);
-        } else if champ.w_cd == 0. {
+        } else if champ.w_cd() == 0. {
             champ.w(target_stats);
             //basic attack directly after
             //sivir w resets cooldown (patch 14.17) but we keep that here for consistency in case it gets changed
-            if champ.basic_attack_cd != 0. {
-                champ.walk(champ.basic_attack_cd + F32_TOL);
+            if champ.basic_attack_cd() != 0. {
+                champ.walk(champ.basic_attack_cd());
             }
             champ.basic_attack(target_stats);
         } else {
-            champ.walk(
-                F32_TOL
-                    + [
-                        champ.q_cd,
-                        champ.basic_attack_cd,
-                        champ.w_cd,
-                        f32::max(0., fight_duration - champ.time),
-                    ]
-                    .into_iter()
-                    .min_by(|a, b| a.partial_cmp(b).expect("Failed to compare floats"))
-                    .unwrap(),
+            champ.walk_until_ready(
+                [champ.q_cd(), champ.basic_attack_cd(), champ.w_cd()],
+                fight_duration,
             );
         }
     }
@@ -277,7 +278,8 @@ impl Unit {
         as_limit: Unit::DEFAULT_AS_LIMIT,
         as_ratio: SIVIR_BASE_AS,
         windup_percent: 0.12,
-        windup_modifier: 1., //"mod" next to attack windup, 1 by default
+        windup_modifier: 1.,     //"mod" next to attack windup, 1 by default
+        projectile_speed: 1750., //basic attack projectile speed
         base_stats: UnitStats {
             hp: 600.,
             mana: 340.,
@@ -297,6 +299,7 @@ impl Unit {
             crit_dmg: Unit::BASE_CRIT_DMG,
             ms_flat: 335.,
             ms_percent: 0.,
+            attack_range: 500.,
             lethality: 0.,
             armor_pen_percent: 0.,
             magic_pen_flat: 0.,
@@ -332,6 +335,7 @@ impl Unit {
             crit_dmg: 0.,
             ms_flat: 0.,
             ms_percent: 0.,
+            attack_range: 0.,
             lethality: 0.,
             armor_pen_percent: 0.,
             magic_pen_flat: 0.,
@@ -388,10 +392,19 @@ impl Unit {
         defaults: UnitDefaults {
             runes_pages: RunesPage {
                 keystone: &RuneKeystone::LETHAL_TEMPO, //todo: prone to change
+                primary_runes: [
+                    &MinorRune::PRESENCE_OF_MIND,
+                    &MinorRune::LEGEND_ALACRITY,
+                    &MinorRune::CUT_DOWN,
+                ],
+                secondary_runes: [&MinorRune::ABSOLUTE_FOCUS, &MinorRune::GATHERING_STORM],
                 shard1: RuneShard::Middle,
                 shard2: RuneShard::Left,
                 shard3: RuneShard::Left,
             },
+            summoner_spells: SummonerSpells {
+                spells: [&SummonerSpell::FLASH, &SummonerSpell::HEAL],
+            },
             skill_order: SkillOrder {
                 //lvls:
                 //  1  2  3  4  5  6  7  8  9 10 11 12 13 14 15 16 17 18
@@ -484,7 +497,13 @@ impl Unit {
                 //&Item::PLATED_STEELCAPS,
                 //&Item::SORCERERS_SHOES,
             ],
-            supp_items_pool: &[],
+            supp_items_pool: &[
+                &Item::BLOODSONG,
+                &Item::CELESTIAL_OPPOSITION,
+                &Item::DREAM_MAKER,
+                &Item::SOLSTICE_SLEIGH,
+                &Item::ZAZZAKS_REALMSPIKE,
+            ],
         },
     };
 }
@@ -2,6 +2,7 @@ use crate::game_data::*;
 
 use items_data::Item;
 use runes_data::*;
+use summoner_spells_data::*;
 use units_data::*;
 
 use enumset::enum_set;
@@ -42,27 +43,23 @@ fn template_fight_scenario(champ: &mut Unit, target_stats: &UnitStats, fight_dur
 
     while champ.time < fight_duration {
         //priority order: q, w, e, basic attack
-        if champ.q_cd == 0. {
+        if champ.q_cd() == 0. {
             champ.q(target_stats);
-        } else if champ.w_cd == 0. {
+        } else if champ.w_cd() == 0. {
             champ.w(target_stats);
-        } else if champ.e_cd == 0. {
+        } else if champ.e_cd() == 0. {
             champ.e(target_stats);
-        } else if champ.basic_attack_cd == 0. {
+        } else if champ.basic_attack_cd() == 0. {
             champ.basic_attack(target_stats);
         } else {
-            champ.walk(
-                F32_TOL
-                    + [
-                        champ.q_cd,
-                        champ.w_cd,
-                        champ.e_cd,
-                        champ.basic_attack_cd,
-                        f32::max(0., fight_duration - champ.time),
-                    ]
-                    .into_iter()
-                    .min_by(|a, b| a.partial_cmp(b).expect("Failed to compare floats"))
-                    .unwrap(),
+            champ.walk_until_ready(
+                [
+                        champ.q_cd(),
+                        champ.w_cd(),
+                        champ.e_cd(),
+                        champ.basic_attack_cd(),
+                ],
+                fight_duration,
             );
         }
     }
@@ -78,6 +75,7 @@ impl Unit {
         as_ratio: TEMPLATE_BASE_AS,       //if not specified, same as base AS //todo
         windup_percent: 0.2193,           //todo
         windup_modifier: 1.,              //"mod" next to attack windup, 1 by default //todo
+        projectile_speed: 2000.,          //todo
         base_stats: UnitStats {
             hp: 610.,     //todo
             mana: 280.,   //todo
@@ -97,6 +95,7 @@ impl Unit {
             crit_dmg: Unit::BASE_CRIT_DMG,
             ms_flat: 325., //todo
             ms_percent: 0.,
+            attack_range: 550., //todo
             lethality: 0.,
             armor_pen_percent: 0.,
             magic_pen_flat: 0.,
@@ -132,6 +131,7 @@ impl Unit {
             crit_dmg: 0.,
             ms_flat: 0.,
             ms_percent: 0.,
+            attack_range: 0.,
             lethality: 0.,
             armor_pen_percent: 0.,
             magic_pen_flat: 0.,
@@ -193,11 +193,16 @@ impl Unit {
         defaults: UnitDefaults {
             runes_pages: RunesPage {
                 keystone: &RuneKeystone::LETHAL_TEMPO, //todo
+                primary_runes: [&MinorRune::EMPTY_MINOR_RUNE; 3], //todo
+                secondary_runes: [&MinorRune::EMPTY_MINOR_RUNE; 2], //todo
                 //todo
                 shard1: RuneShard::Middle,
                 shard2: RuneShard::Left,
                 shard3: RuneShard::Left,
             },
+            summoner_spells: SummonerSpells {
+                spells: [&SummonerSpell::EMPTY_SUMMONER_SPELL; 2], //todo
+            },
             skill_order: SkillOrder {
                 //lvls:
                 //  1  2  3  4  5  6  7  8  9 10 11 12 13 14 15 16 17 18
@@ -295,6 +300,11 @@ impl Unit {
             ],
             supp_items_pool: &[
                 //todo
+                &Item::BLOODSONG,
+                &Item::CELESTIAL_OPPOSITION,
+                &Item::DREAM_MAKER,
+                &Item::SOLSTICE_SLEIGH,
+                &Item::ZAZZAKS_REALMSPIKE,
             ],
         },
     };
@@ -2,6 +2,7 @@ use crate::game_data::*;
 
 use items_data::Item;
 use runes_data::*;
+use summoner_spells_data::*;
 use units_data::*;
 
 use enumset::enum_set;
@@ -12,10 +13,6 @@ const VARUS_ABILITIES_HIT_PERCENT: f32 = 0.9;
 const Q_CHARGE_PERCENT: f32 = 0.67;
 /// Number of targets hit by q arrow.
 const Q_N_TARGETS: f32 = 1.0;
-/// Percentage of the target missing hp for the q arrow empowered by w.
-/// The missing hp taken for the calculation is the value AFTER the usual phys dmg from the arrow hits,
-/// So don't put the value before the arrow hits in this constant, but a higher value to account for the usual arrow dmg.
-const W_TARGET_MISSING_HP_PERCENT: f32 = 0.4;
 /// Number of targets hit by e.
 const E_N_TARGETS: f32 = 1.0;
 
@@ -34,6 +31,11 @@ fn varus_blighted_quiver_on_basic_attack_hit(
     n_targets: f32,
     _from_other_effects: bool,
 ) -> PartDmg {
+    //w passive is not available before w is lvled up
+    if champ.w_lvl == 0 {
+        return PartDmg(0., 0., 0.);
+    }
+
     //if last hit from too long ago, reset stacks and add 1
     if champ.time - champ.effects_values[EffectValueId::VarusBlightLastStackTime]
         >= BLIGH_STACK_DELAY
@@ -88,8 +90,7 @@ fn varus_q(champ: &mut Unit, target_stats: &UnitStats) -> PartDmg {
         champ.effects_stacks[EffectStackId::VarusBlightedQuiverEmpowered] = 0;
         magic_dmg += N_TARGET_COEF
             * ARROW_CHARGE_DMG_COEF
-            * target_stats.hp
-            * W_TARGET_MISSING_HP_PERCENT
+            * (target_stats.hp - champ.get_target_current_hp(target_stats))
             * W_TARGET_MISSING_HP_COEF_BY_W_LVL[usize::from(champ.w_lvl - 1)];
     }
 
@@ -125,31 +126,38 @@ const TOT_CD_REFUND_PERCENT_PER_BLIGHT_STACK: f32 = 0.13;
 fn varus_consume_blight_stacks_magic_dmg(champ: &mut Unit, target_stats: &UnitStats) -> f32 {
     let n_stacks: f32 = f32::from(champ.effects_stacks[EffectStackId::VarusBlightStacks]);
     champ.effects_stacks[EffectStackId::VarusBlightStacks] = 0; //consume all blight stacks
+    if n_stacks == 0. {
+        return 0.; //also the case when w is not lvled up yet
+    }
 
-    champ.q_cd = f32::max(
-        0.,
-        champ.q_cd
-            - n_stacks
-                * VARUS_ABILITIES_HIT_PERCENT
-                * TOT_CD_REFUND_PERCENT_PER_BLIGHT_STACK
-                * champ.properties.q.base_cooldown_by_ability_lvl[usize::from(champ.q_lvl - 1)],
-    );
-    champ.w_cd = f32::max(
+    if champ.q_lvl != 0 {
+        champ.set_q_cd(f32::max(
+            0.,
+            champ.q_cd()
+                - n_stacks
+                    * VARUS_ABILITIES_HIT_PERCENT
+                    * TOT_CD_REFUND_PERCENT_PER_BLIGHT_STACK
+                    * champ.properties.q.base_cooldown_by_ability_lvl[usize::from(champ.q_lvl - 1)],
+        ));
+    }
+    champ.set_w_cd(f32::max(
         0.,
-        champ.w_cd
+        champ.w_cd()
             - n_stacks
                 * VARUS_ABILITIES_HIT_PERCENT
                 * TOT_CD_REFUND_PERCENT_PER_BLIGHT_STACK
                 * champ.properties.w.base_cooldown_by_ability_lvl[usize::from(champ.w_lvl - 1)],
-    );
-    champ.e_cd = f32::max(
-        0.,
-        champ.e_cd
-            - n_stacks
-                * VARUS_ABILITIES_HIT_PERCENT
-                * TOT_CD_REFUND_PERCENT_PER_BLIGHT_STACK
-                * champ.properties.e.base_cooldown_by_ability_lvl[usize::from(champ.e_lvl - 1)],
-    );
+    ));
+    if champ.e_lvl != 0 {
+        champ.set_e_cd(f32::max(
+            0.,
+            champ.e_cd()
+                - n_stacks
+                    * VARUS_ABILITIES_HIT_PERCENT
+                    * TOT_CD_REFUND_PERCENT_PER_BLIGHT_STACK
+                    * champ.properties.e.base_cooldown_by_ability_lvl[usize::from(champ.e_lvl - 1)],
+        ));
+    }
 
     n_stacks
         * target_stats.hp
@@ -178,40 +186,13 @@ fn varus_e(champ: &mut Unit, target_stats: &UnitStats) -> PartDmg {
     )
 }
 
-fn varus_r_add_delayed_blight_stack_enable(_champ: &mut Unit, _availability_coef: f32) {}
-
-fn varus_r_add_delayed_blight_stack_disable(champ: &mut Unit) {
-    //add blight stack after a set duration
+fn varus_r_add_delayed_blight_stack(champ: &mut Unit) {
     champ.effects_stacks[EffectStackId::VarusBlightStacks] = u8::min(
         MAX_BLIGHT_STACKS,
         champ.effects_stacks[EffectStackId::VarusBlightStacks] + 1,
     );
 }
 
-const VARUS_R_ADD_DELAYED_BLIGHT_STACKS_0_5: TemporaryEffect = TemporaryEffect {
-    id: EffectId::VarusRAddDelayedBlightStacks05,
-    add_stack: varus_r_add_delayed_blight_stack_enable,
-    remove_every_stack: varus_r_add_delayed_blight_stack_disable,
-    duration: 0.5 + R_TRAVEL_TIME,
-    cooldown: 0.,
-};
-
-const VARUS_R_ADD_DELAYED_BLIGHT_STACKS_1_0: TemporaryEffect = TemporaryEffect {
-    id: EffectId::VarusRAddDelayedBlightStacks10,
-    add_stack: varus_r_add_delayed_blight_stack_enable,
-    remove_every_stack: varus_r_add_delayed_blight_stack_disable,
-    duration: 1. + R_TRAVEL_TIME,
-    cooldown: 0.,
-};
-
-const VARUS_R_ADD_DELAYED_BLIGHT_STACKS_1_5: TemporaryEffect = TemporaryEffect {
-    id: EffectId::VarusRAddDelayedBlightStacks15,
-    add_stack: varus_r_add_delayed_blight_stack_enable,
-    remove_every_stack: varus_r_add_delayed_blight_stack_disable,
-    duration: 1.5 + R_TRAVEL_TIME,
-    cooldown: 0.,
-};
-
 /// Used to calculate the average travel time of the projectile.
 const R_PROJECTILE_SPEED: f32 = 1500.;
 /// Affects how fast the blight stacks are applied after cast.
@@ -225,9 +206,9 @@ fn varus_r(champ: &mut Unit, target_stats: &UnitStats) -> PartDmg {
     magic_dmg += varus_consume_blight_stacks_magic_dmg(champ, target_stats); //assumes only one target has blights stacks
 
     //add delayed blights stacks
-    champ.add_temporary_effect(&VARUS_R_ADD_DELAYED_BLIGHT_STACKS_0_5, 0.);
-    champ.add_temporary_effect(&VARUS_R_ADD_DELAYED_BLIGHT_STACKS_1_0, 0.);
-    champ.add_temporary_effect(&VARUS_R_ADD_DELAYED_BLIGHT_STACKS_1_5, 0.);
+    for delay in [0.5, 1., 1.5] {
+        champ.schedule_action(delay + R_TRAVEL_TIME, varus_r_add_delayed_blight_stack);
+    }
 
     champ.dmg_on_target(
         target_stats,
@@ -245,35 +226,32 @@ fn varus_fight_scenario_all_out(champ: &mut Unit, target_stats: &UnitStats, figh
 
     while champ.time < fight_duration {
         //priority order: q (+w when available) when at least 2 blight stacks, e when at least 1 blight stacks, basic attack
-        if champ.q_cd == 0. && champ.effects_stacks[EffectStackId::VarusBlightStacks] >= 2 {
-            if champ.w_cd == 0. {
+        if champ.q_cd() == 0. && champ.effects_stacks[EffectStackId::VarusBlightStacks] >= 2 {
+            if champ.w_cd() == 0. {
                 champ.w(target_stats);
             }
             champ.q(target_stats);
-        } else if champ.e_cd == 0. && champ.effects_stacks[EffectStackId::VarusBlightStacks] >= 1 {
+        } else if champ.e_cd() == 0. && champ.effects_stacks[EffectStackId::VarusBlightStacks] >= 1
+        {
             champ.e(target_stats);
-        } else if champ.basic_attack_cd == 0. {
+        } else if champ.basic_attack_cd() == 0. {
             champ.basic_attack(target_stats);
         } else {
-            champ.walk(
-                F32_TOL
-                    + [
-                        if champ.effects_stacks[EffectStackId::VarusBlightStacks] >= 2 {
-                            champ.q_cd
-                        } else {
-                            champ.basic_attack_cd
-                        },
-                        if champ.effects_stacks[EffectStackId::VarusBlightStacks] >= 1 {
-                            champ.e_cd
-                        } else {
-                            champ.basic_attack_cd
-                        },
-                        champ.basic_attack_cd,
-                        f32::max(0., fight_duration - champ.time),
-                    ]
-                    .into_iter()
-                    .min_by(|a, b| a.partial_cmp(b).expect("Failed to compare floats"))
-                    .unwrap(),
+            champ.walk_until_ready(
+                [
+                    if champ.effects_stacks[EffectStackId::VarusBlightStacks] >= 2 {
+                        champ.q_cd()
+                    } else {
+                        champ.basic_attack_cd()
+                    },
+                    if champ.effects_stacks[EffectStackId::VarusBlightStacks] >= 1 {
+                        champ.e_cd()
+                    } else {
+                        champ.basic_attack_cd()
+                    },
+                    champ.basic_attack_cd(),
+                ],
+                fight_duration,
             );
         }
     }
@@ -282,31 +260,21 @@ fn varus_fight_scenario_all_out(champ: &mut Unit, target_stats: &UnitStats, figh
 fn varus_fight_scenario_poke(champ: &mut Unit, target_stats: &UnitStats, fight_duration: f32) {
     while champ.time < fight_duration {
         //priority order: q (+w when available), e (dont use blight stacks for poke scenario)
-        if champ.q_cd == 0. {
-            if champ.w_cd == 0. {
+        if champ.q_cd() == 0. {
+            if champ.w_cd() == 0. {
                 champ.w(target_stats);
             }
             champ.q(target_stats);
-        } else if champ.e_cd == 0. {
+        } else if champ.e_cd() == 0. {
             champ.e(target_stats);
         } else {
-            champ.walk(
-                F32_TOL
-                    + [
-                        champ.q_cd,
-                        champ.e_cd,
-                        f32::max(0., fight_duration - champ.time),
-                    ]
-                    .into_iter()
-                    .min_by(|a, b| a.partial_cmp(b).expect("Failed to compare floats"))
-                    .unwrap(),
-            );
+            champ.walk_until_ready([champ.q_cd(), champ.e_cd()], fight_duration);
         }
     }
     //add weighed r dmg + 2 basic attacks at the end
     champ.weighted_r(target_stats);
     champ.basic_attack(target_stats);
-    champ.walk(champ.basic_attack_cd + F32_TOL);
+    champ.walk(champ.basic_attack_cd());
     champ.basic_attack(target_stats);
 }
 
@@ -317,7 +285,8 @@ impl Unit {
         as_limit: Unit::DEFAULT_AS_LIMIT,
         as_ratio: VARUS_BASE_AS,
         windup_percent: 0.17544,
-        windup_modifier: 1., //"mod" next to attack windup, 1 by default
+        windup_modifier: 1.,     //"mod" next to attack windup, 1 by default
+        projectile_speed: 2000., //basic attack projectile speed
         base_stats: UnitStats {
             hp: 600.,
             mana: 360.,
@@ -337,6 +306,7 @@ impl Unit {
             crit_dmg: Unit::BASE_CRIT_DMG,
             ms_flat: 330.,
             ms_percent: 0.,
+            attack_range: 575.,
             lethality: 0.,
             armor_pen_percent: 0.,
             magic_pen_flat: 0.,
@@ -372,6 +342,7 @@ impl Unit {
             crit_dmg: 0.,
             ms_flat: 0.,
             ms_percent: 0.,
+            attack_range: 0.,
             lethality: 0.,
             armor_pen_percent: 0.,
             magic_pen_flat: 0.,
@@ -431,10 +402,19 @@ impl Unit {
         defaults: UnitDefaults {
             runes_pages: RunesPage {
                 keystone: &RuneKeystone::PRESS_THE_ATTACK, //todo: prone to change
+                primary_runes: [
+                    &MinorRune::PRESENCE_OF_MIND,
+                    &MinorRune::LEGEND_ALACRITY,
+                    &MinorRune::COUP_DE_GRACE,
+                ],
+                secondary_runes: [&MinorRune::ABSOLUTE_FOCUS, &MinorRune::GATHERING_STORM],
                 shard1: RuneShard::Middle,
                 shard2: RuneShard::Left,
                 shard3: RuneShard::Left,
             },
+            summoner_spells: SummonerSpells {
+                spells: [&SummonerSpell::FLASH, &SummonerSpell::HEAL],
+            },
             skill_order: SkillOrder {
                 //lvls:
                 //  1  2  3  4  5  6  7  8  9 10 11 12 13 14 15 16 17 18
@@ -527,7 +507,13 @@ impl Unit {
                 //&Item::PLATED_STEELCAPS,
                 &Item::SORCERERS_SHOES,
             ],
-            supp_items_pool: &[],
+            supp_items_pool: &[
+                &Item::BLOODSONG,
+                &Item::CELESTIAL_OPPOSITION,
+                &Item::DREAM_MAKER,
+                &Item::SOLSTICE_SLEIGH,
+                &Item::ZAZZAKS_REALMSPIKE,
+            ],
         },
     };
 }
@@ -2,6 +2,7 @@ use crate::game_data::*;
 
 use items_data::Item;
 use runes_data::*;
+use summoner_spells_data::*;
 use units_data::*;
 
 use enumset::enum_set;
@@ -91,6 +92,7 @@ const XAYAH_DEADLY_PLUMAGE_MS: TemporaryEffect = TemporaryEffect {
     remove_every_stack: xayah_deadly_plumage_ms_disable,
     duration: 1.5,
     cooldown: 0.,
+    restore_target_debuff: None,
 };
 
 const W_BONUS_AS_BY_W_LVL: [f32; 5] = [0.35, 0.40, 0.45, 0.50, 0.55];
@@ -116,6 +118,7 @@ const XAYAH_DEADLY_PLUMAGE_AS: TemporaryEffect = TemporaryEffect {
     remove_every_stack: xayah_deadly_plumage_as_disable,
     duration: 4.,
     cooldown: 0.,
+    restore_target_debuff: None,
 };
 
 fn xayah_w(champ: &mut Unit, _target_stats: &UnitStats) -> PartDmg {
@@ -183,40 +186,36 @@ fn xayah_fight_scenario(champ: &mut Unit, target_stats: &UnitStats, fight_durati
             > CLEAN_CUTS_MAX_STACKS - CLEAN_CUTS_STACKS_PER_ABILITY
         {
             //wait for the basic attack cooldown if there is one
-            if champ.basic_attack_cd != 0. {
-                champ.walk(champ.basic_attack_cd + F32_TOL);
+            if champ.basic_attack_cd() != 0. {
+                champ.walk(champ.basic_attack_cd());
             }
             champ.basic_attack(target_stats);
-        } else if champ.e_cd == 0.
+        } else if champ.e_cd() == 0.
             && champ.effects_stacks[EffectStackId::XayahNFeathersOnGround]
                 >= N_FEATHERS_BEFORE_RECALL
         {
             champ.e(target_stats);
-        } else if champ.q_cd == 0. {
+        } else if champ.q_cd() == 0. {
             champ.q(target_stats);
-        } else if champ.w_cd == 0. {
+        } else if champ.w_cd() == 0. {
             champ.w(target_stats);
-        } else if champ.basic_attack_cd == 0. {
+        } else if champ.basic_attack_cd() == 0. {
             champ.basic_attack(target_stats);
         } else {
-            champ.walk(
-                F32_TOL
-                    + [
-                        champ.q_cd,
-                        champ.w_cd,
-                        if champ.effects_stacks[EffectStackId::XayahNFeathersOnGround]
-                            >= N_FEATHERS_BEFORE_RECALL
-                        {
-                            champ.e_cd
-                        } else {
-                            fight_duration - champ.time
-                        },
-                        champ.basic_attack_cd,
-                        f32::max(0., fight_duration - champ.time),
-                    ]
-                    .into_iter()
-                    .min_by(|a, b| a.partial_cmp(b).expect("Failed to compare floats"))
-                    .unwrap(),
+            champ.walk_until_ready(
+                [
+                    champ.q_cd(),
+                    champ.w_cd(),
+                    if champ.effects_stacks[EffectStackId::XayahNFeathersOnGround]
+                        >= N_FEATHERS_BEFORE_RECALL
+                    {
+                        champ.e_cd()
+                    } else {
+                        fight_duration - champ.time
+                    },
+                    champ.basic_attack_cd(),
+                ],
+                fight_duration,
             );
         }
     }
@@ -231,7 +230,8 @@ impl Unit {
         as_limit: Unit::DEFAULT_AS_LIMIT,
         as_ratio: XAYAH_BASE_AS, //if not specified, same as base AS
         windup_percent: 0.17687,
-        windup_modifier: 1., //"mod" next to attack windup, 1 by default
+        windup_modifier: 1.,     //"mod" next to attack windup, 1 by default
+        projectile_speed: 2600., //basic attack projectile speed
         base_stats: UnitStats {
             hp: 630.,
             mana: 340.,
@@ -251,6 +251,7 @@ impl Unit {
             crit_dmg: Unit::BASE_CRIT_DMG,
             ms_flat: 330.,
             ms_percent: 0.,
+            attack_range: 525.,
             lethality: 0.,
             armor_pen_percent: 0.,
             magic_pen_flat: 0.,
@@ -286,6 +287,7 @@ impl Unit {
             crit_dmg: 0.,
             ms_flat: 0.,
             ms_percent: 0.,
+            attack_range: 0.,
             lethality: 0.,
             armor_pen_percent: 0.,
             magic_pen_flat: 0.,
@@ -342,10 +344,19 @@ impl Unit {
         defaults: UnitDefaults {
             runes_pages: RunesPage {
                 keystone: &RuneKeystone::LETHAL_TEMPO, //todo: prone to change
+                primary_runes: [
+                    &MinorRune::PRESENCE_OF_MIND,
+                    &MinorRune::LEGEND_ALACRITY,
+                    &MinorRune::CUT_DOWN,
+                ],
+                secondary_runes: [&MinorRune::ABSOLUTE_FOCUS, &MinorRune::GATHERING_STORM],
                 shard1: RuneShard::Middle,
                 shard2: RuneShard::Left,
                 shard3: RuneShard::Left,
             },
+            summoner_spells: SummonerSpells {
+                spells: [&SummonerSpell::FLASH, &SummonerSpell::HEAL],
+            },
             skill_order: SkillOrder {
                 //lvls:
                 //  1  2  3  4  5  6  7  8  9 10 11 12 13 14 15 16 17 18
@@ -438,7 +449,13 @@ impl Unit {
                 //&Item::PLATED_STEELCAPS,
                 //&Item::SORCERERS_SHOES,
             ],
-            supp_items_pool: &[],
+            supp_items_pool: &[
+                &Item::BLOODSONG,
+                &Item::CELESTIAL_OPPOSITION,
+                &Item::DREAM_MAKER,
+                &Item::SOLSTICE_SLEIGH,
+                &Item::ZAZZAKS_REALMSPIKE,
+            ],
         },
     };
 }
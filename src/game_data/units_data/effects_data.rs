@@ -10,18 +10,32 @@ pub(crate) enum EffectId {
     // - the name of the source of the passive/active effect (either an item or a champion ability)
     // - the name of the passive/active effect
     //exemple: YoumuusGhostbladeWraithStep
+    ArcaneComet,
     AsheRangersFocus,
+    Barrier,
     BlackCleaverCarve,
     BlackCleaverFervor,
+    BloodsongExpose,
+    CelestialOpposition,
+    Cleanse,
     Conqueror,
     CosmicDriveSpellDance,
+    DarkHarvest,
     DravenThrowAxe1,
     DravenThrowAxe2,
     DravenBloodRush,
+    Electrocute,
+    Exhaust,
     ExperimentalHexplateOverdrive,
     EzrealRisingSpellForce,
+    FirstStrike,
+    Flash,
     FleetFootworkMS,
+    Ghost,
     GuinsoosRagebladeSeethingStrike,
+    Heal,
+    HailOfBladesAS,
+    Ignite,
     KaisaSuperchargeAS,
     KindredDanceOfArrowsAS,
     LethalTempoAS,
@@ -29,6 +43,7 @@ pub(crate) enum EffectId {
     LucianArdentBlazeMS,
     MalignanceHatefogCurse,
     OpportunityPreparation,
+    PhaseRushMS,
     PhantomDancerSpectralWalkz,
     RiftmakerVoidCorruption,
     SivirFleetOfFoot,
@@ -39,12 +54,10 @@ pub(crate) enum EffectId {
     StormsurgeStormraiderMS,
     StridebreakerBreakingShockwaveMS,
     StridebreakerTemper,
+    SummonAery,
     TerminusJuxtapositionLight,
     TerminusJuxtapositionDark,
     TrinityForceQuicken,
-    VarusRAddDelayedBlightStacks05,
-    VarusRAddDelayedBlightStacks10,
-    VarusRAddDelayedBlightStacks15,
     XayahDeadlyPlumageAS,
     XayahDeadlyPlumageMS,
     YoumuusGhostbladeWraithStep,
@@ -68,10 +81,12 @@ pub(crate) enum EffectStackId {
     DravenAxesInAir,
     DravenAxesInHand,
     EclipseEverRisingMoonStacks,
+    ElectrocuteStacks,
     EzrealRisingSpellForceStacks,
     EzrealEssenceFluxMark,
     GuinsoosRagebladeSeethingStrikeStacks,
     GuinsoosRagebladePhantomStacks,
+    HailOfBladesRemainingAttacks,
     HullbreakerSkipperStacks,
     KaisaSecondSkinStacks,
     KaisaQEvolved,
@@ -83,6 +98,7 @@ pub(crate) enum EffectStackId {
     LucianLightslingerEmpowered,
     LucianVigilanceProcsRemaning,
     PhantomDancerSpectralWalkzStacks,
+    PhaseRushStacks,
     PressTheAttackStacks,
     SpearOfShojinFocusedWillStacks,
     SpellbladeEmpowered,
@@ -91,13 +107,24 @@ pub(crate) enum EffectStackId {
     TerminusJuxtapositionMode,
     TerminusJuxtapositionLightStacks,
     TerminusJuxtapositionDarkStacks,
-    TheCollectorExecuted,
     VarusBlightStacks,
     VarusBlightedQuiverEmpowered,
     XayahNFeathersOnGround,
     XayahCleanCutsStacks,
 }
 
+/// Stacks and marks held by the main target of the unit, kept separately for each enemy unit during a teamfight.
+pub(crate) const TARGET_STACKS: [EffectStackId; 8] = [
+    EffectStackId::BlackCleaverCarveStacks,
+    EffectStackId::ElectrocuteStacks,
+    EffectStackId::EzrealEssenceFluxMark,
+    EffectStackId::KaisaSecondSkinStacks,
+    EffectStackId::KindredMountingDreadStacks,
+    EffectStackId::PhaseRushStacks,
+    EffectStackId::PressTheAttackStacks,
+    EffectStackId::VarusBlightStacks,
+];
+
 #[derive(Enum, Debug)]
 pub(crate) enum EffectValueId {
     //the convention to name variants is to write, in CamelCase (with no extra space between them), the following in order :
@@ -105,28 +132,42 @@ pub(crate) enum EffectValueId {
     // - the name of the passive/active effect
     // - the name of the affected stat (+optionally, additionnal relevant information)
     //exemple : YoumuusGhostbladeWraithStepMsPercent
+    AbsoluteFocusApFlat,
+    AbsoluteFocusBonusAD,
     AsheLastFrostTime,
     AsheRangersFocusBonusAS,
     BlackCleaverCarveArmorRedPercent,
     BlackCleaverFervorMsFlat,
     BlackfireTorchBalefulBlazeLastApplicationTime,
+    BloodsongExposeTotDmgModifier,
+    CelestialOppositionDmgReceivedReduction,
+    CleanseGrievousWoundsRemoval,
     ConquerorAdaptiveAP,
     ConquerorOmnivamp,
-    ConquerorLastAbilityHitTime,
-    ConquerorLastBasicAttackHitTime,
+    ConquerorLastAbilityHitInstance,
+    ConquerorLastBasicAttackHitInstance,
+    CoupDeGraceTotDmgModifier,
+    CutDownTotDmgModifier,
     CosmicDriveSpellDanceMsFlat,
     DeadMansPlateShipwreckerLastHitdistance,
     DravenBloodRushBonusAS,
     DravenBloodRushBonusMsPercent,
     EclipseEverRisingMoonLastStackTime,
     EclipseEverRisingMoonLastTriggerTime,
+    ElectrocuteFirstStackTime,
+    ElectrocuteLastStackInstance,
     EzrealEssenceFluxHitTime,
     EzrealRisingSpellForceBonusAS,
+    ExhaustDmgReceivedReduction,
     ExperimentalHexplateOverdriveBonusAS,
     ExperimentalHexplateOverdriveMsPercent,
+    FirstStrikeTotDmgModifier,
     FleetFootworkLastTriggerDistance,
     FleetFootworkMSPercent,
+    GhostMsPercent,
     GuinsoosRagebladeSeethingStrikeBonusAS,
+    HailOfBladesBonusAS,
+    HealMsPercent,
     HullbreakerSkipperLastStackTime,
     KaisaSecondSkinLastStackTime,
     KaisaSuperchargeBonusAS,
@@ -134,10 +175,9 @@ pub(crate) enum EffectValueId {
     KindredMarks,
     KindredHuntersVigorLastTriggerDistance,
     KindredMountingDreadLastStackTime,
-    KindredWolfsFrenzyLastHitTime,
     KindredWolfsFrenzyLastStartTime,
-    KindredWolfsFrenzyMagicDmgPerSec,
     KrakenSlayerBringItDownLastStackTime,
+    LastStandTotDmgModifier,
     LethalTempoBonusAS,
     LiandrysTormentTormentLastApplicationTime,
     LiandrysTormentSufferingCombatStartTime,
@@ -145,13 +185,18 @@ pub(crate) enum EffectValueId {
     LucianArdentBlazeMsFlat,
     LudensCompanionFireLastConsumeTime,
     MalignanceHatefogCurseMrRedFlat,
-    MuramanaShockLastSpellHitTime,
+    MuramanaShockLastSpellHitInstance,
     OpportunityPreparationLethality,
+    PhaseRushFirstStackTime,
+    PhaseRushLastStackInstance,
+    PhaseRushMsPercent,
     PressTheAttackLastStackTime,
     RiftmakerVoidCorruptionTotDmgModifier,
     RiftmakerVoidCorruptionCombatStartTime,
     RiftmakerVoidCorruptionOmnivamp,
     RapidFirecannonSharpshooterLastTriggerDistance,
+    RapidFirecannonSharpshooterMaxBonusRange,
+    ScorchLastTriggerTime,
     SpellbladeLastEmpowerTime,
     SpellbladeLastConsumeTime,
     SivirFleetOfFootMsFlat,
@@ -162,6 +207,7 @@ pub(crate) enum EffectValueId {
     StridebreakerTemperMsFlat,
     StridebreakerBreakingShockwaveMsPercent,
     SunderedSkyLastTriggerTime,
+    TasteOfBloodLastTriggerTime,
     TerminusJuxtapositionLightRes,
     TerminusJuxtapositionDarkPen,
     TrinityForceQuickenMsFlat,
@@ -172,8 +218,22 @@ pub(crate) enum EffectValueId {
     XayahWBasicAttackCoef,
     YoumuusGhostbladeWraithStepMsPercent,
     YunTalWildarrowsFlurryBonusAS,
+    ZazzaksRealmspikeVoidExplosionLastTriggerTime,
 }
 
+/// Values held by the main target of the unit, kept separately for each enemy unit during a teamfight.
+pub(crate) const TARGET_VALUES: [EffectValueId; 9] = [
+    EffectValueId::BlackCleaverCarveArmorRedPercent,
+    EffectValueId::ElectrocuteFirstStackTime,
+    EffectValueId::EzrealEssenceFluxHitTime,
+    EffectValueId::KaisaSecondSkinLastStackTime,
+    EffectValueId::KindredMountingDreadLastStackTime,
+    EffectValueId::MalignanceHatefogCurseMrRedFlat,
+    EffectValueId::PhaseRushFirstStackTime,
+    EffectValueId::PressTheAttackLastStackTime,
+    EffectValueId::VarusBlightLastStackTime,
+];
+
 #[derive(Debug)]
 pub(crate) struct TemporaryEffect {
     pub(crate) id: EffectId,
@@ -187,6 +247,10 @@ pub(crate) struct TemporaryEffect {
     pub(crate) remove_every_stack: fn(&mut Unit),
     pub(crate) duration: f32,
     pub(crate) cooldown: f32,
+    /// If the effect is a debuff on the main target of the unit, re-adds effect stats from the values recorded on the unit
+    /// (used to restore the debuffs of an enemy unit of a teamfight when it becomes the main target again).
+    /// None for effects on the unit itself.
+    pub(crate) restore_target_debuff: Option<fn(&mut Unit)>,
 }
 
 impl PartialEq for TemporaryEffect {
@@ -0,0 +1,293 @@
+use super::*;
+
+use core::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+/// Dmg applied on the target at a later time than when it was created (projectile hit, dmg over time tick).
+#[derive(Debug, Clone)]
+pub(super) struct DelayedDmg {
+    pub(super) target_stats: UnitStats,
+    pub(super) part_dmg: PartDmg,
+    pub(super) n_instances: (u8, u8),
+    pub(super) dmg_tags: EnumSet<DmgTag>,
+    pub(super) n_targets: f32,
+}
+
+/// Event happening at a given time of the fight simulation.
+#[derive(Debug, Clone)]
+pub(super) enum SimEvent {
+    /// End of the duration of a temporary effect on the unit.
+    /// Ignored if the effect was refreshed or removed since the event was scheduled.
+    EffectExpiry(&'static TemporaryEffect),
+    /// Dmg on the target (see `Unit::dmg_on_target`).
+    Dmg(Box<DelayedDmg>),
+    /// Post mitigation dmg already calculated that hits the target (e.g. basic attack projectile),
+    /// holds (dmg done, target hp, dmg on the main target).
+    DmgLanding(PartDmg, f32, f32),
+    /// Same as `SimEvent::DmgLanding` during a teamfight, holds (dmg done, dmg on each enemy unit).
+    TeamfightDmgLanding(PartDmg, TeamfightDmg),
+    /// Delayed action on the unit (e.g. stacks applied when a projectile hits).
+    Action(fn(&mut Unit)),
+    /// End of a cooldown (basic attack, ability, or temporary effect).
+    /// Nothing to process, it only ensures the simulation stops exactly when the cooldown ends.
+    CooldownReady,
+}
+
+#[derive(Debug, Clone)]
+struct ScheduledEvent {
+    time: f32,
+    /// Insertion order, to process events happening at the same time in the order they were scheduled.
+    seq: u32,
+    event: SimEvent,
+}
+
+impl PartialEq for ScheduledEvent {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for ScheduledEvent {}
+
+impl PartialOrd for ScheduledEvent {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ScheduledEvent {
+    /// Reversed order so that the earliest event is on top of the (max-)heap.
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .time
+            .total_cmp(&self.time)
+            .then_with(|| other.seq.cmp(&self.seq))
+    }
+}
+
+/// Queue of the events of a fight simulation, ordered by time (then by scheduling order).
+#[derive(Debug, Clone, Default)]
+pub(super) struct EventQueue {
+    heap: BinaryHeap<ScheduledEvent>,
+    next_seq: u32,
+}
+
+impl EventQueue {
+    pub(super) fn clear(&mut self) {
+        self.heap.clear();
+        self.next_seq = 0;
+    }
+
+    /// Schedules an event at the given time.
+    pub(super) fn push(&mut self, time: f32, event: SimEvent) {
+        self.heap.push(ScheduledEvent {
+            time,
+            seq: self.next_seq,
+            event,
+        });
+        self.next_seq = self.next_seq.wrapping_add(1);
+    }
+
+    /// Returns the time of the next event, if any.
+    pub(super) fn next_time(&self) -> Option<f32> {
+        self.heap.peek().map(|scheduled| scheduled.time)
+    }
+
+    /// Removes and returns the next event (and its time) if it happens at or before `time`.
+    pub(super) fn pop_due(&mut self, time: f32) -> Option<(f32, SimEvent)> {
+        if self.next_time()? > time {
+            return None;
+        }
+        self.heap
+            .pop()
+            .map(|scheduled| (scheduled.time, scheduled.event))
+    }
+
+    /// Removes and returns every remaining event in chronological order.
+    pub(super) fn take_all(&mut self) -> Vec<(f32, SimEvent)> {
+        let mut scheduled_events: Vec<ScheduledEvent> =
+            core::mem::take(&mut self.heap).into_sorted_vec();
+        scheduled_events.reverse(); //reversed order, see `ScheduledEvent::cmp`
+        self.next_seq = 0;
+        scheduled_events
+            .into_iter()
+            .map(|scheduled| (scheduled.time, scheduled.event))
+            .collect()
+    }
+}
+
+impl Unit {
+    /// Schedules dmg on the target after the given delay (projectile travel time, dmg over time tick, ...).
+    /// The dmg goes through `Unit::dmg_on_target` at that time, so it is affected by the stats of the unit at that time.
+    pub(super) fn schedule_dmg(
+        &mut self,
+        delay: f32,
+        target_stats: &UnitStats,
+        part_dmg: PartDmg,
+        n_instances: (u8, u8),
+        dmg_tags: EnumSet<DmgTag>,
+        n_targets: f32,
+    ) {
+        self.events.push(
+            self.time + delay,
+            SimEvent::Dmg(Box::new(DelayedDmg {
+                target_stats: target_stats.clone(),
+                part_dmg,
+                n_instances,
+                dmg_tags,
+                n_targets,
+            })),
+        );
+    }
+
+    /// Schedules an action on the unit after the given delay.
+    pub(super) fn schedule_action(&mut self, delay: f32, action: fn(&mut Unit)) {
+        self.events
+            .push(self.time + delay, SimEvent::Action(action));
+    }
+
+    /// Schedules the end of a cooldown starting now (nothing is scheduled if it is already over or never ends).
+    pub(super) fn schedule_cooldown_ready(&mut self, cd: f32) {
+        if cd > 0. && cd.is_finite() {
+            self.events.push(self.time + cd, SimEvent::CooldownReady);
+        }
+    }
+
+    /// Lands the dmg still pending at the end of the fight (projectiles in flight, delayed dmg, ...),
+    /// as if the fight was stopped right after them. Other events are discarded and the fight time is unchanged.
+    pub(super) fn land_pending_dmg(&mut self) {
+        let end_time: f32 = self.time;
+        for (event_time, event) in self.events.take_all() {
+            if matches!(
+                event,
+                SimEvent::Dmg(_) | SimEvent::DmgLanding(..) | SimEvent::TeamfightDmgLanding(..)
+            ) {
+                self.time = event_time;
+                self.process_event(event_time, event);
+            }
+        }
+        self.events.clear(); //discard events scheduled while landing dmg
+        self.time = end_time;
+    }
+
+    /// Processes an event, `self.time` must be the time of the event.
+    fn process_event(&mut self, event_time: f32, event: SimEvent) {
+        match event {
+            SimEvent::EffectExpiry(effect_ref) => {
+                //ignore the event if the effect was refreshed (new expiry time) or already removed
+                if self.temporary_effects_expiries.get(effect_ref) == Some(&event_time) {
+                    self.temporary_effects_expiries.remove(effect_ref);
+                    (effect_ref.remove_every_stack)(self); //call after removing effect so it can re-add itself
+                }
+            }
+            SimEvent::Dmg(delayed_dmg) => {
+                self.dmg_on_target(
+                    &delayed_dmg.target_stats,
+                    delayed_dmg.part_dmg,
+                    delayed_dmg.n_instances,
+                    delayed_dmg.dmg_tags,
+                    delayed_dmg.n_targets,
+                );
+            }
+            SimEvent::DmgLanding(part_dmg, target_hp, target_dmg) => {
+                self.land_dmg(part_dmg, target_hp, target_dmg);
+            }
+            SimEvent::TeamfightDmgLanding(part_dmg, targets_dmg) => {
+                self.land_teamfight_dmg(part_dmg, &targets_dmg);
+            }
+            SimEvent::Action(action) => action(self),
+            SimEvent::CooldownReady => (),
+        }
+    }
+
+    /// Advances the simulation by `dt`, processing the events happening until then in chronological order.
+    /// If `moving` is true, the distance travelled by the unit is added to `units_travelled`
+    /// (with the move speed of the unit between each event).
+    pub(super) fn advance_time(&mut self, mut dt: f32, moving: bool) {
+        loop {
+            //advance until the next event if it happens before the end, otherwise until the end
+            let (step, reaches_event): (f32, bool) = match self.events.next_time() {
+                Some(event_time) if event_time - self.time <= dt => {
+                    (f32::max(0., event_time - self.time), true)
+                }
+                _ => (dt, false),
+            };
+            if step > 0. {
+                if moving {
+                    self.units_travelled += self.stats.ms() * step; //before updating effects, to still benefit from effects expiring at the end of the step
+                }
+                self.update_target_distance(step, moving);
+                //receive dmg before updating time
+                self.update_incoming_dmg(step);
+
+                //update time (land exactly on the event time)
+                self.time = match self.events.next_time() {
+                    Some(event_time) if reaches_event => f32::max(self.time, event_time),
+                    _ => self.time + step,
+                };
+
+                dt -= step;
+            }
+
+            //process due events (they can schedule new events)
+            while let Some((event_time, event)) = self.events.pop_due(self.time) {
+                self.process_event(event_time, event);
+            }
+
+            if !reaches_event {
+                return;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Test that events are processed at their exact time and that walking until a cooldown is ready ends it exactly.
+    #[test]
+    pub fn test_events_timing() {
+        let target: Unit = Unit::new_target_dummy();
+        let mut champ: Unit =
+            Unit::from_properties_defaults(&Unit::ASHE_PROPERTIES, 11, Build::default())
+                .expect("Failed to create unit");
+
+        champ.init_fight();
+        champ.schedule_dmg(
+            1.,
+            target.get_stats(),
+            PartDmg(0., 0., 100.),
+            (0, 0),
+            enum_set!(),
+            1.,
+        );
+        champ.wait(0.5);
+        assert!(champ.get_dmg_done().as_sum() == 0.);
+        champ.wait(0.5);
+        assert!(champ.get_dmg_done().as_sum() > 0.);
+
+        champ.basic_attack(target.get_stats());
+        assert!(champ.basic_attack_cd() > 0.);
+        let ready_time: f32 = champ.basic_attack_ready_time;
+        champ.walk_until_ready([champ.basic_attack_cd()], 60.);
+        assert!(champ.basic_attack_cd() == 0.);
+        assert!(champ.get_time() == ready_time);
+
+        //dmg pending at the end of the fight lands without changing the fight time
+        let dmg_before: f32 = champ.get_dmg_done().as_sum();
+        let time_before: f32 = champ.get_time();
+        champ.schedule_dmg(
+            5.,
+            target.get_stats(),
+            PartDmg(0., 0., 100.),
+            (0, 0),
+            enum_set!(),
+            1.,
+        );
+        champ.land_pending_dmg();
+        assert!(champ.get_dmg_done().as_sum() > dmg_before);
+        assert!(champ.get_time() == time_before);
+        assert!(champ.events.next_time().is_none());
+    }
+}
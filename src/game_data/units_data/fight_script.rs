@@ -0,0 +1,330 @@
+use super::*;
+
+use core::str::FromStr;
+
+/// Action of a fight script statement.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ScriptAction {
+    UseActives,
+    BasicAttack,
+    Q,
+    W,
+    E,
+    R,
+    WeightedR,
+    Walk,
+}
+
+impl ScriptAction {
+    fn parse(tokens: &[&str]) -> Option<Self> {
+        match tokens {
+            ["use", "actives"] | ["actives"] => Some(Self::UseActives),
+            ["aa"] | ["basic", "attack"] => Some(Self::BasicAttack),
+            ["q"] => Some(Self::Q),
+            ["w"] => Some(Self::W),
+            ["e"] => Some(Self::E),
+            ["r"] => Some(Self::R),
+            ["weighted", "r"] => Some(Self::WeightedR),
+            ["walk"] => Some(Self::Walk),
+            _ => None,
+        }
+    }
+
+    /// Returns the condition used when none is specified and the conditions allowed for the action.
+    fn conditions(self) -> (ScriptCondition, &'static [ScriptCondition]) {
+        match self {
+            Self::UseActives => (ScriptCondition::Once, &[ScriptCondition::Once]),
+            Self::WeightedR => (ScriptCondition::AtEnd, &[ScriptCondition::AtEnd]),
+            Self::Walk => (ScriptCondition::Otherwise, &[ScriptCondition::Otherwise]),
+            Self::BasicAttack | Self::Q | Self::W | Self::E | Self::R => (
+                ScriptCondition::IfReady,
+                &[
+                    ScriptCondition::IfReady,
+                    ScriptCondition::Once,
+                    ScriptCondition::Otherwise,
+                ],
+            ),
+        }
+    }
+}
+
+impl fmt::Display for ScriptAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::UseActives => "use actives",
+            Self::BasicAttack => "aa",
+            Self::Q => "q",
+            Self::W => "w",
+            Self::E => "e",
+            Self::R => "r",
+            Self::WeightedR => "weighted r",
+            Self::Walk => "walk",
+        })
+    }
+}
+
+/// Condition under which a fight script statement is executed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ScriptCondition {
+    /// When the cooldown of the action is over.
+    IfReady,
+    /// The first time the cooldown of the action is over.
+    Once,
+    /// Always (waits for the cooldown of the action if needed).
+    Otherwise,
+    /// Once at the end of the fight.
+    AtEnd,
+}
+
+impl fmt::Display for ScriptCondition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::IfReady => "if ready",
+            Self::Once => "once",
+            Self::Otherwise => "otherwise",
+            Self::AtEnd => "at end",
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct ScriptStatement {
+    action: ScriptAction,
+    condition: ScriptCondition,
+}
+
+/// Fight scenario written as a priority list, interpreted during the fight simulation (see `Unit::simulate_scripted_fight`).
+///
+/// A script is a list of statements separated by `;` or new lines, each statement being `ACTION [CONDITION]` (case insensitive).
+/// During the fight, the first statement whose condition is met is executed, then the list is evaluated again from the start.
+/// - actions: `q`, `w`, `e`, `r`, `aa` (basic attack), `use actives` (items and summoner spells actives), `walk`
+///   and `weighted r` (r dmg weighted by its availability, like at the end of the compiled fight scenarios).
+/// - conditions: `if ready` (default for abilities and basic attacks, when the cooldown is over),
+///   `once` (only the first time the cooldown is over, default for `use actives`),
+///   `otherwise` (always, waiting for the cooldown if needed, default for `walk` which walks until an action of the script is ready)
+///   and `at end` (at the end of the fight, only for `weighted r`).
+///
+/// When no statement can be executed, the unit walks until an action of the script is ready.
+/// Example: `use actives; r once; q if ready; aa if ready; w if ready; walk otherwise`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FightScript {
+    statements: Vec<ScriptStatement>,
+}
+
+impl FromStr for FightScript {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut statements: Vec<ScriptStatement> = Vec::new();
+        for statement in s.split([';', '\n']) {
+            let statement: String = statement.trim().to_lowercase();
+            if statement.is_empty() {
+                continue;
+            }
+            let tokens: Vec<&str> = statement.split_whitespace().collect();
+            let (action_tokens, condition): (&[&str], Option<ScriptCondition>) = match tokens[..] {
+                [ref action @ .., "if", "ready"] => (action, Some(ScriptCondition::IfReady)),
+                [ref action @ .., "once"] => (action, Some(ScriptCondition::Once)),
+                [ref action @ .., "otherwise"] => (action, Some(ScriptCondition::Otherwise)),
+                [ref action @ .., "at", "end"] => (action, Some(ScriptCondition::AtEnd)),
+                ref action => (action, None),
+            };
+            let action: ScriptAction = ScriptAction::parse(action_tokens)
+                .ok_or_else(|| format!("'{statement}' is not a valid fight script statement"))?;
+            let (default_condition, allowed_conditions) = action.conditions();
+            let condition: ScriptCondition = condition.unwrap_or(default_condition);
+            if !allowed_conditions.contains(&condition) {
+                return Err(format!(
+                    "'{action}' cannot be used with the condition '{condition}' in a fight script"
+                ));
+            }
+            statements.push(ScriptStatement { action, condition });
+        }
+        if statements.is_empty() {
+            return Err("Fight script must contain at least one statement".to_string());
+        }
+        Ok(Self { statements })
+    }
+}
+
+impl fmt::Display for FightScript {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (idx, statement) in self.statements.iter().enumerate() {
+            if idx != 0 {
+                f.write_str("; ")?;
+            }
+            write!(f, "{} {}", statement.action, statement.condition)?;
+        }
+        Ok(())
+    }
+}
+
+impl Unit {
+    /// Returns the remaining cooldown of the given script action (0 for actions without cooldown).
+    fn script_action_cd(&self, action: ScriptAction) -> f32 {
+        match action {
+            ScriptAction::BasicAttack => self.basic_attack_cd(),
+            ScriptAction::Q => self.q_cd(),
+            ScriptAction::W => self.w_cd(),
+            ScriptAction::E => self.e_cd(),
+            ScriptAction::R => self.r_cd(),
+            ScriptAction::UseActives | ScriptAction::WeightedR | ScriptAction::Walk => 0.,
+        }
+    }
+
+    /// Walks until the first action of the script that is on cooldown is ready, without exceeding the fight duration.
+    fn walk_until_script_ready(&mut self, script: &FightScript, fight_duration: f32) {
+        let uses = |action: ScriptAction| -> bool {
+            script
+                .statements
+                .iter()
+                .any(|statement| statement.action == action)
+        };
+        let cooldowns: [f32; 5] = [
+            ScriptAction::BasicAttack,
+            ScriptAction::Q,
+            ScriptAction::W,
+            ScriptAction::E,
+            ScriptAction::R,
+        ]
+        .map(|action| {
+            if uses(action) {
+                self.script_action_cd(action)
+            } else {
+                0.
+            }
+        });
+        self.walk_until_ready(cooldowns, fight_duration);
+    }
+
+    /// Simulates a fight for the unit hitting the specified target according to the given fight script
+    /// (instead of a fight scenario from the unit properties, see `Unit::simulate_fight`).
+    /// This function will always start by initializing the unit with `self.init_fight`,
+    /// items actives are only used if the script says so.
+    pub fn simulate_scripted_fight(
+        &mut self,
+        target_stats: &UnitStats,
+        script: &FightScript,
+        fight_duration: f32,
+    ) {
+        self.init_fight();
+        self.start_target_movement(target_stats);
+
+        let mut done: Vec<bool> = vec![false; script.statements.len()]; //for statements executed only once
+        while self.time < fight_duration {
+            let statement: Option<(usize, &ScriptStatement)> = script
+                .statements
+                .iter()
+                .enumerate()
+                .find(|&(idx, statement)| match statement.condition {
+                    ScriptCondition::IfReady => self.script_action_cd(statement.action) == 0.,
+                    ScriptCondition::Once => {
+                        !done[idx] && self.script_action_cd(statement.action) == 0.
+                    }
+                    //skip actions that will never be ready (abilities not lvled up)
+                    ScriptCondition::Otherwise => {
+                        self.script_action_cd(statement.action).is_finite()
+                    }
+                    ScriptCondition::AtEnd => false,
+                });
+            let Some((idx, statement)) = statement else {
+                self.walk_until_script_ready(script, fight_duration);
+                continue;
+            };
+            done[idx] = true;
+
+            match statement.action {
+                ScriptAction::Walk => self.walk_until_script_ready(script, fight_duration),
+                ScriptAction::UseActives => {
+                    self.use_all_special_actives(target_stats);
+                }
+                action => {
+                    //wait for the cooldown if needed (`otherwise` condition), without exceeding the fight duration
+                    let cd: f32 = self.script_action_cd(action);
+                    if cd > 0. {
+                        self.walk(f32::min(cd, fight_duration - self.time));
+                        if self.script_action_cd(action) > 0. {
+                            continue; //fight ended before the action was ready
+                        }
+                    }
+                    match action {
+                        ScriptAction::BasicAttack => self.basic_attack(target_stats),
+                        ScriptAction::Q => self.q(target_stats),
+                        ScriptAction::W => self.w(target_stats),
+                        ScriptAction::E => self.e(target_stats),
+                        ScriptAction::R => self.r(target_stats),
+                        ScriptAction::UseActives | ScriptAction::WeightedR | ScriptAction::Walk => {
+                            unreachable!("Action already handled")
+                        }
+                    };
+                }
+            }
+        }
+
+        for statement in &script.statements {
+            if statement.action == ScriptAction::WeightedR {
+                self.weighted_r(target_stats);
+            }
+        }
+        self.land_pending_dmg();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Test that fight scripts are parsed and interpreted.
+    #[test]
+    pub fn test_fight_script() {
+        let script: FightScript =
+            "Use actives; r once\nq if ready; aa; weighted r;; walk otherwise"
+                .parse()
+                .expect("Failed to parse valid fight script");
+        assert_eq!(
+            script.to_string(),
+            "use actives once; r once; q if ready; aa if ready; weighted r at end; walk otherwise"
+        );
+        assert_eq!(
+            script.to_string().parse::<FightScript>(),
+            Ok(script.clone())
+        );
+
+        //invalid scripts
+        assert!("".parse::<FightScript>().is_err());
+        assert!("q; dance".parse::<FightScript>().is_err());
+        assert!("walk if ready".parse::<FightScript>().is_err());
+
+        let target: Unit = Unit::new_target_dummy();
+        let mut champ: Unit =
+            Unit::from_properties_defaults(&Unit::ASHE_PROPERTIES, 11, Build::default())
+                .expect("Failed to create unit");
+        champ.simulate_scripted_fight(target.get_stats(), &script, 6.);
+        assert!(champ.get_time() >= 6.);
+        assert!(champ.get_dmg_done().as_sum() > 0.);
+
+        //basic attacks only deal less dmg than with abilities
+        let dmg_with_abilities: f32 = champ.get_dmg_done().as_sum();
+        champ.simulate_scripted_fight(
+            target.get_stats(),
+            &"aa".parse().expect("Failed to parse valid fight script"),
+            6.,
+        );
+        assert!(champ.get_dmg_done().as_sum() < dmg_with_abilities);
+
+        //actions not lvled up yet are skipped at low lvls
+        let otherwise_script: FightScript = "r otherwise; aa"
+            .parse()
+            .expect("Failed to parse valid fight script");
+        for lvl in MIN_UNIT_LVL..6 {
+            let mut champ: Unit =
+                Unit::from_properties_defaults(&Unit::ASHE_PROPERTIES, lvl, Build::default())
+                    .expect("Failed to create unit");
+            for script in [&script, &otherwise_script] {
+                champ.simulate_scripted_fight(target.get_stats(), script, 6.);
+                assert!(champ.get_time().is_finite(), "lvl {lvl}: {script}");
+                assert!(champ.get_dmg_done().as_sum() > 0., "lvl {lvl}: {script}");
+            }
+        }
+    }
+}
@@ -0,0 +1,423 @@
+use super::*;
+
+use items_data::ItemGroups;
+
+use serde::{Deserialize, Serialize};
+
+/// Heals reduction when the unit is affected by grievous wounds.
+const GRIEVOUS_WOUNDS_HEALS_REDUCTION: f32 = 0.40;
+/// Duration of the stasis of stasis items (zhonyas hourglass, ...).
+const STASIS_DURATION: f32 = 2.5;
+/// Hp% (of max hp) under which the unit uses its stasis item (if it has one).
+const STASIS_HP_PERCENT_THRESHOLD: f32 = 0.25;
+/// Hp% (of max hp) under which lifeline shields are obtained (they trigger on the dmg that would bring the hp under it).
+const LIFELINE_HP_PERCENT_THRESHOLD: f32 = 0.30;
+
+/// Dmg received by the unit during a fight simulation. Dmg values are pre-mitigation and scale with the unit lvl.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct IncomingDmgProfile {
+    /// Dmg received continuously, per second and per lvl.
+    pub sustained_dps_per_lvl: f32,
+    /// Dmg received at once at `burst_time`, per lvl.
+    pub burst_dmg_per_lvl: f32,
+    /// Time at which the burst is received (in seconds from the start of the fight).
+    pub burst_time: f32,
+    /// Percentage of the dmg received that is true dmg (the rest is split between physical and magic dmg).
+    pub true_dmg_percent: f32,
+    /// If the heals received by the unit are reduced by grievous wounds (shields are not affected).
+    pub grievous_wounds: bool,
+}
+
+impl Default for IncomingDmgProfile {
+    fn default() -> Self {
+        Self {
+            sustained_dps_per_lvl: 20.,
+            burst_dmg_per_lvl: 60.,
+            burst_time: 1.,
+            true_dmg_percent: 0.10,
+            grievous_wounds: false,
+        }
+    }
+}
+
+impl fmt::Display for IncomingDmgProfile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} dps/lvl + {} burst dmg/lvl at {}s, {:.0}% true dmg",
+            self.sustained_dps_per_lvl,
+            self.burst_dmg_per_lvl,
+            self.burst_time,
+            100. * self.true_dmg_percent
+        )?;
+        if self.grievous_wounds {
+            f.write_str(", grievous wounds")?;
+        }
+        Ok(())
+    }
+}
+
+impl IncomingDmgProfile {
+    /// Returns an Err with the corresponding error message if the profile values are invalid.
+    pub fn check_validity(&self) -> Result<(), String> {
+        if !self.sustained_dps_per_lvl.is_finite() || self.sustained_dps_per_lvl <= 0. {
+            return Err(format!(
+                "Sustained dmg received must be greater than 0 (got {})",
+                self.sustained_dps_per_lvl
+            ));
+        }
+        if !self.burst_dmg_per_lvl.is_finite() || self.burst_dmg_per_lvl < 0. {
+            return Err(format!(
+                "Burst dmg received must be positive (got {})",
+                self.burst_dmg_per_lvl
+            ));
+        }
+        if !self.burst_time.is_finite() || self.burst_time < 0. {
+            return Err(format!(
+                "Burst time must be positive (got {}s)",
+                self.burst_time
+            ));
+        }
+        if !self.true_dmg_percent.is_finite() || !(0.0..=1.0).contains(&self.true_dmg_percent) {
+            return Err(format!(
+                "Percentage of true dmg received must be between 0% and 100% (got {}%)",
+                100. * self.true_dmg_percent
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Hp tracking of an unit receiving dmg during a fight simulation.
+#[derive(Debug, Clone)]
+pub(crate) struct IncomingDmgState {
+    profile: IncomingDmgProfile,
+    phys_dmg_received_percent: f32,
+    hp: f32,
+    shield: f32,
+    /// Lifeline shields not triggered yet.
+    lifeline_shield: f32,
+    /// Values of `heals_received`, `shields_received` and `lifeline_shields_received` already applied.
+    heals_shields_applied: (f32, f32, f32),
+    burst_received: bool,
+    stasis_available: bool,
+    stasis_end: f32,
+    /// Post-mitigation sustained dps received at the last update.
+    sustained_dps: f32,
+    time_of_death: Option<f32>,
+}
+
+impl IncomingDmgState {
+    pub(crate) fn new(profile: IncomingDmgProfile, phys_dmg_received_percent: f32) -> Self {
+        Self {
+            profile,
+            phys_dmg_received_percent,
+            hp: 0.,
+            shield: 0.,
+            lifeline_shield: 0.,
+            heals_shields_applied: (0., 0., 0.),
+            burst_received: false,
+            stasis_available: false,
+            stasis_end: 0.,
+            sustained_dps: 0.,
+            time_of_death: None,
+        }
+    }
+
+    /// Receives the given post-mitigation dmg at once (shield absorbs dmg first).
+    fn receive_dmg(&mut self, dmg: f32, time: f32, thresholds: &HpThresholds) {
+        if time < self.stasis_end {
+            return;
+        }
+        //lifeline shields trigger before the dmg that would bring the hp under the threshold
+        if self.lifeline_shield > 0.
+            && self.hp - f32::max(0., dmg - self.shield) < thresholds.lifeline_hp
+        {
+            self.shield += self.lifeline_shield;
+            self.lifeline_shield = 0.;
+        }
+        let absorbed: f32 = f32::min(self.shield, dmg);
+        self.shield -= absorbed;
+        self.hp -= dmg - absorbed;
+        if self.hp <= 0. {
+            self.hp = 0.;
+            self.time_of_death = Some(time);
+        } else if self.stasis_available && self.hp <= thresholds.stasis_hp {
+            self.stasis_available = false;
+            self.stasis_end = time + STASIS_DURATION;
+        }
+    }
+
+    /// Receives the post-mitigation `self.sustained_dps` from `start` to `end`.
+    fn receive_sustained_dmg(&mut self, start: f32, end: f32, thresholds: &HpThresholds) {
+        let mut time: f32 = start;
+        while self.time_of_death.is_none() && end - time >= F32_TOL {
+            if time < self.stasis_end {
+                time = f32::min(end, self.stasis_end);
+                continue;
+            }
+
+            //receive dmg until the next event (shield broken, lifeline shield obtained, stasis used or death) or until the end
+            let pool_until_event: f32 = if self.shield > 0. {
+                self.shield
+            } else {
+                let next_threshold_hp: f32 = [
+                    (self.lifeline_shield > 0.).then_some(thresholds.lifeline_hp),
+                    self.stasis_available.then_some(thresholds.stasis_hp),
+                ]
+                .into_iter()
+                .flatten()
+                .filter(|&threshold_hp| threshold_hp < self.hp)
+                .fold(0., f32::max);
+                self.hp - next_threshold_hp
+            };
+            let dt: f32 = f32::min(end - time, pool_until_event / self.sustained_dps);
+            time += dt;
+            self.receive_dmg(self.sustained_dps * dt, time, thresholds);
+        }
+    }
+}
+
+/// Hp under which the effects triggered by low hp are used.
+struct HpThresholds {
+    stasis_hp: f32,
+    lifeline_hp: f32,
+}
+
+impl Unit {
+    /// Adds a heal obtained by the unit (reduced by grievous wounds when receiving dmg).
+    /// `periodic` is true for heals obtained over a duration (recurring effects), false for heals obtained once.
+    pub(crate) fn add_heal(&mut self, heal: f32, periodic: bool) {
+        if periodic {
+            self.periodic_heals_shields += heal;
+        } else {
+            self.single_use_heals_shields += heal;
+        }
+        self.heals_received += heal;
+    }
+
+    /// Adds a shield obtained by the unit (not affected by grievous wounds).
+    /// `periodic` is true for shields obtained over a duration (recurring effects), false for shields obtained once.
+    pub(crate) fn add_shield(&mut self, shield: f32, periodic: bool) {
+        if periodic {
+            self.periodic_heals_shields += shield;
+        } else {
+            self.single_use_heals_shields += shield;
+        }
+        self.shields_received += shield;
+    }
+
+    /// Adds a lifeline shield, obtained once when the hp of the unit falls under the lifeline threshold.
+    pub(crate) fn add_lifeline_shield(&mut self, shield: f32) {
+        self.single_use_heals_shields += shield;
+        self.lifeline_shields_received += shield;
+    }
+
+    /// Sets the dmg received by the unit during fight simulations (`None` for no dmg received).
+    /// `phys_dmg_received_percent` is the percentage of physical dmg among the physical and magic dmg received.
+    pub fn set_incoming_dmg(
+        &mut self,
+        profile: Option<IncomingDmgProfile>,
+        phys_dmg_received_percent: f32,
+    ) {
+        self.incoming_dmg =
+            profile.map(|profile| IncomingDmgState::new(profile, phys_dmg_received_percent));
+        self.init_incoming_dmg();
+    }
+
+    /// Resets the hp tracking at the start of a fight (must be called after stats initialization).
+    pub(crate) fn init_incoming_dmg(&mut self) {
+        if let Some(state) = &mut self.incoming_dmg {
+            state.hp = self.stats.hp;
+            state.shield = 0.;
+            state.lifeline_shield = 0.;
+            state.heals_shields_applied = (0., 0., 0.);
+            state.burst_received = false;
+            state.stasis_available = self
+                .build
+                .iter()
+                .any(|item| item.item_groups.contains(ItemGroups::Stasis));
+            state.stasis_end = 0.;
+            state.sustained_dps = 0.;
+            state.time_of_death = None;
+        }
+    }
+
+    /// Updates the hp of the unit with the heals and shields obtained since the last update and the dmg received
+    /// over the next `dt` seconds. Must be called before updating the unit time.
+    ///
+    /// Heals and shields are applied when they are obtained (see `Unit::add_heal`, `Unit::add_shield`)
+    /// and lifeline shields when the hp falls under their threshold (see `Unit::add_lifeline_shield`).
+    pub(crate) fn update_incoming_dmg(&mut self, dt: f32) {
+        let Some(state) = &mut self.incoming_dmg else {
+            return;
+        };
+
+        //heals and shields obtained since the last update (can be negative when weighted by `weighted_r`)
+        let heals: f32 = self.heals_received - state.heals_shields_applied.0;
+        let shields: f32 = self.shields_received - state.heals_shields_applied.1;
+        let lifeline_shields: f32 = self.lifeline_shields_received - state.heals_shields_applied.2;
+        state.heals_shields_applied = (
+            self.heals_received,
+            self.shields_received,
+            self.lifeline_shields_received,
+        );
+        if state.time_of_death.is_some() {
+            return;
+        }
+        let heals_coef: f32 = if state.profile.grievous_wounds {
+            1. - GRIEVOUS_WOUNDS_HEALS_REDUCTION
+                * (1. - self.effects_values[EffectValueId::CleanseGrievousWoundsRemoval])
+        } else {
+            1.
+        };
+        state.hp = f32::clamp(state.hp + heals_coef * heals, F32_TOL, self.stats.hp);
+        state.shield = f32::max(0., state.shield + shields);
+        state.lifeline_shield += lifeline_shields;
+
+        //dmg received
+        let lvl: f32 = f32::from(self.lvl.get());
+        let mitigation_coef: f32 = (1.
+            - self.effects_values[EffectValueId::ExhaustDmgReceivedReduction])
+            * (1. - self.effects_values[EffectValueId::CelestialOppositionDmgReceivedReduction])
+            * (state.profile.true_dmg_percent
+                + (1. - state.profile.true_dmg_percent)
+                    * (state.phys_dmg_received_percent * resistance_formula(self.stats.armor)
+                        + (1. - state.phys_dmg_received_percent)
+                            * resistance_formula(self.stats.mr)));
+        state.sustained_dps = mitigation_coef * lvl * state.profile.sustained_dps_per_lvl;
+        let thresholds: HpThresholds = HpThresholds {
+            stasis_hp: STASIS_HP_PERCENT_THRESHOLD * self.stats.hp,
+            lifeline_hp: LIFELINE_HP_PERCENT_THRESHOLD * self.stats.hp,
+        };
+        let end: f32 = self.time + dt;
+        let mut start: f32 = self.time;
+        if !state.burst_received && state.profile.burst_time < end {
+            let burst_time: f32 = f32::max(start, state.profile.burst_time);
+            state.receive_sustained_dmg(start, burst_time, &thresholds);
+            state.receive_dmg(
+                mitigation_coef * lvl * state.profile.burst_dmg_per_lvl,
+                burst_time,
+                &thresholds,
+            );
+            state.burst_received = true;
+            start = burst_time;
+        }
+        state.receive_sustained_dmg(start, end, &thresholds);
+    }
+
+    /// Returns the time survived by the unit during the last fight simulation, or `None` if the unit receives no dmg.
+    /// If the unit is still alive at the end of the fight, its remaining hp and shields are converted to the time
+    /// needed to lose them with the sustained dmg received.
+    #[must_use]
+    pub fn get_time_survived(&self) -> Option<f32> {
+        let state: &IncomingDmgState = self.incoming_dmg.as_ref()?;
+        Some(state.time_of_death.unwrap_or_else(|| {
+            f32::max(self.time, state.stasis_end)
+                + (state.hp + state.shield + state.lifeline_shield) / state.sustained_dps
+        }))
+    }
+
+    /// Returns the current hp of the unit tracked during the fight simulation, or `None` if the unit receives no dmg.
+    #[must_use]
+    pub fn get_current_hp(&self) -> Option<f32> {
+        self.incoming_dmg.as_ref().map(|state| state.hp)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use items_data::Build;
+    use runes_data::RunesPage;
+
+    #[test]
+    pub fn test_incoming_dmg_time_survived() {
+        let profile: IncomingDmgProfile = IncomingDmgProfile {
+            burst_dmg_per_lvl: 0.,
+            ..Default::default()
+        };
+        let time_survived = |build: Build| -> f32 {
+            let mut champ: Unit = Unit::new(
+                &Unit::ASHE_PROPERTIES,
+                RunesPage::default(),
+                Unit::ASHE_PROPERTIES.defaults.skill_order.clone(),
+                11,
+                build,
+            )
+            .expect("Failed to create unit");
+            champ.set_incoming_dmg(Some(profile), 0.5);
+            champ.init_fight();
+            champ.walk(30.);
+            champ
+                .get_time_survived()
+                .expect("Unit should have a time survived")
+        };
+
+        let mut build: Build = Build::default();
+        let base_time_survived: f32 = time_survived(build);
+        assert!(base_time_survived > 1. && base_time_survived < 30.); //dies during the fight
+
+        //zhonyas stasis and armor make the unit survive longer
+        build[0] = &Item::ZHONYAS_HOURGLASS;
+        assert!(time_survived(build) > base_time_survived + STASIS_DURATION);
+    }
+
+    #[test]
+    pub fn test_incoming_dmg_heals_shields() {
+        let profile: IncomingDmgProfile = IncomingDmgProfile {
+            burst_dmg_per_lvl: 0.,
+            ..Default::default()
+        };
+        let new_champ = |grievous_wounds: bool| -> Unit {
+            let mut champ: Unit =
+                Unit::from_properties_defaults(&Unit::ASHE_PROPERTIES, 11, Build::default())
+                    .expect("Failed to create unit");
+            champ.set_incoming_dmg(
+                Some(IncomingDmgProfile {
+                    grievous_wounds,
+                    ..profile
+                }),
+                0.5,
+            );
+            champ.init_fight();
+            champ.walk(3.); //lose some hp before receiving heals
+            champ
+        };
+        let hp = |champ: &Unit| -> f32 { champ.get_current_hp().expect("Unit should have hp") };
+
+        //grievous wounds reduce heals
+        let mut champ: Unit = new_champ(false);
+        let mut gw_champ: Unit = new_champ(true);
+        champ.add_heal(100., false);
+        gw_champ.add_heal(100., false);
+        champ.walk(1.);
+        gw_champ.walk(1.);
+        assert!((hp(&champ) - hp(&gw_champ) - GRIEVOUS_WOUNDS_HEALS_REDUCTION * 100.).abs() < 0.1);
+
+        //but not shields
+        let mut champ: Unit = new_champ(false);
+        let mut gw_champ: Unit = new_champ(true);
+        let hp_before_shield: f32 = hp(&champ);
+        champ.add_shield(1000., false);
+        gw_champ.add_shield(1000., false);
+        champ.walk(1.);
+        gw_champ.walk(1.);
+        assert!(hp(&champ) == hp(&gw_champ));
+        assert!(hp(&champ) == hp_before_shield); //the shield absorbed the dmg
+
+        //lifeline shields are only obtained when the hp falls under the threshold
+        let mut champ: Unit = new_champ(false);
+        let base_time_survived: f32 = champ.get_time_survived().unwrap();
+        champ.add_lifeline_shield(100.);
+        champ.walk(F32_TOL);
+        let state: &IncomingDmgState = champ.incoming_dmg.as_ref().unwrap();
+        assert!(state.shield == 0. && state.lifeline_shield == 100.);
+        champ.walk(60.);
+        let state: &IncomingDmgState = champ.incoming_dmg.as_ref().unwrap();
+        assert!(state.lifeline_shield == 0.);
+        assert!(champ.get_time_survived().unwrap() > base_time_survived);
+    }
+}
@@ -0,0 +1,340 @@
+use super::passives::serialize_non_zero_stats;
+use super::*;
+
+use serde::{Deserialize, Serialize};
+
+use std::fs;
+use std::path::Path;
+use std::sync::LazyLock;
+
+/// Location of the items data file read at startup by default (if it exists).
+pub const DEFAULT_ITEMS_DATA_PATH: &str = "data/items.toml";
+
+/// Source name of the compiled-in items database.
+pub const BUILTIN_ITEMS_SOURCE: &str = "built-in";
+
+/// Content of an items data file.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ItemsFile {
+    items: Vec<ItemDefinition>,
+}
+
+/// Human-editable representation of an `Item` in an items data file.
+///
+/// An item whose full name matches a compiled-in item keeps the on-action-fns of the compiled-in item
+/// (only its values are overridden). Other items only have the declarative passives given in the file.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ItemDefinition {
+    full_name: String,
+    short_name: String,
+    cost: f32,
+    /// Boots, support items, components, starter items and consumables are recognized by their
+    /// `Boots`, `Support`, `Component`, `Starter` and `Consumable` item groups.
+    #[serde(default)]
+    item_groups: Vec<ItemGroups>,
+    #[serde(default)]
+    utils: Vec<ItemUtils>,
+    /// Stats that are not specified are 0.
+    #[serde(default, serialize_with = "serialize_non_zero_stats")]
+    stats: UnitStats,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    passives: Vec<ItemPassive>,
+}
+
+impl ItemDefinition {
+    fn from_item(item: &Item) -> Self {
+        Self {
+            full_name: item.full_name.to_string(),
+            short_name: item.short_name.to_string(),
+            cost: item.cost,
+            item_groups: item.item_groups.iter().collect(),
+            utils: item.utils.iter().collect(),
+            stats: item.stats.clone(),
+            passives: item.passives.to_vec(),
+        }
+    }
+}
+
+/// Set of items available to the builds generation, either the compiled-in items or items loaded from an items data file.
+#[derive(Debug)]
+pub struct ItemsDatabase {
+    /// Where the items come from (path of the file or `BUILTIN_ITEMS_SOURCE`).
+    pub source: String,
+    legendary_items: Vec<&'static Item>,
+    boots: Vec<&'static Item>,
+    supp_items: Vec<&'static Item>,
+    component_items: Vec<&'static Item>,
+    starter_items: Vec<&'static Item>,
+    consumables: Vec<&'static Item>,
+}
+
+static BUILTIN_ITEMS_DATABASE: LazyLock<ItemsDatabase> = LazyLock::new(|| ItemsDatabase {
+    source: BUILTIN_ITEMS_SOURCE.to_string(),
+    legendary_items: Vec::from(ALL_LEGENDARY_ITEMS),
+    boots: Vec::from(ALL_BOOTS),
+    supp_items: Vec::from(ALL_SUPP_ITEMS),
+    component_items: Vec::from(ALL_COMPONENT_ITEMS),
+    starter_items: Vec::from(ALL_STARTER_ITEMS),
+    consumables: Vec::from(ALL_CONSUMABLES),
+});
+
+impl ItemsDatabase {
+    /// Returns the database of compiled-in items.
+    #[must_use]
+    pub fn builtin() -> &'static Self {
+        &BUILTIN_ITEMS_DATABASE
+    }
+
+    /// Reads an items database from a TOML file.
+    ///
+    /// Items loaded are leaked to get `&'static Item` like compiled-in items,
+    /// so this function is meant to be called a few times at most (e.g. at startup).
+    pub fn load(path: &Path) -> Result<&'static Self, String> {
+        let content: String = fs::read_to_string(path)
+            .map_err(|error| format!("Failed to read '{}': {error}", path.display()))?;
+        Self::parse(&content, &path.display().to_string())
+    }
+
+    /// Creates an items database from the content of an items data file,
+    /// `source` is only used to identify the database and in error messages.
+    pub fn parse(content: &str, source: &str) -> Result<&'static Self, String> {
+        let file: ItemsFile = toml::from_str(content)
+            .map_err(|error| format!("Failed to parse '{source}': {error}"))?;
+
+        let builtin_items: Vec<&'static Item> = Self::builtin().all_items().collect();
+        let mut items: Vec<&'static Item> = Vec::with_capacity(file.items.len());
+        let mut n_custom_items: u16 = 0;
+        for definition in file.items {
+            if sanitize_item_name(&definition.full_name).is_empty()
+                || sanitize_item_name(&definition.short_name).is_empty()
+            {
+                return Err(format!("Item names cannot be empty (in '{source}')"));
+            }
+            for name in [&definition.full_name, &definition.short_name] {
+                if find_item_by_name(name, &items).is_some() {
+                    return Err(format!("Item '{name}' is defined twice in '{source}'"));
+                }
+            }
+            if !definition.cost.is_finite() || definition.cost < 0. {
+                return Err(format!(
+                    "Cost of '{}' must be positive (got {})",
+                    definition.full_name, definition.cost
+                ));
+            }
+            for passive in &definition.passives {
+                passive.check_validity().map_err(|error_msg| {
+                    format!(
+                        "Invalid passive for '{}': {error_msg}",
+                        definition.full_name
+                    )
+                })?;
+            }
+
+            //items matching a compiled-in item keep its id and on-action-fns
+            let builtin_item: Option<&'static Item> = builtin_items.iter().copied().find(|item| {
+                sanitize_item_name(item.full_name) == sanitize_item_name(&definition.full_name)
+            });
+            let (id, on_action_fns): (ItemId, OnActionFns) = if let Some(item) = builtin_item {
+                (item.id, item.on_action_fns)
+            } else {
+                let id: ItemId = ItemId::Custom(n_custom_items);
+                n_custom_items = n_custom_items
+                    .checked_add(1)
+                    .ok_or_else(|| format!("Too many items in '{source}'"))?;
+                (id, Item::NULL_ITEM.on_action_fns)
+            };
+
+            items.push(Box::leak(Box::new(Item {
+                id,
+                full_name: Box::leak(definition.full_name.into_boxed_str()),
+                short_name: Box::leak(definition.short_name.into_boxed_str()),
+                cost: definition.cost,
+                item_groups: definition.item_groups.into_iter().collect(),
+                utils: definition.utils.into_iter().collect(),
+                stats: definition.stats,
+                passives: Box::leak(definition.passives.into_boxed_slice()),
+                on_action_fns,
+            })));
+        }
+
+        Ok(Self::from_items(source, items))
+    }
+
+    /// Creates an items database from the given items, boots, support items, components, starter items and consumables
+    /// are recognized by their item groups.
+    /// The database is leaked to get a `&'static ItemsDatabase`.
+    #[must_use]
+    pub fn from_items(source: &str, items: Vec<&'static Item>) -> &'static Self {
+        let mut database: Self = Self {
+            source: source.to_string(),
+            legendary_items: Vec::new(),
+            boots: Vec::new(),
+            supp_items: Vec::new(),
+            component_items: Vec::new(),
+            starter_items: Vec::new(),
+            consumables: Vec::new(),
+        };
+        for item in items {
+            if item.item_groups.contains(ItemGroups::Boots) {
+                database.boots.push(item);
+            } else if item.item_groups.contains(ItemGroups::Support) {
+                database.supp_items.push(item);
+            } else if item.is_component() {
+                database.component_items.push(item);
+            } else if item.is_starter() {
+                database.starter_items.push(item);
+            } else if item.is_consumable() {
+                database.consumables.push(item);
+            } else {
+                database.legendary_items.push(item);
+            }
+        }
+        Box::leak(Box::new(database))
+    }
+
+    /// Writes the items of the database to a TOML file (creates the parent directories if needed).
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let file: ItemsFile = ItemsFile {
+            items: self.all_items().map(ItemDefinition::from_item).collect(),
+        };
+        let content: String = toml::to_string_pretty(&file)
+            .map_err(|error| format!("Failed to serialize items: {error}"))?;
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(|error| {
+                format!("Failed to create directory '{}': {error}", parent.display())
+            })?;
+        }
+        fs::write(path, content)
+            .map_err(|error| format!("Failed to write '{}': {error}", path.display()))
+    }
+
+    /// Returns the legendary items (finished items that are not boots or support items) of the database.
+    #[must_use]
+    pub fn legendary_items(&self) -> &[&'static Item] {
+        &self.legendary_items
+    }
+
+    /// Returns the boots of the database.
+    #[must_use]
+    pub fn boots(&self) -> &[&'static Item] {
+        &self.boots
+    }
+
+    /// Returns the support items of the database.
+    #[must_use]
+    pub fn supp_items(&self) -> &[&'static Item] {
+        &self.supp_items
+    }
+
+    /// Returns the component items of the database.
+    #[must_use]
+    pub fn component_items(&self) -> &[&'static Item] {
+        &self.component_items
+    }
+
+    /// Returns the starter items of the database.
+    #[must_use]
+    pub fn starter_items(&self) -> &[&'static Item] {
+        &self.starter_items
+    }
+
+    /// Returns the consumables of the database.
+    #[must_use]
+    pub fn consumables(&self) -> &[&'static Item] {
+        &self.consumables
+    }
+
+    /// Returns every item of the database
+    /// (legendary items, then boots, then support items, then components, then starter items, then consumables).
+    pub fn all_items(&self) -> impl Iterator<Item = &'static Item> + '_ {
+        self.legendary_items
+            .iter()
+            .chain(self.boots.iter())
+            .chain(self.supp_items.iter())
+            .chain(self.component_items.iter())
+            .chain(self.starter_items.iter())
+            .chain(self.consumables.iter())
+            .copied()
+    }
+
+    /// Returns the number of items in the database.
+    #[must_use]
+    pub fn n_items(&self) -> usize {
+        self.legendary_items.len()
+            + self.boots.len()
+            + self.supp_items.len()
+            + self.component_items.len()
+            + self.starter_items.len()
+            + self.consumables.len()
+    }
+
+    /// Returns the item of the database whose full name or short name matches the given name, if any.
+    #[must_use]
+    pub fn find_item_by_name(&self, name: &str) -> Option<&'static Item> {
+        self.all_items().find(|item| {
+            let sanitized_name: String = sanitize_item_name(name);
+            (sanitized_name == sanitize_item_name(item.full_name))
+                || (sanitized_name == sanitize_item_name(item.short_name))
+        })
+    }
+
+    /// Returns the version of the given item in this database (items are matched by id), if any.
+    /// `Item::NULL_ITEM` is always returned as is.
+    #[must_use]
+    pub fn get_item(&self, item: &Item) -> Option<&'static Item> {
+        if *item == Item::NULL_ITEM {
+            return Some(&Item::NULL_ITEM);
+        }
+        self.all_items()
+            .find(|&database_item| *database_item == *item)
+    }
+
+    /// Returns the versions of the given items in this database, items not in the database are discarded.
+    #[must_use]
+    pub fn get_items(&self, items: &[&Item]) -> Vec<&'static Item> {
+        items
+            .iter()
+            .filter_map(|item| self.get_item(item))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_items_database_round_trip() {
+        let builtin: &ItemsDatabase = ItemsDatabase::builtin();
+        let file: ItemsFile = ItemsFile {
+            items: builtin.all_items().map(ItemDefinition::from_item).collect(),
+        };
+        let content: String = toml::to_string_pretty(&file).expect("Failed to serialize items");
+        let loaded: &ItemsDatabase =
+            ItemsDatabase::parse(&content, "test").expect("Failed to parse items");
+
+        assert_eq!(
+            loaded.legendary_items().len(),
+            builtin.legendary_items().len()
+        );
+        assert_eq!(loaded.boots().len(), builtin.boots().len());
+        assert_eq!(loaded.supp_items().len(), builtin.supp_items().len());
+        assert_eq!(
+            loaded.component_items().len(),
+            builtin.component_items().len()
+        );
+        assert_eq!(loaded.starter_items().len(), builtin.starter_items().len());
+        assert_eq!(loaded.consumables().len(), builtin.consumables().len());
+        for item in builtin.all_items() {
+            let loaded_item: &Item = loaded
+                .get_item(item)
+                .unwrap_or_else(|| panic!("'{}' is missing from loaded items", item.full_name));
+            assert_eq!(loaded_item.short_name, item.short_name);
+            assert!((loaded_item.cost - item.cost).abs() < F32_TOL);
+            assert!((loaded_item.stats.bonus_ad - item.stats.bonus_ad).abs() < F32_TOL);
+            assert_eq!(loaded_item.item_groups, item.item_groups);
+        }
+    }
+}
@@ -5,7 +5,7 @@ use crate::game_data::*;
 use effects_data::{EffectId, EffectStackId, EffectValueId, TemporaryEffect};
 use units_data::*;
 
-use enumset::{enum_set, EnumSet};
+use enumset::enum_set;
 
 // This is the file containing every items stats + their passive/active effects scripts
 
@@ -22,8 +22,10 @@ const HORIZON_FOCUS_HYPERSHOT_ABILITIES_TRIGGER_PERCENT: f32 = 0.60;
 /// Actual duration of Malignance hatefog curse on the ennemy
 const MALIGNANCE_HATEFOG_CURSE_TIME: f32 = 0.8;
 /// Number of bolts fired by Runaan's hurricane wind's fury on average (adding to the primary basic attack).
-pub(crate) const RUNAANS_HURRICANE_WINDS_FURY_AVG_BOLTS: f32 =
+const RUNAANS_HURRICANE_WINDS_FURY_AVG_BOLTS: f32 =
     basic_attack_aoe_effect_avg_additionnal_targets!(550.); //todo: should use champion attack range
+/// Maximum number of bolts fired by Runaan's hurricane wind's fury.
+const RUNAANS_HURRICANE_WINDS_FURY_MAX_BOLTS: f32 = 2.;
 /// Number of targets hit by statikk shiv electrospark (must be at least 1).
 const STATIKK_SHIV_ELECTROSPARK_AVG_TARGETS: f32 =
     1. + basic_attack_aoe_effect_avg_additionnal_targets!(500.); //use statikk electrospark range
@@ -119,6 +121,7 @@ const TEMPLATE_EFFECT: TemporaryEffect = TemporaryEffect {
     remove_every_stack: template_effect_disable,
     duration: some_duration,
     cooldown: some_cooldown,
+    restore_target_debuff: None,
 };
 
 impl Item {
@@ -148,6 +151,7 @@ impl Item {
             crit_dmg: 0.,
             ms_flat: 0.,
             ms_percent: 0.,
+            attack_range: 0.,
             lethality: 0.,
             armor_pen_percent: 0.,
             magic_pen_flat: 0.,
@@ -164,6 +168,7 @@ impl Item {
             true_dmg_modifier: 0.,
             tot_dmg_modifier: 0.,
         },
+        passives: &[],
         on_action_fns: OnActionFns {
             on_lvl_set: None,
             on_fight_init: None,
@@ -238,6 +243,7 @@ impl Item {
             crit_dmg: 0.,
             ms_flat: 0.,
             ms_percent: 0.,
+            attack_range: 0.,
             lethality: 0.,
             armor_pen_percent: 0.,
             magic_pen_flat: 0.,
@@ -254,6 +260,7 @@ impl Item {
             true_dmg_modifier: 0.,
             tot_dmg_modifier: 0.,
         },
+        passives: &[],
         on_action_fns: OnActionFns {
             on_lvl_set: None,
             on_fight_init: None,
@@ -272,8 +279,6 @@ impl Item {
     };
 }
 
-//todo: support items?
-
 //Abyssal mask
 fn abyssal_mask_init(champ: &mut Unit) {
     //unmake passive
@@ -310,6 +315,7 @@ impl Item {
             crit_dmg: 0.,
             ms_flat: 0.,
             ms_percent: 0.,
+            attack_range: 0.,
             lethality: 0.,
             armor_pen_percent: 0.,
             magic_pen_flat: 0.,
@@ -326,6 +332,7 @@ impl Item {
             true_dmg_modifier: 0.,
             tot_dmg_modifier: 0.,
         },
+        passives: &[],
         on_action_fns: OnActionFns {
             on_lvl_set: None,
             on_fight_init: Some(abyssal_mask_init),
@@ -376,6 +383,7 @@ impl Item {
             crit_dmg: 0.,
             ms_flat: 0.,
             ms_percent: 0.,
+            attack_range: 0.,
             lethality: 18.,
             armor_pen_percent: 0.,
             magic_pen_flat: 0.,
@@ -392,6 +400,7 @@ impl Item {
             true_dmg_modifier: 0.,
             tot_dmg_modifier: 0.,
         },
+        passives: &[],
         on_action_fns: OnActionFns {
             on_lvl_set: None,
             on_fight_init: None,
@@ -438,6 +447,7 @@ impl Item {
             crit_dmg: 0.,
             ms_flat: 0.,
             ms_percent: 0.,
+            attack_range: 0.,
             lethality: 0.,
             armor_pen_percent: 0.,
             magic_pen_flat: 0.,
@@ -454,6 +464,7 @@ impl Item {
             true_dmg_modifier: 0.,
             tot_dmg_modifier: 0.,
         },
+        passives: &[],
         on_action_fns: OnActionFns {
             on_lvl_set: None,
             on_fight_init: None,
@@ -506,12 +517,20 @@ fn black_cleaver_carve_remove_every_stack(champ: &mut Unit) {
     champ.effects_stacks[EffectStackId::BlackCleaverCarveStacks] = 0;
 }
 
+fn black_cleaver_carve_restore_every_stack(champ: &mut Unit) {
+    increase_multiplicatively_scaling_stat(
+        &mut champ.stats.armor_red_percent,
+        champ.effects_values[EffectValueId::BlackCleaverCarveArmorRedPercent],
+    );
+}
+
 const BLACK_CLEAVER_CARVE: TemporaryEffect = TemporaryEffect {
     id: EffectId::BlackCleaverCarve,
     add_stack: black_cleaver_carve_add_stack,
     remove_every_stack: black_cleaver_carve_remove_every_stack,
     duration: 6.,
     cooldown: 0.,
+    restore_target_debuff: Some(black_cleaver_carve_restore_every_stack),
 };
 
 fn black_cleaver_fervor_enable(champ: &mut Unit, _availability_coef: f32) {
@@ -533,6 +552,7 @@ const BLACK_CLEAVER_FERVOR: TemporaryEffect = TemporaryEffect {
     remove_every_stack: black_cleaver_fervor_disable,
     duration: 2.,
     cooldown: 0.,
+    restore_target_debuff: None,
 };
 
 fn black_cleaver_on_phys_hit(champ: &mut Unit) {
@@ -567,6 +587,7 @@ impl Item {
             crit_dmg: 0.,
             ms_flat: 0.,
             ms_percent: 0.,
+            attack_range: 0.,
             lethality: 0.,
             armor_pen_percent: 0.,
             magic_pen_flat: 0.,
@@ -584,6 +605,7 @@ impl Item {
             tot_dmg_modifier: 0.,
         },
 
+        passives: &[],
         on_action_fns: OnActionFns {
             on_lvl_set: None,
             on_fight_init: Some(black_cleaver_init),
@@ -654,6 +676,7 @@ impl Item {
             crit_dmg: 0.,
             ms_flat: 0.,
             ms_percent: 0.,
+            attack_range: 0.,
             lethality: 0.,
             armor_pen_percent: 0.,
             magic_pen_flat: 0.,
@@ -670,6 +693,7 @@ impl Item {
             true_dmg_modifier: 0.,
             tot_dmg_modifier: 0.,
         },
+        passives: &[],
         on_action_fns: OnActionFns {
             on_lvl_set: None,
             on_fight_init: Some(blackfire_torch_init),
@@ -690,7 +714,7 @@ impl Item {
 
 //Blade of the ruined king
 fn blade_of_the_ruined_king_mists_edge(
-    _champion: &mut Unit,
+    champion: &mut Unit,
     target_stats: &UnitStats,
     n_targets: f32,
     from_other_effect: bool,
@@ -699,7 +723,7 @@ fn blade_of_the_ruined_king_mists_edge(
         return PartDmg(0., 0., 0.);
     }
     PartDmg(
-        n_targets * (0.05 * ((1. - MEAN_MISSING_HP_PERCENT) * target_stats.hp)),
+        n_targets * (0.05 * champion.get_target_current_hp(target_stats)),
         0.,
         0.,
     ) //value for ranged champions
@@ -732,6 +756,7 @@ impl Item {
             crit_dmg: 0.,
             ms_flat: 0.,
             ms_percent: 0.,
+            attack_range: 0.,
             lethality: 0.,
             armor_pen_percent: 0.,
             magic_pen_flat: 0.,
@@ -748,6 +773,7 @@ impl Item {
             true_dmg_modifier: 0.,
             tot_dmg_modifier: 0.,
         },
+        passives: &[],
         on_action_fns: OnActionFns {
             on_lvl_set: None,
             on_fight_init: None,
@@ -789,8 +815,10 @@ const BLOODTHIRSTER_ICHORSHIELD_MAX_SHIELD_BY_LVL: [f32; MAX_UNIT_LVL] = [
 ];
 fn bloodthirster_init(champ: &mut Unit) {
     //ichorshield passive
-    champ.single_use_heals_shields +=
-        BLOODTHIRSTER_ICHORSHIELD_MAX_SHIELD_BY_LVL[usize::from(champ.lvl.get() - 1)];
+    champ.add_shield(
+        BLOODTHIRSTER_ICHORSHIELD_MAX_SHIELD_BY_LVL[usize::from(champ.lvl.get() - 1)],
+        false,
+    );
 }
 
 impl Item {
@@ -820,6 +848,7 @@ impl Item {
             crit_dmg: 0.,
             ms_flat: 0.,
             ms_percent: 0.,
+            attack_range: 0.,
             lethality: 0.,
             armor_pen_percent: 0.,
             magic_pen_flat: 0.,
@@ -836,6 +865,7 @@ impl Item {
             true_dmg_modifier: 0.,
             tot_dmg_modifier: 0.,
         },
+        passives: &[],
         on_action_fns: OnActionFns {
             on_lvl_set: None,
             on_fight_init: Some(bloodthirster_init),
@@ -882,6 +912,7 @@ impl Item {
             crit_dmg: 0.,
             ms_flat: 0.,
             ms_percent: 0.,
+            attack_range: 0.,
             lethality: 0.,
             armor_pen_percent: 0.,
             magic_pen_flat: 0.,
@@ -898,6 +929,7 @@ impl Item {
             true_dmg_modifier: 0.,
             tot_dmg_modifier: 0.,
         },
+        passives: &[],
         on_action_fns: OnActionFns {
             on_lvl_set: None,
             on_fight_init: None,
@@ -940,6 +972,7 @@ const COSMIC_DRIVE_SPELLDANCE: TemporaryEffect = TemporaryEffect {
     remove_every_stack: cosmic_drive_spelldance_disable,
     duration: 4.,
     cooldown: 0.,
+    restore_target_debuff: None,
 };
 
 fn cosmic_drive_spelldance_on_magic_or_true_dmg_hit(champ: &mut Unit) {
@@ -973,6 +1006,7 @@ impl Item {
             crit_dmg: 0.,
             ms_flat: 0.,
             ms_percent: 0.04,
+            attack_range: 0.,
             lethality: 0.,
             armor_pen_percent: 0.,
             magic_pen_flat: 0.,
@@ -989,6 +1023,7 @@ impl Item {
             true_dmg_modifier: 0.,
             tot_dmg_modifier: 0.,
         },
+        passives: &[],
         on_action_fns: OnActionFns {
             on_lvl_set: None,
             on_fight_init: Some(cosmic_drive_init),
@@ -1035,6 +1070,7 @@ impl Item {
             crit_dmg: 0.,
             ms_flat: 0.,
             ms_percent: 0.,
+            attack_range: 0.,
             lethality: 0.,
             armor_pen_percent: 0.,
             magic_pen_flat: 0.,
@@ -1051,6 +1087,7 @@ impl Item {
             true_dmg_modifier: 0.,
             tot_dmg_modifier: 0.,
         },
+        passives: &[],
         on_action_fns: OnActionFns {
             on_lvl_set: None,
             on_fight_init: None,
@@ -1135,6 +1172,7 @@ impl Item {
             crit_dmg: 0.,
             ms_flat: 0.,
             ms_percent: 0.04,
+            attack_range: 0.,
             lethality: 0.,
             armor_pen_percent: 0.,
             magic_pen_flat: 0.,
@@ -1151,6 +1189,7 @@ impl Item {
             true_dmg_modifier: 0.,
             tot_dmg_modifier: 0.,
         },
+        passives: &[],
         on_action_fns: OnActionFns {
             on_lvl_set: None,
             on_fight_init: Some(dead_mans_plate_init),
@@ -1197,6 +1236,7 @@ impl Item {
             crit_dmg: 0.,
             ms_flat: 0.,
             ms_percent: 0.,
+            attack_range: 0.,
             lethality: 0.,
             armor_pen_percent: 0.,
             magic_pen_flat: 0.,
@@ -1213,6 +1253,7 @@ impl Item {
             true_dmg_modifier: 0.,
             tot_dmg_modifier: 0.,
         },
+        passives: &[],
         on_action_fns: OnActionFns {
             on_lvl_set: None,
             on_fight_init: None,
@@ -1272,7 +1313,7 @@ fn eclipse_ever_rising_moon(champ: &mut Unit, target_stats: &UnitStats) -> PartD
     //if last hit is recent enough and fully stacked (previous condition), reset stacks and trigger ever rising moon
     champ.effects_stacks[EffectStackId::EclipseEverRisingMoonStacks] = 0;
     champ.effects_values[EffectValueId::EclipseEverRisingMoonLastTriggerTime] = champ.time;
-    champ.periodic_heals_shields += 80. + 0.2 * champ.stats.bonus_ad; //value for ranged champions
+    champ.add_shield(80. + 0.2 * champ.stats.bonus_ad, true); //value for ranged champions
     PartDmg(0.04 * target_stats.hp, 0., 0.)
 }
 
@@ -1303,6 +1344,7 @@ impl Item {
             crit_dmg: 0.,
             ms_flat: 0.,
             ms_percent: 0.,
+            attack_range: 0.,
             lethality: 0.,
             armor_pen_percent: 0.,
             magic_pen_flat: 0.,
@@ -1319,6 +1361,7 @@ impl Item {
             true_dmg_modifier: 0.,
             tot_dmg_modifier: 0.,
         },
+        passives: &[],
         on_action_fns: OnActionFns {
             on_lvl_set: None,
             on_fight_init: Some(eclipse_init),
@@ -1365,6 +1408,7 @@ impl Item {
             crit_dmg: 0.,
             ms_flat: 0.,
             ms_percent: 0.,
+            attack_range: 0.,
             lethality: 15.,
             armor_pen_percent: 0.,
             magic_pen_flat: 0.,
@@ -1381,6 +1425,7 @@ impl Item {
             true_dmg_modifier: 0.,
             tot_dmg_modifier: 0.,
         },
+        passives: &[],
         on_action_fns: OnActionFns {
             on_lvl_set: None,
             on_fight_init: None,
@@ -1427,6 +1472,7 @@ impl Item {
             crit_dmg: 0.,
             ms_flat: 0.,
             ms_percent: 0.,
+            attack_range: 0.,
             lethality: 0.,
             armor_pen_percent: 0.,
             magic_pen_flat: 0.,
@@ -1443,6 +1489,7 @@ impl Item {
             true_dmg_modifier: 0.,
             tot_dmg_modifier: 0.,
         },
+        passives: &[],
         on_action_fns: OnActionFns {
             on_lvl_set: None,
             on_fight_init: None,
@@ -1494,6 +1541,7 @@ const EXPERIMENTAL_HEXPLATE_OVERDRIVE: TemporaryEffect = TemporaryEffect {
     remove_every_stack: experimental_hexplate_disable,
     duration: 8.,
     cooldown: 30.,
+    restore_target_debuff: None,
 };
 
 fn experimental_hexplate_overdrive(champ: &mut Unit) {
@@ -1527,6 +1575,7 @@ impl Item {
             crit_dmg: 0.,
             ms_flat: 0.,
             ms_percent: 0.,
+            attack_range: 0.,
             lethality: 0.,
             armor_pen_percent: 0.,
             magic_pen_flat: 0.,
@@ -1543,6 +1592,7 @@ impl Item {
             true_dmg_modifier: 0.,
             tot_dmg_modifier: 0.,
         },
+        passives: &[],
         on_action_fns: OnActionFns {
             on_lvl_set: None,
             on_fight_init: Some(experimental_hexplate_init),
@@ -1593,6 +1643,7 @@ impl Item {
             crit_dmg: 0.,
             ms_flat: 0.,
             ms_percent: 0.,
+            attack_range: 0.,
             lethality: 0.,
             armor_pen_percent: 0.,
             magic_pen_flat: 0.,
@@ -1609,6 +1660,7 @@ impl Item {
             true_dmg_modifier: 0.,
             tot_dmg_modifier: 0.,
         },
+        passives: &[],
         on_action_fns: OnActionFns {
             on_lvl_set: None,
             on_fight_init: None,
@@ -1655,6 +1707,7 @@ impl Item {
             crit_dmg: 0.,
             ms_flat: 0.,
             ms_percent: 0.,
+            attack_range: 0.,
             lethality: 0.,
             armor_pen_percent: 0.,
             magic_pen_flat: 0.,
@@ -1671,6 +1724,7 @@ impl Item {
             true_dmg_modifier: 0.,
             tot_dmg_modifier: 0.,
         },
+        passives: &[],
         on_action_fns: OnActionFns {
             on_lvl_set: None,
             on_fight_init: None,
@@ -1723,6 +1777,7 @@ const GUINSOOS_RAGEBLADE_SEETHING_STRIKE: TemporaryEffect = TemporaryEffect {
     remove_every_stack: guinsoos_rageblade_seething_strike_remove_every_stack,
     duration: 3.,
     cooldown: 0.,
+    restore_target_debuff: None,
 };
 
 fn guinsoos_rageblade_on_basic_attack_hit(
@@ -1790,6 +1845,7 @@ impl Item {
             crit_dmg: 0.,
             ms_flat: 0.,
             ms_percent: 0.,
+            attack_range: 0.,
             lethality: 0.,
             armor_pen_percent: 0.,
             magic_pen_flat: 0.,
@@ -1806,6 +1862,7 @@ impl Item {
             true_dmg_modifier: 0.,
             tot_dmg_modifier: 0.,
         },
+        passives: &[],
         on_action_fns: OnActionFns {
             on_lvl_set: None,
             on_fight_init: Some(guinsoos_rageblade_init),
@@ -1828,8 +1885,9 @@ impl Item {
 
 //Hextech Rocketbelt
 fn hextech_rocketbelt_supersonic(champ: &mut Unit, target_stats: &UnitStats) -> PartDmg {
-    let availability_coef: f32 =
-        effect_availability_formula(40. * haste_formula(champ.stats.item_haste));
+    let availability_coef: f32 = champ
+        .economy
+        .effect_availability_formula(40. * haste_formula(champ.stats.item_haste));
     champ.units_travelled += availability_coef * 275.; //maximum dash distance
     let magic_dmg: f32 = availability_coef * (100. + 0.1 * champ.stats.ap());
     champ.dmg_on_target(
@@ -1868,6 +1926,7 @@ impl Item {
             crit_dmg: 0.,
             ms_flat: 0.,
             ms_percent: 0.,
+            attack_range: 0.,
             lethality: 0.,
             armor_pen_percent: 0.,
             magic_pen_flat: 0.,
@@ -1884,6 +1943,7 @@ impl Item {
             true_dmg_modifier: 0.,
             tot_dmg_modifier: 0.,
         },
+        passives: &[],
         on_action_fns: OnActionFns {
             on_lvl_set: None,
             on_fight_init: None,
@@ -1932,6 +1992,7 @@ impl Item {
             crit_dmg: 0.,
             ms_flat: 0.,
             ms_percent: 0.,
+            attack_range: 0.,
             lethality: 0.,
             armor_pen_percent: 0.,
             magic_pen_flat: 0.,
@@ -1948,6 +2009,7 @@ impl Item {
             true_dmg_modifier: 0.,
             tot_dmg_modifier: 0.,
         },
+        passives: &[],
         on_action_fns: OnActionFns {
             on_lvl_set: None,
             on_fight_init: None,
@@ -1994,6 +2056,7 @@ impl Item {
             crit_dmg: 0.,
             ms_flat: 0.,
             ms_percent: 0.,
+            attack_range: 0.,
             lethality: 18.,
             armor_pen_percent: 0.,
             magic_pen_flat: 0.,
@@ -2010,6 +2073,7 @@ impl Item {
             true_dmg_modifier: 0.,
             tot_dmg_modifier: 0.,
         },
+        passives: &[],
         on_action_fns: OnActionFns {
             on_lvl_set: None,
             on_fight_init: None,
@@ -2088,6 +2152,7 @@ impl Item {
             crit_dmg: 0.,
             ms_flat: 0.,
             ms_percent: 0.04,
+            attack_range: 0.,
             lethality: 0.,
             armor_pen_percent: 0.,
             magic_pen_flat: 0.,
@@ -2104,6 +2169,7 @@ impl Item {
             true_dmg_modifier: 0.,
             tot_dmg_modifier: 0.,
         },
+        passives: &[],
         on_action_fns: OnActionFns {
             on_lvl_set: None,
             on_fight_init: Some(hullbreaker_init),
@@ -2178,6 +2244,7 @@ impl Item {
             crit_dmg: 0.,
             ms_flat: 0.,
             ms_percent: 0.,
+            attack_range: 0.,
             lethality: 0.,
             armor_pen_percent: 0.,
             magic_pen_flat: 0.,
@@ -2194,6 +2261,7 @@ impl Item {
             true_dmg_modifier: 0.,
             tot_dmg_modifier: 0.,
         },
+        passives: &[],
         on_action_fns: OnActionFns {
             on_lvl_set: None,
             on_fight_init: Some(spellblade_init),
@@ -2235,11 +2303,12 @@ const IMMORTAL_SHIELDBOW_LIFELINE_SHIELD_BY_LVL: [f32; MAX_UNIT_LVL] = [
 ];
 fn immortal_shieldbow_init(champ: &mut Unit) {
     //lifeline passive
-    champ.single_use_heals_shields += IMMORTAL_SHIELDBOW_LIFELINE_SHIELD_BY_LVL
-        [usize::from(champ.lvl.get() - 1)]
-        * effect_availability_formula(
-            90. * haste_formula(champ.lvl_stats.item_haste + champ.items_stats.item_haste),
-        );
+    champ.add_lifeline_shield(
+        IMMORTAL_SHIELDBOW_LIFELINE_SHIELD_BY_LVL[usize::from(champ.lvl.get() - 1)]
+            * champ.economy.effect_availability_formula(
+                90. * haste_formula(champ.lvl_stats.item_haste + champ.items_stats.item_haste),
+            ),
+    );
 }
 
 impl Item {
@@ -2269,6 +2338,7 @@ impl Item {
             crit_dmg: 0.,
             ms_flat: 0.,
             ms_percent: 0.,
+            attack_range: 0.,
             lethality: 0.,
             armor_pen_percent: 0.,
             magic_pen_flat: 0.,
@@ -2285,6 +2355,7 @@ impl Item {
             true_dmg_modifier: 0.,
             tot_dmg_modifier: 0.,
         },
+        passives: &[],
         on_action_fns: OnActionFns {
             on_lvl_set: None,
             on_fight_init: Some(immortal_shieldbow_init),
@@ -2333,6 +2404,7 @@ impl Item {
             crit_dmg: 0.40,
             ms_flat: 0.,
             ms_percent: 0.,
+            attack_range: 0.,
             lethality: 0.,
             armor_pen_percent: 0.,
             magic_pen_flat: 0.,
@@ -2349,6 +2421,7 @@ impl Item {
             true_dmg_modifier: 0.,
             tot_dmg_modifier: 0.,
         },
+        passives: &[],
         on_action_fns: OnActionFns {
             on_lvl_set: None,
             on_fight_init: None,
@@ -2395,6 +2468,7 @@ impl Item {
             crit_dmg: 0.,
             ms_flat: 0.,
             ms_percent: 0.,
+            attack_range: 0.,
             lethality: 0.,
             armor_pen_percent: 0.,
             magic_pen_flat: 0.,
@@ -2411,6 +2485,7 @@ impl Item {
             true_dmg_modifier: 0.,
             tot_dmg_modifier: 0.,
         },
+        passives: &[],
         on_action_fns: OnActionFns {
             on_lvl_set: None,
             on_fight_init: None,
@@ -2432,7 +2507,7 @@ impl Item {
 //Kaenic Rookern
 fn kaenic_rookern_init(champ: &mut Unit) {
     //magebane passive
-    champ.single_use_heals_shields += 0.15 * (champ.lvl_stats.hp + champ.items_stats.hp);
+    champ.add_shield(0.15 * (champ.lvl_stats.hp + champ.items_stats.hp), false);
 }
 
 impl Item {
@@ -2463,6 +2538,7 @@ impl Item {
             crit_dmg: 0.,
             ms_flat: 0.,
             ms_percent: 0.,
+            attack_range: 0.,
             lethality: 0.,
             armor_pen_percent: 0.,
             magic_pen_flat: 0.,
@@ -2479,6 +2555,7 @@ impl Item {
             true_dmg_modifier: 0.,
             tot_dmg_modifier: 0.,
         },
+        passives: &[],
         on_action_fns: OnActionFns {
             on_lvl_set: None,
             on_fight_init: Some(kaenic_rookern_init),
@@ -2529,7 +2606,7 @@ const KRAKEN_SLAYER_BRING_IT_DOWN_PHYS_DMG_BY_LVL: [f32; MAX_UNIT_LVL] = [
 ];
 fn kraken_slayer_bring_it_down(
     champ: &mut Unit,
-    _target_stats: &UnitStats,
+    target_stats: &UnitStats,
     _n_targets: f32,
     _from_other_effect: bool,
 ) -> PartDmg {
@@ -2549,7 +2626,7 @@ fn kraken_slayer_bring_it_down(
     }
     //if fully stacked (previous conditions), reset stacks, return bring it down dmg
     champ.effects_stacks[EffectStackId::KrakenSlayerBringItDownStacks] = 0;
-    let phys_dmg: f32 = (1. + 0.5 * MEAN_MISSING_HP_PERCENT)
+    let phys_dmg: f32 = (1. + 0.5 * champ.get_target_missing_hp_percent(target_stats))
         * KRAKEN_SLAYER_BRING_IT_DOWN_PHYS_DMG_BY_LVL[usize::from(champ.lvl.get() - 1)];
     PartDmg(phys_dmg, 0., 0.) //value for ranged champions
 }
@@ -2581,6 +2658,7 @@ impl Item {
             crit_dmg: 0.,
             ms_flat: 0.,
             ms_percent: 0.04,
+            attack_range: 0.,
             lethality: 0.,
             armor_pen_percent: 0.,
             magic_pen_flat: 0.,
@@ -2597,6 +2675,7 @@ impl Item {
             true_dmg_modifier: 0.,
             tot_dmg_modifier: 0.,
         },
+        passives: &[],
         on_action_fns: OnActionFns {
             on_lvl_set: None,
             on_fight_init: Some(kraken_slayer_init),
@@ -2685,6 +2764,7 @@ const LIANDRYS_TORMENT_SUFFERING: TemporaryEffect = TemporaryEffect {
     remove_every_stack: liandrys_torment_suffering_disable,
     duration: 3.,
     cooldown: 0.,
+    restore_target_debuff: None,
 };
 
 fn liandrys_torment_suffering(champ: &mut Unit, _target_stats: &UnitStats) -> PartDmg {
@@ -2719,6 +2799,7 @@ impl Item {
             crit_dmg: 0.,
             ms_flat: 0.,
             ms_percent: 0.,
+            attack_range: 0.,
             lethality: 0.,
             armor_pen_percent: 0.,
             magic_pen_flat: 0.,
@@ -2735,6 +2816,7 @@ impl Item {
             true_dmg_modifier: 0.,
             tot_dmg_modifier: 0.,
         },
+        passives: &[],
         on_action_fns: OnActionFns {
             on_lvl_set: None,
             on_fight_init: Some(liandrys_torment_init),
@@ -2822,6 +2904,7 @@ impl Item {
             crit_dmg: 0.,
             ms_flat: 0.,
             ms_percent: 0.04,
+            attack_range: 0.,
             lethality: 0.,
             armor_pen_percent: 0.,
             magic_pen_flat: 0.,
@@ -2838,6 +2921,7 @@ impl Item {
             true_dmg_modifier: 0.,
             tot_dmg_modifier: 0.,
         },
+        passives: &[],
         on_action_fns: OnActionFns {
             on_lvl_set: None,
             on_fight_init: Some(spellblade_init),
@@ -2886,6 +2970,7 @@ impl Item {
             crit_dmg: 0.,
             ms_flat: 0.,
             ms_percent: 0.,
+            attack_range: 0.,
             lethality: 0.,
             armor_pen_percent: 0.35,
             magic_pen_flat: 0.,
@@ -2902,6 +2987,7 @@ impl Item {
             true_dmg_modifier: 0.,
             tot_dmg_modifier: 0.,
         },
+        passives: &[],
         on_action_fns: OnActionFns {
             on_lvl_set: None,
             on_fight_init: None,
@@ -2976,6 +3062,7 @@ impl Item {
             crit_dmg: 0.,
             ms_flat: 0.,
             ms_percent: 0.,
+            attack_range: 0.,
             lethality: 0.,
             armor_pen_percent: 0.,
             magic_pen_flat: 0.,
@@ -2992,6 +3079,7 @@ impl Item {
             true_dmg_modifier: 0.,
             tot_dmg_modifier: 0.,
         },
+        passives: &[],
         on_action_fns: OnActionFns {
             on_lvl_set: None,
             on_fight_init: Some(ludens_companion_init),
@@ -3028,12 +3116,17 @@ fn malignance_hatefog_curse_disable(champ: &mut Unit) {
     champ.effects_values[EffectValueId::MalignanceHatefogCurseMrRedFlat] = 0.;
 }
 
+fn malignance_hatefog_curse_restore(champ: &mut Unit) {
+    champ.stats.mr_red_flat += champ.effects_values[EffectValueId::MalignanceHatefogCurseMrRedFlat];
+}
+
 const MALIGNANCE_HATEFOG_CURSE: TemporaryEffect = TemporaryEffect {
     id: EffectId::MalignanceHatefogCurse,
     add_stack: malignance_hatefog_curse_enable,
     remove_every_stack: malignance_hatefog_curse_disable,
     duration: MALIGNANCE_HATEFOG_CURSE_TIME,
     cooldown: 3.,
+    restore_target_debuff: Some(malignance_hatefog_curse_restore),
 };
 
 fn malignance_hatefog(champ: &mut Unit, _target_stats: &UnitStats, n_targets: f32) -> PartDmg {
@@ -3076,6 +3169,7 @@ impl Item {
             crit_dmg: 0.,
             ms_flat: 0.,
             ms_percent: 0.,
+            attack_range: 0.,
             lethality: 0.,
             armor_pen_percent: 0.,
             magic_pen_flat: 0.,
@@ -3092,6 +3186,7 @@ impl Item {
             true_dmg_modifier: 0.,
             tot_dmg_modifier: 0.,
         },
+        passives: &[],
         on_action_fns: OnActionFns {
             on_lvl_set: None,
             on_fight_init: Some(malignance_init),
@@ -3115,11 +3210,12 @@ impl Item {
 //Maw of malmortius
 fn maw_of_malmortius_init(champ: &mut Unit) {
     //lifeline passive (omnivamp not implemented)
-    champ.single_use_heals_shields += (150.
-        + 1.125 * (champ.lvl_stats.bonus_ad + champ.items_stats.bonus_ad))
-        * effect_availability_formula(
-            90. * haste_formula(champ.lvl_stats.item_haste + champ.items_stats.item_haste),
-        );
+    champ.add_lifeline_shield(
+        (150. + 1.125 * (champ.lvl_stats.bonus_ad + champ.items_stats.bonus_ad))
+            * champ.economy.effect_availability_formula(
+                90. * haste_formula(champ.lvl_stats.item_haste + champ.items_stats.item_haste),
+            ),
+    );
     //value for ranged champions
 }
 
@@ -3150,6 +3246,7 @@ impl Item {
             crit_dmg: 0.,
             ms_flat: 0.,
             ms_percent: 0.,
+            attack_range: 0.,
             lethality: 0.,
             armor_pen_percent: 0.,
             magic_pen_flat: 0.,
@@ -3166,6 +3263,7 @@ impl Item {
             true_dmg_modifier: 0.,
             tot_dmg_modifier: 0.,
         },
+        passives: &[],
         on_action_fns: OnActionFns {
             on_lvl_set: None,
             on_fight_init: Some(maw_of_malmortius_init),
@@ -3214,6 +3312,7 @@ impl Item {
             crit_dmg: 0.,
             ms_flat: 0.,
             ms_percent: 0.,
+            attack_range: 0.,
             lethality: 0.,
             armor_pen_percent: 0.,
             magic_pen_flat: 0.,
@@ -3230,6 +3329,7 @@ impl Item {
             true_dmg_modifier: 0.,
             tot_dmg_modifier: 0.,
         },
+        passives: &[],
         on_action_fns: OnActionFns {
             on_lvl_set: None,
             on_fight_init: None,
@@ -3280,6 +3380,7 @@ impl Item {
             crit_dmg: 0.,
             ms_flat: 0.,
             ms_percent: 0.,
+            attack_range: 0.,
             lethality: 0.,
             armor_pen_percent: 0.,
             magic_pen_flat: 0.,
@@ -3296,6 +3397,7 @@ impl Item {
             true_dmg_modifier: 0.,
             tot_dmg_modifier: 0.,
         },
+        passives: &[],
         on_action_fns: OnActionFns {
             on_lvl_set: None,
             on_fight_init: None,
@@ -3342,6 +3444,7 @@ impl Item {
             crit_dmg: 0.,
             ms_flat: 0.,
             ms_percent: 0.,
+            attack_range: 0.,
             lethality: 0.,
             armor_pen_percent: 0.30,
             magic_pen_flat: 0.,
@@ -3358,6 +3461,7 @@ impl Item {
             true_dmg_modifier: 0.,
             tot_dmg_modifier: 0.,
         },
+        passives: &[],
         on_action_fns: OnActionFns {
             on_lvl_set: None,
             on_fight_init: None,
@@ -3378,7 +3482,7 @@ impl Item {
 
 //Muramana
 fn muramana_init(champ: &mut Unit) {
-    champ.effects_values[EffectValueId::MuramanaShockLastSpellHitTime] = -F32_TOL; //to allow for effect at time == 0
+    champ.effects_values[EffectValueId::MuramanaShockLastSpellHitInstance] = -1.; //to allow for effect on the first dmg instance
 
     //awe passive
     champ.stats.bonus_ad += 0.02 * (champ.lvl_stats.mana + champ.items_stats.mana);
@@ -3390,7 +3494,8 @@ fn muramana_shock_on_ability_hit(
     n_targets: f32,
 ) -> PartDmg {
     //set shock last ability hit, to prevent potential on basic attack hit effects triggered by this ability to apply shock twice
-    champ.effects_values[EffectValueId::MuramanaShockLastSpellHitTime] = champ.time;
+    champ.effects_values[EffectValueId::MuramanaShockLastSpellHitInstance] =
+        champ.get_dmg_instance_id();
     PartDmg(n_targets * 0.03 * champ.stats.mana, 0., 0.) //value for ranged champions
 }
 
@@ -3400,8 +3505,10 @@ fn muramana_shock_on_basic_attack_hit(
     n_targets: f32,
     _from_other_effect: bool,
 ) -> PartDmg {
-    //if same instance of dmg (==exact same time) as muramana_shock_on_ability_hit, do nothing (to prevent basic attack that trigger on hit to apply muramana passive twice)
-    if champ.time == champ.effects_values[EffectValueId::MuramanaShockLastSpellHitTime] {
+    //if same instance of dmg as muramana_shock_on_ability_hit, do nothing (to prevent basic attack that trigger on hit to apply muramana passive twice)
+    if champ.get_dmg_instance_id()
+        == champ.effects_values[EffectValueId::MuramanaShockLastSpellHitInstance]
+    {
         return PartDmg(0., 0., 0.);
     }
     //if not the same instance, return dmg (no need to update shock last ability hit time since ability effects are called first)
@@ -3435,6 +3542,7 @@ impl Item {
             crit_dmg: 0.,
             ms_flat: 0.,
             ms_percent: 0.,
+            attack_range: 0.,
             lethality: 0.,
             armor_pen_percent: 0.,
             magic_pen_flat: 0.,
@@ -3451,6 +3559,7 @@ impl Item {
             true_dmg_modifier: 0.,
             tot_dmg_modifier: 0.,
         },
+        passives: &[],
         on_action_fns: OnActionFns {
             on_lvl_set: None,
             on_fight_init: Some(muramana_init),
@@ -3506,6 +3615,7 @@ impl Item {
             crit_dmg: 0.,
             ms_flat: 0.,
             ms_percent: 0.,
+            attack_range: 0.,
             lethality: 0.,
             armor_pen_percent: 0.,
             magic_pen_flat: 0.,
@@ -3522,6 +3632,7 @@ impl Item {
             true_dmg_modifier: 0.,
             tot_dmg_modifier: 0.,
         },
+        passives: &[],
         on_action_fns: OnActionFns {
             on_lvl_set: None,
             on_fight_init: None,
@@ -3543,9 +3654,9 @@ impl Item {
 //Navori flickerblade
 const NAVORI_FLICKERBLADE_TRANSCENDENCE_CD_REFUND_PERCENT: f32 = 0.15;
 fn navori_flickerblade_transcendence(champ: &mut Unit) {
-    champ.q_cd *= 1. - NAVORI_FLICKERBLADE_TRANSCENDENCE_CD_REFUND_PERCENT;
-    champ.w_cd *= 1. - NAVORI_FLICKERBLADE_TRANSCENDENCE_CD_REFUND_PERCENT;
-    champ.e_cd *= 1. - NAVORI_FLICKERBLADE_TRANSCENDENCE_CD_REFUND_PERCENT;
+    champ.set_q_cd(champ.q_cd() * (1. - NAVORI_FLICKERBLADE_TRANSCENDENCE_CD_REFUND_PERCENT));
+    champ.set_w_cd(champ.w_cd() * (1. - NAVORI_FLICKERBLADE_TRANSCENDENCE_CD_REFUND_PERCENT));
+    champ.set_e_cd(champ.e_cd() * (1. - NAVORI_FLICKERBLADE_TRANSCENDENCE_CD_REFUND_PERCENT));
 }
 
 impl Item {
@@ -3575,6 +3686,7 @@ impl Item {
             crit_dmg: 0.,
             ms_flat: 0.,
             ms_percent: 0.04,
+            attack_range: 0.,
             lethality: 0.,
             armor_pen_percent: 0.,
             magic_pen_flat: 0.,
@@ -3591,6 +3703,7 @@ impl Item {
             true_dmg_modifier: 0.,
             tot_dmg_modifier: 0.,
         },
+        passives: &[],
         on_action_fns: OnActionFns {
             on_lvl_set: None,
             on_fight_init: None,
@@ -3639,6 +3752,7 @@ const OPPORTUNITY_PREPARATION: TemporaryEffect = TemporaryEffect {
     remove_every_stack: opportunity_preparation_disable,
     duration: 3.,
     cooldown: 0., //cooldown too small to be relevant (as of patch 14.08)
+    restore_target_debuff: None,
 };
 
 impl Item {
@@ -3668,6 +3782,7 @@ impl Item {
             crit_dmg: 0.,
             ms_flat: 0.,
             ms_percent: 0.,
+            attack_range: 0.,
             lethality: 15.,
             armor_pen_percent: 0.,
             magic_pen_flat: 0.,
@@ -3684,6 +3799,7 @@ impl Item {
             true_dmg_modifier: 0.,
             tot_dmg_modifier: 0.,
         },
+        passives: &[],
         on_action_fns: OnActionFns {
             on_lvl_set: None,
             on_fight_init: Some(opportunity_init),
@@ -3737,6 +3853,7 @@ impl Item {
             crit_dmg: 0.,
             ms_flat: 0.,
             ms_percent: 0.,
+            attack_range: 0.,
             lethality: 0.,
             armor_pen_percent: 0.,
             magic_pen_flat: 0.,
@@ -3753,6 +3870,7 @@ impl Item {
             true_dmg_modifier: 0.,
             tot_dmg_modifier: 0.,
         },
+        passives: &[],
         on_action_fns: OnActionFns {
             on_lvl_set: None,
             on_fight_init: Some(overlords_bloodmail_init),
@@ -3799,6 +3917,7 @@ impl Item {
             crit_dmg: 0.,
             ms_flat: 0.,
             ms_percent: 0.08,
+            attack_range: 0.,
             lethality: 0.,
             armor_pen_percent: 0.,
             magic_pen_flat: 0.,
@@ -3815,6 +3934,7 @@ impl Item {
             true_dmg_modifier: 0.,
             tot_dmg_modifier: 0.,
         },
+        passives: &[],
         on_action_fns: OnActionFns {
             on_lvl_set: None,
             on_fight_init: None,
@@ -3886,6 +4006,7 @@ impl Item {
             crit_dmg: 0.,
             ms_flat: 0.,
             ms_percent: 0.,
+            attack_range: 0.,
             lethality: 18.,
             armor_pen_percent: 0.,
             magic_pen_flat: 0.,
@@ -3902,6 +4023,7 @@ impl Item {
             true_dmg_modifier: 0.,
             tot_dmg_modifier: 0.,
         },
+        passives: &[],
         on_action_fns: OnActionFns {
             on_lvl_set: None,
             on_fight_init: None,
@@ -3948,6 +4070,7 @@ impl Item {
             crit_dmg: 0.,
             ms_flat: 0.,
             ms_percent: 0.,
+            attack_range: 0.,
             lethality: 0.,
             armor_pen_percent: 0.,
             magic_pen_flat: 0.,
@@ -3964,6 +4087,7 @@ impl Item {
             true_dmg_modifier: 0.,
             tot_dmg_modifier: 0.,
         },
+        passives: &[],
         on_action_fns: OnActionFns {
             on_lvl_set: None,
             on_fight_init: None,
@@ -4010,6 +4134,7 @@ impl Item {
             crit_dmg: 0.,
             ms_flat: 0.,
             ms_percent: 0.,
+            attack_range: 0.,
             lethality: 0.,
             armor_pen_percent: 0.,
             magic_pen_flat: 0.,
@@ -4026,6 +4151,7 @@ impl Item {
             true_dmg_modifier: 0.,
             tot_dmg_modifier: 0.,
         },
+        passives: &[],
         on_action_fns: OnActionFns {
             on_lvl_set: None,
             on_fight_init: None,
@@ -4045,9 +4171,33 @@ impl Item {
 }
 
 //Rapid firecannon
+const RAPID_FIRECANNON_SHARPSHOOTER_BONUS_RANGE_PERCENT: f32 = 0.35;
+const RAPID_FIRECANNON_SHARPSHOOTER_MAX_BONUS_RANGE: f32 = 150.;
+
 fn rapid_firecannon_init(champ: &mut Unit) {
     champ.effects_values[EffectValueId::RapidFirecannonSharpshooterLastTriggerDistance] =
         -(ENERGIZED_ATTACKS_TRAVEL_REQUIRED + F32_TOL); // to allow for effect at time == 0
+    champ.effects_values[EffectValueId::RapidFirecannonSharpshooterMaxBonusRange] =
+        RAPID_FIRECANNON_SHARPSHOOTER_MAX_BONUS_RANGE;
+}
+
+/// Returns the bonus range of the next basic attack given by rapid firecannon
+/// (0 if the unit doesn't have the item or if the next basic attack is not energized).
+pub(crate) fn rapid_firecannon_sharpshooter_bonus_range(champ: &Unit) -> f32 {
+    //max bonus range is only set if the unit has the item (effects values are reset before each fight)
+    let max_bonus_range: f32 =
+        champ.effects_values[EffectValueId::RapidFirecannonSharpshooterMaxBonusRange];
+    if max_bonus_range == 0.
+        || champ.units_travelled
+            - champ.effects_values[EffectValueId::RapidFirecannonSharpshooterLastTriggerDistance]
+            < ENERGIZED_ATTACKS_TRAVEL_REQUIRED
+    {
+        return 0.;
+    }
+    f32::min(
+        max_bonus_range,
+        RAPID_FIRECANNON_SHARPSHOOTER_BONUS_RANGE_PERCENT * champ.stats.attack_range,
+    )
 }
 
 fn rapid_firecannon_sharpshooter(
@@ -4082,7 +4232,7 @@ impl Item {
         short_name: "RFC",
         cost: 2650.,
         item_groups: enum_set!(),
-        utils: enum_set!(ItemUtils::Special), //sharpshooter bonus range
+        utils: enum_set!(),
         stats: UnitStats {
             hp: 0.,
             mana: 0.,
@@ -4102,6 +4252,7 @@ impl Item {
             crit_dmg: 0.,
             ms_flat: 0.,
             ms_percent: 0.04,
+            attack_range: 0.,
             lethality: 0.,
             armor_pen_percent: 0.,
             magic_pen_flat: 0.,
@@ -4118,6 +4269,7 @@ impl Item {
             true_dmg_modifier: 0.,
             tot_dmg_modifier: 0.,
         },
+        passives: &[],
         on_action_fns: OnActionFns {
             on_lvl_set: None,
             on_fight_init: Some(rapid_firecannon_init),
@@ -4146,7 +4298,7 @@ fn _ravenous_hydra_ravenous_crescent(champ: &mut Unit, target_stats: &UnitStats)
         enum_set!(),
         1.,
     );
-    champ.periodic_heals_shields += dmg.as_sum() * champ.stats.life_steal; //life steal applies to crescent
+    champ.add_heal(dmg.as_sum() * champ.stats.life_steal, true); //life steal applies to crescent
     dmg
 }
 
@@ -4191,6 +4343,7 @@ impl Item {
             crit_dmg: 0.,
             ms_flat: 0.,
             ms_percent: 0.,
+            attack_range: 0.,
             lethality: 0.,
             armor_pen_percent: 0.,
             magic_pen_flat: 0.,
@@ -4207,6 +4360,7 @@ impl Item {
             true_dmg_modifier: 0.,
             tot_dmg_modifier: 0.,
         },
+        passives: &[],
         on_action_fns: OnActionFns {
             on_lvl_set: None,
             on_fight_init: None,
@@ -4295,6 +4449,7 @@ const RIFTMAKER_VOID_CORRUPTION: TemporaryEffect = TemporaryEffect {
     remove_every_stack: riftmaker_void_corruption_disable,
     duration: 4.,
     cooldown: 0.,
+    restore_target_debuff: None,
 };
 
 fn riftmaker_void_corruption(champ: &mut Unit, _target_stats: &UnitStats) -> PartDmg {
@@ -4329,6 +4484,7 @@ impl Item {
             crit_dmg: 0.,
             ms_flat: 0.,
             ms_percent: 0.,
+            attack_range: 0.,
             lethality: 0.,
             armor_pen_percent: 0.,
             magic_pen_flat: 0.,
@@ -4345,6 +4501,7 @@ impl Item {
             true_dmg_modifier: 0.,
             tot_dmg_modifier: 0.,
         },
+        passives: &[],
         on_action_fns: OnActionFns {
             on_lvl_set: None,
             on_fight_init: Some(riftmaker_init),
@@ -4376,7 +4533,7 @@ fn rod_of_ages_timeless_init(champ: &mut Unit) {
             take_item = true;
         }
     }
-    let min_since_bought: f32 = f32::min(10., cost_since_bought / TOT_GOLDS_PER_MIN);
+    let min_since_bought: f32 = f32::min(10., cost_since_bought / champ.economy.golds_per_min());
 
     //add timeless stats based on time elapsed
     champ.stats.hp += min_since_bought * 10.;
@@ -4411,6 +4568,7 @@ impl Item {
             crit_dmg: 0.,
             ms_flat: 0.,
             ms_percent: 0.,
+            attack_range: 0.,
             lethality: 0.,
             armor_pen_percent: 0.,
             magic_pen_flat: 0.,
@@ -4427,6 +4585,7 @@ impl Item {
             true_dmg_modifier: 0.,
             tot_dmg_modifier: 0.,
         },
+        passives: &[],
         on_action_fns: OnActionFns {
             on_lvl_set: None,
             on_fight_init: Some(rod_of_ages_timeless_init),
@@ -4446,23 +4605,42 @@ impl Item {
 }
 
 //Runaan's hurricane
+/// Returns the number of bolts fired by Runaan's hurricane wind's fury.
+pub(crate) fn runaans_hurricane_winds_fury_bolts(champ: &Unit) -> f32 {
+    champ.aoe_additionnal_targets(
+        RUNAANS_HURRICANE_WINDS_FURY_AVG_BOLTS,
+        RUNAANS_HURRICANE_WINDS_FURY_MAX_BOLTS,
+    )
+}
+
 fn runaans_hurricane_winds_fury(
     champ: &mut Unit,
-    _target_stats: &UnitStats,
+    target_stats: &UnitStats,
     mut n_targets: f32,
     from_other_effect: bool,
 ) -> PartDmg {
     if from_other_effect {
         return PartDmg(0., 0., 0.);
     }
-    n_targets /= 1. + RUNAANS_HURRICANE_WINDS_FURY_AVG_BOLTS; //get number of targets without runaans bolts
-    PartDmg(
-        n_targets
-            * RUNAANS_HURRICANE_WINDS_FURY_AVG_BOLTS
-            * (0.55 * champ.stats.ad() * champ.stats.crit_coef()),
+    let n_bolts: f32 = runaans_hurricane_winds_fury_bolts(champ);
+    n_targets /= 1. + n_bolts; //get number of targets without runaans bolts
+    let bolts_dmg: PartDmg = PartDmg(
+        n_targets * n_bolts * (0.55 * champ.stats.ad() * champ.stats.crit_coef()),
         0.,
         0.,
-    )
+    );
+    if champ.is_in_teamfight() {
+        //bolts hit other enemy units
+        champ.dmg_on_target(
+            target_stats,
+            bolts_dmg,
+            (0, 0),
+            enum_set!(DmgTag::BasicAttack | DmgTag::AroundTarget),
+            n_targets * n_bolts,
+        );
+        return PartDmg(0., 0., 0.);
+    }
+    bolts_dmg
 }
 
 impl Item {
@@ -4492,6 +4670,7 @@ impl Item {
             crit_dmg: 0.,
             ms_flat: 0.,
             ms_percent: 0.04,
+            attack_range: 0.,
             lethality: 0.,
             armor_pen_percent: 0.,
             magic_pen_flat: 0.,
@@ -4508,6 +4687,7 @@ impl Item {
             true_dmg_modifier: 0.,
             tot_dmg_modifier: 0.,
         },
+        passives: &[],
         on_action_fns: OnActionFns {
             on_lvl_set: None,
             on_fight_init: None,
@@ -4554,6 +4734,7 @@ impl Item {
             crit_dmg: 0.,
             ms_flat: 0.,
             ms_percent: 0.,
+            attack_range: 0.,
             lethality: 0.,
             armor_pen_percent: 0.,
             magic_pen_flat: 0.,
@@ -4570,6 +4751,7 @@ impl Item {
             true_dmg_modifier: 0.,
             tot_dmg_modifier: 0.,
         },
+        passives: &[],
         on_action_fns: OnActionFns {
             on_lvl_set: None,
             on_fight_init: None,
@@ -4594,13 +4776,15 @@ fn seraphs_embrace_init(champ: &mut Unit) {
     champ.stats.ap_flat += 0.02 * champ.items_stats.mana; //only take bonus mana into account
 
     //lifeline passive
-    champ.single_use_heals_shields += (200.
-        + SERAPHS_EMBRACE_LIFELINE_MANA_PERCENT
-            * 0.2
-            * (champ.lvl_stats.mana + champ.items_stats.mana))
-        * effect_availability_formula(
-            90. * haste_formula(champ.lvl_stats.item_haste + champ.items_stats.item_haste),
-        );
+    champ.add_lifeline_shield(
+        (200.
+            + SERAPHS_EMBRACE_LIFELINE_MANA_PERCENT
+                * 0.2
+                * (champ.lvl_stats.mana + champ.items_stats.mana))
+            * champ.economy.effect_availability_formula(
+                90. * haste_formula(champ.lvl_stats.item_haste + champ.items_stats.item_haste),
+            ),
+    );
     //shield depends on current mana
 }
 
@@ -4631,6 +4815,7 @@ impl Item {
             crit_dmg: 0.,
             ms_flat: 0.,
             ms_percent: 0.,
+            attack_range: 0.,
             lethality: 0.,
             armor_pen_percent: 0.,
             magic_pen_flat: 0.,
@@ -4647,6 +4832,7 @@ impl Item {
             true_dmg_modifier: 0.,
             tot_dmg_modifier: 0.,
         },
+        passives: &[],
         on_action_fns: OnActionFns {
             on_lvl_set: None,
             on_fight_init: Some(seraphs_embrace_init),
@@ -4693,6 +4879,7 @@ impl Item {
             crit_dmg: 0.,
             ms_flat: 0.,
             ms_percent: 0.,
+            attack_range: 0.,
             lethality: 15.,
             armor_pen_percent: 0.,
             magic_pen_flat: 0.,
@@ -4709,6 +4896,7 @@ impl Item {
             true_dmg_modifier: 0.,
             tot_dmg_modifier: 0.,
         },
+        passives: &[],
         on_action_fns: OnActionFns {
             on_lvl_set: None,
             on_fight_init: None,
@@ -4755,6 +4943,7 @@ impl Item {
             crit_dmg: 0.,
             ms_flat: 0.,
             ms_percent: 0.,
+            attack_range: 0.,
             lethality: 0.,
             armor_pen_percent: 0.30,
             magic_pen_flat: 0.,
@@ -4771,6 +4960,7 @@ impl Item {
             true_dmg_modifier: 0.,
             tot_dmg_modifier: 0.,
         },
+        passives: &[],
         on_action_fns: OnActionFns {
             on_lvl_set: None,
             on_fight_init: None,
@@ -4824,6 +5014,7 @@ impl Item {
             crit_dmg: 0.,
             ms_flat: 0.,
             ms_percent: 0.,
+            attack_range: 0.,
             lethality: 0.,
             armor_pen_percent: 0.,
             magic_pen_flat: 15.,
@@ -4840,6 +5031,7 @@ impl Item {
             true_dmg_modifier: 0.,
             tot_dmg_modifier: 0.,
         },
+        passives: &[],
         on_action_fns: OnActionFns {
             on_lvl_set: None,
             on_fight_init: Some(shadowflame_init),
@@ -4903,6 +5095,7 @@ const SPEAR_OF_SHOJIN_FOCUSED_WILL: TemporaryEffect = TemporaryEffect {
     remove_every_stack: spear_of_shojin_focused_will_disable,
     duration: 6.,
     cooldown: 0.,
+    restore_target_debuff: None,
 };
 
 fn spear_of_shojin_focused_will(
@@ -4941,6 +5134,7 @@ impl Item {
             crit_dmg: 0.,
             ms_flat: 0.,
             ms_percent: 0.,
+            attack_range: 0.,
             lethality: 0.,
             armor_pen_percent: 0.,
             magic_pen_flat: 0.,
@@ -4957,6 +5151,7 @@ impl Item {
             true_dmg_modifier: 0.,
             tot_dmg_modifier: 0.,
         },
+        passives: &[],
         on_action_fns: OnActionFns {
             on_lvl_set: None,
             on_fight_init: Some(spear_of_shojin_init),
@@ -4988,14 +5183,16 @@ fn statikk_shiv_electrospark_enable(champ: &mut Unit, _availability_coef: f32) {
     champ.effects_stacks[EffectStackId::StatikkShivElectrosparkRemainingStacks] = 3;
 
     //cooldown based on lvl
-    let current_cooldown: &mut f32 = champ
+    let time: f32 = champ.time;
+    let cooldown_end: &mut f32 = champ
         .temporary_effects_cooldowns
         .get_mut(&STATIKK_SHIV_ELECTROSPARK)
-        .expect("Failed to get value from IndexMap"); //effect should always be active here since champ.add_temporary_effect() should have been called before
+        .expect("Failed to get value from HashMap"); //effect should always be active here since champ.add_temporary_effect() should have been called before
 
-    *current_cooldown *= STATIKK_SHIV_ELECTROSPARK_COOLDOWN_BY_LVL
-        [usize::from(champ.lvl.get() - 1)]
-        / STATIKK_SHIV_ELECTROSPARK_COOLDOWN_BY_LVL[0]; //multiply by ratio (don't replace directly) in case champ has item haste
+    *cooldown_end = time
+        + (*cooldown_end - time)
+            * STATIKK_SHIV_ELECTROSPARK_COOLDOWN_BY_LVL[usize::from(champ.lvl.get() - 1)]
+            / STATIKK_SHIV_ELECTROSPARK_COOLDOWN_BY_LVL[0]; //multiply remaining cooldown by ratio (don't replace directly) in case champ has item haste
 }
 
 fn statikk_shiv_electrospark_disable(champ: &mut Unit) {
@@ -5028,6 +5225,7 @@ const STATIKK_SHIV_ELECTROSPARK: TemporaryEffect = TemporaryEffect {
     remove_every_stack: statikk_shiv_electrospark_disable,
     duration: 8.,
     cooldown: STATIKK_SHIV_ELECTROSPARK_COOLDOWN_BY_LVL[0],
+    restore_target_debuff: None,
 };
 
 fn statikk_shiv_electrospark(
@@ -5072,6 +5270,7 @@ impl Item {
             crit_dmg: 0.,
             ms_flat: 0.,
             ms_percent: 0.04,
+            attack_range: 0.,
             lethality: 0.,
             armor_pen_percent: 0.,
             magic_pen_flat: 0.,
@@ -5088,6 +5287,7 @@ impl Item {
             true_dmg_modifier: 0.,
             tot_dmg_modifier: 0.,
         },
+        passives: &[],
         on_action_fns: OnActionFns {
             on_lvl_set: None,
             on_fight_init: Some(statikk_shiv_init),
@@ -5112,12 +5312,13 @@ fn steraks_gage_init(champ: &mut Unit) {
     champ.stats.bonus_ad += 0.45 * (champ.lvl_stats.base_ad + champ.items_stats.base_ad);
 
     //lifeline passive
-    champ.single_use_heals_shields += 0.5
-        * 0.6
-        * champ.items_stats.hp
-        * effect_availability_formula(
-            90. * haste_formula(champ.lvl_stats.item_haste + champ.items_stats.item_haste),
-        );
+    champ.add_lifeline_shield(
+        0.5 * 0.6
+            * champ.items_stats.hp
+            * champ.economy.effect_availability_formula(
+                90. * haste_formula(champ.lvl_stats.item_haste + champ.items_stats.item_haste),
+            ),
+    );
     //actual value halved because shield decays, only counts bonus hp
 }
 
@@ -5149,6 +5350,7 @@ impl Item {
             crit_dmg: 0.,
             ms_flat: 0.,
             ms_percent: 0.,
+            attack_range: 0.,
             lethality: 0.,
             armor_pen_percent: 0.,
             magic_pen_flat: 0.,
@@ -5165,6 +5367,7 @@ impl Item {
             true_dmg_modifier: 0.,
             tot_dmg_modifier: 0.,
         },
+        passives: &[],
         on_action_fns: OnActionFns {
             on_lvl_set: None,
             on_fight_init: Some(steraks_gage_init),
@@ -5208,6 +5411,7 @@ const STORMSURGE_STORMRAIDER_MS: TemporaryEffect = TemporaryEffect {
     remove_every_stack: stormsurge_stormraider_ms_disable,
     duration: 1.5,
     cooldown: STORMSURGE_STORMRAIDER_COOLDOWN,
+    restore_target_debuff: None,
 };
 
 const STORMSURGE_STORMRAIDER_COOLDOWN: f32 = 30.;
@@ -5217,7 +5421,7 @@ fn stormsurge_stormraider(champ: &mut Unit, _target_stats: &UnitStats) -> PartDm
     {
         champ.effects_stacks[EffectStackId::StormsurgeStormraiderTriggered] = 1;
         champ.add_temporary_effect(&STORMSURGE_STORMRAIDER_MS, champ.stats.item_haste);
-        let avalability_coef: f32 = effect_availability_formula(
+        let avalability_coef: f32 = champ.economy.effect_availability_formula(
             STORMSURGE_STORMRAIDER_COOLDOWN * haste_formula(champ.stats.item_haste),
         );
         return PartDmg(
@@ -5256,6 +5460,7 @@ impl Item {
             crit_dmg: 0.,
             ms_flat: 0.,
             ms_percent: 0.04,
+            attack_range: 0.,
             lethality: 0.,
             armor_pen_percent: 0.,
             magic_pen_flat: 15.,
@@ -5272,6 +5477,7 @@ impl Item {
             true_dmg_modifier: 0.,
             tot_dmg_modifier: 0.,
         },
+        passives: &[],
         on_action_fns: OnActionFns {
             on_lvl_set: None,
             on_fight_init: Some(stormsurge_init),
@@ -5316,6 +5522,7 @@ const STRIDEBREAKER_BREAKING_SHOCKWAVE_MS: TemporaryEffect = TemporaryEffect {
     remove_every_stack: stridebreaker_braking_shockwave_ms_disable,
     duration: 3.,
     cooldown: 0.,
+    restore_target_debuff: None,
 };
 
 fn stridebreaker_breaking_shockwave(champ: &mut Unit, target_stats: &UnitStats) -> PartDmg {
@@ -5397,6 +5604,7 @@ impl Item {
             crit_dmg: 0.,
             ms_flat: 0.,
             ms_percent: 0.,
+            attack_range: 0.,
             lethality: 0.,
             armor_pen_percent: 0.,
             magic_pen_flat: 0.,
@@ -5413,6 +5621,7 @@ impl Item {
             true_dmg_modifier: 0.,
             tot_dmg_modifier: 0.,
         },
+        passives: &[],
         on_action_fns: OnActionFns {
             on_lvl_set: None,
             on_fight_init: Some(stridebreaker_init),
@@ -5456,8 +5665,10 @@ fn sundered_sky_lightshield_strike(
     }
     //if not on cooldown, put on cooldown and trigger effect
     champ.effects_values[EffectValueId::SunderedSkyLastTriggerTime] = champ.time;
-    champ.periodic_heals_shields +=
-        champ.stats.base_ad + 0.06 * (MEAN_MISSING_HP_PERCENT * champ.stats.hp);
+    champ.add_heal(
+        champ.stats.base_ad + 0.06 * (MEAN_MISSING_HP_PERCENT * champ.stats.hp),
+        true,
+    );
     let phys_dmg: f32 =
         champ.stats.ad() * (1. - champ.stats.crit_chance) * (champ.stats.crit_dmg - 1.); //bonus dmg from a basic attack with 100% crit chance compared to an average basic_attack
     PartDmg(phys_dmg, 0., 0.)
@@ -5490,6 +5701,7 @@ impl Item {
             crit_dmg: 0.,
             ms_flat: 0.,
             ms_percent: 0.,
+            attack_range: 0.,
             lethality: 0.,
             armor_pen_percent: 0.,
             magic_pen_flat: 0.,
@@ -5506,6 +5718,7 @@ impl Item {
             true_dmg_modifier: 0.,
             tot_dmg_modifier: 0.,
         },
+        passives: &[],
         on_action_fns: OnActionFns {
             on_lvl_set: None,
             on_fight_init: Some(sundered_sky_init),
@@ -5583,6 +5796,7 @@ const TERMINUS_JUXTAPOSITION_LIGHT: TemporaryEffect = TemporaryEffect {
     remove_every_stack: terminus_juxtaposition_remove_every_light_stack,
     duration: TERMINUS_JUXTAPOSITION_DURATION,
     cooldown: 0.,
+    restore_target_debuff: None,
 };
 
 const TERMINUS_JUXTAPOSITION_PEN_PER_DARK_STACK: f32 = 0.10;
@@ -5633,6 +5847,7 @@ const TERMINUS_JUXTAPOSITION_DARK: TemporaryEffect = TemporaryEffect {
     remove_every_stack: terminus_juxtaposition_remove_every_dark_stack,
     duration: TERMINUS_JUXTAPOSITION_DURATION,
     cooldown: 0.,
+    restore_target_debuff: None,
 };
 
 fn terminus_on_basic_attack_hit(
@@ -5680,6 +5895,7 @@ impl Item {
             crit_dmg: 0.,
             ms_flat: 0.,
             ms_percent: 0.,
+            attack_range: 0.,
             lethality: 0.,
             armor_pen_percent: 0.,
             magic_pen_flat: 0.,
@@ -5696,6 +5912,7 @@ impl Item {
             true_dmg_modifier: 0.,
             tot_dmg_modifier: 0.,
         },
+        passives: &[],
         on_action_fns: OnActionFns {
             on_lvl_set: None,
             on_fight_init: Some(terminus_init),
@@ -5715,17 +5932,12 @@ impl Item {
 }
 
 //The collector
-fn the_collector_init(champ: &mut Unit) {
-    champ.effects_stacks[EffectStackId::TheCollectorExecuted] = 0;
-}
-
 const THE_COLLECTOR_DEATH_EXECUTE_THRESHOLD: f32 = 0.05;
 fn the_collector_death(champ: &mut Unit, target_stats: &UnitStats) -> PartDmg {
-    if champ.effects_stacks[EffectStackId::TheCollectorExecuted] != 1
-        && champ.dmg_done.as_sum() >= (1. - THE_COLLECTOR_DEATH_EXECUTE_THRESHOLD) * target_stats.hp
-    {
-        champ.dmg_done.2 += THE_COLLECTOR_DEATH_EXECUTE_THRESHOLD * target_stats.hp;
-        champ.effects_stacks[EffectStackId::TheCollectorExecuted] = 1;
+    //checked on the target hp before the dmg of this hit (so the execute happens on the next hit)
+    let current_hp: f32 = champ.get_target_current_hp(target_stats);
+    if current_hp > 0. && current_hp <= THE_COLLECTOR_DEATH_EXECUTE_THRESHOLD * target_stats.hp {
+        champ.execute_target(target_stats);
     }
     PartDmg(0., 0., 0.)
 }
@@ -5757,6 +5969,7 @@ impl Item {
             crit_dmg: 0.,
             ms_flat: 0.,
             ms_percent: 0.,
+            attack_range: 0.,
             lethality: 10.,
             armor_pen_percent: 0.,
             magic_pen_flat: 0.,
@@ -5773,9 +5986,10 @@ impl Item {
             true_dmg_modifier: 0.,
             tot_dmg_modifier: 0.,
         },
+        passives: &[],
         on_action_fns: OnActionFns {
             on_lvl_set: None,
-            on_fight_init: Some(the_collector_init),
+            on_fight_init: None,
             special_active: None,
             on_ability_cast: None,
             on_ultimate_cast: None,
@@ -5850,6 +6064,7 @@ impl Item {
             crit_dmg: 0.,
             ms_flat: 0.,
             ms_percent: 0.,
+            attack_range: 0.,
             lethality: 0.,
             armor_pen_percent: 0.,
             magic_pen_flat: 0.,
@@ -5866,6 +6081,7 @@ impl Item {
             true_dmg_modifier: 0.,
             tot_dmg_modifier: 0.,
         },
+        passives: &[],
         on_action_fns: OnActionFns {
             on_lvl_set: None,
             on_fight_init: None,
@@ -5914,6 +6130,7 @@ const TRINITY_FORCE_QUICKEN: TemporaryEffect = TemporaryEffect {
     remove_every_stack: trinity_force_quicken_disable,
     duration: 2.,
     cooldown: 0.,
+    restore_target_debuff: None,
 };
 
 fn trinity_force_spellblade_on_basic_attack_hit(
@@ -5974,6 +6191,7 @@ impl Item {
             crit_dmg: 0.,
             ms_flat: 0.,
             ms_percent: 0.,
+            attack_range: 0.,
             lethality: 0.,
             armor_pen_percent: 0.,
             magic_pen_flat: 0.,
@@ -5990,6 +6208,7 @@ impl Item {
             true_dmg_modifier: 0.,
             tot_dmg_modifier: 0.,
         },
+        passives: &[],
         on_action_fns: OnActionFns {
             on_lvl_set: None,
             on_fight_init: Some(trinity_force_init),
@@ -6036,6 +6255,7 @@ impl Item {
             crit_dmg: 0.,
             ms_flat: 0.,
             ms_percent: 0.,
+            attack_range: 0.,
             lethality: 15.,
             armor_pen_percent: 0.,
             magic_pen_flat: 0.,
@@ -6052,6 +6272,7 @@ impl Item {
             true_dmg_modifier: 0.,
             tot_dmg_modifier: 0.,
         },
+        passives: &[],
         on_action_fns: OnActionFns {
             on_lvl_set: None,
             on_fight_init: None,
@@ -6102,6 +6323,7 @@ impl Item {
             crit_dmg: 0.,
             ms_flat: 0.,
             ms_percent: 0.,
+            attack_range: 0.,
             lethality: 0.,
             armor_pen_percent: 0.,
             magic_pen_flat: 0.,
@@ -6118,6 +6340,7 @@ impl Item {
             true_dmg_modifier: 0.,
             tot_dmg_modifier: 0.,
         },
+        passives: &[],
         on_action_fns: OnActionFns {
             on_lvl_set: None,
             on_fight_init: None,
@@ -6194,6 +6417,7 @@ impl Item {
             crit_dmg: 0.,
             ms_flat: 0.,
             ms_percent: 0.,
+            attack_range: 0.,
             lethality: 18.,
             armor_pen_percent: 0.,
             magic_pen_flat: 0.,
@@ -6210,6 +6434,7 @@ impl Item {
             true_dmg_modifier: 0.,
             tot_dmg_modifier: 0.,
         },
+        passives: &[],
         on_action_fns: OnActionFns {
             on_lvl_set: None,
             on_fight_init: Some(voltaic_cyclosword_init),
@@ -6270,6 +6495,7 @@ impl Item {
             crit_dmg: 0.,
             ms_flat: 0.,
             ms_percent: 0.,
+            attack_range: 0.,
             lethality: 0.,
             armor_pen_percent: 0.,
             magic_pen_flat: 0.,
@@ -6286,6 +6512,7 @@ impl Item {
             true_dmg_modifier: 0.,
             tot_dmg_modifier: 0.,
         },
+        passives: &[],
         on_action_fns: OnActionFns {
             on_lvl_set: None,
             on_fight_init: None,
@@ -6334,6 +6561,7 @@ const YOUMUUS_GHOSTBLADE_WRAITH_STEP: TemporaryEffect = TemporaryEffect {
     remove_every_stack: youmuus_ghostblade_wraith_step_disable,
     duration: 4.,
     cooldown: 45.,
+    restore_target_debuff: None,
 };
 
 impl Item {
@@ -6363,6 +6591,7 @@ impl Item {
             crit_dmg: 0.,
             ms_flat: 0.,
             ms_percent: 0.04,
+            attack_range: 0.,
             lethality: 18.,
             armor_pen_percent: 0.,
             magic_pen_flat: 0.,
@@ -6379,6 +6608,7 @@ impl Item {
             true_dmg_modifier: 0.,
             tot_dmg_modifier: 0.,
         },
+        passives: &[],
         on_action_fns: OnActionFns {
             on_lvl_set: None,
             on_fight_init: Some(youmuus_ghostblade_init),
@@ -6431,6 +6661,7 @@ const YUN_TAL_WILDARROWS_FLURRY: TemporaryEffect = TemporaryEffect {
     remove_every_stack: yun_tal_wildarrows_flurry_disable,
     duration: 6.,
     cooldown: 30.,
+    restore_target_debuff: None,
 };
 
 fn yun_tal_flurry_on_basic_attack_cast(champ: &mut Unit) {
@@ -6448,11 +6679,13 @@ fn yun_tal_flurry_on_basic_attack_hit(
     }
 
     //effect should always be active here since basic attack cast should have been called before
-    if let Some(cooldown) = champ
+    let time: f32 = champ.time;
+    if let Some(cooldown_end) = champ
         .temporary_effects_cooldowns
         .get_mut(&YUN_TAL_WILDARROWS_FLURRY)
     {
-        *cooldown = f32::max(0., *cooldown - (1. + champ.stats.crit_chance)); //average: 2sec if crit, 1sec if not crit
+        *cooldown_end = f32::max(time, *cooldown_end - (1. + champ.stats.crit_chance));
+        //average: 2sec if crit, 1sec if not crit
     }
 
     PartDmg(0., 0., 0.)
@@ -6485,6 +6718,7 @@ impl Item {
             crit_dmg: 0.,
             ms_flat: 0.,
             ms_percent: 0.,
+            attack_range: 0.,
             lethality: 0.,
             armor_pen_percent: 0.,
             magic_pen_flat: 0.,
@@ -6501,6 +6735,7 @@ impl Item {
             true_dmg_modifier: 0.,
             tot_dmg_modifier: 0.,
         },
+        passives: &[],
         on_action_fns: OnActionFns {
             on_lvl_set: None,
             on_fight_init: Some(yun_tal_wildarrows_init),
@@ -6519,8 +6754,6 @@ impl Item {
     };
 }
 
-//Zak'Zak's realmspike
-
 //Zeke's convergence (useless?)
 
 //Zhonya's hourglass
@@ -6551,6 +6784,7 @@ impl Item {
             crit_dmg: 0.,
             ms_flat: 0.,
             ms_percent: 0.,
+            attack_range: 0.,
             lethality: 0.,
             armor_pen_percent: 0.,
             magic_pen_flat: 0.,
@@ -6567,6 +6801,7 @@ impl Item {
             true_dmg_modifier: 0.,
             tot_dmg_modifier: 0.,
         },
+        passives: &[],
         on_action_fns: OnActionFns {
             on_lvl_set: None,
             on_fight_init: None,
@@ -6617,6 +6852,7 @@ impl Item {
             crit_dmg: 0.,
             ms_flat: 45.,
             ms_percent: 0.,
+            attack_range: 0.,
             lethality: 0.,
             armor_pen_percent: 0.,
             magic_pen_flat: 0.,
@@ -6633,6 +6869,7 @@ impl Item {
             true_dmg_modifier: 0.,
             tot_dmg_modifier: 0.,
         },
+        passives: &[],
         on_action_fns: OnActionFns {
             on_lvl_set: None,
             on_fight_init: None,
@@ -6679,6 +6916,7 @@ impl Item {
             crit_dmg: 0.,
             ms_flat: 60.,
             ms_percent: 0.,
+            attack_range: 0.,
             lethality: 0.,
             armor_pen_percent: 0.,
             magic_pen_flat: 0.,
@@ -6695,6 +6933,7 @@ impl Item {
             true_dmg_modifier: 0.,
             tot_dmg_modifier: 0.,
         },
+        passives: &[],
         on_action_fns: OnActionFns {
             on_lvl_set: None,
             on_fight_init: None,
@@ -6741,6 +6980,7 @@ impl Item {
             crit_dmg: 0.,
             ms_flat: 45.,
             ms_percent: 0.,
+            attack_range: 0.,
             lethality: 0.,
             armor_pen_percent: 0.,
             magic_pen_flat: 0.,
@@ -6757,6 +6997,7 @@ impl Item {
             true_dmg_modifier: 0.,
             tot_dmg_modifier: 0.,
         },
+        passives: &[],
         on_action_fns: OnActionFns {
             on_lvl_set: None,
             on_fight_init: None,
@@ -6803,6 +7044,7 @@ impl Item {
             crit_dmg: 0.,
             ms_flat: 45.,
             ms_percent: 0.,
+            attack_range: 0.,
             lethality: 0.,
             armor_pen_percent: 0.,
             magic_pen_flat: 0.,
@@ -6819,6 +7061,7 @@ impl Item {
             true_dmg_modifier: 0.,
             tot_dmg_modifier: 0.,
         },
+        passives: &[],
         on_action_fns: OnActionFns {
             on_lvl_set: None,
             on_fight_init: None,
@@ -6865,6 +7108,7 @@ impl Item {
             crit_dmg: 0.,
             ms_flat: 45.,
             ms_percent: 0.,
+            attack_range: 0.,
             lethality: 0.,
             armor_pen_percent: 0.,
             magic_pen_flat: 0.,
@@ -6881,6 +7125,7 @@ impl Item {
             true_dmg_modifier: 0.,
             tot_dmg_modifier: 0.,
         },
+        passives: &[],
         on_action_fns: OnActionFns {
             on_lvl_set: None,
             on_fight_init: None,
@@ -6927,6 +7172,7 @@ impl Item {
             crit_dmg: 0.,
             ms_flat: 45.,
             ms_percent: 0.,
+            attack_range: 0.,
             lethality: 0.,
             armor_pen_percent: 0.,
             magic_pen_flat: 12.,
@@ -6943,6 +7189,7 @@ impl Item {
             true_dmg_modifier: 0.,
             tot_dmg_modifier: 0.,
         },
+        passives: &[],
         on_action_fns: OnActionFns {
             on_lvl_set: None,
             on_fight_init: None,
@@ -6960,3 +7207,1186 @@ impl Item {
         },
     };
 }
+
+//
+// --- SUPPORT ITEMS LISTING --- //
+//
+
+//support items are the last upgrades of World atlas (support quest completed), their passives that only affect allies are not implemented
+
+//Bloodsong
+//no init function needed since we use generic spellblade variables
+/// Percentage of increased dmg taken by a target exposed by Bloodsong spellblade (value for ranged champions).
+const BLOODSONG_EXPOSE_TOT_DMG_MODIFIER: f32 = 0.05;
+
+fn bloodsong_spellblade_on_basic_attack_hit(
+    champ: &mut Unit,
+    _target_stats: &UnitStats,
+    _n_targets: f32,
+    from_other_effect: bool,
+) -> PartDmg {
+    if from_other_effect {
+        return PartDmg(0., 0., 0.);
+    }
+
+    //do nothing if not empowered
+    if champ.effects_stacks[EffectStackId::SpellbladeEmpowered] != 1 {
+        return PartDmg(0., 0., 0.);
+    }
+    //if empowered (previous condition) but last ability cast from too long ago, reset spellblade
+    if champ.time - champ.effects_values[EffectValueId::SpellbladeLastEmpowerTime]
+        >= SPELLBLADE_DELAY
+    {
+        champ.effects_stacks[EffectStackId::SpellbladeEmpowered] = 0;
+        return PartDmg(0., 0., 0.);
+    }
+    //if empowered and last ability cast is recent enough (previous condition), reset and trigger spellblade
+    champ.effects_stacks[EffectStackId::SpellbladeEmpowered] = 0;
+    champ.effects_values[EffectValueId::SpellbladeLastConsumeTime] = champ.time;
+    champ.add_temporary_effect(&BLOODSONG_EXPOSE, 0.);
+    PartDmg(0., 0., 0.)
+}
+
+fn bloodsong_expose_enable(champ: &mut Unit, _availability_coef: f32) {
+    if champ.effects_values[EffectValueId::BloodsongExposeTotDmgModifier] == 0. {
+        increase_exponentially_scaling_stat(
+            &mut champ.stats.tot_dmg_modifier,
+            BLOODSONG_EXPOSE_TOT_DMG_MODIFIER,
+        );
+        champ.effects_values[EffectValueId::BloodsongExposeTotDmgModifier] =
+            BLOODSONG_EXPOSE_TOT_DMG_MODIFIER;
+    }
+}
+
+fn bloodsong_expose_disable(champ: &mut Unit) {
+    decrease_exponentially_scaling_stat(
+        &mut champ.stats.tot_dmg_modifier,
+        champ.effects_values[EffectValueId::BloodsongExposeTotDmgModifier],
+    );
+    champ.effects_values[EffectValueId::BloodsongExposeTotDmgModifier] = 0.;
+}
+
+const BLOODSONG_EXPOSE: TemporaryEffect = TemporaryEffect {
+    id: EffectId::BloodsongExpose,
+    add_stack: bloodsong_expose_enable,
+    remove_every_stack: bloodsong_expose_disable,
+    duration: 6.,
+    cooldown: 0.,
+    restore_target_debuff: None,
+};
+
+impl Item {
+    pub const BLOODSONG: Item = Item {
+        id: ItemId::Bloodsong,
+        full_name: "Bloodsong",
+        short_name: "Bloodsong",
+        cost: 400.,
+        item_groups: enum_set!(ItemGroups::Spellblade | ItemGroups::Support),
+        utils: enum_set!(),
+        stats: UnitStats {
+            hp: 100.,
+            mana: 0.,
+            base_ad: 0.,
+            bonus_ad: 0.,
+            ap_flat: 0.,
+            ap_percent: 0.,
+            armor: 0.,
+            mr: 0.,
+            base_as: 0.,
+            bonus_as: 0.,
+            ability_haste: 10.,
+            basic_haste: 0.,
+            ultimate_haste: 0.,
+            item_haste: 0.,
+            crit_chance: 0.,
+            crit_dmg: 0.,
+            ms_flat: 0.,
+            ms_percent: 0.,
+            attack_range: 0.,
+            lethality: 0.,
+            armor_pen_percent: 0.,
+            magic_pen_flat: 0.,
+            magic_pen_percent: 0.,
+            armor_red_flat: 0.,
+            armor_red_percent: 0.,
+            mr_red_flat: 0.,
+            mr_red_percent: 0.,
+            life_steal: 0.,
+            omnivamp: 0.,
+            ability_dmg_modifier: 0.,
+            phys_dmg_modifier: 0.,
+            magic_dmg_modifier: 0.,
+            true_dmg_modifier: 0.,
+            tot_dmg_modifier: 0.,
+        },
+        passives: &[],
+        on_action_fns: OnActionFns {
+            on_lvl_set: None,
+            on_fight_init: Some(spellblade_init),
+            special_active: None,
+            on_ability_cast: Some(spellblade_on_spell_cast),
+            on_ultimate_cast: None,
+            on_ability_hit: None,
+            on_ultimate_hit: None,
+            on_basic_attack_cast: None,
+            on_basic_attack_hit: Some(bloodsong_spellblade_on_basic_attack_hit),
+            on_phys_hit: None,
+            on_magic_hit: None,
+            on_true_dmg_hit: None,
+            on_any_hit: None,
+        },
+    };
+}
+
+//Celestial opposition, slow aura not implemented
+fn celestial_opposition_init(champ: &mut Unit) {
+    champ.effects_values[EffectValueId::CelestialOppositionDmgReceivedReduction] = 0.;
+    //the unit is assumed to take dmg from an enemy champion at the start of the fight
+    champ.add_temporary_effect(&CELESTIAL_OPPOSITION, 0.);
+}
+
+fn celestial_opposition_enable(champ: &mut Unit, availability_coef: f32) {
+    //only has an effect if the unit receives dmg during the fight (see `IncomingDmgProfile`)
+    champ.effects_values[EffectValueId::CelestialOppositionDmgReceivedReduction] =
+        availability_coef * 0.25; //value for ranged champions
+}
+
+fn celestial_opposition_disable(champ: &mut Unit) {
+    champ.effects_values[EffectValueId::CelestialOppositionDmgReceivedReduction] = 0.;
+}
+
+const CELESTIAL_OPPOSITION: TemporaryEffect = TemporaryEffect {
+    id: EffectId::CelestialOpposition,
+    add_stack: celestial_opposition_enable,
+    remove_every_stack: celestial_opposition_disable,
+    duration: 1.5,
+    cooldown: 90.,
+    restore_target_debuff: None,
+};
+
+impl Item {
+    pub const CELESTIAL_OPPOSITION: Item = Item {
+        id: ItemId::CelestialOpposition,
+        full_name: "Celestial_opposition",
+        short_name: "Celestial_opposition",
+        cost: 400.,
+        item_groups: enum_set!(ItemGroups::Support),
+        utils: enum_set!(ItemUtils::Survivability),
+        stats: UnitStats {
+            hp: 200.,
+            mana: 0.,
+            base_ad: 0.,
+            bonus_ad: 0.,
+            ap_flat: 0.,
+            ap_percent: 0.,
+            armor: 0.,
+            mr: 0.,
+            base_as: 0.,
+            bonus_as: 0.,
+            ability_haste: 10.,
+            basic_haste: 0.,
+            ultimate_haste: 0.,
+            item_haste: 0.,
+            crit_chance: 0.,
+            crit_dmg: 0.,
+            ms_flat: 0.,
+            ms_percent: 0.,
+            attack_range: 0.,
+            lethality: 0.,
+            armor_pen_percent: 0.,
+            magic_pen_flat: 0.,
+            magic_pen_percent: 0.,
+            armor_red_flat: 0.,
+            armor_red_percent: 0.,
+            mr_red_flat: 0.,
+            mr_red_percent: 0.,
+            life_steal: 0.,
+            omnivamp: 0.,
+            ability_dmg_modifier: 0.,
+            phys_dmg_modifier: 0.,
+            magic_dmg_modifier: 0.,
+            true_dmg_modifier: 0.,
+            tot_dmg_modifier: 0.,
+        },
+        passives: &[],
+        on_action_fns: OnActionFns {
+            on_lvl_set: None,
+            on_fight_init: Some(celestial_opposition_init),
+            special_active: None,
+            on_ability_cast: None,
+            on_ultimate_cast: None,
+            on_ability_hit: None,
+            on_ultimate_hit: None,
+            on_basic_attack_cast: None,
+            on_basic_attack_hit: None,
+            on_phys_hit: None,
+            on_magic_hit: None,
+            on_true_dmg_hit: None,
+            on_any_hit: None,
+        },
+    };
+}
+
+//Dream maker, passive not implemented (only affects allies)
+impl Item {
+    pub const DREAM_MAKER: Item = Item {
+        id: ItemId::DreamMaker,
+        full_name: "Dream_maker",
+        short_name: "Dream_maker",
+        cost: 400.,
+        item_groups: enum_set!(ItemGroups::Support),
+        utils: enum_set!(),
+        stats: UnitStats {
+            hp: 100.,
+            mana: 0.,
+            base_ad: 0.,
+            bonus_ad: 0.,
+            ap_flat: 0.,
+            ap_percent: 0.,
+            armor: 0.,
+            mr: 0.,
+            base_as: 0.,
+            bonus_as: 0.,
+            ability_haste: 15.,
+            basic_haste: 0.,
+            ultimate_haste: 0.,
+            item_haste: 0.,
+            crit_chance: 0.,
+            crit_dmg: 0.,
+            ms_flat: 0.,
+            ms_percent: 0.,
+            attack_range: 0.,
+            lethality: 0.,
+            armor_pen_percent: 0.,
+            magic_pen_flat: 0.,
+            magic_pen_percent: 0.,
+            armor_red_flat: 0.,
+            armor_red_percent: 0.,
+            mr_red_flat: 0.,
+            mr_red_percent: 0.,
+            life_steal: 0.,
+            omnivamp: 0.,
+            ability_dmg_modifier: 0.,
+            phys_dmg_modifier: 0.,
+            magic_dmg_modifier: 0.,
+            true_dmg_modifier: 0.,
+            tot_dmg_modifier: 0.,
+        },
+        passives: &[],
+        on_action_fns: OnActionFns {
+            on_lvl_set: None,
+            on_fight_init: None,
+            special_active: None,
+            on_ability_cast: None,
+            on_ultimate_cast: None,
+            on_ability_hit: None,
+            on_ultimate_hit: None,
+            on_basic_attack_cast: None,
+            on_basic_attack_hit: None,
+            on_phys_hit: None,
+            on_magic_hit: None,
+            on_true_dmg_hit: None,
+            on_any_hit: None,
+        },
+    };
+}
+
+//Solstice sleigh, passive not implemented (only affects allies)
+impl Item {
+    pub const SOLSTICE_SLEIGH: Item = Item {
+        id: ItemId::SolsticeSleigh,
+        full_name: "Solstice_sleigh",
+        short_name: "Solstice_sleigh",
+        cost: 400.,
+        item_groups: enum_set!(ItemGroups::Support),
+        utils: enum_set!(),
+        stats: UnitStats {
+            hp: 100.,
+            mana: 0.,
+            base_ad: 0.,
+            bonus_ad: 0.,
+            ap_flat: 0.,
+            ap_percent: 0.,
+            armor: 0.,
+            mr: 0.,
+            base_as: 0.,
+            bonus_as: 0.,
+            ability_haste: 15.,
+            basic_haste: 0.,
+            ultimate_haste: 0.,
+            item_haste: 0.,
+            crit_chance: 0.,
+            crit_dmg: 0.,
+            ms_flat: 0.,
+            ms_percent: 0.,
+            attack_range: 0.,
+            lethality: 0.,
+            armor_pen_percent: 0.,
+            magic_pen_flat: 0.,
+            magic_pen_percent: 0.,
+            armor_red_flat: 0.,
+            armor_red_percent: 0.,
+            mr_red_flat: 0.,
+            mr_red_percent: 0.,
+            life_steal: 0.,
+            omnivamp: 0.,
+            ability_dmg_modifier: 0.,
+            phys_dmg_modifier: 0.,
+            magic_dmg_modifier: 0.,
+            true_dmg_modifier: 0.,
+            tot_dmg_modifier: 0.,
+        },
+        passives: &[],
+        on_action_fns: OnActionFns {
+            on_lvl_set: None,
+            on_fight_init: None,
+            special_active: None,
+            on_ability_cast: None,
+            on_ultimate_cast: None,
+            on_ability_hit: None,
+            on_ultimate_hit: None,
+            on_basic_attack_cast: None,
+            on_basic_attack_hit: None,
+            on_phys_hit: None,
+            on_magic_hit: None,
+            on_true_dmg_hit: None,
+            on_any_hit: None,
+        },
+    };
+}
+
+//Zaz'Zak's realmspike
+const ZAZZAKS_REALMSPIKE_VOID_EXPLOSION_COOLDOWN: f32 = 10.;
+
+fn zazzaks_realmspike_init(champ: &mut Unit) {
+    champ.effects_values[EffectValueId::ZazzaksRealmspikeVoidExplosionLastTriggerTime] =
+        -(ZAZZAKS_REALMSPIKE_VOID_EXPLOSION_COOLDOWN + F32_TOL); //to allow for effect at time = 0.
+}
+
+fn zazzaks_realmspike_void_explosion(
+    champ: &mut Unit,
+    target_stats: &UnitStats,
+    _n_targets: f32,
+) -> PartDmg {
+    //do nothing if on cooldown
+    if champ.time
+        - champ.effects_values[EffectValueId::ZazzaksRealmspikeVoidExplosionLastTriggerTime]
+        <= ZAZZAKS_REALMSPIKE_VOID_EXPLOSION_COOLDOWN
+    {
+        return PartDmg(0., 0., 0.);
+    }
+    //if not on cooldown (previous condition), trigger void explosion
+    champ.effects_values[EffectValueId::ZazzaksRealmspikeVoidExplosionLastTriggerTime] = champ.time;
+    PartDmg(0., 30. + 0.035 * target_stats.hp, 0.)
+}
+
+impl Item {
+    pub const ZAZZAKS_REALMSPIKE: Item = Item {
+        id: ItemId::ZazzaksRealmspike,
+        full_name: "Zazzaks_realmspike",
+        short_name: "Zazzaks",
+        cost: 400.,
+        item_groups: enum_set!(ItemGroups::Support),
+        utils: enum_set!(),
+        stats: UnitStats {
+            hp: 100.,
+            mana: 0.,
+            base_ad: 0.,
+            bonus_ad: 0.,
+            ap_flat: 15.,
+            ap_percent: 0.,
+            armor: 0.,
+            mr: 0.,
+            base_as: 0.,
+            bonus_as: 0.,
+            ability_haste: 10.,
+            basic_haste: 0.,
+            ultimate_haste: 0.,
+            item_haste: 0.,
+            crit_chance: 0.,
+            crit_dmg: 0.,
+            ms_flat: 0.,
+            ms_percent: 0.,
+            attack_range: 0.,
+            lethality: 0.,
+            armor_pen_percent: 0.,
+            magic_pen_flat: 0.,
+            magic_pen_percent: 0.,
+            armor_red_flat: 0.,
+            armor_red_percent: 0.,
+            mr_red_flat: 0.,
+            mr_red_percent: 0.,
+            life_steal: 0.,
+            omnivamp: 0.,
+            ability_dmg_modifier: 0.,
+            phys_dmg_modifier: 0.,
+            magic_dmg_modifier: 0.,
+            true_dmg_modifier: 0.,
+            tot_dmg_modifier: 0.,
+        },
+        passives: &[],
+        on_action_fns: OnActionFns {
+            on_lvl_set: None,
+            on_fight_init: Some(zazzaks_realmspike_init),
+            special_active: None,
+            on_ability_cast: None,
+            on_ultimate_cast: None,
+            on_ability_hit: Some(zazzaks_realmspike_void_explosion),
+            on_ultimate_hit: None,
+            on_basic_attack_cast: None,
+            on_basic_attack_hit: None,
+            on_phys_hit: None,
+            on_magic_hit: None,
+            on_true_dmg_hit: None,
+            on_any_hit: None,
+        },
+    };
+}
+
+//
+// --- COMPONENT ITEMS LISTING --- //
+//
+
+//passives against minions and monsters are not implemented
+
+//B. F. Sword
+impl Item {
+    pub const BF_SWORD: Item = Item {
+        id: ItemId::BFSword,
+        full_name: "B_F_sword",
+        short_name: "BF_sword",
+        cost: 1300.,
+        item_groups: enum_set!(ItemGroups::Component),
+        utils: enum_set!(),
+        stats: UnitStats {
+            hp: 0.,
+            mana: 0.,
+            base_ad: 0.,
+            bonus_ad: 40.,
+            ap_flat: 0.,
+            ap_percent: 0.,
+            armor: 0.,
+            mr: 0.,
+            base_as: 0.,
+            bonus_as: 0.,
+            ability_haste: 0.,
+            basic_haste: 0.,
+            ultimate_haste: 0.,
+            item_haste: 0.,
+            crit_chance: 0.,
+            crit_dmg: 0.,
+            ms_flat: 0.,
+            ms_percent: 0.,
+            attack_range: 0.,
+            lethality: 0.,
+            armor_pen_percent: 0.,
+            magic_pen_flat: 0.,
+            magic_pen_percent: 0.,
+            armor_red_flat: 0.,
+            armor_red_percent: 0.,
+            mr_red_flat: 0.,
+            mr_red_percent: 0.,
+            life_steal: 0.,
+            omnivamp: 0.,
+            ability_dmg_modifier: 0.,
+            phys_dmg_modifier: 0.,
+            magic_dmg_modifier: 0.,
+            true_dmg_modifier: 0.,
+            tot_dmg_modifier: 0.,
+        },
+        passives: &[],
+        on_action_fns: OnActionFns {
+            on_lvl_set: None,
+            on_fight_init: None,
+            special_active: None,
+            on_ability_cast: None,
+            on_ultimate_cast: None,
+            on_ability_hit: None,
+            on_ultimate_hit: None,
+            on_basic_attack_cast: None,
+            on_basic_attack_hit: None,
+            on_phys_hit: None,
+            on_magic_hit: None,
+            on_true_dmg_hit: None,
+            on_any_hit: None,
+        },
+    };
+}
+
+//Cloak of agility
+impl Item {
+    pub const CLOAK_OF_AGILITY: Item = Item {
+        id: ItemId::CloakOfAgility,
+        full_name: "Cloak_of_agility",
+        short_name: "Cloak",
+        cost: 600.,
+        item_groups: enum_set!(ItemGroups::Component),
+        utils: enum_set!(),
+        stats: UnitStats {
+            hp: 0.,
+            mana: 0.,
+            base_ad: 0.,
+            bonus_ad: 0.,
+            ap_flat: 0.,
+            ap_percent: 0.,
+            armor: 0.,
+            mr: 0.,
+            base_as: 0.,
+            bonus_as: 0.,
+            ability_haste: 0.,
+            basic_haste: 0.,
+            ultimate_haste: 0.,
+            item_haste: 0.,
+            crit_chance: 0.15,
+            crit_dmg: 0.,
+            ms_flat: 0.,
+            ms_percent: 0.,
+            attack_range: 0.,
+            lethality: 0.,
+            armor_pen_percent: 0.,
+            magic_pen_flat: 0.,
+            magic_pen_percent: 0.,
+            armor_red_flat: 0.,
+            armor_red_percent: 0.,
+            mr_red_flat: 0.,
+            mr_red_percent: 0.,
+            life_steal: 0.,
+            omnivamp: 0.,
+            ability_dmg_modifier: 0.,
+            phys_dmg_modifier: 0.,
+            magic_dmg_modifier: 0.,
+            true_dmg_modifier: 0.,
+            tot_dmg_modifier: 0.,
+        },
+        passives: &[],
+        on_action_fns: OnActionFns {
+            on_lvl_set: None,
+            on_fight_init: None,
+            special_active: None,
+            on_ability_cast: None,
+            on_ultimate_cast: None,
+            on_ability_hit: None,
+            on_ultimate_hit: None,
+            on_basic_attack_cast: None,
+            on_basic_attack_hit: None,
+            on_phys_hit: None,
+            on_magic_hit: None,
+            on_true_dmg_hit: None,
+            on_any_hit: None,
+        },
+    };
+}
+
+//Noonquiver
+impl Item {
+    pub const NOONQUIVER: Item = Item {
+        id: ItemId::Noonquiver,
+        full_name: "Noonquiver",
+        short_name: "Noonquiver",
+        cost: 1300.,
+        item_groups: enum_set!(ItemGroups::Component),
+        utils: enum_set!(),
+        stats: UnitStats {
+            hp: 0.,
+            mana: 0.,
+            base_ad: 0.,
+            bonus_ad: 30.,
+            ap_flat: 0.,
+            ap_percent: 0.,
+            armor: 0.,
+            mr: 0.,
+            base_as: 0.,
+            bonus_as: 0.15,
+            ability_haste: 0.,
+            basic_haste: 0.,
+            ultimate_haste: 0.,
+            item_haste: 0.,
+            crit_chance: 0.,
+            crit_dmg: 0.,
+            ms_flat: 0.,
+            ms_percent: 0.,
+            attack_range: 0.,
+            lethality: 0.,
+            armor_pen_percent: 0.,
+            magic_pen_flat: 0.,
+            magic_pen_percent: 0.,
+            armor_red_flat: 0.,
+            armor_red_percent: 0.,
+            mr_red_flat: 0.,
+            mr_red_percent: 0.,
+            life_steal: 0.,
+            omnivamp: 0.,
+            ability_dmg_modifier: 0.,
+            phys_dmg_modifier: 0.,
+            magic_dmg_modifier: 0.,
+            true_dmg_modifier: 0.,
+            tot_dmg_modifier: 0.,
+        },
+        passives: &[],
+        on_action_fns: OnActionFns {
+            on_lvl_set: None,
+            on_fight_init: None,
+            special_active: None,
+            on_ability_cast: None,
+            on_ultimate_cast: None,
+            on_ability_hit: None,
+            on_ultimate_hit: None,
+            on_basic_attack_cast: None,
+            on_basic_attack_hit: None,
+            on_phys_hit: None,
+            on_magic_hit: None,
+            on_true_dmg_hit: None,
+            on_any_hit: None,
+        },
+    };
+}
+
+//Pickaxe
+impl Item {
+    pub const PICKAXE: Item = Item {
+        id: ItemId::Pickaxe,
+        full_name: "Pickaxe",
+        short_name: "Pickaxe",
+        cost: 875.,
+        item_groups: enum_set!(ItemGroups::Component),
+        utils: enum_set!(),
+        stats: UnitStats {
+            hp: 0.,
+            mana: 0.,
+            base_ad: 0.,
+            bonus_ad: 25.,
+            ap_flat: 0.,
+            ap_percent: 0.,
+            armor: 0.,
+            mr: 0.,
+            base_as: 0.,
+            bonus_as: 0.,
+            ability_haste: 0.,
+            basic_haste: 0.,
+            ultimate_haste: 0.,
+            item_haste: 0.,
+            crit_chance: 0.,
+            crit_dmg: 0.,
+            ms_flat: 0.,
+            ms_percent: 0.,
+            attack_range: 0.,
+            lethality: 0.,
+            armor_pen_percent: 0.,
+            magic_pen_flat: 0.,
+            magic_pen_percent: 0.,
+            armor_red_flat: 0.,
+            armor_red_percent: 0.,
+            mr_red_flat: 0.,
+            mr_red_percent: 0.,
+            life_steal: 0.,
+            omnivamp: 0.,
+            ability_dmg_modifier: 0.,
+            phys_dmg_modifier: 0.,
+            magic_dmg_modifier: 0.,
+            true_dmg_modifier: 0.,
+            tot_dmg_modifier: 0.,
+        },
+        passives: &[],
+        on_action_fns: OnActionFns {
+            on_lvl_set: None,
+            on_fight_init: None,
+            special_active: None,
+            on_ability_cast: None,
+            on_ultimate_cast: None,
+            on_ability_hit: None,
+            on_ultimate_hit: None,
+            on_basic_attack_cast: None,
+            on_basic_attack_hit: None,
+            on_phys_hit: None,
+            on_magic_hit: None,
+            on_true_dmg_hit: None,
+            on_any_hit: None,
+        },
+    };
+}
+
+//Recurve bow
+fn recurve_bow_steel_tipped(
+    _champ: &mut Unit,
+    _target_stats: &UnitStats,
+    n_targets: f32,
+    _from_other_effect: bool,
+) -> PartDmg {
+    PartDmg(n_targets * 15., 0., 0.)
+}
+
+impl Item {
+    pub const RECURVE_BOW: Item = Item {
+        id: ItemId::RecurveBow,
+        full_name: "Recurve_bow",
+        short_name: "Recurve_bow",
+        cost: 700.,
+        item_groups: enum_set!(ItemGroups::Component),
+        utils: enum_set!(),
+        stats: UnitStats {
+            hp: 0.,
+            mana: 0.,
+            base_ad: 0.,
+            bonus_ad: 0.,
+            ap_flat: 0.,
+            ap_percent: 0.,
+            armor: 0.,
+            mr: 0.,
+            base_as: 0.,
+            bonus_as: 0.15,
+            ability_haste: 0.,
+            basic_haste: 0.,
+            ultimate_haste: 0.,
+            item_haste: 0.,
+            crit_chance: 0.,
+            crit_dmg: 0.,
+            ms_flat: 0.,
+            ms_percent: 0.,
+            attack_range: 0.,
+            lethality: 0.,
+            armor_pen_percent: 0.,
+            magic_pen_flat: 0.,
+            magic_pen_percent: 0.,
+            armor_red_flat: 0.,
+            armor_red_percent: 0.,
+            mr_red_flat: 0.,
+            mr_red_percent: 0.,
+            life_steal: 0.,
+            omnivamp: 0.,
+            ability_dmg_modifier: 0.,
+            phys_dmg_modifier: 0.,
+            magic_dmg_modifier: 0.,
+            true_dmg_modifier: 0.,
+            tot_dmg_modifier: 0.,
+        },
+        passives: &[],
+        on_action_fns: OnActionFns {
+            on_lvl_set: None,
+            on_fight_init: None,
+            special_active: None,
+            on_ability_cast: None,
+            on_ultimate_cast: None,
+            on_ability_hit: None,
+            on_ultimate_hit: None,
+            on_basic_attack_cast: None,
+            on_basic_attack_hit: Some(recurve_bow_steel_tipped),
+            on_phys_hit: None,
+            on_magic_hit: None,
+            on_true_dmg_hit: None,
+            on_any_hit: None,
+        },
+    };
+}
+
+//Vampiric scepter
+impl Item {
+    pub const VAMPIRIC_SCEPTER: Item = Item {
+        id: ItemId::VampiricScepter,
+        full_name: "Vampiric_scepter",
+        short_name: "Vampiric",
+        cost: 900.,
+        item_groups: enum_set!(ItemGroups::Component),
+        utils: enum_set!(),
+        stats: UnitStats {
+            hp: 0.,
+            mana: 0.,
+            base_ad: 0.,
+            bonus_ad: 15.,
+            ap_flat: 0.,
+            ap_percent: 0.,
+            armor: 0.,
+            mr: 0.,
+            base_as: 0.,
+            bonus_as: 0.,
+            ability_haste: 0.,
+            basic_haste: 0.,
+            ultimate_haste: 0.,
+            item_haste: 0.,
+            crit_chance: 0.,
+            crit_dmg: 0.,
+            ms_flat: 0.,
+            ms_percent: 0.,
+            attack_range: 0.,
+            lethality: 0.,
+            armor_pen_percent: 0.,
+            magic_pen_flat: 0.,
+            magic_pen_percent: 0.,
+            armor_red_flat: 0.,
+            armor_red_percent: 0.,
+            mr_red_flat: 0.,
+            mr_red_percent: 0.,
+            life_steal: 0.07,
+            omnivamp: 0.,
+            ability_dmg_modifier: 0.,
+            phys_dmg_modifier: 0.,
+            magic_dmg_modifier: 0.,
+            true_dmg_modifier: 0.,
+            tot_dmg_modifier: 0.,
+        },
+        passives: &[],
+        on_action_fns: OnActionFns {
+            on_lvl_set: None,
+            on_fight_init: None,
+            special_active: None,
+            on_ability_cast: None,
+            on_ultimate_cast: None,
+            on_ability_hit: None,
+            on_ultimate_hit: None,
+            on_basic_attack_cast: None,
+            on_basic_attack_hit: None,
+            on_phys_hit: None,
+            on_magic_hit: None,
+            on_true_dmg_hit: None,
+            on_any_hit: None,
+        },
+    };
+}
+
+//Zeal
+impl Item {
+    pub const ZEAL: Item = Item {
+        id: ItemId::Zeal,
+        full_name: "Zeal",
+        short_name: "Zeal",
+        cost: 1200.,
+        item_groups: enum_set!(ItemGroups::Component),
+        utils: enum_set!(),
+        stats: UnitStats {
+            hp: 0.,
+            mana: 0.,
+            base_ad: 0.,
+            bonus_ad: 0.,
+            ap_flat: 0.,
+            ap_percent: 0.,
+            armor: 0.,
+            mr: 0.,
+            base_as: 0.,
+            bonus_as: 0.15,
+            ability_haste: 0.,
+            basic_haste: 0.,
+            ultimate_haste: 0.,
+            item_haste: 0.,
+            crit_chance: 0.15,
+            crit_dmg: 0.,
+            ms_flat: 0.,
+            ms_percent: 0.04,
+            attack_range: 0.,
+            lethality: 0.,
+            armor_pen_percent: 0.,
+            magic_pen_flat: 0.,
+            magic_pen_percent: 0.,
+            armor_red_flat: 0.,
+            armor_red_percent: 0.,
+            mr_red_flat: 0.,
+            mr_red_percent: 0.,
+            life_steal: 0.,
+            omnivamp: 0.,
+            ability_dmg_modifier: 0.,
+            phys_dmg_modifier: 0.,
+            magic_dmg_modifier: 0.,
+            true_dmg_modifier: 0.,
+            tot_dmg_modifier: 0.,
+        },
+        passives: &[],
+        on_action_fns: OnActionFns {
+            on_lvl_set: None,
+            on_fight_init: None,
+            special_active: None,
+            on_ability_cast: None,
+            on_ultimate_cast: None,
+            on_ability_hit: None,
+            on_ultimate_hit: None,
+            on_basic_attack_cast: None,
+            on_basic_attack_hit: None,
+            on_phys_hit: None,
+            on_magic_hit: None,
+            on_true_dmg_hit: None,
+            on_any_hit: None,
+        },
+    };
+}
+
+//
+// --- STARTER ITEMS LISTING --- //
+//
+
+//passives against minions and monsters are not implemented
+
+//Cull
+impl Item {
+    pub const CULL: Item = Item {
+        id: ItemId::Cull,
+        full_name: "Cull",
+        short_name: "Cull",
+        cost: 450.,
+        item_groups: enum_set!(ItemGroups::Starter),
+        utils: enum_set!(),
+        stats: UnitStats {
+            hp: 0.,
+            mana: 0.,
+            base_ad: 0.,
+            bonus_ad: 7.,
+            ap_flat: 0.,
+            ap_percent: 0.,
+            armor: 0.,
+            mr: 0.,
+            base_as: 0.,
+            bonus_as: 0.,
+            ability_haste: 0.,
+            basic_haste: 0.,
+            ultimate_haste: 0.,
+            item_haste: 0.,
+            crit_chance: 0.,
+            crit_dmg: 0.,
+            ms_flat: 0.,
+            ms_percent: 0.,
+            attack_range: 0.,
+            lethality: 0.,
+            armor_pen_percent: 0.,
+            magic_pen_flat: 0.,
+            magic_pen_percent: 0.,
+            armor_red_flat: 0.,
+            armor_red_percent: 0.,
+            mr_red_flat: 0.,
+            mr_red_percent: 0.,
+            life_steal: 0.,
+            omnivamp: 0.,
+            ability_dmg_modifier: 0.,
+            phys_dmg_modifier: 0.,
+            magic_dmg_modifier: 0.,
+            true_dmg_modifier: 0.,
+            tot_dmg_modifier: 0.,
+        },
+        passives: &[],
+        on_action_fns: OnActionFns {
+            on_lvl_set: None,
+            on_fight_init: None,
+            special_active: None,
+            on_ability_cast: None,
+            on_ultimate_cast: None,
+            on_ability_hit: None,
+            on_ultimate_hit: None,
+            on_basic_attack_cast: None,
+            on_basic_attack_hit: None,
+            on_phys_hit: None,
+            on_magic_hit: None,
+            on_true_dmg_hit: None,
+            on_any_hit: None,
+        },
+    };
+}
+
+//Doran's blade
+impl Item {
+    pub const DORANS_BLADE: Item = Item {
+        id: ItemId::DoransBlade,
+        full_name: "Dorans_blade",
+        short_name: "Dorans_blade",
+        cost: 450.,
+        item_groups: enum_set!(ItemGroups::Starter),
+        utils: enum_set!(),
+        stats: UnitStats {
+            hp: 80.,
+            mana: 0.,
+            base_ad: 0.,
+            bonus_ad: 10.,
+            ap_flat: 0.,
+            ap_percent: 0.,
+            armor: 0.,
+            mr: 0.,
+            base_as: 0.,
+            bonus_as: 0.,
+            ability_haste: 0.,
+            basic_haste: 0.,
+            ultimate_haste: 0.,
+            item_haste: 0.,
+            crit_chance: 0.,
+            crit_dmg: 0.,
+            ms_flat: 0.,
+            ms_percent: 0.,
+            attack_range: 0.,
+            lethality: 0.,
+            armor_pen_percent: 0.,
+            magic_pen_flat: 0.,
+            magic_pen_percent: 0.,
+            armor_red_flat: 0.,
+            armor_red_percent: 0.,
+            mr_red_flat: 0.,
+            mr_red_percent: 0.,
+            life_steal: 0.035,
+            omnivamp: 0.,
+            ability_dmg_modifier: 0.,
+            phys_dmg_modifier: 0.,
+            magic_dmg_modifier: 0.,
+            true_dmg_modifier: 0.,
+            tot_dmg_modifier: 0.,
+        },
+        passives: &[],
+        on_action_fns: OnActionFns {
+            on_lvl_set: None,
+            on_fight_init: None,
+            special_active: None,
+            on_ability_cast: None,
+            on_ultimate_cast: None,
+            on_ability_hit: None,
+            on_ultimate_hit: None,
+            on_basic_attack_cast: None,
+            on_basic_attack_hit: None,
+            on_phys_hit: None,
+            on_magic_hit: None,
+            on_true_dmg_hit: None,
+            on_any_hit: None,
+        },
+    };
+}
+
+//
+// --- CONSUMABLES LISTING --- //
+//
+
+//consumables are assumed to be used at the start of the fight
+
+//Elixir of wrath
+//bloodlust passive not implemented (only heals from dmg to champions)
+impl Item {
+    pub const ELIXIR_OF_WRATH: Item = Item {
+        id: ItemId::ElixirOfWrath,
+        full_name: "Elixir_of_wrath",
+        short_name: "Wrath_elixir",
+        cost: 500.,
+        item_groups: enum_set!(ItemGroups::Consumable),
+        utils: enum_set!(),
+        stats: UnitStats {
+            hp: 0.,
+            mana: 0.,
+            base_ad: 0.,
+            bonus_ad: 30.,
+            ap_flat: 0.,
+            ap_percent: 0.,
+            armor: 0.,
+            mr: 0.,
+            base_as: 0.,
+            bonus_as: 0.,
+            ability_haste: 0.,
+            basic_haste: 0.,
+            ultimate_haste: 0.,
+            item_haste: 0.,
+            crit_chance: 0.,
+            crit_dmg: 0.,
+            ms_flat: 0.,
+            ms_percent: 0.,
+            attack_range: 0.,
+            lethality: 0.,
+            armor_pen_percent: 0.,
+            magic_pen_flat: 0.,
+            magic_pen_percent: 0.,
+            armor_red_flat: 0.,
+            armor_red_percent: 0.,
+            mr_red_flat: 0.,
+            mr_red_percent: 0.,
+            life_steal: 0.,
+            omnivamp: 0.,
+            ability_dmg_modifier: 0.,
+            phys_dmg_modifier: 0.,
+            magic_dmg_modifier: 0.,
+            true_dmg_modifier: 0.,
+            tot_dmg_modifier: 0.,
+        },
+        passives: &[],
+        on_action_fns: OnActionFns {
+            on_lvl_set: None,
+            on_fight_init: None,
+            special_active: None,
+            on_ability_cast: None,
+            on_ultimate_cast: None,
+            on_ability_hit: None,
+            on_ultimate_hit: None,
+            on_basic_attack_cast: None,
+            on_basic_attack_hit: None,
+            on_phys_hit: None,
+            on_magic_hit: None,
+            on_true_dmg_hit: None,
+            on_any_hit: None,
+        },
+    };
+}
+
+//Health potion
+const HEALTH_POTION_HEAL: f32 = 120.;
+const HEALTH_POTION_DURATION: u8 = 15;
+fn health_potion_init(champ: &mut Unit) {
+    //the heal over time is received every second (ticks after the end of the fight are lost)
+    for tick in 1..=HEALTH_POTION_DURATION {
+        champ.schedule_action(f32::from(tick), health_potion_tick);
+    }
+}
+
+fn health_potion_tick(champ: &mut Unit) {
+    champ.add_heal(
+        HEALTH_POTION_HEAL / f32::from(HEALTH_POTION_DURATION),
+        false,
+    );
+}
+
+impl Item {
+    pub const HEALTH_POTION: Item = Item {
+        id: ItemId::HealthPotion,
+        full_name: "Health_potion",
+        short_name: "Potion",
+        cost: 50.,
+        item_groups: enum_set!(ItemGroups::Consumable),
+        utils: enum_set!(),
+        stats: UnitStats {
+            hp: 0.,
+            mana: 0.,
+            base_ad: 0.,
+            bonus_ad: 0.,
+            ap_flat: 0.,
+            ap_percent: 0.,
+            armor: 0.,
+            mr: 0.,
+            base_as: 0.,
+            bonus_as: 0.,
+            ability_haste: 0.,
+            basic_haste: 0.,
+            ultimate_haste: 0.,
+            item_haste: 0.,
+            crit_chance: 0.,
+            crit_dmg: 0.,
+            ms_flat: 0.,
+            ms_percent: 0.,
+            attack_range: 0.,
+            lethality: 0.,
+            armor_pen_percent: 0.,
+            magic_pen_flat: 0.,
+            magic_pen_percent: 0.,
+            armor_red_flat: 0.,
+            armor_red_percent: 0.,
+            mr_red_flat: 0.,
+            mr_red_percent: 0.,
+            life_steal: 0.,
+            omnivamp: 0.,
+            ability_dmg_modifier: 0.,
+            phys_dmg_modifier: 0.,
+            magic_dmg_modifier: 0.,
+            true_dmg_modifier: 0.,
+            tot_dmg_modifier: 0.,
+        },
+        passives: &[],
+        on_action_fns: OnActionFns {
+            on_lvl_set: None,
+            on_fight_init: Some(health_potion_init),
+            special_active: None,
+            on_ability_cast: None,
+            on_ultimate_cast: None,
+            on_ability_hit: None,
+            on_ultimate_hit: None,
+            on_basic_attack_cast: None,
+            on_basic_attack_hit: None,
+            on_phys_hit: None,
+            on_magic_hit: None,
+            on_true_dmg_hit: None,
+            on_any_hit: None,
+        },
+    };
+}
@@ -1,11 +1,15 @@
+mod data_file;
 pub mod items;
+mod passives;
 
-use crate::OnActionFns;
+pub use data_file::*;
+pub use passives::*;
 
 use super::*;
-use units_data::{UnitStats, MAX_UNIT_ITEMS};
+use units_data::{OnActionFns, UnitStats, MAX_UNIT_ITEMS};
 
 use enumset::{EnumSet, EnumSetType};
+use serde::{Deserialize, Serialize};
 #[allow(unused_imports)]
 use strum::EnumCount; //this import is necessary for strum_macros::EnumCount to work but it triggers the lint for some reason
 use strum_macros::EnumCount as EnumCountMacro;
@@ -98,17 +102,39 @@ pub(crate) enum ItemId {
     MercurysTreads,
     PlatedSteelcaps,
     SorcerersShoes,
+    Bloodsong,
+    CelestialOpposition,
+    DreamMaker,
+    SolsticeSleigh,
+    ZazzaksRealmspike,
+    BFSword,
+    CloakOfAgility,
+    Noonquiver,
+    Pickaxe,
+    RecurveBow,
+    VampiricScepter,
+    Zeal,
+    Cull,
+    DoransBlade,
+    ElixirOfWrath,
+    HealthPotion,
+    /// Item defined only in an items data file (not compiled in), holds its index in the file.
+    #[strum(disabled)]
+    Custom(u16),
 }
 
 /// Holds item groups of an item, an item can have multiple item groups (implemented using an `EnumSet`).
 ///
-/// A build cannot have multiple items of the same item group.
+/// A build cannot have multiple items of the same item group, except for `Component` and `Consumable`
+/// (that mark component items and consumables, which can be held several times).
 /// <https://leagueoflegends.fandom.com/wiki/Item_group>
-#[derive(EnumSetType, Debug)]
+#[derive(EnumSetType, Debug, Serialize, Deserialize)]
 pub enum ItemGroups {
     Annul,
     Blight,
     Boots,
+    Component,
+    Consumable,
     Eternity,
     Fatality,
     Hydra,
@@ -120,19 +146,20 @@ pub enum ItemGroups {
     Quicksilver,
     Spellblade,
     Stasis,
+    Starter,
 }
 
 /// Describe if item has specific actives/passives utilities.
 ///
 /// An item can have a variant only for important effects like big powerspikes or one that unlocks a win condition.
-#[derive(EnumSetType, Debug)]
+#[derive(EnumSetType, Debug, Serialize, Deserialize)]
 pub enum ItemUtils {
     AntiHealShield,
     Survivability,
     Special,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Item {
     //attributes
     id: ItemId,
@@ -145,6 +172,9 @@ pub struct Item {
     //stats
     pub stats: UnitStats,
 
+    //declarative passives (defined in items data files)
+    pub passives: &'static [ItemPassive],
+
     //on action fns (passives/actives)
     pub(crate) on_action_fns: OnActionFns,
 }
@@ -278,24 +308,122 @@ pub const ALL_BOOTS: [&Item; 6] = [
 ];
 
 /// Lists support items.
-pub const ALL_SUPP_ITEMS: [&Item; 0] = [];
+pub const ALL_SUPP_ITEMS: [&Item; 5] = [
+    &Item::BLOODSONG,
+    &Item::CELESTIAL_OPPOSITION,
+    &Item::DREAM_MAKER,
+    &Item::SOLSTICE_SLEIGH,
+    &Item::ZAZZAKS_REALMSPIKE,
+];
+
+/// Lists component items.
+pub const ALL_COMPONENT_ITEMS: [&Item; 7] = [
+    &Item::BF_SWORD,
+    &Item::CLOAK_OF_AGILITY,
+    &Item::NOONQUIVER,
+    &Item::PICKAXE,
+    &Item::RECURVE_BOW,
+    &Item::VAMPIRIC_SCEPTER,
+    &Item::ZEAL,
+];
+
+/// Lists starter items.
+pub const ALL_STARTER_ITEMS: [&Item; 2] = [&Item::CULL, &Item::DORANS_BLADE];
+
+/// Lists consumables.
+pub const ALL_CONSUMABLES: [&Item; 2] = [&Item::ELIXIR_OF_WRATH, &Item::HEALTH_POTION];
+
+/// Recipes of finished items, only the components that are implemented are listed
+/// (the rest of the cost of a finished item is made of other components and of its combine cost).
+const ITEMS_RECIPES: [(&Item, &[&Item]); 12] = [
+    (
+        &Item::BLADE_OF_THE_RUINED_KING,
+        &[&Item::VAMPIRIC_SCEPTER, &Item::RECURVE_BOW],
+    ),
+    (
+        &Item::BLOODTHIRSTER,
+        &[&Item::BF_SWORD, &Item::PICKAXE, &Item::VAMPIRIC_SCEPTER],
+    ),
+    (&Item::DEATHS_DANCE, &[&Item::PICKAXE]),
+    (&Item::GUINSOOS_RAGEBLADE, &[&Item::RECURVE_BOW]),
+    (
+        &Item::INFINITY_EDGE,
+        &[&Item::BF_SWORD, &Item::PICKAXE, &Item::CLOAK_OF_AGILITY],
+    ),
+    (
+        &Item::KRAKEN_SLAYER,
+        &[&Item::NOONQUIVER, &Item::RECURVE_BOW],
+    ),
+    (&Item::NAVORI_FLICKERBLADE, &[&Item::ZEAL]),
+    (&Item::PHANTOM_DANCER, &[&Item::ZEAL]),
+    (&Item::RAPID_FIRECANNON, &[&Item::ZEAL]),
+    (&Item::RUNAANS_HURRICANE, &[&Item::ZEAL]),
+    (&Item::WITS_END, &[&Item::RECURVE_BOW]),
+    (
+        &Item::YUN_TAL_WILDARROWS,
+        &[&Item::NOONQUIVER, &Item::CLOAK_OF_AGILITY],
+    ),
+];
+
+impl Item {
+    /// Returns true if the item is a component item (see `ALL_COMPONENT_ITEMS`).
+    #[must_use]
+    pub fn is_component(&self) -> bool {
+        self.item_groups.contains(ItemGroups::Component)
+    }
+
+    /// Returns true if the item is a starter item (see `ALL_STARTER_ITEMS`).
+    #[must_use]
+    pub fn is_starter(&self) -> bool {
+        self.item_groups.contains(ItemGroups::Starter)
+    }
+
+    /// Returns true if the item is a consumable (see `ALL_CONSUMABLES`).
+    #[must_use]
+    pub fn is_consumable(&self) -> bool {
+        self.item_groups.contains(ItemGroups::Consumable)
+    }
+
+    /// Returns true if the item is a finished item (i.e. not a component, a starter item or a consumable).
+    #[must_use]
+    pub fn is_finished(&self) -> bool {
+        !self.is_component() && !self.is_starter() && !self.is_consumable()
+    }
+
+    /// Returns the component items of the recipe of the item (see `ITEMS_RECIPES`),
+    /// empty if the item has no implemented component.
+    #[must_use]
+    pub fn components(&self) -> &'static [&'static Item] {
+        ITEMS_RECIPES
+            .iter()
+            .find(|(item, _)| *item == self)
+            .map_or(&[], |(_, components)| components)
+    }
+}
+
+/// Returns the item name in a normalized form, in order to compare item names regardless of their case,
+/// and of underscores, dashes, apostrophes and dots.
+#[must_use]
+pub fn sanitize_item_name(name: &str) -> String {
+    name.replace('_', " ") //replace underscores with spaces
+        .replace(&['-', '\'', '.'][..], "") //remove -, ' and .
+        .to_lowercase()
+}
+
+/// Returns the item among the given ones whose full name or short name matches the given name, if any.
+#[must_use]
+pub fn find_item_by_name(name: &str, items: &[&'static Item]) -> Option<&'static Item> {
+    let sanitized_name: String = sanitize_item_name(name);
+    items.iter().copied().find(|item| {
+        (sanitized_name == sanitize_item_name(item.full_name))
+            || (sanitized_name == sanitize_item_name(item.short_name))
+    })
+}
 
 //set manually because f32 calcs are forbidden in constants :)))
 pub const AVG_LEGENDARY_ITEM_COST: f32 = 2979.;
 pub const AVG_BOOTS_COST: f32 = 1100.;
-pub const AVG_SUPP_ITEM_COST: f32 = 0.;
-
-/// Amount of experience gained farming for the average legendary item.
-/// We approximate that the gold income is only from cs golds and passive golds generation.
-pub const XP_PER_LEGENDARY_ITEM: f32 =
-    AVG_XP_PER_CS * CS_PER_MIN * AVG_LEGENDARY_ITEM_COST / TOT_GOLDS_PER_MIN;
-/// Amount of experience gained farming for the average boots item.
-/// We approximate that the gold income is only from cs golds and passive golds generation.
-pub const XP_PER_BOOTS_ITEM: f32 = AVG_XP_PER_CS * CS_PER_MIN * AVG_BOOTS_COST / TOT_GOLDS_PER_MIN;
-/// Amount of experience gained farming for the average support item.
-/// We approximate that the gold income is only from cs golds and passive golds generation.
-pub const XP_PER_SUPP_ITEM: f32 =
-    AVG_XP_PER_CS * CS_PER_MIN * AVG_SUPP_ITEM_COST / TOT_GOLDS_PER_MIN;
+pub const AVG_SUPP_ITEM_COST: f32 = 400.;
 
 /// Assumes 1 build slot for boots and the remaining slots for legendary items.
 #[allow(clippy::cast_precision_loss)] //`MAX_UNIT_ITEMS` is well whithin f32's range to avoid precision loss
@@ -352,12 +480,36 @@ impl Build {
             .count()
     }
 
-    /// Returns the build cost
+    /// Returns the build cost.
+    /// Components count for their own cost, which is part of the cost of the items they build into,
+    /// so the cost of a partial build (with components) is the gold spent to get it.
     #[must_use]
     pub fn cost(&self) -> f32 {
         self.iter().map(|item| item.cost).sum()
     }
 
+    /// Returns the gold needed to buy the given item on top of this build, the components of its recipe held in the build
+    /// being used for the recipe (each component held can only be used once).
+    #[must_use]
+    pub fn upgrade_cost(&self, item: &Item) -> f32 {
+        let mut held_components: Vec<&Item> = self
+            .iter()
+            .filter(|held_item| held_item.is_component())
+            .copied()
+            .collect();
+        let mut cost: f32 = item.cost;
+        for component in item.components() {
+            if let Some(idx) = held_components
+                .iter()
+                .position(|held_component| held_component == component)
+            {
+                held_components.swap_remove(idx);
+                cost -= component.cost;
+            }
+        }
+        cost
+    }
+
     /// Returns the build hash. Builds with same items but in different item order will produce the same hash.
     /// If there is no id collision between items, this function doesn't produces collisions either
     #[must_use]
@@ -376,7 +528,12 @@ impl Build {
         ];
         ids.sort_unstable();
         for window in ids.windows(2) {
-            if window[0] == window[1] && window[0] != Item::NULL_ITEM.id {
+            if window[0] == window[1]
+                && window[0] != Item::NULL_ITEM.id
+                && !self.iter().any(|item| {
+                    item.id == window[0] && (item.is_component() || item.is_consumable())
+                })
+            {
                 return Err(format!("Duplicates in build: {:?}", window[0]));
             }
         }
@@ -386,14 +543,17 @@ impl Build {
         Ok(())
     }
 
+    /// Returns true if several items of the build have the same item group (components and consumables are ignored).
     #[must_use]
     pub fn has_item_groups_overlap(&self) -> bool {
-        let mut cum_item_groups: EnumSet<ItemGroups> = self[0].item_groups;
-        for item in self[1..].iter() {
-            if !((cum_item_groups & item.item_groups).is_empty()) {
+        let mut cum_item_groups: EnumSet<ItemGroups> = EnumSet::empty();
+        for item in self.iter() {
+            let item_groups: EnumSet<ItemGroups> =
+                item.item_groups - ItemGroups::Component - ItemGroups::Consumable;
+            if !((cum_item_groups & item_groups).is_empty()) {
                 return true;
             }
-            cum_item_groups |= item.item_groups;
+            cum_item_groups |= item_groups;
         }
         false
     }
@@ -411,6 +571,9 @@ mod tests {
         &ALL_LEGENDARY_ITEMS,
         &ALL_BOOTS,
         &ALL_SUPP_ITEMS,
+        &ALL_COMPONENT_ITEMS,
+        &ALL_STARTER_ITEMS,
+        &ALL_CONSUMABLES,
         &[&Item::NULL_ITEM],
     );
 
@@ -445,6 +608,8 @@ mod tests {
 
     #[test]
     pub fn test_average_legendary_item_cost() {
+        #[allow(clippy::cast_precision_loss)]
+        //`ALL_LEGENDARY_ITEMS.len()` is well whithin f32's range to avoid precision loss
         let true_legendary_avg: f32 = ALL_LEGENDARY_ITEMS
             .iter()
             .map(|item| item.cost)
@@ -461,6 +626,8 @@ mod tests {
 
     #[test]
     pub fn test_average_boots_cost() {
+        #[allow(clippy::cast_precision_loss)]
+        //`ALL_BOOTS.len()` is well whithin f32's range to avoid precision loss
         let true_boots_avg: f32 =
             ALL_BOOTS.iter().map(|item| item.cost).sum::<f32>() / (ALL_BOOTS.len() as f32);
 
@@ -472,16 +639,73 @@ mod tests {
         );
     }
 
-    //#[test] //todo: enable this test once support items are added (currently divides by len of `ALL_SUPP_ITEMS` which is 0)
-    //pub fn test_average_supp_item_cost() {
-    //    let true_supp_avg: f32 = ALL_SUPP_ITEMS.iter().map(|item| item.cost).sum::<f32>()
-    //        / (ALL_SUPP_ITEMS.len() as f32);
-    //
-    //    assert!(((AVG_SUPP_ITEM_COST) - true_supp_avg).abs() < ITEMS_AVG_COST_TOL,
-    //        "Constant `AVG_SUPP_ITEM_COST` of value {} is too far from the true average boots cost of {} (-> put its value to {:.0})",
-    //        AVG_SUPP_ITEM_COST,
-    //        true_supp_avg,
-    //        true_supp_avg
-    //    );
-    //}
+    #[test]
+    pub fn test_average_supp_item_cost() {
+        #[allow(clippy::cast_precision_loss)]
+        //`ALL_SUPP_ITEMS.len()` is well whithin f32's range to avoid precision loss
+        let true_supp_avg: f32 = ALL_SUPP_ITEMS.iter().map(|item| item.cost).sum::<f32>()
+            / (ALL_SUPP_ITEMS.len() as f32);
+
+        assert!(((AVG_SUPP_ITEM_COST) - true_supp_avg).abs() < ITEMS_AVG_COST_TOL,
+            "Constant `AVG_SUPP_ITEM_COST` of value {} is too far from the true average support item cost of {} (-> put its value to {:.0})",
+            AVG_SUPP_ITEM_COST,
+            true_supp_avg,
+            true_supp_avg
+        );
+    }
+
+    #[test]
+    pub fn test_build_with_components() {
+        let partial_build: Build = Build([
+            &Item::KRAKEN_SLAYER,
+            &Item::BF_SWORD,
+            &Item::BF_SWORD,
+            &Item::CLOAK_OF_AGILITY,
+            &Item::NULL_ITEM,
+            &Item::NULL_ITEM,
+        ]);
+        assert!(partial_build.check_validity().is_ok()); //components can be held several times
+        assert!(
+            (partial_build.cost()
+                - (Item::KRAKEN_SLAYER.cost
+                    + 2. * Item::BF_SWORD.cost
+                    + Item::CLOAK_OF_AGILITY.cost))
+                .abs()
+                < F32_TOL
+        );
+        //only one b. f. sword is used for the infinity edge recipe
+        assert!(
+            (partial_build.upgrade_cost(&Item::INFINITY_EDGE)
+                - (Item::INFINITY_EDGE.cost - Item::BF_SWORD.cost - Item::CLOAK_OF_AGILITY.cost))
+                .abs()
+                < F32_TOL
+        );
+        assert!(
+            (partial_build.upgrade_cost(&Item::PHANTOM_DANCER) - Item::PHANTOM_DANCER.cost).abs()
+                < F32_TOL
+        );
+
+        let mut invalid_build: Build = partial_build;
+        invalid_build[1] = &Item::KRAKEN_SLAYER;
+        assert!(invalid_build.check_validity().is_err());
+    }
+
+    #[test]
+    pub fn test_build_with_starting_items() {
+        let mut starting_build: Build = Build([
+            &Item::DORANS_BLADE,
+            &Item::HEALTH_POTION,
+            &Item::HEALTH_POTION,
+            &Item::ELIXIR_OF_WRATH,
+            &Item::NULL_ITEM,
+            &Item::NULL_ITEM,
+        ]);
+        assert!(starting_build.check_validity().is_ok()); //consumables can be held several times
+        assert!(!starting_build
+            .iter()
+            .any(|item| **item != Item::NULL_ITEM && item.is_finished()));
+
+        starting_build[4] = &Item::CULL; //only one starter item
+        assert!(starting_build.check_validity().is_err());
+    }
 }
@@ -0,0 +1,224 @@
+use super::Item;
+
+use crate::game_data::*;
+
+use units_data::{Unit, UnitStats};
+
+use serde::{Deserialize, Serialize, Serializer};
+
+/// Type of dmg dealt by a declarative item passive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PassiveDmgType {
+    Phys,
+    Magic,
+    True,
+}
+
+/// Common item passive patterns that can be described directly in an items data file,
+/// without writing specific on-action-fns for the item.
+///
+/// Unusual passives still need to be written as on-action-fns in `items.rs`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case", deny_unknown_fields)]
+pub enum ItemPassive {
+    /// Dmg dealt on every basic attack hit: `flat + bonus_ad_ratio * bonus_ad + ap_ratio * ap`.
+    OnHitDmg {
+        dmg_type: PassiveDmgType,
+        #[serde(default)]
+        flat: f32,
+        #[serde(default)]
+        bonus_ad_ratio: f32,
+        #[serde(default)]
+        ap_ratio: f32,
+    },
+    /// Bonus attack speed gained on every basic attack hit, up to a maximum number of stacks.
+    /// Stacks are kept for the whole fight.
+    StackingAsOnHit {
+        bonus_as_per_stack: f32,
+        max_stacks: u8,
+    },
+    /// Stats gained for the whole fight, additionnal to the item stats
+    /// (they are not counted as item stats, like the stats given by a passive).
+    StatBonus {
+        #[serde(serialize_with = "serialize_non_zero_stats")]
+        stats: UnitStats,
+    },
+}
+
+/// Serializes only the non-zero stats, to keep items data files short.
+pub(super) fn serialize_non_zero_stats<S: Serializer>(
+    stats: &UnitStats,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    use serde::ser::Error;
+
+    let value: toml::Value = toml::Value::try_from(stats).map_err(S::Error::custom)?;
+    let non_zero_stats: toml::Table = value
+        .as_table()
+        .ok_or_else(|| S::Error::custom("stats are not serialized as a table"))?
+        .iter()
+        .filter_map(|(name, value)| {
+            let stat: f64 = value.as_float()?;
+            if stat == 0. {
+                return None;
+            }
+            //stats are f32, use their shortest representation instead of the f64 conversion (e.g. 0.04 instead of 0.03999999910593033)
+            #[allow(clippy::cast_possible_truncation)]
+            let stat: f64 = (stat as f32).to_string().parse::<f64>().ok()?;
+            Some((name.clone(), toml::Value::Float(stat)))
+        })
+        .collect();
+    non_zero_stats.serialize(serializer)
+}
+
+impl ItemPassive {
+    /// Returns an Err with the corresponding error message if the passive parameters are invalid.
+    pub fn check_validity(&self) -> Result<(), String> {
+        match self {
+            Self::OnHitDmg {
+                flat,
+                bonus_ad_ratio,
+                ap_ratio,
+                ..
+            } => {
+                if [flat, bonus_ad_ratio, ap_ratio]
+                    .iter()
+                    .any(|value| !value.is_finite() || **value < 0.)
+                {
+                    return Err("on-hit dmg values must be positive and finite".to_string());
+                }
+            }
+            Self::StackingAsOnHit {
+                bonus_as_per_stack,
+                max_stacks,
+            } => {
+                if !bonus_as_per_stack.is_finite() || *bonus_as_per_stack < 0. {
+                    return Err(
+                        "bonus attack speed per stack must be positive and finite".to_string()
+                    );
+                }
+                if *max_stacks == 0 {
+                    return Err("maximum number of stacks must be at least 1".to_string());
+                }
+            }
+            Self::StatBonus { .. } => (),
+        }
+        Ok(())
+    }
+}
+
+impl Unit {
+    /// Clears the items declarative passives from the unit and re-add those of the current build.
+    pub(crate) fn reload_items_passives(&mut self) {
+        self.items_passives.clear();
+        for item in self.build.iter().filter(|&&item| *item != Item::NULL_ITEM) {
+            for passive in item.passives {
+                self.items_passives.push((passive, 0));
+            }
+        }
+    }
+
+    /// Resets the items declarative passives stacks and applies their permanent effects at the start of a fight.
+    pub(crate) fn items_passives_on_fight_init(&mut self) {
+        for (passive, stacks) in &mut self.items_passives {
+            *stacks = 0;
+            if let ItemPassive::StatBonus { stats } = passive {
+                self.stats.add(stats);
+            }
+        }
+    }
+
+    /// Returns the on-basic-attack-hit dmg of the items declarative passives and updates their stacks.
+    #[must_use]
+    pub(crate) fn items_passives_on_basic_attack_hit(&mut self, n_targets: f32) -> PartDmg {
+        let mut sum: PartDmg = PartDmg(0., 0., 0.);
+        for (passive, stacks) in &mut self.items_passives {
+            match passive {
+                ItemPassive::OnHitDmg {
+                    dmg_type,
+                    flat,
+                    bonus_ad_ratio,
+                    ap_ratio,
+                } => {
+                    let dmg: f32 = n_targets
+                        * (flat
+                            + bonus_ad_ratio * self.stats.bonus_ad
+                            + ap_ratio * self.stats.ap());
+                    match dmg_type {
+                        PassiveDmgType::Phys => sum.0 += dmg,
+                        PassiveDmgType::Magic => sum.1 += dmg,
+                        PassiveDmgType::True => sum.2 += dmg,
+                    }
+                }
+                ItemPassive::StackingAsOnHit {
+                    bonus_as_per_stack,
+                    max_stacks,
+                } => {
+                    if *stacks < *max_stacks {
+                        *stacks += 1;
+                        self.stats.bonus_as += bonus_as_per_stack;
+                    }
+                }
+                ItemPassive::StatBonus { .. } => (),
+            }
+        }
+        sum
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::game_data::units_data::{
+        items_data::{Build, ItemsDatabase},
+        runes_data::RunesPage,
+    };
+
+    #[test]
+    pub fn test_declarative_passives() {
+        let items_database: &ItemsDatabase = ItemsDatabase::parse(
+            "[[items]]\n\
+             full_name = \"Test_blade\"\n\
+             short_name = \"Test\"\n\
+             cost = 3000\n\
+             [items.stats]\n\
+             bonus_ad = 40\n\
+             [[items.passives]]\n\
+             type = \"on_hit_dmg\"\n\
+             dmg_type = \"magic\"\n\
+             flat = 30\n\
+             [[items.passives]]\n\
+             type = \"stacking_as_on_hit\"\n\
+             bonus_as_per_stack = 0.1\n\
+             max_stacks = 2\n",
+            "test",
+        )
+        .expect("Failed to parse items data");
+        let item: &'static Item = items_database
+            .find_item_by_name("test")
+            .expect("Failed to find item from items data");
+
+        let mut build: Build = Build::default();
+        build[0] = item;
+        let mut champ: Unit = Unit::new(
+            &Unit::ASHE_PROPERTIES,
+            RunesPage::default(),
+            Unit::ASHE_PROPERTIES.defaults.skill_order.clone(),
+            6,
+            build,
+        )
+        .expect("Failed to create unit");
+        champ.init_fight();
+        let initial_bonus_as: f32 = champ.stats.bonus_as;
+
+        let dmg: PartDmg = champ.items_passives_on_basic_attack_hit(1.);
+        assert!((dmg.1 - 30.).abs() < F32_TOL);
+        for _ in 0..3 {
+            let _ = champ.items_passives_on_basic_attack_hit(1.);
+        }
+        //stacks are capped
+        assert!((champ.stats.bonus_as - initial_bonus_as - 0.2).abs() < F32_TOL);
+    }
+}
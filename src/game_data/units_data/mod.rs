@@ -1,17 +1,29 @@
 mod champions;
 mod effects_data;
+mod events;
+mod fight_script;
+mod incoming_dmg;
 pub mod items_data;
+mod positioning;
 pub mod runes_data;
+pub mod summoner_spells_data;
+mod teamfight;
 
 use super::*;
 use effects_data::*;
-use items_data::{items::RUNAANS_HURRICANE_WINDS_FURY_AVG_BOLTS, Build, Item};
+use events::{EventQueue, SimEvent};
+pub use fight_script::FightScript;
+pub use incoming_dmg::*;
+use items_data::{items::runaans_hurricane_winds_fury_bolts, Build, Item, ItemPassive};
 use runes_data::RunesPage;
+use summoner_spells_data::SummonerSpells;
+pub use teamfight::MAX_TEAMFIGHT_TARGETS;
+use teamfight::{TeamfightDmg, TeamfightState};
 
 use enum_map::EnumMap;
 use enumset::{enum_set, EnumSet, EnumSetType};
-use indexmap::IndexMap;
-use rustc_hash::FxBuildHasher;
+use rustc_hash::FxHashMap;
+use serde::{Deserialize, Serialize};
 
 use core::fmt;
 use core::num::NonZeroU8;
@@ -21,10 +33,10 @@ use core::num::NonZeroU8;
 //units constants
 /// Maximum lvl value of a Unit.
 pub const MAX_UNIT_LVL: usize = 18;
-/// Minimum lvl value of a Unit in the program (can't be below lvl 6 because we want all abilities to be available).
-pub const MIN_UNIT_LVL: u8 = 6;
+/// Minimum lvl value of a Unit (abilities that are not lvled up yet at low lvls can't be cast).
+pub const MIN_UNIT_LVL: u8 = 1;
 /// Maximum number of items an Unit can hold.
-pub(crate) const MAX_UNIT_ITEMS: usize = 6;
+pub const MAX_UNIT_ITEMS: usize = 6;
 /// Mean missing hp% for a champion, assuming the probability density function for the hp% is 2*x (from x=0 to x=1).
 const MEAN_MISSING_HP_PERCENT: f32 = 1. / 3.;
 
@@ -77,6 +89,9 @@ macro_rules! basic_attack_aoe_effect_avg_additionnal_targets {
 }
 use basic_attack_aoe_effect_avg_additionnal_targets; //to make it accessible in submodules
 
+/// Resistances reductions applied on an enemy unit (flat armor, % armor, flat mr, % mr reductions, see `UnitStats`).
+type ResistancesReductions = (f32, f32, f32, f32);
+
 //default target dummy properties & stats
 const TARGET_DUMMY_BASE_AS: f32 = 0.658;
 pub const TARGET_DUMMY_PROPERTIES: UnitProperties = UnitProperties {
@@ -85,6 +100,7 @@ pub const TARGET_DUMMY_PROPERTIES: UnitProperties = UnitProperties {
     as_ratio: TARGET_DUMMY_BASE_AS,
     windup_percent: 0.5,
     windup_modifier: 1.,
+    projectile_speed: 0.,
     base_stats: UnitStats {
         //in game default values
         hp: 1000.,
@@ -105,6 +121,7 @@ pub const TARGET_DUMMY_PROPERTIES: UnitProperties = UnitProperties {
         crit_dmg: Unit::BASE_CRIT_DMG,
         ms_flat: 370.,
         ms_percent: 0.,
+        attack_range: 0.,
         lethality: 0.,
         armor_pen_percent: 0.,
         magic_pen_flat: 0.,
@@ -145,6 +162,7 @@ pub const TARGET_DUMMY_PROPERTIES: UnitProperties = UnitProperties {
     fight_scenarios: &[(null_simulate_fight, "null")],
     defaults: UnitDefaults {
         runes_pages: RunesPage::const_default(),
+        summoner_spells: SummonerSpells::const_default(),
         skill_order: SkillOrder::const_default(), //does nothing since dummy has null abilities
         legendary_items_pool: &items_data::ALL_LEGENDARY_ITEMS,
         boots_pool: &items_data::ALL_BOOTS,
@@ -265,7 +283,8 @@ fn decrease_exponentially_scaling_stat(stat: &mut f32, amount: f32) {
     *stat = (1. + *stat) / (1. + amount) - 1.;
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
 pub struct UnitStats {
     pub hp: f32,                   //health points
     pub mana: f32,                 //mana
@@ -285,6 +304,7 @@ pub struct UnitStats {
     pub crit_dmg: f32,             //crit damage
     pub ms_flat: f32,              //flat movement speed
     pub ms_percent: f32,           //% movement speed
+    pub attack_range: f32,         //attack range
     pub lethality: f32,            //lethality (kinda "flat armor penetration")
     pub armor_pen_percent: f32,    //% armor penetration, stacks multiplicatively
     pub magic_pen_flat: f32,       //flat magic penetration
@@ -331,6 +351,7 @@ impl UnitStats {
             crit_dmg: 0.,
             ms_flat: 0.,
             ms_percent: 0.,
+            attack_range: 0.,
             lethality: 0.,
             armor_pen_percent: 0.,
             magic_pen_flat: 0.,
@@ -432,6 +453,7 @@ impl UnitStats {
         self.crit_dmg += other.crit_dmg;
         self.ms_flat += other.ms_flat;
         self.ms_percent += other.ms_percent;
+        self.attack_range += other.attack_range;
         self.lethality += other.lethality;
 
         increase_multiplicatively_scaling_stat(
@@ -470,16 +492,16 @@ impl UnitStats {
     }
 }
 
-#[derive(Debug)]
-pub(crate) struct BasicAbility {
+#[derive(Debug, Clone)]
+pub struct BasicAbility {
     /// Returns ability dmg and triggers effects.
     cast: fn(&mut Unit, &UnitStats) -> PartDmg,
     cast_time: f32,
     base_cooldown_by_ability_lvl: [f32; 6], //length 6 to account aphelios case, normal abilities only use the first 5 values
 }
 
-#[derive(Debug)]
-pub(crate) struct UltimateAbility {
+#[derive(Debug, Clone)]
+pub struct UltimateAbility {
     /// Returns ability dmg and triggers effects.
     /// Should call `Unit.dmg_on_target()` only for the return value at the end of the function !
     cast: fn(&mut Unit, &UnitStats) -> PartDmg,
@@ -489,9 +511,10 @@ pub(crate) struct UltimateAbility {
 
 pub(crate) type FightScenario = (fn(&mut Unit, &UnitStats, f32), &'static str);
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct UnitDefaults {
     pub runes_pages: RunesPage,
+    pub summoner_spells: SummonerSpells,
     pub skill_order: SkillOrder,
     pub legendary_items_pool: &'static [&'static Item],
     pub boots_pool: &'static [&'static Item],
@@ -499,13 +522,14 @@ pub struct UnitDefaults {
 }
 
 /// Holds properties that don't change at runtime for a given unit.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct UnitProperties {
     pub name: &'static str,
     pub as_limit: f32, //as limit of the unit (can be practical limit, e.g. kalista passive is not effective after a certain attack speed value, default as limit is 2.5)
     pub as_ratio: f32, //attack speed ratio, if not specified, same as base AS
     pub windup_percent: f32, //% attack wind up
     pub windup_modifier: f32, //get it from <https://leagueoflegends.fandom.com/wiki/List_of_champions/Basic_attacks>
+    pub projectile_speed: f32, //basic attacks projectile speed, 0 if basic attacks land instantly (melee)
     pub base_stats: UnitStats,
     pub growth_stats: UnitStats,
     pub(crate) basic_attack: fn(&mut Unit, &UnitStats) -> PartDmg, //returns basic attack dmg and triggers effects
@@ -519,6 +543,13 @@ pub struct UnitProperties {
     pub defaults: UnitDefaults,
 }
 
+impl UnitProperties {
+    /// Returns the names of the fight scenarios available for the unit, in order.
+    pub fn fight_scenarios_names(&self) -> impl Iterator<Item = &'static str> {
+        self.fight_scenarios.iter().map(|scenario| scenario.1)
+    }
+}
+
 impl PartialEq for UnitProperties {
     fn eq(&self, other: &Self) -> bool {
         self.name == other.name //assumes every Unit name is different, or rather that i'm not too retaaarded to put the same name on different units
@@ -598,6 +629,163 @@ impl SkillOrder {
     }
 }
 
+/// Basic ability of a unit (i.e. not the ultimate), used to describe skill orders.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BasicAbilitySlot {
+    Q,
+    W,
+    E,
+}
+
+impl fmt::Display for BasicAbilitySlot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Q => "Q",
+            Self::W => "W",
+            Self::E => "E",
+        })
+    }
+}
+
+/// Skill order described by the order in which basic abilities are maxed (ultimate is lvled up at lvl 6, 11 and 16).
+///
+/// The first point of each basic ability is taken at lvl 1, 2 and 3 (in the max order). Without `early_last_ability`,
+/// the last ability of the max order only gets its first point at lvl 5 instead (so that every ability is available
+/// at lvl 6, abilities without points can't be cast before).
+/// The other points go to the first ability of the max order that can be lvled up (an ability can't reach
+/// lvl n before the unit reaches lvl 2n-1).
+///
+/// Written as `Q>W>E` (early last ability) or `Q>W>E:late` (case insensitive, `>` are optional).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MaxOrder {
+    pub priority: [BasicAbilitySlot; 3],
+    pub early_last_ability: bool,
+}
+
+impl MaxOrder {
+    /// Returns every max order (every priority, with the last ability taken early or late).
+    #[must_use]
+    pub fn all() -> Vec<Self> {
+        use BasicAbilitySlot::{E, Q, W};
+        let priorities: [[BasicAbilitySlot; 3]; 6] = [
+            [Q, W, E],
+            [Q, E, W],
+            [W, Q, E],
+            [W, E, Q],
+            [E, Q, W],
+            [E, W, Q],
+        ];
+        [true, false]
+            .into_iter()
+            .flat_map(|early_last_ability| {
+                priorities.map(|priority| Self {
+                    priority,
+                    early_last_ability,
+                })
+            })
+            .collect()
+    }
+
+    /// Returns the skill order corresponding to the max order (not valid for Aphelios).
+    #[must_use]
+    pub fn to_skill_order(self) -> SkillOrder {
+        const ULTIMATE_LVLS: [usize; 3] = [6, 11, 16];
+        const MAX_BASIC_ABILITY_LVL: u8 = 5;
+        const LATE_LAST_ABILITY_LVL: usize = 5;
+
+        let mut skill_order: SkillOrder = SkillOrder {
+            q: [0; MAX_UNIT_LVL],
+            w: [0; MAX_UNIT_LVL],
+            e: [0; MAX_UNIT_LVL],
+            r: [0; MAX_UNIT_LVL],
+        };
+        let mut abilities_lvls: [u8; 3] = [0; 3]; //indexed like `self.priority`
+        for lvl in 1..=MAX_UNIT_LVL {
+            if ULTIMATE_LVLS.contains(&lvl) {
+                skill_order.r[lvl - 1] = 1;
+                continue;
+            }
+
+            let last_ability_lvl: usize = if self.early_last_ability {
+                3
+            } else {
+                LATE_LAST_ABILITY_LVL
+            };
+            #[allow(clippy::cast_possible_truncation)] //`MAX_UNIT_LVL` fits in u8
+            let max_ability_lvl: u8 = u8::min(MAX_BASIC_ABILITY_LVL, lvl.div_ceil(2) as u8);
+            let idx: usize = if lvl <= 2 {
+                lvl - 1 //first point of the 2 first abilities
+            } else if lvl == last_ability_lvl {
+                2 //first point of the last ability
+            } else {
+                (0..3)
+                    .find(|&idx| {
+                        abilities_lvls[idx] < max_ability_lvl
+                            && (idx != 2 || lvl > last_ability_lvl)
+                    })
+                    .expect("Max order should always have an ability to lvl up")
+            };
+            abilities_lvls[idx] += 1;
+            match self.priority[idx] {
+                BasicAbilitySlot::Q => skill_order.q[lvl - 1] = 1,
+                BasicAbilitySlot::W => skill_order.w[lvl - 1] = 1,
+                BasicAbilitySlot::E => skill_order.e[lvl - 1] = 1,
+            }
+        }
+        skill_order
+    }
+}
+
+impl fmt::Display for MaxOrder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let [first, second, third] = self.priority;
+        write!(f, "{first}>{second}>{third}")?;
+        if !self.early_last_ability {
+            f.write_str(":late")?;
+        }
+        Ok(())
+    }
+}
+
+impl core::str::FromStr for MaxOrder {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let lowercase: String = s.trim().to_lowercase();
+        let (priority_str, early_last_ability): (&str, bool) = match lowercase.split_once(':') {
+            None => (&lowercase, true),
+            Some((priority_str, "early")) => (priority_str, true),
+            Some((priority_str, "late")) => (priority_str, false),
+            Some(_) => {
+                return Err(format!(
+                    "'{s}' is not a valid max order (suffix must be ':early' or ':late')"
+                ))
+            }
+        };
+        let priority: Vec<BasicAbilitySlot> = priority_str
+            .chars()
+            .filter(|c| *c != '>' && !c.is_whitespace())
+            .map(|c| match c {
+                'q' => Ok(BasicAbilitySlot::Q),
+                'w' => Ok(BasicAbilitySlot::W),
+                'e' => Ok(BasicAbilitySlot::E),
+                _ => Err(format!("'{c}' is not a basic ability (expected q, w or e)")),
+            })
+            .collect::<Result<Vec<BasicAbilitySlot>, String>>()?;
+        match priority[..] {
+            [first, second, third] if first != second && first != third && second != third => {
+                Ok(Self {
+                    priority: [first, second, third],
+                    early_last_ability,
+                })
+            }
+            _ => Err(format!(
+                "'{s}' is not a valid max order (expected each of q, w and e once, e.g. q>w>e)"
+            )),
+        }
+    }
+}
+
 /// Holds different function that must be executed on the `Unit` after specific trigger events.
 /// Trigger event are basic attacks, ability hits, ...
 ///
@@ -608,9 +796,9 @@ impl SkillOrder {
 /// Second argument of type `&Unit` (if any) is the 'receiver' of the action (e.g. the target for on hit).
 ///
 /// For program correctness, these function should NEVER modify the `Unit` outside of temporary effects and effect variables.
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy)]
 #[allow(clippy::type_complexity)]
-pub(crate) struct OnActionFns {
+pub struct OnActionFns {
     /// Perform specific actions required when setting the Unit lvl (exemple: add veigar passive stacks ap to `lvl_stats`).
     pub(crate) on_lvl_set: Option<fn(&mut Unit)>,
 
@@ -920,8 +1108,10 @@ pub struct Unit {
     //properties
     pub properties: &'static UnitProperties,
     runes_page: RunesPage,
+    summoner_spells: SummonerSpells,
     skill_order: SkillOrder,
     build: Build,
+    economy: EconomyProfile,
 
     //stats
     /// Stats that only comes from the Unit base stats (only change with lvl).
@@ -942,24 +1132,40 @@ pub struct Unit {
 
     //simulation timings & variables
     time: f32,
-    basic_attack_cd: f32,
-    q_cd: f32,
-    w_cd: f32,
-    e_cd: f32,
-    r_cd: f32,
+    basic_attack_ready_time: f32, //cooldowns are stored as the time at which the action is ready
+    q_ready_time: f32,
+    w_ready_time: f32,
+    e_ready_time: f32,
+    r_ready_time: f32,
     dmg_done: PartDmg,
     periodic_heals_shields: f32, //heals and shields obtained over a duration
     single_use_heals_shields: f32, //heals and shields obtained once
+    heals_received: f32, //heals obtained (also counted in one of the above), tracked separately from shields for the incoming dmg
+    shields_received: f32, //shields obtained (also counted in one of the above)
+    lifeline_shields_received: f32, //shields obtained when the hp of the unit falls under the lifeline threshold (also counted as single use)
     units_travelled: f32,
+    incoming_dmg: Option<IncomingDmgState>, //hp tracking if the unit receives dmg during the fight
+    target_hp_lost: f32, //hp lost by the current target (a killed target is replaced by a new one with full hp)
+    time_to_kill: Option<f32>, //time at which the first target was killed, if any
+    target_flee_percent: f32, //percentage of time the target spends moving away from the unit
+    target_flee_speed: f32, //average speed at which the target moves away from the unit
+    in_basic_attack: bool, //true while the basic attack of the unit is dealing its dmg (only this dmg travels with the projectile)
+    target_distance: f32,  //distance between the unit and its target
+    teamfight: Option<TeamfightState>, //enemy units fought if the unit fights several targets
 
     //on action functions
     on_action_fns_holder: OnActionFnsHolder,
+    items_passives: Vec<(&'static ItemPassive, u8)>, //declarative passives of the items in the build and their current stacks
 
     //temporary effects
     effects_stacks: EnumMap<EffectStackId, u8>, //holds various effects integers values on the unit
     effects_values: EnumMap<EffectValueId, f32>, //holds various effects floats values on the unit
-    temporary_effects_durations: IndexMap<&'static TemporaryEffect, f32, FxBuildHasher>, //IndexMap of active temporary effects on the unit and their remaining duration
-    temporary_effects_cooldowns: IndexMap<&'static TemporaryEffect, f32, FxBuildHasher>, //IndexMap of temporary effects on cooldown on the unit
+    temporary_effects_expiries: FxHashMap<&'static TemporaryEffect, f32>, //active temporary effects on the unit and the time at which they expire
+    temporary_effects_cooldowns: FxHashMap<&'static TemporaryEffect, f32>, //temporary effects activated on the unit and the time at which their cooldown ends
+
+    //simulation events
+    events: EventQueue, //events scheduled during the fight (effects expiries, delayed dmg, ...)
+    dmg_instances: u32, //number of dmg instances done during the fight, to differentiate them
 
     //simulation logs
     actions_log: Vec<(f32, UnitAction)>, //records each action performed and at what time in execution order, purely for debug purposes
@@ -1017,6 +1223,7 @@ impl fmt::Display for Unit {
             self.stats.ms_flat,
             100. * self.stats.ms_percent,
         )?;
+        writeln!(f, "attack range: {:.0}", self.stats.attack_range)?;
         writeln!(f, "lethality: {:.0}", self.stats.lethality)?;
         writeln!(
             f,
@@ -1073,6 +1280,9 @@ enum DmgTag {
     BasicAttack,
     Ability,
     Ultimate,
+    /// Dmg that only hits units around the main target (bounces, splash, ...).
+    /// It doesn't hit the main target during a teamfight (it is done on the main target otherwise).
+    AroundTarget,
 }
 
 impl Unit {
@@ -1088,6 +1298,20 @@ impl Unit {
         &self.runes_page
     }
 
+    #[allow(dead_code)]
+    #[must_use]
+    #[inline]
+    pub fn get_summoner_spells(&self) -> &SummonerSpells {
+        &self.summoner_spells
+    }
+
+    #[allow(dead_code)]
+    #[must_use]
+    #[inline]
+    pub fn get_economy(&self) -> &EconomyProfile {
+        &self.economy
+    }
+
     #[allow(dead_code)]
     #[must_use]
     #[inline]
@@ -1120,39 +1344,118 @@ impl Unit {
         self.time
     }
 
+    /// Returns an id identifying the current dmg instance (dmg done in the same call to `Unit::dmg_on_target` share the same id).
+    #[must_use]
+    #[inline]
+    pub(crate) fn get_dmg_instance_id(&self) -> f32 {
+        #[allow(clippy::cast_precision_loss)]
+        //the number of dmg instances in a fight is well within f32 precision range
+        (self.dmg_instances as f32)
+    }
+
+    /// Returns the remaining cooldown of the basic attack (infinite if it can't be used in this fight).
+    #[must_use]
+    #[inline]
+    fn basic_attack_cd(&self) -> f32 {
+        f32::max(0., self.basic_attack_ready_time - self.time)
+    }
+
+    /// Sets the remaining cooldown of the basic attack and schedules the event of when it becomes ready.
+    #[inline]
+    fn set_basic_attack_cd(&mut self, cd: f32) {
+        self.basic_attack_ready_time = self.time + cd;
+        self.schedule_cooldown_ready(cd);
+    }
+
+    /// Returns the remaining cooldown of the q (infinite if it can't be used in this fight).
+    #[must_use]
+    #[inline]
+    fn q_cd(&self) -> f32 {
+        f32::max(0., self.q_ready_time - self.time)
+    }
+
+    /// Sets the remaining cooldown of the q and schedules the event of when it becomes ready.
+    #[inline]
+    fn set_q_cd(&mut self, cd: f32) {
+        self.q_ready_time = self.time + cd;
+        self.schedule_cooldown_ready(cd);
+    }
+
+    /// Returns the remaining cooldown of the w (infinite if it can't be used in this fight).
+    #[must_use]
+    #[inline]
+    fn w_cd(&self) -> f32 {
+        f32::max(0., self.w_ready_time - self.time)
+    }
+
+    /// Sets the remaining cooldown of the w and schedules the event of when it becomes ready.
+    #[inline]
+    fn set_w_cd(&mut self, cd: f32) {
+        self.w_ready_time = self.time + cd;
+        self.schedule_cooldown_ready(cd);
+    }
+
+    /// Returns the remaining cooldown of the e (infinite if it can't be used in this fight).
+    #[must_use]
+    #[inline]
+    fn e_cd(&self) -> f32 {
+        f32::max(0., self.e_ready_time - self.time)
+    }
+
+    /// Sets the remaining cooldown of the e and schedules the event of when it becomes ready.
+    #[inline]
+    fn set_e_cd(&mut self, cd: f32) {
+        self.e_ready_time = self.time + cd;
+        self.schedule_cooldown_ready(cd);
+    }
+
+    /// Returns the remaining cooldown of the r (infinite if it can't be used in this fight).
+    #[must_use]
+    #[inline]
+    fn r_cd(&self) -> f32 {
+        f32::max(0., self.r_ready_time - self.time)
+    }
+
+    /// Sets the remaining cooldown of the r and schedules the event of when it becomes ready.
+    #[inline]
+    fn set_r_cd(&mut self, cd: f32) {
+        self.r_ready_time = self.time + cd;
+        self.schedule_cooldown_ready(cd);
+    }
+
     #[allow(dead_code)]
     #[must_use]
     #[inline]
     pub fn get_basic_attack_cd(&self) -> f32 {
-        self.basic_attack_cd
+        self.basic_attack_cd()
     }
 
     #[allow(dead_code)]
     #[must_use]
     #[inline]
     pub fn get_q_cd(&self) -> f32 {
-        self.q_cd
+        self.q_cd()
     }
 
     #[allow(dead_code)]
     #[must_use]
     #[inline]
     pub fn get_w_cd(&self) -> f32 {
-        self.w_cd
+        self.w_cd()
     }
 
     #[allow(dead_code)]
     #[must_use]
     #[inline]
     pub fn get_e_cd(&self) -> f32 {
-        self.e_cd
+        self.e_cd()
     }
 
     #[allow(dead_code)]
     #[must_use]
     #[inline]
     pub fn get_r_cd(&self) -> f32 {
-        self.r_cd
+        self.r_cd()
     }
 
     #[allow(dead_code)]
@@ -1162,6 +1465,47 @@ impl Unit {
         self.dmg_done
     }
 
+    /// Returns the current hp of the main target, tracked from the dmg done during the fight.
+    /// During a teamfight, the missing hp% of the current main target is applied to `target_stats.hp`
+    /// (so that formulas using both stay consistent).
+    #[must_use]
+    #[inline]
+    pub fn get_target_current_hp(&self, target_stats: &UnitStats) -> f32 {
+        if let Some(missing_hp_percent) = self.teamfight_current_target_missing_hp_percent() {
+            return target_stats.hp * (1. - missing_hp_percent);
+        }
+        f32::max(0., target_stats.hp - self.target_hp_lost)
+    }
+
+    /// Returns the missing hp% of the main target (between 0 and 1), tracked from the dmg done during the fight.
+    #[must_use]
+    #[inline]
+    pub fn get_target_missing_hp_percent(&self, target_stats: &UnitStats) -> f32 {
+        if let Some(missing_hp_percent) = self.teamfight_current_target_missing_hp_percent() {
+            return missing_hp_percent;
+        }
+        if target_stats.hp <= 0. {
+            return 0.;
+        }
+        f32::clamp(self.target_hp_lost / target_stats.hp, 0., 1.)
+    }
+
+    /// Returns the time needed to kill the main target (every enemy unit during a teamfight) during the last fight simulation.
+    /// If the target is still alive at the end of the fight, the time to kill is extrapolated
+    /// from the average dps on the target (infinite if no dmg was done).
+    #[must_use]
+    pub fn get_time_to_kill(&self, target_stats: &UnitStats) -> f32 {
+        self.time_to_kill.unwrap_or_else(|| {
+            let (target_hp, target_hp_lost): (f32, f32) = self
+                .teamfight_hp_lost()
+                .unwrap_or((target_stats.hp, self.target_hp_lost));
+            if target_hp_lost <= 0. {
+                return f32::INFINITY;
+            }
+            self.time * f32::max(target_hp, target_hp_lost) / target_hp_lost
+        })
+    }
+
     #[allow(dead_code)]
     #[must_use]
     #[inline]
@@ -1254,6 +1598,8 @@ impl Unit {
         self.lvl_stats.ms_flat = growth_stat_formula(self.lvl, base.ms_flat, growth.ms_flat);
         self.lvl_stats.ms_percent =
             growth_stat_formula(self.lvl, base.ms_percent, growth.ms_percent);
+        self.lvl_stats.attack_range =
+            growth_stat_formula(self.lvl, base.attack_range, growth.attack_range);
         self.lvl_stats.lethality = growth_stat_formula(self.lvl, base.lethality, growth.lethality);
         self.lvl_stats.armor_pen_percent =
             growth_stat_formula(self.lvl, base.armor_pen_percent, growth.armor_pen_percent);
@@ -1304,6 +1650,14 @@ impl Unit {
         Ok(())
     }
 
+    /// Sets the Unit economy profile, returns Ok if success or Err if failure (depending on the validity of the given profile).
+    /// In case of a failure, the unit is not modified.
+    pub fn set_economy(&mut self, economy: EconomyProfile) -> Result<(), String> {
+        economy.check_validity()?;
+        self.economy = economy;
+        Ok(())
+    }
+
     /// Updates unit abilities lvl.
     ///
     /// Because they depend on unit lvl, this function is called when setting lvl and skill order.
@@ -1316,15 +1670,21 @@ impl Unit {
         self.r_lvl = self.skill_order.r[..lvl].iter().sum();
     }
 
-    /// Clears the items on-action-fns from the unit, leaving only on-action-fns from the unit properties and runes.
+    /// Clears the items on-action-fns from the unit, leaving only on-action-fns from the unit properties, runes and summoner spells.
     fn clear_items_on_action_fns(&mut self) {
         self.on_action_fns_holder.clear();
 
-        //add base on-action-fns (from unit properties) and runes on-action-fns only
+        //add base on-action-fns (from unit properties), runes and summoner spells on-action-fns only
         self.on_action_fns_holder
             .extend(&self.properties.on_action_fns);
         self.on_action_fns_holder
             .extend(&self.runes_page.keystone.on_action_fns);
+        for rune in self.runes_page.minor_runes() {
+            self.on_action_fns_holder.extend(&rune.on_action_fns);
+        }
+        for spell in self.summoner_spells.spells {
+            self.on_action_fns_holder.extend(&spell.on_action_fns);
+        }
     }
 
     /// Clears every on-action-fns from the unit and re-add them.
@@ -1361,6 +1721,7 @@ impl Unit {
             self.items_stats.add(&item.stats);
             self.on_action_fns_holder.extend(&item.on_action_fns);
         }
+        self.reload_items_passives();
     }
 
     /// Creates a new Unit with the given properties, runes, skill order, lvl and build.
@@ -1443,8 +1804,10 @@ impl Unit {
             //properties
             properties,
             runes_page: RunesPage::default(), //temporary value until initialized by setter function
+            summoner_spells: SummonerSpells::default(), //temporary value until initialized by setter function
             skill_order: SkillOrder::default(), //temporary value until initialized by setter function (must still be a valid skill order!)
             build: Build::default(),
+            economy: EconomyProfile::default(),
 
             //stats
             lvl_stats: UnitStats::default(), //temporary value until initialized by setter function
@@ -1461,15 +1824,26 @@ impl Unit {
 
             //simulation timings
             time: 0.,
-            basic_attack_cd: 0.,
-            q_cd: 0.,
-            w_cd: 0.,
-            e_cd: 0.,
-            r_cd: 0.,
+            basic_attack_ready_time: 0.,
+            q_ready_time: 0.,
+            w_ready_time: 0.,
+            e_ready_time: 0.,
+            r_ready_time: 0.,
             dmg_done: PartDmg(0., 0., 0.),
             periodic_heals_shields: 0.,
             single_use_heals_shields: 0.,
+            heals_received: 0.,
+            shields_received: 0.,
+            lifeline_shields_received: 0.,
             units_travelled: 0.,
+            incoming_dmg: None,
+            target_hp_lost: 0.,
+            time_to_kill: None,
+            target_flee_percent: 0.,
+            target_flee_speed: 0.,
+            in_basic_attack: false,
+            target_distance: 0.,
+            teamfight: None,
 
             //on action functions
             on_action_fns_holder: OnActionFnsHolder {
@@ -1487,12 +1861,17 @@ impl Unit {
                 on_true_dmg_hit: Vec::new(),
                 on_any_hit: Vec::new(),
             },
+            items_passives: Vec::new(),
 
             //temporary effects
             effects_stacks: EnumMap::default(),
             effects_values: EnumMap::default(),
-            temporary_effects_durations: IndexMap::with_hasher(FxBuildHasher),
-            temporary_effects_cooldowns: IndexMap::with_hasher(FxBuildHasher),
+            temporary_effects_expiries: FxHashMap::default(),
+            temporary_effects_cooldowns: FxHashMap::default(),
+
+            //simulation events
+            events: EventQueue::default(),
+            dmg_instances: 0,
 
             //simulation logs
             actions_log: Vec::new(),
@@ -1504,6 +1883,9 @@ impl Unit {
         //check and set runes
         new_unit.set_runes(runes_page)?;
 
+        //check and set summoner spells (from the unit properties defaults, can be changed later with the setter function)
+        new_unit.set_summoner_spells(properties.defaults.summoner_spells)?;
+
         //check and set lvl
         new_unit.set_lvl(lvl)?;
 
@@ -1536,6 +1918,7 @@ impl Unit {
     }
 
     /// Creates a new Unit with the properties of a target dummy.
+    #[must_use]
     pub fn new_target_dummy() -> Self {
         Self::from_properties_defaults(&TARGET_DUMMY_PROPERTIES, MIN_UNIT_LVL, Build::default())
             .expect("Failed to create target dummy")
@@ -1548,21 +1931,29 @@ impl Unit {
     /// The haste argument is to specify which haste value to use for the effect cooldown (ability haste, item haste, ...)
     fn add_temporary_effect(&mut self, effect_ref: &'static TemporaryEffect, haste: f32) -> bool {
         //return early if effect is on cooldown
-        if self.temporary_effects_cooldowns.contains_key(effect_ref) {
+        if self
+            .temporary_effects_cooldowns
+            .get(effect_ref)
+            .is_some_and(|&cooldown_end| cooldown_end > self.time)
+        {
             return false;
         }
 
-        //store effect remaining duration
-        self.temporary_effects_durations
-            .insert(effect_ref, effect_ref.duration);
+        //store effect expiry time and schedule its removal (refreshing the effect makes previously scheduled removals obsolete)
+        let expiry_time: f32 = self.time + effect_ref.duration;
+        self.temporary_effects_expiries
+            .insert(effect_ref, expiry_time);
+        self.events
+            .push(expiry_time, SimEvent::EffectExpiry(effect_ref));
 
         //store effect cooldown only if cooldown is non-zero (cooldown starts on activation)
         let mut availability_coef: f32 = 1.;
         if effect_ref.cooldown != 0. {
             let real_cooldown: f32 = effect_ref.cooldown * haste_formula(haste);
             self.temporary_effects_cooldowns
-                .insert(effect_ref, real_cooldown);
-            availability_coef = effect_availability_formula(real_cooldown);
+                .insert(effect_ref, self.time + real_cooldown);
+            self.schedule_cooldown_ready(real_cooldown);
+            availability_coef = self.economy.effect_availability_formula(real_cooldown);
         }
 
         //add effect stack to the unit
@@ -1570,88 +1961,64 @@ impl Unit {
         true
     }
 
-    /// Wait immobile for the given amount of time. Removes effects if they expire.
+    /// Returns true if the given effect is currently active on the Unit.
+    fn has_temporary_effect(&self, effect_ref: &'static TemporaryEffect) -> bool {
+        self.temporary_effects_expiries.contains_key(effect_ref)
+    }
+
+    /// Returns the remaining duration of the given effect on the Unit (0 if the effect is not active).
+    fn get_temporary_effect_remaining_duration(&self, effect_ref: &'static TemporaryEffect) -> f32 {
+        self.temporary_effects_expiries
+            .get(effect_ref)
+            .map_or(0., |&expiry_time| f32::max(0., expiry_time - self.time))
+    }
+
+    /// Wait immobile for the given amount of time. Processes the events happening in the meantime (effects expiries, delayed dmg, ...).
     pub fn wait(&mut self, dt: f32) {
         //sanity check, can be removed
         assert!(
             dt > 0.,
             "Cannot wait for a negative or null amount of time (got {dt})"
         );
+        self.advance_time(dt, false);
+    }
 
-        //update time
-        self.time += dt;
-
-        //update cooldowns, cannot go below 0
-        self.basic_attack_cd = f32::max(0., self.basic_attack_cd - dt);
-        self.q_cd = f32::max(0., self.q_cd - dt);
-        self.w_cd = f32::max(0., self.w_cd - dt);
-        self.e_cd = f32::max(0., self.e_cd - dt);
-        self.r_cd = f32::max(0., self.r_cd - dt);
-
-        //update effects cooldowns
-        let mut idx: usize = self.temporary_effects_cooldowns.len();
-        while idx > 0 {
-            idx -= 1;
-
-            //update effect cooldown
-            let (_, cooldown_ref) = self.temporary_effects_cooldowns.get_index_mut(idx).unwrap();
-            *cooldown_ref -= dt;
-
-            //remove effect from storage if its cooldown ends
-            if *cooldown_ref < F32_TOL {
-                self.temporary_effects_cooldowns.swap_remove_index(idx);
-            }
-        }
-
-        //update effects durations, must be done after effects cooldowns to not interfere when effects re-add themselves when removed
-        //traverse hash map in reverse index order to allow for effects to re-add themselves when removed (e.g.: kindred wolf's frenzy)
-        let mut idx: usize = self.temporary_effects_durations.len();
-        while idx > 0 {
-            idx -= 1;
-
-            //update effect duration
-            let (&effect_ref, duration_ref) =
-                self.temporary_effects_durations.get_index_mut(idx).unwrap();
-            *duration_ref -= dt;
-
-            //remove effect from the unit if its duration ends
-            if *duration_ref < F32_TOL {
-                self.temporary_effects_durations.swap_remove_index(idx);
-                (effect_ref.remove_every_stack)(self); //call after removing effect from hashmap so it can re-add itself
-            }
+    /// Move for the given amount of time. Processes the events happening in the meantime and add distance to `self.units_travelled`.
+    /// Does nothing if the given time is negative or null.
+    pub fn walk(&mut self, dt: f32) {
+        if dt > 0. {
+            self.advance_time(dt, true);
         }
     }
 
-    /// Move for the given amount of time. Removes effects if they expire and add distance to `self.simulation_results.units_travelled`.
-    pub fn walk(&mut self, mut dt: f32) {
-        while dt >= F32_TOL {
-            //find minimum time until next expiring effect
-            let min_duration: f32 = *self
-                .temporary_effects_durations
-                .values()
-                .chain(core::iter::once(&dt))
-                .min_by(|a, b| a.partial_cmp(b).expect("Failed to compare floats"))
-                .unwrap(); //will never panic as we chain with once so there is at minimum 1 element
-
-            //walk until next expiring effect
-            self.units_travelled += self.stats.ms() * min_duration; //must be before self.wait() to still benefit from temporary effects
-            self.wait(min_duration);
-            dt -= min_duration;
-        }
+    /// Walk until the first of the given cooldowns that is not ready yet (i.e. non-zero) ends,
+    /// without exceeding the fight duration.
+    pub fn walk_until_ready<const N: usize>(&mut self, cooldowns: [f32; N], fight_duration: f32) {
+        let dt: f32 = cooldowns
+            .into_iter()
+            .filter(|&cooldown| cooldown > 0.)
+            .fold(fight_duration - self.time, f32::min);
+        self.walk(dt);
     }
 
     pub fn init_fight(&mut self) {
         //simulation timings & variables
         self.time = 0.;
-        self.basic_attack_cd = 0.;
-        self.q_cd = 0.;
-        self.w_cd = 0.;
-        self.e_cd = 0.;
-        self.r_cd = 0.;
+        self.set_basic_attack_cd(0.);
+        //abilities that are not lvled up yet are never ready
+        self.set_q_cd(if self.q_lvl == 0 { f32::INFINITY } else { 0. });
+        self.set_w_cd(if self.w_lvl == 0 { f32::INFINITY } else { 0. });
+        self.set_e_cd(if self.e_lvl == 0 { f32::INFINITY } else { 0. });
+        self.set_r_cd(if self.r_lvl == 0 { f32::INFINITY } else { 0. });
         self.dmg_done = PartDmg(0., 0., 0.);
         self.periodic_heals_shields = 0.;
         self.single_use_heals_shields = 0.;
+        self.heals_received = 0.;
+        self.shields_received = 0.;
+        self.lifeline_shields_received = 0.;
         self.units_travelled = 0.;
+        self.target_hp_lost = 0.;
+        self.time_to_kill = None;
 
         //init stats (runes are done later, need to do it after items passives init)
         self.stats.clear();
@@ -1661,16 +2028,28 @@ impl Unit {
         //reset temporary effects
         self.effects_stacks.clear(); //this is not really needed since we init the variables later, but we do it to clear unused variables for debugging convenience
         self.effects_values.clear(); //same as above
-        self.temporary_effects_durations.clear(); //this is needed to remove every temporary effects
+        self.temporary_effects_expiries.clear(); //this is needed to remove every temporary effects
         self.temporary_effects_cooldowns.clear(); //same as above
+        self.events.clear(); //events from a previous fight are obsolete
+        self.dmg_instances = 0;
 
         //init effect variables and temporary effects on the unit (after effects reset)
         self.all_on_fight_init();
+        self.items_passives_on_fight_init();
 
         //runes stats (after items passives init)
         self.update_runes_stats();
         self.stats.add(&self.runes_stats);
 
+        //hp tracking (after stats init)
+        self.init_incoming_dmg();
+
+        //positions (after stats init)
+        self.init_positioning();
+
+        //teamfight targets hp
+        self.init_teamfight();
+
         //reset actions logs
         self.actions_log.clear();
     }
@@ -1712,33 +2091,14 @@ impl Unit {
         dmg_tags: EnumSet<DmgTag>,
         n_targets: f32,
     ) -> PartDmg {
-        //calculation order: flat res reduction -> % res reduction -> % res penetration -> flat res penetration (i.e. lethality for armor)
-        //calculate res before applying effects
-        let mut virtual_armor: f32 = target_stats.armor - self.stats.armor_red_flat; //flat armor reduction, can reduce armor below 0
-        let armor_coef: f32;
-        if virtual_armor > 0. {
-            //% armor reduction, % armor penetration and lethality cannot reduce armor below 0
-            virtual_armor *= 1. - self.stats.armor_red_percent; //% armor reduction
-            virtual_armor *= 1. - self.stats.armor_pen_percent; //% armor penetration
-            virtual_armor = f32::max(0., virtual_armor - self.stats.lethality); //lethality, cannot reduce armor below 0
-
-            armor_coef = resistance_formula_pos(virtual_armor);
-        } else {
-            armor_coef = resistance_formula_neg(virtual_armor);
-        }
-
-        let mut virtual_mr: f32 = target_stats.mr - self.stats.mr_red_flat; //flat mr reduction, can reduce mr below 0
-        let mr_coef: f32;
-        if virtual_mr > 0. {
-            //% mr reduction, % magic penetration and flat magic penetration cannot reduce mr below 0
-            virtual_mr *= 1. - self.stats.mr_red_percent; //% mr reduction
-            virtual_mr *= 1. - self.stats.magic_pen_percent; //% magic penetration
-            virtual_mr = f32::max(0., virtual_mr - self.stats.magic_pen_flat); //flat magic pen, cannot reduce mr below 0
-
-            mr_coef = resistance_formula_pos(virtual_mr);
-        } else {
-            mr_coef = resistance_formula_neg(virtual_mr);
-        }
+        //calculate res before applying effects (during a teamfight, use the resistances of the current main target)
+        let (target_armor, target_mr): (f32, f32) = self
+            .teamfight_current_target_stats()
+            .map_or((target_stats.armor, target_stats.mr), |stats| {
+                (stats.armor, stats.mr)
+            });
+        let res_coefs: (f32, f32) =
+            self.resistance_coefs(target_armor, target_mr, self.resistances_reductions());
 
         //use stats values before they get modified by effects
         let life_steal: f32 = self.stats.life_steal;
@@ -1770,13 +2130,14 @@ impl Unit {
             //runaans increases the number of targets hit by on-basic-attack-hit
             //exceptionally, use runaans variables here (shouldn't because outside of module, but I didn't find a better way)
             let basic_attack_n_targets: f32 = if self.build.contains(&&Item::RUNAANS_HURRICANE) {
-                n_targets * (1. + RUNAANS_HURRICANE_WINDS_FURY_AVG_BOLTS)
+                n_targets * (1. + runaans_hurricane_winds_fury_bolts(self))
             } else {
                 n_targets
             };
             for _ in 0..n_stacking_instances {
                 part_dmg +=
                     self.all_on_basic_attack_hit(target_stats, basic_attack_n_targets, false);
+                part_dmg += self.items_passives_on_basic_attack_hit(basic_attack_n_targets);
             }
         }
 
@@ -1806,29 +2167,155 @@ impl Unit {
             part_dmg += self.all_on_any_hit(target_stats);
         }
 
-        self.time += F32_TOL; //to differentiate different dmg instances
+        self.dmg_instances += 1; //to differentiate different dmg instances
 
         //dmg modifiers
-        part_dmg.0 *= armor_coef * (1. + phys_dmg_modifier);
-        part_dmg.1 *= mr_coef * (1. + magic_dmg_modifier);
+        part_dmg.0 *= 1. + phys_dmg_modifier;
+        part_dmg.1 *= 1. + magic_dmg_modifier;
         part_dmg.2 *= 1. + true_dmg_modifier;
         part_dmg *= 1. + tot_dmg_modifier;
 
+        //resistances (during a teamfight, dmg is split between the enemy units)
+        let teamfight_dmg: Option<TeamfightDmg> = if let Some((dmg_done, targets_dmg)) = self
+            .teamfight_split_dmg(
+                part_dmg,
+                n_targets,
+                res_coefs,
+                dmg_tags.contains(DmgTag::AroundTarget),
+            ) {
+            part_dmg = dmg_done;
+            Some(targets_dmg)
+        } else {
+            part_dmg.0 *= res_coefs.0;
+            part_dmg.1 *= res_coefs.1;
+            None
+        };
+
         //update simulation logs
         let tot_dmg: f32 = part_dmg.as_sum();
         //omnivamp
-        self.periodic_heals_shields += tot_dmg * omnivamp;
+        self.add_heal(tot_dmg * omnivamp, true);
         //lifesteal
         if dmg_tags.contains(DmgTag::BasicAttack) {
-            self.periodic_heals_shields += tot_dmg * life_steal;
+            self.add_heal(tot_dmg * life_steal, true);
         }
 
-        //dmg done
-        self.dmg_done += part_dmg;
+        //dmg done (basic attacks projectiles hit the target after their travel time)
+        //abilities that apply on-hit effects have the `BasicAttack` tag but don't travel with a basic attack projectile
+        let travel_time: f32 = if self.in_basic_attack {
+            self.basic_attack_travel_time()
+        } else {
+            0.
+        };
+        if let Some(targets_dmg) = teamfight_dmg {
+            if travel_time > 0. {
+                self.events.push(
+                    self.time + travel_time,
+                    SimEvent::TeamfightDmgLanding(part_dmg, targets_dmg),
+                );
+            } else {
+                self.land_teamfight_dmg(part_dmg, &targets_dmg);
+            }
+            return part_dmg;
+        }
+        let target_dmg: f32 = tot_dmg / f32::max(1., n_targets);
+        if travel_time > 0. {
+            self.events.push(
+                self.time + travel_time,
+                SimEvent::DmgLanding(part_dmg, target_stats.hp, target_dmg),
+            );
+        } else {
+            self.land_dmg(part_dmg, target_stats.hp, target_dmg);
+        }
         part_dmg
     }
 
-    /// Triggers every item actives on the unit and returns dmg done.
+    /// Returns the resistances reductions of the unit on its main target (flat armor, % armor, flat mr, % mr).
+    fn resistances_reductions(&self) -> ResistancesReductions {
+        (
+            self.stats.armor_red_flat,
+            self.stats.armor_red_percent,
+            self.stats.mr_red_flat,
+            self.stats.mr_red_percent,
+        )
+    }
+
+    /// Returns the dmg coefs (armor coef, mr coef) for the given target resistances, after the given resistances reductions
+    /// (see `Unit::resistances_reductions`, they differ between the enemy units of a teamfight) and penetration.
+    fn resistance_coefs(
+        &self,
+        armor: f32,
+        mr: f32,
+        (armor_red_flat, armor_red_percent, mr_red_flat, mr_red_percent): ResistancesReductions,
+    ) -> (f32, f32) {
+        //calculation order: flat res reduction -> % res reduction -> % res penetration -> flat res penetration (i.e. lethality for armor)
+        let mut virtual_armor: f32 = armor - armor_red_flat; //flat armor reduction, can reduce armor below 0
+        let armor_coef: f32;
+        if virtual_armor > 0. {
+            //% armor reduction, % armor penetration and lethality cannot reduce armor below 0
+            virtual_armor *= 1. - armor_red_percent; //% armor reduction
+            virtual_armor *= 1. - self.stats.armor_pen_percent; //% armor penetration
+            virtual_armor = f32::max(0., virtual_armor - self.stats.lethality); //lethality, cannot reduce armor below 0
+
+            armor_coef = resistance_formula_pos(virtual_armor);
+        } else {
+            armor_coef = resistance_formula_neg(virtual_armor);
+        }
+
+        let mut virtual_mr: f32 = mr - mr_red_flat; //flat mr reduction, can reduce mr below 0
+        let mr_coef: f32;
+        if virtual_mr > 0. {
+            //% mr reduction, % magic penetration and flat magic penetration cannot reduce mr below 0
+            virtual_mr *= 1. - mr_red_percent; //% mr reduction
+            virtual_mr *= 1. - self.stats.magic_pen_percent; //% magic penetration
+            virtual_mr = f32::max(0., virtual_mr - self.stats.magic_pen_flat); //flat magic pen, cannot reduce mr below 0
+
+            mr_coef = resistance_formula_pos(virtual_mr);
+        } else {
+            mr_coef = resistance_formula_neg(virtual_mr);
+        }
+        (armor_coef, mr_coef)
+    }
+
+    /// Records post mitigation dmg done and updates the hp of the main target (with hp `target_hp`) with `target_dmg`.
+    fn land_dmg(&mut self, part_dmg: PartDmg, target_hp: f32, target_dmg: f32) {
+        self.dmg_done += part_dmg;
+        self.target_receives_dmg(target_hp, target_dmg);
+    }
+
+    /// Records post mitigation dmg done and updates the hp of the enemy units of a teamfight.
+    fn land_teamfight_dmg(&mut self, part_dmg: PartDmg, targets_dmg: &TeamfightDmg) {
+        self.dmg_done += part_dmg;
+        self.teamfight_targets_receive_dmg(targets_dmg);
+    }
+
+    /// Updates the hp of the main target (with hp `target_hp`) with the given post mitigation dmg.
+    /// When the target dies, the remaining dmg is carried over to a new target with full hp.
+    fn target_receives_dmg(&mut self, target_hp: f32, dmg: f32) {
+        if target_hp <= 0. {
+            return;
+        }
+        self.target_hp_lost += dmg;
+        if self.target_hp_lost >= target_hp {
+            self.time_to_kill.get_or_insert(self.time);
+            self.target_hp_lost %= target_hp;
+        }
+    }
+
+    /// Kills the main target by dealing its remaining hp as true dmg (not affected by dmg modifiers) and returns dmg done.
+    pub(crate) fn execute_target(&mut self, target_stats: &UnitStats) -> PartDmg {
+        if let Some(remaining_hp) = self.teamfight_execute_current_target() {
+            let execute_dmg: PartDmg = PartDmg(0., 0., remaining_hp);
+            self.dmg_done += execute_dmg;
+            return execute_dmg;
+        }
+        let execute_dmg: PartDmg = PartDmg(0., 0., self.get_target_current_hp(target_stats));
+        self.dmg_done += execute_dmg;
+        self.target_receives_dmg(target_stats.hp, execute_dmg.2);
+        execute_dmg
+    }
+
+    /// Triggers every item actives and summoner spells actives on the unit and returns dmg done.
     pub fn use_all_special_actives(&mut self, target_stats: &UnitStats) -> PartDmg {
         //save log
         self.actions_log
@@ -1839,6 +2326,9 @@ impl Unit {
 
     /// Performs a basic attack and returns dmg done.
     pub fn basic_attack(&mut self, target_stats: &UnitStats) -> PartDmg {
+        //get in range of the target before attacking
+        self.walk_in_basic_attack_range();
+
         //save log
         self.actions_log.push((self.time, UnitAction::BasicAttack));
 
@@ -1855,20 +2345,29 @@ impl Unit {
         self.all_on_basic_attack_cast();
 
         //set cd
-        self.basic_attack_cd = f32::max(
+        self.set_basic_attack_cd(f32::max(
             0.,
             1. / f32::min(
                 self.properties.as_limit,
                 self.stats.attack_speed(self.properties.as_ratio),
             ) - windup_time,
-        ); //limit as cd to the unit as limit
+        )); //limit as cd to the unit as limit
 
-        //return dmg
-        (self.properties.basic_attack)(self, target_stats)
+        //return dmg (dealt with the projectile of the basic attack)
+        self.in_basic_attack = true;
+        let dmg: PartDmg = (self.properties.basic_attack)(self, target_stats);
+        self.in_basic_attack = false;
+        dmg
     }
 
-    /// cast q and returns dmg done.
+    /// cast q and returns dmg done (does nothing if q is not lvled up yet).
     pub fn q(&mut self, target_stats: &UnitStats) -> PartDmg {
+        //cannot be cast before being lvled up (the cooldown is reset in case an effect refunded it)
+        if self.q_lvl == 0 {
+            self.set_q_cd(f32::INFINITY);
+            return PartDmg(0., 0., 0.);
+        }
+
         //save log
         self.actions_log.push((self.time, UnitAction::Q));
 
@@ -1879,15 +2378,23 @@ impl Unit {
         self.all_on_ability_cast();
 
         //set cd
-        self.q_cd = haste_formula(self.stats.ability_haste_basic())
-            * self.properties.q.base_cooldown_by_ability_lvl[usize::from(self.q_lvl - 1)];
+        self.set_q_cd(
+            haste_formula(self.stats.ability_haste_basic())
+                * self.properties.q.base_cooldown_by_ability_lvl[usize::from(self.q_lvl - 1)],
+        );
 
         //return dmg
         (self.properties.q.cast)(self, target_stats)
     }
 
-    /// cast w and returns dmg done.
+    /// cast w and returns dmg done (does nothing if w is not lvled up yet).
     pub fn w(&mut self, target_stats: &UnitStats) -> PartDmg {
+        //cannot be cast before being lvled up (the cooldown is reset in case an effect refunded it)
+        if self.w_lvl == 0 {
+            self.set_w_cd(f32::INFINITY);
+            return PartDmg(0., 0., 0.);
+        }
+
         //save log
         self.actions_log.push((self.time, UnitAction::W));
 
@@ -1898,15 +2405,23 @@ impl Unit {
         self.all_on_ability_cast();
 
         //set cd
-        self.w_cd = haste_formula(self.stats.ability_haste_basic())
-            * self.properties.w.base_cooldown_by_ability_lvl[usize::from(self.w_lvl - 1)];
+        self.set_w_cd(
+            haste_formula(self.stats.ability_haste_basic())
+                * self.properties.w.base_cooldown_by_ability_lvl[usize::from(self.w_lvl - 1)],
+        );
 
         //return dmg
         (self.properties.w.cast)(self, target_stats)
     }
 
-    /// cast e and returns dmg done.
+    /// cast e and returns dmg done (does nothing if e is not lvled up yet).
     pub fn e(&mut self, target_stats: &UnitStats) -> PartDmg {
+        //cannot be cast before being lvled up (the cooldown is reset in case an effect refunded it)
+        if self.e_lvl == 0 {
+            self.set_e_cd(f32::INFINITY);
+            return PartDmg(0., 0., 0.);
+        }
+
         //save log
         self.actions_log.push((self.time, UnitAction::E));
 
@@ -1917,15 +2432,23 @@ impl Unit {
         self.all_on_ability_cast();
 
         //set cd
-        self.e_cd = haste_formula(self.stats.ability_haste_basic())
-            * self.properties.e.base_cooldown_by_ability_lvl[usize::from(self.e_lvl - 1)];
+        self.set_e_cd(
+            haste_formula(self.stats.ability_haste_basic())
+                * self.properties.e.base_cooldown_by_ability_lvl[usize::from(self.e_lvl - 1)],
+        );
 
         //return dmg
         (self.properties.e.cast)(self, target_stats)
     }
 
-    /// cast r and returns dmg done.
+    /// cast r and returns dmg done (does nothing if r is not lvled up yet).
     pub fn r(&mut self, target_stats: &UnitStats) -> PartDmg {
+        //cannot be cast before being lvled up (the cooldown is reset in case an effect refunded it)
+        if self.r_lvl == 0 {
+            self.set_r_cd(f32::INFINITY);
+            return PartDmg(0., 0., 0.);
+        }
+
         //save log
         self.actions_log.push((self.time, UnitAction::R));
 
@@ -1937,8 +2460,10 @@ impl Unit {
         self.all_on_ultimate_cast();
 
         //set cd
-        self.r_cd = haste_formula(self.stats.ability_haste_ultimate())
-            * self.properties.r.base_cooldown_by_ability_lvl[usize::from(self.r_lvl - 1)];
+        self.set_r_cd(
+            haste_formula(self.stats.ability_haste_ultimate())
+                * self.properties.r.base_cooldown_by_ability_lvl[usize::from(self.r_lvl - 1)],
+        );
 
         //return dmg
         (self.properties.r.cast)(self, target_stats)
@@ -1951,12 +2476,16 @@ impl Unit {
         let phys_dmg_done_before_r: f32 = self.dmg_done.0;
         let magic_dmg_done_before_r: f32 = self.dmg_done.1;
         let true_dmg_done_before_r: f32 = self.dmg_done.2;
+        let target_hp_lost_before_r: f32 = self.target_hp_lost;
+        let teamfight_hp_lost_before_r: Option<TeamfightDmg> = self.teamfight_hp_lost_by_target();
 
         let periodic_heals_shields_before_r: f32 = self.periodic_heals_shields;
         let single_use_heals_shields_before_r: f32 = self.single_use_heals_shields;
+        let heals_received_before_r: f32 = self.heals_received;
+        let shields_received_before_r: f32 = self.shields_received;
         let units_travelled_before_r: f32 = self.units_travelled;
         self.r(target_stats);
-        let percent_to_remove: f32 = 1. - effect_availability_formula(self.r_cd);
+        let percent_to_remove: f32 = 1. - self.economy.effect_availability_formula(self.r_cd());
 
         let phys_dmg: f32 = self.dmg_done.0 - phys_dmg_done_before_r;
         let magic_dmg: f32 = self.dmg_done.1 - magic_dmg_done_before_r;
@@ -1964,11 +2493,22 @@ impl Unit {
         self.dmg_done.0 -= percent_to_remove * phys_dmg;
         self.dmg_done.1 -= percent_to_remove * magic_dmg;
         self.dmg_done.2 -= percent_to_remove * true_dmg;
+        if self.target_hp_lost >= target_hp_lost_before_r {
+            //only if the target didn't die during the r
+            self.target_hp_lost -=
+                percent_to_remove * (self.target_hp_lost - target_hp_lost_before_r);
+        }
+        if let Some(hp_lost_before_r) = teamfight_hp_lost_before_r {
+            self.teamfight_reduce_hp_lost_since(&hp_lost_before_r, percent_to_remove);
+        }
 
         self.periodic_heals_shields -=
             percent_to_remove * (self.periodic_heals_shields - periodic_heals_shields_before_r);
         self.single_use_heals_shields -=
             percent_to_remove * (self.single_use_heals_shields - single_use_heals_shields_before_r);
+        self.heals_received -= percent_to_remove * (self.heals_received - heals_received_before_r);
+        self.shields_received -=
+            percent_to_remove * (self.shields_received - shields_received_before_r);
         self.units_travelled -=
             percent_to_remove * (self.units_travelled - units_travelled_before_r);
 
@@ -1991,8 +2531,10 @@ impl Unit {
         );
 
         self.init_fight();
+        self.start_target_movement(target_stats);
         self.use_all_special_actives(target_stats);
         (self.properties.fight_scenarios[index].0)(self, target_stats, fight_duration);
+        self.land_pending_dmg();
     }
 }
 
@@ -2055,7 +2597,92 @@ mod tests {
 
     /// Test that the target dummy properties are valid.
     #[test]
+    #[allow(unused_must_use)]
     pub fn test_target_dummy_properties() {
         Unit::new_target_dummy(); //can panic inside if `TARGET_DUMMY_PROPERTIES` is invalid
     }
+
+    /// Test that the target hp is tracked during the fight and that the time to kill is recorded.
+    #[test]
+    pub fn test_target_hp_tracking() {
+        let target: Unit = Unit::new_target_dummy();
+        let mut champ: Unit =
+            Unit::from_properties_defaults(&Unit::ASHE_PROPERTIES, 11, Build::default())
+                .expect("Failed to create unit");
+
+        champ.init_fight();
+        assert!(champ.get_target_missing_hp_percent(target.get_stats()) == 0.);
+        champ.basic_attack(target.get_stats());
+        champ.wait(champ.basic_attack_travel_time()); //wait for the projectile to hit
+        assert!(champ.get_target_missing_hp_percent(target.get_stats()) > 0.);
+        let extrapolated_time_to_kill: f32 = champ.get_time_to_kill(target.get_stats());
+        assert!(extrapolated_time_to_kill > champ.get_time());
+
+        //the target dies during a long fight
+        champ.simulate_fight(target.get_stats(), 0, 60.);
+        assert!(champ.get_dmg_done().as_sum() > target.get_stats().hp);
+        assert!(champ.get_time_to_kill(target.get_stats()) < champ.get_time());
+    }
+
+    /// Test that fights can be simulated at low lvls, when some abilities are not lvled up yet.
+    #[test]
+    pub fn test_low_lvls_fights() {
+        let target: Unit = Unit::new_target_dummy();
+        for properties in Unit::ALL_CHAMPIONS.iter() {
+            for lvl in MIN_UNIT_LVL..6 {
+                let mut champ: Unit =
+                    Unit::from_properties_defaults(properties, lvl, Build::default())
+                        .expect("Failed to create unit");
+                for index in 0..properties.fight_scenarios.len() {
+                    champ.simulate_fight(target.get_stats(), index, 6.);
+                    let dmg_done: f32 = champ.get_dmg_done().as_sum();
+                    assert!(
+                        dmg_done.is_finite() && dmg_done > 0.,
+                        "Invalid dmg done by '{}' at lvl {lvl} (got {dmg_done})",
+                        properties.name
+                    );
+                }
+            }
+        }
+    }
+
+    /// Test that every max order gives a valid skill order and can be parsed back from its string representation.
+    #[test]
+    pub fn test_max_orders() {
+        for max_order in MaxOrder::all() {
+            if let Err(error_msg) = max_order.to_skill_order().check_skill_order_validity(false) {
+                panic!("Skill order for max order {max_order} is not valid: {error_msg}");
+            }
+            assert_eq!(
+                max_order.to_string().parse::<MaxOrder>(),
+                Ok(max_order),
+                "Failed to parse back max order {max_order}"
+            );
+        }
+
+        //q>w>e with early e is the classic skill order
+        let skill_order: SkillOrder = "q>w>e"
+            .parse::<MaxOrder>()
+            .expect("Failed to parse valid max order")
+            .to_skill_order();
+        let classic: SkillOrder = SkillOrder::const_default();
+        assert_eq!(
+            (skill_order.q, skill_order.w, skill_order.e, skill_order.r),
+            (classic.q, classic.w, classic.e, classic.r)
+        );
+
+        //late e only gets its first point at lvl 5
+        let skill_order: SkillOrder = "QWE:late"
+            .parse::<MaxOrder>()
+            .expect("Failed to parse valid max order")
+            .to_skill_order();
+        assert_eq!(
+            skill_order.e.iter().position(|&lvl_up| lvl_up == 1),
+            Some(4)
+        );
+
+        assert!("q>q>e".parse::<MaxOrder>().is_err());
+        assert!("q>w".parse::<MaxOrder>().is_err());
+        assert!("q>w>e:soon".parse::<MaxOrder>().is_err());
+    }
 }
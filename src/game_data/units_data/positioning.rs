@@ -0,0 +1,170 @@
+use super::*;
+
+use items_data::items::rapid_firecannon_sharpshooter_bonus_range;
+
+/// Distance between the unit and its target at the start of a fight.
+/// The unit is in range of its target at the start of the fight if its attack range is at least this value.
+const FIGHT_START_DISTANCE: f32 = 450.;
+
+impl Unit {
+    /// Sets the percentage of time the target spends moving away from the unit during fights (0 for a stationary target).
+    /// When the target moves away, the unit must walk to get back in range before basic attacking,
+    /// so move speed and attack range affect the number of basic attacks landed.
+    pub fn set_target_flee_percent(&mut self, target_flee_percent: f32) -> Result<(), String> {
+        if !target_flee_percent.is_finite() || !(0.0..1.0).contains(&target_flee_percent) {
+            return Err(format!(
+                "Percentage of time the target flees must be between 0% and 100% (excluded) (got {}%)",
+                100. * target_flee_percent
+            ));
+        }
+        self.target_flee_percent = target_flee_percent;
+        Ok(())
+    }
+
+    /// Returns the distance between the unit and its target.
+    #[must_use]
+    #[inline]
+    pub fn get_target_distance(&self) -> f32 {
+        self.target_distance
+    }
+
+    /// Resets the positions at the start of a fight (the target is stationary until `Unit::start_target_movement` is called).
+    pub(crate) fn init_positioning(&mut self) {
+        self.target_distance = FIGHT_START_DISTANCE;
+        self.target_flee_speed = 0.;
+    }
+
+    /// Makes the target move away from the unit during the fight, according to its move speed.
+    pub(crate) fn start_target_movement(&mut self, target_stats: &UnitStats) {
+        self.target_flee_speed = self.target_flee_percent * target_stats.ms();
+    }
+
+    /// Updates the distance to the target after `dt` seconds.
+    /// If the unit is moving, it moves toward the target until it is in range (and then follows it while kiting).
+    pub(crate) fn update_target_distance(&mut self, dt: f32, moving: bool) {
+        let distance_before: f32 = self.target_distance;
+        self.target_distance += self.target_flee_speed * dt;
+        if moving {
+            self.target_distance = f32::max(
+                f32::min(distance_before, self.basic_attack_range()),
+                self.target_distance - self.stats.ms() * dt,
+            );
+        }
+    }
+
+    /// Returns the range of the next basic attack of the unit.
+    #[must_use]
+    pub fn basic_attack_range(&self) -> f32 {
+        self.stats.attack_range + rapid_firecannon_sharpshooter_bonus_range(self)
+    }
+
+    /// Walks toward the target until it is in range of the next basic attack.
+    pub(crate) fn walk_in_basic_attack_range(&mut self) {
+        let range: f32 = self.basic_attack_range();
+        let gap: f32 = self.target_distance - range;
+        if gap <= 0. {
+            return;
+        }
+        //the target cannot move away all the time (it has to fight back),
+        //so the unit closes in at least while the target isn't moving
+        let closing_speed: f32 = f32::max(
+            self.stats.ms() - self.target_flee_speed,
+            (1. - self.target_flee_percent) * self.stats.ms(),
+        );
+        self.walk(gap / closing_speed);
+        self.target_distance = f32::min(self.target_distance, range);
+    }
+
+    /// Returns the travel time of a basic attack projectile launched now (0 if basic attacks land instantly).
+    #[must_use]
+    pub(crate) fn basic_attack_travel_time(&self) -> f32 {
+        if self.properties.projectile_speed <= 0. {
+            return 0.;
+        }
+        self.target_distance
+            / f32::max(
+                self.properties.projectile_speed - self.target_flee_speed,
+                1.,
+            )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Test that basic attacks projectiles still in flight at the end of the fight are landed
+    /// and that abilities with the `BasicAttack` tag aren't delayed by the projectile travel time.
+    #[test]
+    pub fn test_basic_attack_projectiles() {
+        let target: Unit = Unit::new_target_dummy();
+        let mut champ: Unit =
+            Unit::from_properties_defaults(&Unit::ASHE_PROPERTIES, 11, Build::default())
+                .expect("Failed to create unit");
+
+        champ.simulate_fight(target.get_stats(), 0, 3.);
+        let dmg_done: f32 = champ.get_dmg_done().as_sum();
+        assert!(dmg_done > 0.);
+        champ.wait(1.);
+        assert!(champ.get_dmg_done().as_sum() == dmg_done);
+
+        champ.init_fight();
+        assert!(champ.basic_attack_travel_time() > 0.);
+        let dmg: PartDmg = champ.dmg_on_target(
+            target.get_stats(),
+            PartDmg(100., 0., 0.),
+            (1, 1),
+            enum_set!(DmgTag::Ability | DmgTag::BasicAttack),
+            1.,
+        );
+        assert!(champ.get_dmg_done().as_sum() == dmg.as_sum());
+    }
+
+    /// Test that a fleeing target reduces the number of basic attacks landed
+    /// and that rapid firecannon increases the range of energized basic attacks.
+    #[test]
+    pub fn test_target_positioning() {
+        let target: Unit = Unit::new_target_dummy();
+        let mut champ: Unit =
+            Unit::from_properties_defaults(&Unit::ASHE_PROPERTIES, 11, Build::default())
+                .expect("Failed to create unit");
+        let count_basic_attacks = |champ: &Unit| -> usize {
+            champ
+                .get_actions_log()
+                .iter()
+                .filter(|(_, action)| matches!(action, UnitAction::BasicAttack))
+                .count()
+        };
+
+        champ.simulate_fight(target.get_stats(), 0, 10.);
+        let stationary_basic_attacks: usize = count_basic_attacks(&champ);
+        let stationary_dmg: f32 = champ.get_dmg_done().as_sum();
+
+        champ
+            .set_target_flee_percent(0.5)
+            .expect("Failed to set target flee percent");
+        champ.simulate_fight(target.get_stats(), 0, 10.);
+        assert!(count_basic_attacks(&champ) < stationary_basic_attacks);
+        assert!(champ.get_dmg_done().as_sum() < stationary_dmg);
+        assert!(champ.units_travelled > 0.);
+
+        let mut rfc_champ: Unit = Unit::from_properties_defaults(
+            &Unit::ASHE_PROPERTIES,
+            11,
+            Build([
+                &Item::RAPID_FIRECANNON,
+                &Item::NULL_ITEM,
+                &Item::NULL_ITEM,
+                &Item::NULL_ITEM,
+                &Item::NULL_ITEM,
+                &Item::NULL_ITEM,
+            ]),
+        )
+        .expect("Failed to create unit");
+        rfc_champ.init_fight();
+        let base_range: f32 = rfc_champ.stats.attack_range;
+        assert!(rfc_champ.basic_attack_range() > base_range); //first basic attack is energized
+        rfc_champ.basic_attack(target.get_stats());
+        assert!(rfc_champ.basic_attack_range() == base_range);
+    }
+}
@@ -1,11 +1,32 @@
 use super::*;
 
 use core::fmt;
+use core::iter::zip;
+use core::str::FromStr;
+
+/// Runes tree, keystones and minor runes belong to one of them.
+#[allow(dead_code)] //each tree doesn't have implemented runes yet
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RuneTree {
+    Precision,
+    Domination,
+    Sorcery,
+    Resolve,
+    Inspiration,
+}
+
+impl fmt::Display for RuneTree {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{self:?}")
+    }
+}
 
 #[derive(Debug)]
 pub struct RuneKeystone {
     pub full_name: &'static str,
     pub short_name: &'static str,
+    /// Tree of the keystone (None for the empty keystone).
+    pub tree: Option<RuneTree>,
     pub on_action_fns: OnActionFns,
 }
 
@@ -19,24 +40,112 @@ impl fmt::Display for RuneKeystone {
     }
 }
 
+impl PartialEq for RuneKeystone {
+    fn eq(&self, other: &Self) -> bool {
+        self.full_name == other.full_name //every keystone has a different name
+    }
+}
+impl Eq for RuneKeystone {}
+
+/// Rune under the keystone of a runes tree (not to be confused with runes shards).
+#[derive(Debug)]
+pub struct MinorRune {
+    pub full_name: &'static str,
+    pub short_name: &'static str,
+    /// Tree of the rune (None for the empty minor rune).
+    pub tree: Option<RuneTree>,
+    /// Row of the rune in its tree, from 1 to 3 (row 0 being the keystones row).
+    pub row: u8,
+    pub on_action_fns: OnActionFns,
+}
+
+impl fmt::Display for MinorRune {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            f.write_str(self.full_name)
+        } else {
+            f.write_str(self.short_name)
+        }
+    }
+}
+
+impl PartialEq for MinorRune {
+    fn eq(&self, other: &Self) -> bool {
+        self.full_name == other.full_name //every minor rune has a different name
+    }
+}
+impl Eq for MinorRune {}
+
 #[allow(dead_code)] //each shard is not always used
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum RuneShard {
     Left,
     Middle,
     Right,
 }
 
+impl RuneShard {
+    /// Every choice of a runes shard row.
+    pub const ALL: [RuneShard; 3] = [RuneShard::Left, RuneShard::Middle, RuneShard::Right];
+}
+
 /// Represents the runes page of a champion.
 /// Doesn't implement
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct RunesPage {
     pub keystone: &'static RuneKeystone,
+    /// Minor runes of the keystone tree, one per row (from row 1 to 3).
+    pub primary_runes: [&'static MinorRune; 3],
+    /// Minor runes of the secondary tree (different from the keystone tree), from two different rows.
+    pub secondary_runes: [&'static MinorRune; 2],
     pub shard1: RuneShard,
     pub shard2: RuneShard,
     pub shard3: RuneShard,
 }
 
+impl FromStr for RuneShard {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "left" | "l" => Ok(RuneShard::Left),
+            "middle" | "m" => Ok(RuneShard::Middle),
+            "right" | "r" => Ok(RuneShard::Right),
+            _ => Err(format!(
+                "'{s}' is not a recognized rune shard (expected left, middle or right)"
+            )),
+        }
+    }
+}
+
+impl fmt::Display for RunesPage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:#}", self.keystone)?;
+        let names = |runes: &[&MinorRune]| -> Vec<&str> {
+            runes
+                .iter()
+                .filter(|&&rune| *rune != MinorRune::EMPTY_MINOR_RUNE)
+                .map(|rune| rune.short_name)
+                .collect()
+        };
+        let primary_names: Vec<&str> = names(&self.primary_runes);
+        let secondary_names: Vec<&str> = names(&self.secondary_runes);
+        if !primary_names.is_empty() || !secondary_names.is_empty() {
+            write!(
+                f,
+                " [{} | {}]",
+                primary_names.join(", "),
+                secondary_names.join(", ")
+            )?;
+        }
+        write!(
+            f,
+            " (shards: {:?}, {:?}, {:?})",
+            self.shard1, self.shard2, self.shard3
+        )
+    }
+}
+
 impl Default for RunesPage {
     /// Returns runes pages with an empty `RuneKeystone` and only Left `RuneShards`.
     fn default() -> Self {
@@ -48,22 +157,132 @@ impl Default for RunesPage {
 const RUNES_SHARDS_ADAPTIVE_AP: f32 = 9.;
 
 impl RunesPage {
-    /// Returns runes pages with an empty `RuneKeystone` and only Left `RuneShards`.
+    /// Returns runes pages with an empty `RuneKeystone`, empty `MinorRune`s and only Left `RuneShards`.
     /// Provides a default valid value for `SkillOrder` usable in compile time constants (unlike `Default::default()` which is not const).
     #[must_use]
     pub const fn const_default() -> Self {
         Self {
             keystone: &RuneKeystone::EMPTY_RUNE_KEYSTONE,
+            primary_runes: [&MinorRune::EMPTY_MINOR_RUNE; 3],
+            secondary_runes: [&MinorRune::EMPTY_MINOR_RUNE; 2],
             shard1: RuneShard::Left,
             shard2: RuneShard::Left,
             shard3: RuneShard::Left,
         }
     }
 
-    /// In the current state, this function will always succeed because all possible runes pages are valid (but it may change in the future).
+    /// Returns an Err with the corresponding error message if the runes page is invalid:
+    /// - primary runes must be from the keystone tree, each in its own row (no primary runes with the empty keystone).
+    /// - secondary runes must be from the same tree (different from the keystone tree) and from different rows.
+    ///
+    /// Empty minor runes are always allowed.
     pub fn check_validity(&self) -> Result<(), String> {
+        for (slot, rune) in zip(1.., self.primary_runes) {
+            if *rune == MinorRune::EMPTY_MINOR_RUNE {
+                continue;
+            }
+            if rune.tree != self.keystone.tree {
+                return Err(format!(
+                    "Primary rune '{rune:#}' is not from the tree of the keystone '{:#}'",
+                    self.keystone
+                ));
+            }
+            if rune.row != slot {
+                return Err(format!(
+                    "Primary rune '{rune:#}' is from row {} and cannot be in primary slot {slot}",
+                    rune.row
+                ));
+            }
+        }
+
+        let secondary_runes: Vec<&MinorRune> = self
+            .secondary_runes
+            .into_iter()
+            .filter(|&rune| *rune != MinorRune::EMPTY_MINOR_RUNE)
+            .collect();
+        if let Some(rune) = secondary_runes
+            .iter()
+            .find(|rune| rune.tree == self.keystone.tree)
+        {
+            return Err(format!(
+                "Secondary rune '{rune:#}' cannot be from the tree of the keystone '{:#}'",
+                self.keystone
+            ));
+        }
+        if let [rune1, rune2] = secondary_runes[..] {
+            if rune1.tree != rune2.tree {
+                return Err(format!(
+                    "Secondary runes '{rune1:#}' and '{rune2:#}' must be from the same tree"
+                ));
+            }
+            if rune1.row == rune2.row {
+                return Err(format!(
+                    "Secondary runes '{rune1:#}' and '{rune2:#}' must be from different rows"
+                ));
+            }
+        }
         Ok(())
     }
+
+    /// Returns the minor runes of the runes page (primary runes then secondary runes).
+    pub fn minor_runes(&self) -> impl Iterator<Item = &'static MinorRune> {
+        self.primary_runes.into_iter().chain(self.secondary_runes)
+    }
+
+    /// Changes the keystone of the runes page, updating the minor runes so that the page stays valid.
+    ///
+    /// When the keystone tree changes, primary runes are taken from the previous minor runes of the new tree
+    /// (or the first minor rune of the new tree for their row if there is none), so that keystones from different trees
+    /// are compared with a complete page. Secondary runes from the new tree are emptied, if no secondary runes remain,
+    /// the previous primary runes become the secondary runes (the previous tree becomes the secondary tree).
+    /// Primary runes are emptied with the empty keystone.
+    pub fn set_keystone(&mut self, keystone: &'static RuneKeystone) {
+        let old_page: RunesPage = *self;
+        self.keystone = keystone;
+        if keystone.tree == old_page.keystone.tree {
+            return;
+        }
+
+        for (slot, rune) in zip(1.., &mut self.primary_runes) {
+            *rune = old_page
+                .minor_runes()
+                .chain(ALL_MINOR_RUNES.iter().copied())
+                .find(|candidate| {
+                    keystone.tree.is_some()
+                        && candidate.tree == keystone.tree
+                        && candidate.row == slot
+                })
+                .unwrap_or(&MinorRune::EMPTY_MINOR_RUNE);
+        }
+
+        for rune in &mut self.secondary_runes {
+            if rune.tree == keystone.tree {
+                *rune = &MinorRune::EMPTY_MINOR_RUNE;
+            }
+        }
+        if keystone.tree.is_some() && self.secondary_runes == [&MinorRune::EMPTY_MINOR_RUNE; 2] {
+            let mut old_primary_runes = old_page
+                .primary_runes
+                .into_iter()
+                .filter(|rune| **rune != MinorRune::EMPTY_MINOR_RUNE);
+            for rune in &mut self.secondary_runes {
+                *rune = old_primary_runes
+                    .next()
+                    .unwrap_or(&MinorRune::EMPTY_MINOR_RUNE);
+            }
+        }
+    }
+}
+
+/// Returns the value of a rune that scales linearly with lvl (from `lvl_1_value` at lvl 1 to `lvl_18_value` at lvl 18).
+fn rune_value_by_lvl(lvl: NonZeroU8, lvl_1_value: f32, lvl_18_value: f32) -> f32 {
+    lvl_1_value + (lvl_18_value - lvl_1_value) * f32::from(lvl.get() - 1) / 17.
+}
+
+/// Returns the haste to give to `Unit::add_temporary_effect` so that an effect with `base_cooldown`
+/// has a cooldown of `real_cooldown` (for runes whose cooldown scales with lvl).
+fn haste_for_cooldown(base_cooldown: f32, real_cooldown: f32) -> f32 {
+    100. * (base_cooldown / real_cooldown - 1.)
 }
 
 impl Unit {
@@ -123,6 +342,7 @@ impl RuneKeystone {
     pub const EMPTY_RUNE_KEYSTONE: RuneKeystone = RuneKeystone {
         full_name: "Empty keystone",
         short_name: "Empty keystone",
+        tree: None,
         on_action_fns: OnActionFns {
             on_lvl_set: None,
             on_fight_init: None,
@@ -214,10 +434,40 @@ fn press_the_attack_on_basic_attack_hit(
     }
 }
 
+/// Removes press the attack stacks and exposure from the target (when the unit switches target).
+pub(super) fn press_the_attack_reset_exposure(champ: &mut Unit) {
+    if champ.effects_stacks[EffectStackId::PressTheAttackStacks] == PRESS_THE_ATTACK_MAX_STACKS {
+        decrease_exponentially_scaling_stat(
+            &mut champ.stats.phys_dmg_modifier,
+            PRESS_THE_ATTACK_DMG_MODIFIER,
+        );
+        decrease_exponentially_scaling_stat(
+            &mut champ.stats.magic_dmg_modifier,
+            PRESS_THE_ATTACK_DMG_MODIFIER,
+        );
+    }
+    champ.effects_stacks[EffectStackId::PressTheAttackStacks] = 0;
+}
+
+/// Re-adds press the attack exposure if the target is fully stacked (when the unit switches back to a previous target).
+pub(super) fn press_the_attack_restore_exposure(champ: &mut Unit) {
+    if champ.effects_stacks[EffectStackId::PressTheAttackStacks] == PRESS_THE_ATTACK_MAX_STACKS {
+        increase_exponentially_scaling_stat(
+            &mut champ.stats.phys_dmg_modifier,
+            PRESS_THE_ATTACK_DMG_MODIFIER,
+        );
+        increase_exponentially_scaling_stat(
+            &mut champ.stats.magic_dmg_modifier,
+            PRESS_THE_ATTACK_DMG_MODIFIER,
+        );
+    }
+}
+
 impl RuneKeystone {
     pub const PRESS_THE_ATTACK: RuneKeystone = RuneKeystone {
         full_name: "Press the attack",
         short_name: "PTA",
+        tree: Some(RuneTree::Precision),
         on_action_fns: OnActionFns {
             on_lvl_set: None,
             on_fight_init: Some(press_the_attack_init),
@@ -264,6 +514,7 @@ const LETHAL_TEMPO_AS: TemporaryEffect = TemporaryEffect {
     remove_every_stack: lethal_tempo_remove_every_stack,
     duration: 6.,
     cooldown: 0.,
+    restore_target_debuff: None,
 };
 
 fn lethal_tempo_on_basic_attack_cast(champ: &mut Unit) {
@@ -315,6 +566,7 @@ impl RuneKeystone {
     pub const LETHAL_TEMPO: RuneKeystone = RuneKeystone {
         full_name: "Lethal tempo",
         short_name: "Lethal tempo",
+        tree: Some(RuneTree::Precision),
         on_action_fns: OnActionFns {
             on_lvl_set: None,
             on_fight_init: Some(lethal_tempo_init),
@@ -359,6 +611,7 @@ const FLEET_FOOTWORK_MS: TemporaryEffect = TemporaryEffect {
     remove_every_stack: fleet_footwork_ms_disable,
     duration: 1.,
     cooldown: 0.,
+    restore_target_debuff: None,
 };
 
 fn fleet_footwork_on_basic_attack_hit(
@@ -381,9 +634,12 @@ fn fleet_footwork_on_basic_attack_hit(
     }
     //if enough energy (previous condition), trigger energized attack
     champ.effects_values[EffectValueId::FleetFootworkLastTriggerDistance] = champ.units_travelled;
-    champ.periodic_heals_shields += FLEET_FOOTWORK_HEAL_BY_LVL[usize::from(champ.lvl.get() - 1)]
-        + 0.06 * champ.stats.bonus_ad
-        + 0.03 * champ.stats.ap(); //ranged value
+    champ.add_heal(
+        FLEET_FOOTWORK_HEAL_BY_LVL[usize::from(champ.lvl.get() - 1)]
+            + 0.06 * champ.stats.bonus_ad
+            + 0.03 * champ.stats.ap(), //ranged value
+        true,
+    );
     champ.add_temporary_effect(&FLEET_FOOTWORK_MS, 0.);
     PartDmg(0., 0., 0.)
 }
@@ -413,6 +669,7 @@ impl RuneKeystone {
     pub const FLEET_FOOTWORK: RuneKeystone = RuneKeystone {
         full_name: "Fleet footwork",
         short_name: "Fleet",
+        tree: Some(RuneTree::Precision),
         on_action_fns: OnActionFns {
             on_lvl_set: None,
             on_fight_init: Some(fleet_footwork_init),
@@ -438,9 +695,8 @@ fn conqueror_init(champ: &mut Unit) {
     champ.effects_stacks[EffectStackId::ConquerorStacks] = 0;
     champ.effects_values[EffectValueId::ConquerorAdaptiveAP] = 0.;
     champ.effects_values[EffectValueId::ConquerorOmnivamp] = 0.;
-    champ.effects_values[EffectValueId::ConquerorLastAbilityHitTime] = -F32_TOL; //to allow for effect at time == 0
-    champ.effects_values[EffectValueId::ConquerorLastBasicAttackHitTime] = -F32_TOL;
-    //to allow for effect at time == 0
+    champ.effects_values[EffectValueId::ConquerorLastAbilityHitInstance] = -1.; //to allow for effect on the first dmg instance
+    champ.effects_values[EffectValueId::ConquerorLastBasicAttackHitInstance] = -1.;
 }
 
 const CONQUEROR_ADAPTIVE_AP_PER_STACK_BY_LVL: [f32; MAX_UNIT_LVL] = [
@@ -509,6 +765,7 @@ const CONQUEROR: TemporaryEffect = TemporaryEffect {
     remove_every_stack: conqueror_remove_every_stack,
     duration: 5.,
     cooldown: 0.,
+    restore_target_debuff: None,
 };
 
 fn conqueror_on_basic_attack_hit(
@@ -522,8 +779,11 @@ fn conqueror_on_basic_attack_hit(
     }
 
     //set last basic attack hit time only if real basic attack (not ability that applies basic attack effects)
-    if champ.effects_values[EffectValueId::ConquerorLastAbilityHitTime] != champ.time {
-        champ.effects_values[EffectValueId::ConquerorLastBasicAttackHitTime] = champ.time;
+    if champ.effects_values[EffectValueId::ConquerorLastAbilityHitInstance]
+        != champ.get_dmg_instance_id()
+    {
+        champ.effects_values[EffectValueId::ConquerorLastBasicAttackHitInstance] =
+            champ.get_dmg_instance_id();
     }
     PartDmg(0., 0., 0.)
 }
@@ -533,7 +793,8 @@ fn conqueror_on_ability_hit(
     _target_stats: &UnitStats,
     _n_targets: f32,
 ) -> PartDmg {
-    champ.effects_values[EffectValueId::ConquerorLastAbilityHitTime] = champ.time;
+    champ.effects_values[EffectValueId::ConquerorLastAbilityHitInstance] =
+        champ.get_dmg_instance_id();
     PartDmg(0., 0., 0.)
 }
 
@@ -542,7 +803,9 @@ fn conqueror_on_any_hit(champ: &mut Unit, _target_stats: &UnitStats) -> PartDmg
     champ.add_temporary_effect(&CONQUEROR, 0.);
 
     //if not same instance of dmg as last basic attack hit -> apply second stack because not a basic attack
-    if champ.time != champ.effects_values[EffectValueId::ConquerorLastBasicAttackHitTime] {
+    if champ.get_dmg_instance_id()
+        != champ.effects_values[EffectValueId::ConquerorLastBasicAttackHitInstance]
+    {
         champ.add_temporary_effect(&CONQUEROR, 0.);
     }
     PartDmg(0., 0., 0.)
@@ -552,6 +815,7 @@ impl RuneKeystone {
     pub const CONQUEROR: RuneKeystone = RuneKeystone {
         full_name: "Conqueror",
         short_name: "Conqueror",
+        tree: Some(RuneTree::Precision),
         on_action_fns: OnActionFns {
             on_lvl_set: None,
             on_fight_init: Some(conqueror_init),
@@ -570,27 +834,1362 @@ impl RuneKeystone {
     };
 }
 
-//todo: electrocute
+/// Returns adaptive dmg (physical or magic depending on the unit stats).
+fn adaptive_part_dmg(champ: &Unit, adaptive_dmg: f32) -> PartDmg {
+    if champ.adaptive_is_phys() {
+        PartDmg(adaptive_dmg, 0., 0.)
+    } else {
+        PartDmg(0., adaptive_dmg, 0.)
+    }
+}
 
-//todo: dark harvest
+//functions for temporary effects that are only used for their cooldown
+fn cooldown_only_effect_enable(_champ: &mut Unit, _availability_coef: f32) {}
+fn cooldown_only_effect_disable(_champ: &mut Unit) {}
 
-//todo: hail of blades
+//electrocute
+const ELECTROCUTE_STACKS_WINDOW: f32 = 3.;
+fn electrocute_init(champ: &mut Unit) {
+    champ.effects_stacks[EffectStackId::ElectrocuteStacks] = 0;
+    champ.effects_values[EffectValueId::ElectrocuteFirstStackTime] =
+        -(ELECTROCUTE_STACKS_WINDOW + F32_TOL); //to allow for effect at time == 0
+    champ.effects_values[EffectValueId::ElectrocuteLastStackInstance] = -1.; //to allow for effect on the first dmg instance
+}
 
-//todo: summon aery
+/// Removes electrocute stacks from the target (when the unit switches target).
+pub(super) fn electrocute_reset_stacks(champ: &mut Unit) {
+    champ.effects_stacks[EffectStackId::ElectrocuteStacks] = 0;
+    champ.effects_values[EffectValueId::ElectrocuteFirstStackTime] =
+        -(ELECTROCUTE_STACKS_WINDOW + F32_TOL);
+}
 
-//todo: arcane comet
+const ELECTROCUTE_COOLDOWN: f32 = 25.;
+const ELECTROCUTE: TemporaryEffect = TemporaryEffect {
+    id: EffectId::Electrocute,
+    add_stack: cooldown_only_effect_enable,
+    remove_every_stack: cooldown_only_effect_disable,
+    duration: 0.,
+    cooldown: ELECTROCUTE_COOLDOWN,
+    restore_target_debuff: None,
+};
 
-//todo: phase rush
+const ELECTROCUTE_MAX_STACKS: u8 = 3;
+fn electrocute_on_any_hit(champ: &mut Unit, _target_stats: &UnitStats) -> PartDmg {
+    //only separate attacks or abilities give stacks
+    if champ.effects_values[EffectValueId::ElectrocuteLastStackInstance]
+        == champ.get_dmg_instance_id()
+    {
+        return PartDmg(0., 0., 0.);
+    }
+    champ.effects_values[EffectValueId::ElectrocuteLastStackInstance] = champ.get_dmg_instance_id();
 
-//todo: grasp of the undying
+    //if first stack from too long ago, reset stacks and add 1
+    if champ.time - champ.effects_values[EffectValueId::ElectrocuteFirstStackTime]
+        > ELECTROCUTE_STACKS_WINDOW
+    {
+        champ.effects_values[EffectValueId::ElectrocuteFirstStackTime] = champ.time;
+        champ.effects_stacks[EffectStackId::ElectrocuteStacks] = 1;
+        return PartDmg(0., 0., 0.);
+    }
+    champ.effects_stacks[EffectStackId::ElectrocuteStacks] += 1;
+    if champ.effects_stacks[EffectStackId::ElectrocuteStacks] < ELECTROCUTE_MAX_STACKS {
+        return PartDmg(0., 0., 0.);
+    }
 
-//todo: aftershock
+    //if fully stacked (previous conditions), reset stacks and trigger if not on cooldown
+    champ.effects_stacks[EffectStackId::ElectrocuteStacks] = 0;
+    champ.effects_values[EffectValueId::ElectrocuteFirstStackTime] =
+        -(ELECTROCUTE_STACKS_WINDOW + F32_TOL);
+    let haste: f32 = haste_for_cooldown(
+        ELECTROCUTE_COOLDOWN,
+        rune_value_by_lvl(champ.lvl, ELECTROCUTE_COOLDOWN, 20.),
+    );
+    if !champ.add_temporary_effect(&ELECTROCUTE, haste) {
+        return PartDmg(0., 0., 0.);
+    }
+    adaptive_part_dmg(
+        champ,
+        rune_value_by_lvl(champ.lvl, 30., 220.)
+            + 0.10 * champ.stats.bonus_ad
+            + 0.05 * champ.stats.ap(),
+    )
+}
+
+impl RuneKeystone {
+    pub const ELECTROCUTE: RuneKeystone = RuneKeystone {
+        full_name: "Electrocute",
+        short_name: "Electrocute",
+        tree: Some(RuneTree::Domination),
+        on_action_fns: OnActionFns {
+            on_lvl_set: None,
+            on_fight_init: Some(electrocute_init),
+            special_active: None,
+            on_ability_cast: None,
+            on_ultimate_cast: None,
+            on_ability_hit: None,
+            on_ultimate_hit: None,
+            on_basic_attack_cast: None,
+            on_basic_attack_hit: None,
+            on_phys_hit: None,
+            on_magic_hit: None,
+            on_true_dmg_hit: None,
+            on_any_hit: Some(electrocute_on_any_hit),
+        },
+    };
+}
 
-//todo: first strike
+//dark harvest
+const DARK_HARVEST: TemporaryEffect = TemporaryEffect {
+    id: EffectId::DarkHarvest,
+    add_stack: cooldown_only_effect_enable,
+    remove_every_stack: cooldown_only_effect_disable,
+    duration: 0.,
+    cooldown: 45.,
+    restore_target_debuff: None,
+};
 
-pub const ALL_RUNES_KEYSTONES: [&RuneKeystone; 4] = [
-    &RuneKeystone::PRESS_THE_ATTACK,
-    &RuneKeystone::LETHAL_TEMPO,
-    &RuneKeystone::FLEET_FOOTWORK,
-    &RuneKeystone::CONQUEROR,
-];
+const DARK_HARVEST_HP_PERCENT_THRESHOLD: f32 = 0.50;
+/// Souls are collected during the whole game, assumes the unit collected about one soul per lvl.
+const DARK_HARVEST_SOULS_PER_LVL: f32 = 1.;
+fn dark_harvest_on_any_hit(champ: &mut Unit, target_stats: &UnitStats) -> PartDmg {
+    if 1. - champ.get_target_missing_hp_percent(target_stats) >= DARK_HARVEST_HP_PERCENT_THRESHOLD {
+        return PartDmg(0., 0., 0.);
+    }
+    //cooldown reset on takedowns not implemented
+    if !champ.add_temporary_effect(&DARK_HARVEST, 0.) {
+        return PartDmg(0., 0., 0.);
+    }
+    let souls: f32 = DARK_HARVEST_SOULS_PER_LVL * f32::from(champ.lvl.get());
+    adaptive_part_dmg(
+        champ,
+        rune_value_by_lvl(champ.lvl, 20., 60.)
+            + 5. * souls
+            + 0.10 * champ.stats.bonus_ad
+            + 0.05 * champ.stats.ap(),
+    )
+}
+
+impl RuneKeystone {
+    pub const DARK_HARVEST: RuneKeystone = RuneKeystone {
+        full_name: "Dark harvest",
+        short_name: "Dark harvest",
+        tree: Some(RuneTree::Domination),
+        on_action_fns: OnActionFns {
+            on_lvl_set: None,
+            on_fight_init: None,
+            special_active: None,
+            on_ability_cast: None,
+            on_ultimate_cast: None,
+            on_ability_hit: None,
+            on_ultimate_hit: None,
+            on_basic_attack_cast: None,
+            on_basic_attack_hit: None,
+            on_phys_hit: None,
+            on_magic_hit: None,
+            on_true_dmg_hit: None,
+            on_any_hit: Some(dark_harvest_on_any_hit),
+        },
+    };
+}
+
+//hail of blades
+fn hail_of_blades_init(champ: &mut Unit) {
+    champ.effects_stacks[EffectStackId::HailOfBladesRemainingAttacks] = 0;
+    champ.effects_values[EffectValueId::HailOfBladesBonusAS] = 0.;
+}
+
+const HAIL_OF_BLADES_N_ATTACKS: u8 = 3;
+fn hail_of_blades_as_enable(champ: &mut Unit, _availability_coef: f32) {
+    if champ.effects_values[EffectValueId::HailOfBladesBonusAS] == 0. {
+        let bonus_as_buff: f32 = 1.10;
+        champ.stats.bonus_as += bonus_as_buff;
+        champ.effects_values[EffectValueId::HailOfBladesBonusAS] = bonus_as_buff;
+        champ.effects_stacks[EffectStackId::HailOfBladesRemainingAttacks] =
+            HAIL_OF_BLADES_N_ATTACKS;
+    }
+}
+
+fn hail_of_blades_as_disable(champ: &mut Unit) {
+    champ.stats.bonus_as -= champ.effects_values[EffectValueId::HailOfBladesBonusAS];
+    champ.effects_values[EffectValueId::HailOfBladesBonusAS] = 0.;
+    champ.effects_stacks[EffectStackId::HailOfBladesRemainingAttacks] = 0;
+}
+
+const HAIL_OF_BLADES_AS: TemporaryEffect = TemporaryEffect {
+    id: EffectId::HailOfBladesAS,
+    add_stack: hail_of_blades_as_enable,
+    remove_every_stack: hail_of_blades_as_disable,
+    duration: 3.,
+    cooldown: 12.,
+    restore_target_debuff: None,
+};
+
+fn hail_of_blades_on_basic_attack_cast(champ: &mut Unit) {
+    //the basic attack that triggers the effect doesn't consume it, so the next 3 basic attacks are faster
+    if champ.effects_stacks[EffectStackId::HailOfBladesRemainingAttacks] == 0 {
+        champ.add_temporary_effect(&HAIL_OF_BLADES_AS, 0.);
+        return;
+    }
+    champ.effects_stacks[EffectStackId::HailOfBladesRemainingAttacks] -= 1;
+    if champ.effects_stacks[EffectStackId::HailOfBladesRemainingAttacks] == 0 {
+        hail_of_blades_as_disable(champ); //the effect expiry that comes later does nothing
+    }
+}
+
+impl RuneKeystone {
+    pub const HAIL_OF_BLADES: RuneKeystone = RuneKeystone {
+        full_name: "Hail of blades",
+        short_name: "HoB",
+        tree: Some(RuneTree::Domination),
+        on_action_fns: OnActionFns {
+            on_lvl_set: None,
+            on_fight_init: Some(hail_of_blades_init),
+            special_active: None,
+            on_ability_cast: None,
+            on_ultimate_cast: None,
+            on_ability_hit: None,
+            on_ultimate_hit: None,
+            on_basic_attack_cast: Some(hail_of_blades_on_basic_attack_cast),
+            on_basic_attack_hit: None,
+            on_phys_hit: None,
+            on_magic_hit: None,
+            on_true_dmg_hit: None,
+            on_any_hit: None,
+        },
+    };
+}
+
+//summon aery
+const SUMMON_AERY: TemporaryEffect = TemporaryEffect {
+    id: EffectId::SummonAery,
+    add_stack: cooldown_only_effect_enable,
+    remove_every_stack: cooldown_only_effect_disable,
+    duration: 0.,
+    cooldown: 2., //time for aery to come back, assumes the unit stays close to the target
+    restore_target_debuff: None,
+};
+
+fn summon_aery_on_any_hit(champ: &mut Unit, _target_stats: &UnitStats) -> PartDmg {
+    if !champ.add_temporary_effect(&SUMMON_AERY, 0.) {
+        return PartDmg(0., 0., 0.);
+    }
+    adaptive_part_dmg(
+        champ,
+        rune_value_by_lvl(champ.lvl, 10., 50.)
+            + 0.10 * champ.stats.bonus_ad
+            + 0.05 * champ.stats.ap(),
+    )
+}
+
+impl RuneKeystone {
+    pub const SUMMON_AERY: RuneKeystone = RuneKeystone {
+        full_name: "Summon aery",
+        short_name: "Aery",
+        tree: Some(RuneTree::Sorcery),
+        on_action_fns: OnActionFns {
+            on_lvl_set: None,
+            on_fight_init: None,
+            special_active: None,
+            on_ability_cast: None,
+            on_ultimate_cast: None,
+            on_ability_hit: None,
+            on_ultimate_hit: None,
+            on_basic_attack_cast: None,
+            on_basic_attack_hit: None,
+            on_phys_hit: None,
+            on_magic_hit: None,
+            on_true_dmg_hit: None,
+            on_any_hit: Some(summon_aery_on_any_hit),
+        },
+    };
+}
+
+//arcane comet
+const ARCANE_COMET_COOLDOWN: f32 = 20.;
+const ARCANE_COMET: TemporaryEffect = TemporaryEffect {
+    id: EffectId::ArcaneComet,
+    add_stack: cooldown_only_effect_enable,
+    remove_every_stack: cooldown_only_effect_disable,
+    duration: 0.,
+    cooldown: ARCANE_COMET_COOLDOWN,
+    restore_target_debuff: None,
+};
+
+fn arcane_comet_on_ability_hit(
+    champ: &mut Unit,
+    _target_stats: &UnitStats,
+    _n_targets: f32,
+) -> PartDmg {
+    //assumes the comet always hits, cooldown reduction from abilities dmg not implemented
+    let haste: f32 = haste_for_cooldown(
+        ARCANE_COMET_COOLDOWN,
+        rune_value_by_lvl(champ.lvl, ARCANE_COMET_COOLDOWN, 8.),
+    );
+    if !champ.add_temporary_effect(&ARCANE_COMET, haste) {
+        return PartDmg(0., 0., 0.);
+    }
+    adaptive_part_dmg(
+        champ,
+        rune_value_by_lvl(champ.lvl, 30., 130.)
+            + 0.10 * champ.stats.bonus_ad
+            + 0.05 * champ.stats.ap(),
+    )
+}
+
+impl RuneKeystone {
+    pub const ARCANE_COMET: RuneKeystone = RuneKeystone {
+        full_name: "Arcane comet",
+        short_name: "Comet",
+        tree: Some(RuneTree::Sorcery),
+        on_action_fns: OnActionFns {
+            on_lvl_set: None,
+            on_fight_init: None,
+            special_active: None,
+            on_ability_cast: None,
+            on_ultimate_cast: None,
+            on_ability_hit: Some(arcane_comet_on_ability_hit),
+            on_ultimate_hit: None,
+            on_basic_attack_cast: None,
+            on_basic_attack_hit: None,
+            on_phys_hit: None,
+            on_magic_hit: None,
+            on_true_dmg_hit: None,
+            on_any_hit: None,
+        },
+    };
+}
+
+//phase rush
+const PHASE_RUSH_STACKS_WINDOW: f32 = 4.;
+fn phase_rush_init(champ: &mut Unit) {
+    champ.effects_stacks[EffectStackId::PhaseRushStacks] = 0;
+    champ.effects_values[EffectValueId::PhaseRushFirstStackTime] =
+        -(PHASE_RUSH_STACKS_WINDOW + F32_TOL); //to allow for effect at time == 0
+    champ.effects_values[EffectValueId::PhaseRushLastStackInstance] = -1.; //to allow for effect on the first dmg instance
+    champ.effects_values[EffectValueId::PhaseRushMsPercent] = 0.;
+}
+
+fn phase_rush_ms_enable(champ: &mut Unit, _availability_coef: f32) {
+    if champ.effects_values[EffectValueId::PhaseRushMsPercent] == 0. {
+        let ms_percent_buff: f32 = rune_value_by_lvl(champ.lvl, 0.15, 0.40); //ranged value
+        champ.stats.ms_percent += ms_percent_buff;
+        champ.effects_values[EffectValueId::PhaseRushMsPercent] = ms_percent_buff;
+    }
+}
+
+fn phase_rush_ms_disable(champ: &mut Unit) {
+    champ.stats.ms_percent -= champ.effects_values[EffectValueId::PhaseRushMsPercent];
+    champ.effects_values[EffectValueId::PhaseRushMsPercent] = 0.;
+}
+
+/// Removes phase rush stacks from the target (when the unit switches target).
+pub(super) fn phase_rush_reset_stacks(champ: &mut Unit) {
+    champ.effects_stacks[EffectStackId::PhaseRushStacks] = 0;
+    champ.effects_values[EffectValueId::PhaseRushFirstStackTime] =
+        -(PHASE_RUSH_STACKS_WINDOW + F32_TOL);
+}
+
+const PHASE_RUSH_COOLDOWN: f32 = 30.;
+const PHASE_RUSH_MS: TemporaryEffect = TemporaryEffect {
+    id: EffectId::PhaseRushMS,
+    add_stack: phase_rush_ms_enable,
+    remove_every_stack: phase_rush_ms_disable,
+    duration: 3.,
+    cooldown: PHASE_RUSH_COOLDOWN,
+    restore_target_debuff: None,
+};
+
+const PHASE_RUSH_MAX_STACKS: u8 = 3;
+fn phase_rush_on_any_hit(champ: &mut Unit, _target_stats: &UnitStats) -> PartDmg {
+    //only separate attacks or abilities give stacks
+    if champ.effects_values[EffectValueId::PhaseRushLastStackInstance]
+        == champ.get_dmg_instance_id()
+    {
+        return PartDmg(0., 0., 0.);
+    }
+    champ.effects_values[EffectValueId::PhaseRushLastStackInstance] = champ.get_dmg_instance_id();
+
+    //if first stack from too long ago, reset stacks and add 1
+    if champ.time - champ.effects_values[EffectValueId::PhaseRushFirstStackTime]
+        > PHASE_RUSH_STACKS_WINDOW
+    {
+        champ.effects_values[EffectValueId::PhaseRushFirstStackTime] = champ.time;
+        champ.effects_stacks[EffectStackId::PhaseRushStacks] = 1;
+        return PartDmg(0., 0., 0.);
+    }
+    champ.effects_stacks[EffectStackId::PhaseRushStacks] += 1;
+    if champ.effects_stacks[EffectStackId::PhaseRushStacks] < PHASE_RUSH_MAX_STACKS {
+        return PartDmg(0., 0., 0.);
+    }
+
+    //if fully stacked (previous conditions), reset stacks and trigger if not on cooldown
+    champ.effects_stacks[EffectStackId::PhaseRushStacks] = 0;
+    champ.effects_values[EffectValueId::PhaseRushFirstStackTime] =
+        -(PHASE_RUSH_STACKS_WINDOW + F32_TOL);
+    let haste: f32 = haste_for_cooldown(
+        PHASE_RUSH_COOLDOWN,
+        rune_value_by_lvl(champ.lvl, PHASE_RUSH_COOLDOWN, 10.),
+    );
+    champ.add_temporary_effect(&PHASE_RUSH_MS, haste);
+    PartDmg(0., 0., 0.)
+}
+
+impl RuneKeystone {
+    pub const PHASE_RUSH: RuneKeystone = RuneKeystone {
+        full_name: "Phase rush",
+        short_name: "Phase rush",
+        tree: Some(RuneTree::Sorcery),
+        on_action_fns: OnActionFns {
+            on_lvl_set: None,
+            on_fight_init: Some(phase_rush_init),
+            special_active: None,
+            on_ability_cast: None,
+            on_ultimate_cast: None,
+            on_ability_hit: None,
+            on_ultimate_hit: None,
+            on_basic_attack_cast: None,
+            on_basic_attack_hit: None,
+            on_phys_hit: None,
+            on_magic_hit: None,
+            on_true_dmg_hit: None,
+            on_any_hit: Some(phase_rush_on_any_hit),
+        },
+    };
+}
+
+//todo: grasp of the undying
+
+//todo: aftershock
+
+//first strike
+fn first_strike_init(champ: &mut Unit) {
+    champ.effects_values[EffectValueId::FirstStrikeTotDmgModifier] = 0.;
+    //assumes the unit starts the fight by hitting the target
+    let haste: f32 = haste_for_cooldown(
+        FIRST_STRIKE_COOLDOWN,
+        rune_value_by_lvl(champ.lvl, FIRST_STRIKE_COOLDOWN, 15.),
+    );
+    champ.add_temporary_effect(&FIRST_STRIKE, haste);
+}
+
+fn first_strike_enable(champ: &mut Unit, _availability_coef: f32) {
+    if champ.effects_values[EffectValueId::FirstStrikeTotDmgModifier] == 0. {
+        //bonus true dmg equal to 7% of the dmg done, same as a tot dmg modifier since resistances apply linearly
+        let tot_dmg_modifier_buff: f32 = 0.07;
+        increase_exponentially_scaling_stat(
+            &mut champ.stats.tot_dmg_modifier,
+            tot_dmg_modifier_buff,
+        );
+        champ.effects_values[EffectValueId::FirstStrikeTotDmgModifier] = tot_dmg_modifier_buff;
+    }
+}
+
+fn first_strike_disable(champ: &mut Unit) {
+    decrease_exponentially_scaling_stat(
+        &mut champ.stats.tot_dmg_modifier,
+        champ.effects_values[EffectValueId::FirstStrikeTotDmgModifier],
+    );
+    champ.effects_values[EffectValueId::FirstStrikeTotDmgModifier] = 0.;
+}
+
+const FIRST_STRIKE_COOLDOWN: f32 = 25.;
+const FIRST_STRIKE: TemporaryEffect = TemporaryEffect {
+    id: EffectId::FirstStrike,
+    add_stack: first_strike_enable,
+    remove_every_stack: first_strike_disable,
+    duration: 3.,
+    cooldown: FIRST_STRIKE_COOLDOWN,
+    restore_target_debuff: None,
+};
+
+impl RuneKeystone {
+    pub const FIRST_STRIKE: RuneKeystone = RuneKeystone {
+        full_name: "First strike",
+        short_name: "First strike",
+        tree: Some(RuneTree::Inspiration),
+        on_action_fns: OnActionFns {
+            on_lvl_set: None,
+            on_fight_init: Some(first_strike_init),
+            special_active: None,
+            on_ability_cast: None,
+            on_ultimate_cast: None,
+            on_ability_hit: None,
+            on_ultimate_hit: None,
+            on_basic_attack_cast: None,
+            on_basic_attack_hit: None,
+            on_phys_hit: None,
+            on_magic_hit: None,
+            on_true_dmg_hit: None,
+            on_any_hit: None,
+        },
+    };
+}
+
+pub const ALL_RUNES_KEYSTONES: [&RuneKeystone; 11] = [
+    &RuneKeystone::PRESS_THE_ATTACK,
+    &RuneKeystone::LETHAL_TEMPO,
+    &RuneKeystone::FLEET_FOOTWORK,
+    &RuneKeystone::CONQUEROR,
+    &RuneKeystone::ELECTROCUTE,
+    &RuneKeystone::DARK_HARVEST,
+    &RuneKeystone::HAIL_OF_BLADES,
+    &RuneKeystone::SUMMON_AERY,
+    &RuneKeystone::ARCANE_COMET,
+    &RuneKeystone::PHASE_RUSH,
+    &RuneKeystone::FIRST_STRIKE,
+];
+
+impl RuneKeystone {
+    /// Returns the keystone whose full name or short name matches the given name (case insensitive), if any.
+    /// The empty keystone is included in the search.
+    #[must_use]
+    pub fn find_by_name(name: &str) -> Option<&'static RuneKeystone> {
+        let name: String = name.trim().to_lowercase();
+        ALL_RUNES_KEYSTONES
+            .iter()
+            .copied()
+            .chain([&RuneKeystone::EMPTY_RUNE_KEYSTONE])
+            .find(|keystone| {
+                keystone.full_name.to_lowercase() == name
+                    || keystone.short_name.to_lowercase() == name
+            })
+    }
+}
+
+//minor runes
+impl MinorRune {
+    pub const EMPTY_MINOR_RUNE: MinorRune = MinorRune {
+        full_name: "Empty minor rune",
+        short_name: "None",
+        tree: None,
+        row: 0,
+        on_action_fns: OnActionFns {
+            on_lvl_set: None,
+            on_fight_init: None,
+            special_active: None,
+            on_ability_cast: None,
+            on_ultimate_cast: None,
+            on_ability_hit: None,
+            on_ultimate_hit: None,
+            on_basic_attack_cast: None,
+            on_basic_attack_hit: None,
+            on_phys_hit: None,
+            on_magic_hit: None,
+            on_true_dmg_hit: None,
+            on_any_hit: None,
+        },
+    };
+}
+
+/// Replaces the tot dmg modifier given by a minor rune (`rune_tot_dmg_modifier`) by `new_value`.
+/// Dmg modifiers are read before on-hit effects, so the new value only affects the next dmg instances.
+fn update_minor_rune_tot_dmg_modifier(
+    tot_dmg_modifier: &mut f32,
+    rune_tot_dmg_modifier: &mut f32,
+    new_value: f32,
+) {
+    if *rune_tot_dmg_modifier == new_value {
+        return;
+    }
+    decrease_exponentially_scaling_stat(tot_dmg_modifier, *rune_tot_dmg_modifier);
+    increase_exponentially_scaling_stat(tot_dmg_modifier, new_value);
+    *rune_tot_dmg_modifier = new_value;
+}
+
+//precision: presence of mind
+//mana is not simulated, so this rune has no effect on fights simulations (but it can still be part of a runes page)
+impl MinorRune {
+    pub const PRESENCE_OF_MIND: MinorRune = MinorRune {
+        full_name: "Presence of mind",
+        short_name: "PoM",
+        tree: Some(RuneTree::Precision),
+        row: 1,
+        on_action_fns: OnActionFns {
+            on_lvl_set: None,
+            on_fight_init: None,
+            special_active: None,
+            on_ability_cast: None,
+            on_ultimate_cast: None,
+            on_ability_hit: None,
+            on_ultimate_hit: None,
+            on_basic_attack_cast: None,
+            on_basic_attack_hit: None,
+            on_phys_hit: None,
+            on_magic_hit: None,
+            on_true_dmg_hit: None,
+            on_any_hit: None,
+        },
+    };
+}
+
+//precision: todo: absorb life, triumph
+
+//precision: legend runes
+//legend stacks are obtained from takedowns and minions kills earlier in the game, legend runes assume they are fully stacked
+fn legend_alacrity_init(champ: &mut Unit) {
+    champ.stats.bonus_as += 0.03 + 0.015 * 10.; //3% + 1.5% per legend stack (10 stacks)
+}
+
+impl MinorRune {
+    pub const LEGEND_ALACRITY: MinorRune = MinorRune {
+        full_name: "Legend: alacrity",
+        short_name: "Alacrity",
+        tree: Some(RuneTree::Precision),
+        row: 2,
+        on_action_fns: OnActionFns {
+            on_lvl_set: None,
+            on_fight_init: Some(legend_alacrity_init),
+            special_active: None,
+            on_ability_cast: None,
+            on_ultimate_cast: None,
+            on_ability_hit: None,
+            on_ultimate_hit: None,
+            on_basic_attack_cast: None,
+            on_basic_attack_hit: None,
+            on_phys_hit: None,
+            on_magic_hit: None,
+            on_true_dmg_hit: None,
+            on_any_hit: None,
+        },
+    };
+}
+
+fn legend_haste_init(champ: &mut Unit) {
+    champ.stats.basic_haste += 1.5 * 10.; //1.5 basic ability haste per legend stack (10 stacks)
+}
+
+impl MinorRune {
+    pub const LEGEND_HASTE: MinorRune = MinorRune {
+        full_name: "Legend: haste",
+        short_name: "Haste",
+        tree: Some(RuneTree::Precision),
+        row: 2,
+        on_action_fns: OnActionFns {
+            on_lvl_set: None,
+            on_fight_init: Some(legend_haste_init),
+            special_active: None,
+            on_ability_cast: None,
+            on_ultimate_cast: None,
+            on_ability_hit: None,
+            on_ultimate_hit: None,
+            on_basic_attack_cast: None,
+            on_basic_attack_hit: None,
+            on_phys_hit: None,
+            on_magic_hit: None,
+            on_true_dmg_hit: None,
+            on_any_hit: None,
+        },
+    };
+}
+
+fn legend_bloodline_init(champ: &mut Unit) {
+    champ.stats.life_steal += 0.00525 * 10.; //0.525% life steal per legend stack (10 stacks)
+    champ.stats.hp += 85.; //when fully stacked
+}
+
+impl MinorRune {
+    pub const LEGEND_BLOODLINE: MinorRune = MinorRune {
+        full_name: "Legend: bloodline",
+        short_name: "Bloodline",
+        tree: Some(RuneTree::Precision),
+        row: 2,
+        on_action_fns: OnActionFns {
+            on_lvl_set: None,
+            on_fight_init: Some(legend_bloodline_init),
+            special_active: None,
+            on_ability_cast: None,
+            on_ultimate_cast: None,
+            on_ability_hit: None,
+            on_ultimate_hit: None,
+            on_basic_attack_cast: None,
+            on_basic_attack_hit: None,
+            on_phys_hit: None,
+            on_magic_hit: None,
+            on_true_dmg_hit: None,
+            on_any_hit: None,
+        },
+    };
+}
+
+//precision: coup de grace
+fn coup_de_grace_init(champ: &mut Unit) {
+    champ.effects_values[EffectValueId::CoupDeGraceTotDmgModifier] = 0.;
+}
+
+const COUP_DE_GRACE_HP_PERCENT_THRESHOLD: f32 = 0.40;
+const COUP_DE_GRACE_TOT_DMG_MODIFIER: f32 = 0.08;
+fn coup_de_grace_on_any_hit(champ: &mut Unit, target_stats: &UnitStats) -> PartDmg {
+    let new_value: f32 = if 1. - champ.get_target_missing_hp_percent(target_stats)
+        < COUP_DE_GRACE_HP_PERCENT_THRESHOLD
+    {
+        COUP_DE_GRACE_TOT_DMG_MODIFIER
+    } else {
+        0.
+    };
+    update_minor_rune_tot_dmg_modifier(
+        &mut champ.stats.tot_dmg_modifier,
+        &mut champ.effects_values[EffectValueId::CoupDeGraceTotDmgModifier],
+        new_value,
+    );
+    PartDmg(0., 0., 0.)
+}
+
+impl MinorRune {
+    pub const COUP_DE_GRACE: MinorRune = MinorRune {
+        full_name: "Coup de grace",
+        short_name: "Coup de grace",
+        tree: Some(RuneTree::Precision),
+        row: 3,
+        on_action_fns: OnActionFns {
+            on_lvl_set: None,
+            on_fight_init: Some(coup_de_grace_init),
+            special_active: None,
+            on_ability_cast: None,
+            on_ultimate_cast: None,
+            on_ability_hit: None,
+            on_ultimate_hit: None,
+            on_basic_attack_cast: None,
+            on_basic_attack_hit: None,
+            on_phys_hit: None,
+            on_magic_hit: None,
+            on_true_dmg_hit: None,
+            on_any_hit: Some(coup_de_grace_on_any_hit),
+        },
+    };
+}
+
+//precision: cut down
+fn cut_down_init(champ: &mut Unit) {
+    champ.effects_values[EffectValueId::CutDownTotDmgModifier] = 0.;
+}
+
+fn cut_down_on_any_hit(champ: &mut Unit, target_stats: &UnitStats) -> PartDmg {
+    //during a teamfight, use the max hp of the current main target
+    let target_hp: f32 = champ
+        .teamfight_current_target_stats()
+        .map_or(target_stats.hp, |stats| stats.hp);
+    let bonus_hp_percent: f32 = target_hp / champ.stats.hp - 1.;
+    //5% to 11% more dmg against targets with 10% to 100% more max hp than the unit
+    let new_value: f32 = if bonus_hp_percent < 0.10 {
+        0.
+    } else {
+        0.05 + 0.06 * (f32::min(1., bonus_hp_percent) - 0.10) / 0.90
+    };
+    update_minor_rune_tot_dmg_modifier(
+        &mut champ.stats.tot_dmg_modifier,
+        &mut champ.effects_values[EffectValueId::CutDownTotDmgModifier],
+        new_value,
+    );
+    PartDmg(0., 0., 0.)
+}
+
+impl MinorRune {
+    pub const CUT_DOWN: MinorRune = MinorRune {
+        full_name: "Cut down",
+        short_name: "Cut down",
+        tree: Some(RuneTree::Precision),
+        row: 3,
+        on_action_fns: OnActionFns {
+            on_lvl_set: None,
+            on_fight_init: Some(cut_down_init),
+            special_active: None,
+            on_ability_cast: None,
+            on_ultimate_cast: None,
+            on_ability_hit: None,
+            on_ultimate_hit: None,
+            on_basic_attack_cast: None,
+            on_basic_attack_hit: None,
+            on_phys_hit: None,
+            on_magic_hit: None,
+            on_true_dmg_hit: None,
+            on_any_hit: Some(cut_down_on_any_hit),
+        },
+    };
+}
+
+//precision: last stand
+fn last_stand_init(champ: &mut Unit) {
+    champ.effects_values[EffectValueId::LastStandTotDmgModifier] = 0.;
+}
+
+fn last_stand_on_any_hit(champ: &mut Unit, _target_stats: &UnitStats) -> PartDmg {
+    //5% to 11% more dmg when below 60% to 30% hp (only when the unit receives dmg, see `Unit::set_incoming_dmg`)
+    let new_value: f32 = match champ.get_current_hp() {
+        Some(hp) if hp < 0.60 * champ.stats.hp => {
+            0.05 + 0.06 * (0.60 - f32::max(0.30, hp / champ.stats.hp)) / 0.30
+        }
+        _ => 0.,
+    };
+    update_minor_rune_tot_dmg_modifier(
+        &mut champ.stats.tot_dmg_modifier,
+        &mut champ.effects_values[EffectValueId::LastStandTotDmgModifier],
+        new_value,
+    );
+    PartDmg(0., 0., 0.)
+}
+
+impl MinorRune {
+    pub const LAST_STAND: MinorRune = MinorRune {
+        full_name: "Last stand",
+        short_name: "Last stand",
+        tree: Some(RuneTree::Precision),
+        row: 3,
+        on_action_fns: OnActionFns {
+            on_lvl_set: None,
+            on_fight_init: Some(last_stand_init),
+            special_active: None,
+            on_ability_cast: None,
+            on_ultimate_cast: None,
+            on_ability_hit: None,
+            on_ultimate_hit: None,
+            on_basic_attack_cast: None,
+            on_basic_attack_hit: None,
+            on_phys_hit: None,
+            on_magic_hit: None,
+            on_true_dmg_hit: None,
+            on_any_hit: Some(last_stand_on_any_hit),
+        },
+    };
+}
+
+//domination: taste of blood
+const TASTE_OF_BLOOD_COOLDOWN: f32 = 20.;
+fn taste_of_blood_init(champ: &mut Unit) {
+    champ.effects_values[EffectValueId::TasteOfBloodLastTriggerTime] =
+        -(TASTE_OF_BLOOD_COOLDOWN + F32_TOL); //to allow for effect at time == 0
+}
+
+fn taste_of_blood_on_any_hit(champ: &mut Unit, _target_stats: &UnitStats) -> PartDmg {
+    if champ.time - champ.effects_values[EffectValueId::TasteOfBloodLastTriggerTime]
+        < TASTE_OF_BLOOD_COOLDOWN
+    {
+        return PartDmg(0., 0., 0.);
+    }
+    champ.effects_values[EffectValueId::TasteOfBloodLastTriggerTime] = champ.time;
+    champ.add_heal(
+        rune_value_by_lvl(champ.lvl, 16., 40.)
+            + 0.10 * champ.stats.bonus_ad
+            + 0.05 * champ.stats.ap(),
+        false,
+    );
+    PartDmg(0., 0., 0.)
+}
+
+impl MinorRune {
+    pub const TASTE_OF_BLOOD: MinorRune = MinorRune {
+        full_name: "Taste of blood",
+        short_name: "ToB",
+        tree: Some(RuneTree::Domination),
+        row: 1,
+        on_action_fns: OnActionFns {
+            on_lvl_set: None,
+            on_fight_init: Some(taste_of_blood_init),
+            special_active: None,
+            on_ability_cast: None,
+            on_ultimate_cast: None,
+            on_ability_hit: None,
+            on_ultimate_hit: None,
+            on_basic_attack_cast: None,
+            on_basic_attack_hit: None,
+            on_phys_hit: None,
+            on_magic_hit: None,
+            on_true_dmg_hit: None,
+            on_any_hit: Some(taste_of_blood_on_any_hit),
+        },
+    };
+}
+
+//domination: todo: cheap shot, sudden impact, treasure hunter
+
+//domination: ultimate hunter
+fn ultimate_hunter_init(champ: &mut Unit) {
+    champ.stats.ultimate_haste += 6. + 5. * 5.; //6 + 5 per bounty hunter stack, assumes fully stacked (5 stacks)
+}
+
+impl MinorRune {
+    pub const ULTIMATE_HUNTER: MinorRune = MinorRune {
+        full_name: "Ultimate hunter",
+        short_name: "Ultimate hunter",
+        tree: Some(RuneTree::Domination),
+        row: 3,
+        on_action_fns: OnActionFns {
+            on_lvl_set: None,
+            on_fight_init: Some(ultimate_hunter_init),
+            special_active: None,
+            on_ability_cast: None,
+            on_ultimate_cast: None,
+            on_ability_hit: None,
+            on_ultimate_hit: None,
+            on_basic_attack_cast: None,
+            on_basic_attack_hit: None,
+            on_phys_hit: None,
+            on_magic_hit: None,
+            on_true_dmg_hit: None,
+            on_any_hit: None,
+        },
+    };
+}
+
+//sorcery: absolute focus
+const ABSOLUTE_FOCUS_HP_PERCENT_THRESHOLD: f32 = 0.70;
+fn absolute_focus_init(champ: &mut Unit) {
+    let adaptive_ap: f32 = rune_value_by_lvl(champ.lvl, 3., 30.);
+    if champ.adaptive_is_phys() {
+        let bonus_ad: f32 = ADAPTIVE_AP_TO_AD_RATIO * adaptive_ap;
+        champ.stats.bonus_ad += bonus_ad;
+        champ.effects_values[EffectValueId::AbsoluteFocusBonusAD] = bonus_ad;
+        champ.effects_values[EffectValueId::AbsoluteFocusApFlat] = 0.;
+    } else {
+        champ.stats.ap_flat += adaptive_ap;
+        champ.effects_values[EffectValueId::AbsoluteFocusBonusAD] = 0.;
+        champ.effects_values[EffectValueId::AbsoluteFocusApFlat] = adaptive_ap;
+    }
+}
+
+fn absolute_focus_on_any_hit(champ: &mut Unit, _target_stats: &UnitStats) -> PartDmg {
+    //lose the bonus once below the hp threshold (heals are not tracked, so it is not regained during the fight)
+    if champ
+        .get_current_hp()
+        .is_some_and(|hp| hp < ABSOLUTE_FOCUS_HP_PERCENT_THRESHOLD * champ.stats.hp)
+    {
+        champ.stats.bonus_ad -= champ.effects_values[EffectValueId::AbsoluteFocusBonusAD];
+        champ.stats.ap_flat -= champ.effects_values[EffectValueId::AbsoluteFocusApFlat];
+        champ.effects_values[EffectValueId::AbsoluteFocusBonusAD] = 0.;
+        champ.effects_values[EffectValueId::AbsoluteFocusApFlat] = 0.;
+    }
+    PartDmg(0., 0., 0.)
+}
+
+impl MinorRune {
+    pub const ABSOLUTE_FOCUS: MinorRune = MinorRune {
+        full_name: "Absolute focus",
+        short_name: "Absolute focus",
+        tree: Some(RuneTree::Sorcery),
+        row: 2,
+        on_action_fns: OnActionFns {
+            on_lvl_set: None,
+            on_fight_init: Some(absolute_focus_init),
+            special_active: None,
+            on_ability_cast: None,
+            on_ultimate_cast: None,
+            on_ability_hit: None,
+            on_ultimate_hit: None,
+            on_basic_attack_cast: None,
+            on_basic_attack_hit: None,
+            on_phys_hit: None,
+            on_magic_hit: None,
+            on_true_dmg_hit: None,
+            on_any_hit: Some(absolute_focus_on_any_hit),
+        },
+    };
+}
+
+//sorcery: transcendence
+fn transcendence_init(champ: &mut Unit) {
+    if champ.lvl.get() >= 5 {
+        champ.stats.ability_haste += 5.;
+    }
+    if champ.lvl.get() >= 8 {
+        champ.stats.ability_haste += 5.;
+    }
+}
+
+impl MinorRune {
+    pub const TRANSCENDENCE: MinorRune = MinorRune {
+        full_name: "Transcendence",
+        short_name: "Transcendence",
+        tree: Some(RuneTree::Sorcery),
+        row: 2,
+        on_action_fns: OnActionFns {
+            on_lvl_set: None,
+            on_fight_init: Some(transcendence_init),
+            special_active: None,
+            on_ability_cast: None,
+            on_ultimate_cast: None,
+            on_ability_hit: None,
+            on_ultimate_hit: None,
+            on_basic_attack_cast: None,
+            on_basic_attack_hit: None,
+            on_phys_hit: None,
+            on_magic_hit: None,
+            on_true_dmg_hit: None,
+            on_any_hit: None,
+        },
+    };
+}
+
+//sorcery: celerity
+fn celerity_init(champ: &mut Unit) {
+    champ.stats.ms_percent += 0.01; //bonus ms effects amplification not implemented
+}
+
+impl MinorRune {
+    pub const CELERITY: MinorRune = MinorRune {
+        full_name: "Celerity",
+        short_name: "Celerity",
+        tree: Some(RuneTree::Sorcery),
+        row: 2,
+        on_action_fns: OnActionFns {
+            on_lvl_set: None,
+            on_fight_init: Some(celerity_init),
+            special_active: None,
+            on_ability_cast: None,
+            on_ultimate_cast: None,
+            on_ability_hit: None,
+            on_ultimate_hit: None,
+            on_basic_attack_cast: None,
+            on_basic_attack_hit: None,
+            on_phys_hit: None,
+            on_magic_hit: None,
+            on_true_dmg_hit: None,
+            on_any_hit: None,
+        },
+    };
+}
+
+//sorcery: gathering storm
+//gathering storm scales with game time, which is approximated from the unit lvl
+//(assumes a carry reaches lvl 9 around 10 min, lvl 13 around 20 min, lvl 16 around 30 min and lvl 18 around 40 min)
+const GATHERING_STORM_ADAPTIVE_AP_BY_LVL: [f32; MAX_UNIT_LVL] = [
+    0.,  //lvl 1
+    0.,  //lvl 2
+    0.,  //lvl 3
+    0.,  //lvl 4
+    0.,  //lvl 5
+    0.,  //lvl 6
+    0.,  //lvl 7
+    0.,  //lvl 8
+    8.,  //lvl 9
+    8.,  //lvl 10
+    8.,  //lvl 11
+    8.,  //lvl 12
+    24., //lvl 13
+    24., //lvl 14
+    24., //lvl 15
+    48., //lvl 16
+    48., //lvl 17
+    80., //lvl 18
+];
+
+fn gathering_storm_init(champ: &mut Unit) {
+    let adaptive_ap: f32 = GATHERING_STORM_ADAPTIVE_AP_BY_LVL[usize::from(champ.lvl.get() - 1)];
+    if champ.adaptive_is_phys() {
+        champ.stats.bonus_ad += ADAPTIVE_AP_TO_AD_RATIO * adaptive_ap;
+    } else {
+        champ.stats.ap_flat += adaptive_ap;
+    }
+}
+
+impl MinorRune {
+    pub const GATHERING_STORM: MinorRune = MinorRune {
+        full_name: "Gathering storm",
+        short_name: "Gathering storm",
+        tree: Some(RuneTree::Sorcery),
+        row: 3,
+        on_action_fns: OnActionFns {
+            on_lvl_set: None,
+            on_fight_init: Some(gathering_storm_init),
+            special_active: None,
+            on_ability_cast: None,
+            on_ultimate_cast: None,
+            on_ability_hit: None,
+            on_ultimate_hit: None,
+            on_basic_attack_cast: None,
+            on_basic_attack_hit: None,
+            on_phys_hit: None,
+            on_magic_hit: None,
+            on_true_dmg_hit: None,
+            on_any_hit: None,
+        },
+    };
+}
+
+//sorcery: scorch
+const SCORCH_COOLDOWN: f32 = 10.;
+fn scorch_init(champ: &mut Unit) {
+    champ.effects_values[EffectValueId::ScorchLastTriggerTime] = -(SCORCH_COOLDOWN + F32_TOL);
+    //to allow for effect at time == 0
+}
+
+fn scorch_on_ability_hit(champ: &mut Unit, _target_stats: &UnitStats, _n_targets: f32) -> PartDmg {
+    if champ.time - champ.effects_values[EffectValueId::ScorchLastTriggerTime] < SCORCH_COOLDOWN {
+        return PartDmg(0., 0., 0.);
+    }
+    champ.effects_values[EffectValueId::ScorchLastTriggerTime] = champ.time;
+    PartDmg(0., rune_value_by_lvl(champ.lvl, 20., 40.), 0.) //burn applied instantly instead of after 1s
+}
+
+impl MinorRune {
+    pub const SCORCH: MinorRune = MinorRune {
+        full_name: "Scorch",
+        short_name: "Scorch",
+        tree: Some(RuneTree::Sorcery),
+        row: 3,
+        on_action_fns: OnActionFns {
+            on_lvl_set: None,
+            on_fight_init: Some(scorch_init),
+            special_active: None,
+            on_ability_cast: None,
+            on_ultimate_cast: None,
+            on_ability_hit: Some(scorch_on_ability_hit),
+            on_ultimate_hit: None,
+            on_basic_attack_cast: None,
+            on_basic_attack_hit: None,
+            on_phys_hit: None,
+            on_magic_hit: None,
+            on_true_dmg_hit: None,
+            on_any_hit: None,
+        },
+    };
+}
+
+//sorcery: todo: nimbus cloak, manaflow band
+
+//resolve: conditioning
+//conditioning is active after 12 min, which is approximated from the unit lvl
+const CONDITIONING_MIN_LVL: u8 = 10;
+fn conditioning_init(champ: &mut Unit) {
+    if champ.lvl.get() >= CONDITIONING_MIN_LVL {
+        champ.stats.armor = 1.03 * (champ.stats.armor + 8.);
+        champ.stats.mr = 1.03 * (champ.stats.mr + 8.);
+    }
+}
+
+impl MinorRune {
+    pub const CONDITIONING: MinorRune = MinorRune {
+        full_name: "Conditioning",
+        short_name: "Conditioning",
+        tree: Some(RuneTree::Resolve),
+        row: 2,
+        on_action_fns: OnActionFns {
+            on_lvl_set: None,
+            on_fight_init: Some(conditioning_init),
+            special_active: None,
+            on_ability_cast: None,
+            on_ultimate_cast: None,
+            on_ability_hit: None,
+            on_ultimate_hit: None,
+            on_basic_attack_cast: None,
+            on_basic_attack_hit: None,
+            on_phys_hit: None,
+            on_magic_hit: None,
+            on_true_dmg_hit: None,
+            on_any_hit: None,
+        },
+    };
+}
+
+//resolve: todo: bone plating, second wind, overgrowth, revitalize
+
+//inspiration: todo: magical footwear, biscuit delivery, cosmic insight, jack of all trades
+
+pub const ALL_MINOR_RUNES: [&MinorRune; 15] = [
+    &MinorRune::PRESENCE_OF_MIND,
+    &MinorRune::LEGEND_ALACRITY,
+    &MinorRune::LEGEND_HASTE,
+    &MinorRune::LEGEND_BLOODLINE,
+    &MinorRune::COUP_DE_GRACE,
+    &MinorRune::CUT_DOWN,
+    &MinorRune::LAST_STAND,
+    &MinorRune::TASTE_OF_BLOOD,
+    &MinorRune::ULTIMATE_HUNTER,
+    &MinorRune::ABSOLUTE_FOCUS,
+    &MinorRune::TRANSCENDENCE,
+    &MinorRune::CELERITY,
+    &MinorRune::GATHERING_STORM,
+    &MinorRune::SCORCH,
+    &MinorRune::CONDITIONING,
+];
+
+impl MinorRune {
+    /// Returns the minor rune whose full name or short name matches the given name (case insensitive), if any.
+    /// The empty minor rune is included in the search.
+    #[must_use]
+    pub fn find_by_name(name: &str) -> Option<&'static MinorRune> {
+        let name: String = name.trim().to_lowercase();
+        ALL_MINOR_RUNES
+            .iter()
+            .copied()
+            .chain([&MinorRune::EMPTY_MINOR_RUNE])
+            .find(|rune| {
+                rune.full_name.to_lowercase() == name || rune.short_name.to_lowercase() == name
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use items_data::Build;
+
+    #[test]
+    pub fn test_runes_pages_validity() {
+        for properties in Unit::ALL_CHAMPIONS {
+            if let Err(error_msg) = properties.defaults.runes_pages.check_validity() {
+                panic!(
+                    "Default runes page of {} is invalid: {error_msg}",
+                    properties.name
+                );
+            }
+        }
+
+        let mut runes_page: RunesPage = RunesPage {
+            keystone: &RuneKeystone::LETHAL_TEMPO,
+            primary_runes: [
+                &MinorRune::PRESENCE_OF_MIND,
+                &MinorRune::LEGEND_ALACRITY,
+                &MinorRune::CUT_DOWN,
+            ],
+            secondary_runes: [&MinorRune::TASTE_OF_BLOOD, &MinorRune::ULTIMATE_HUNTER],
+            ..RunesPage::const_default()
+        };
+        assert!(runes_page.check_validity().is_ok());
+
+        let mut invalid_page: RunesPage = runes_page;
+        invalid_page.primary_runes.swap(1, 2); //wrong rows
+        assert!(invalid_page.check_validity().is_err());
+        invalid_page = runes_page;
+        invalid_page.primary_runes[0] = &MinorRune::TASTE_OF_BLOOD; //not from the keystone tree
+        assert!(invalid_page.check_validity().is_err());
+        invalid_page = runes_page;
+        invalid_page.secondary_runes[0] = &MinorRune::CUT_DOWN; //from the keystone tree
+        assert!(invalid_page.check_validity().is_err());
+        invalid_page = runes_page;
+        invalid_page.secondary_runes[1] = &MinorRune::SCORCH; //different secondary trees
+        assert!(invalid_page.check_validity().is_err());
+        invalid_page = runes_page;
+        invalid_page.secondary_runes = [&MinorRune::ABSOLUTE_FOCUS, &MinorRune::CELERITY]; //same row
+        assert!(invalid_page.check_validity().is_err());
+
+        //primary runes are removed with the empty keystone
+        runes_page.set_keystone(&RuneKeystone::EMPTY_RUNE_KEYSTONE);
+        assert_eq!(runes_page.primary_runes, [&MinorRune::EMPTY_MINOR_RUNE; 3]);
+        assert!(runes_page.check_validity().is_ok());
+
+        //primary and secondary trees are swapped when changing the keystone tree
+        runes_page = RunesPage {
+            keystone: &RuneKeystone::LETHAL_TEMPO,
+            primary_runes: [
+                &MinorRune::PRESENCE_OF_MIND,
+                &MinorRune::LEGEND_ALACRITY,
+                &MinorRune::CUT_DOWN,
+            ],
+            secondary_runes: [&MinorRune::TASTE_OF_BLOOD, &MinorRune::ULTIMATE_HUNTER],
+            ..RunesPage::const_default()
+        };
+        runes_page.set_keystone(&RuneKeystone::ELECTROCUTE);
+        assert!(runes_page.check_validity().is_ok());
+        assert_eq!(
+            runes_page.primary_runes,
+            [
+                &MinorRune::TASTE_OF_BLOOD,
+                &MinorRune::EMPTY_MINOR_RUNE,
+                &MinorRune::ULTIMATE_HUNTER
+            ]
+        );
+        assert_eq!(
+            runes_page.secondary_runes,
+            [&MinorRune::PRESENCE_OF_MIND, &MinorRune::LEGEND_ALACRITY]
+        );
+
+        //minor runes effects are applied through their on-action-fns
+        let bonus_as = |runes_page: RunesPage| -> f32 {
+            let mut champ: Unit = Unit::new(
+                &Unit::ASHE_PROPERTIES,
+                runes_page,
+                Unit::ASHE_PROPERTIES.defaults.skill_order.clone(),
+                11,
+                Build::default(),
+            )
+            .expect("Failed to create unit");
+            champ.init_fight();
+            champ.get_stats().bonus_as
+        };
+        let mut alacrity_page: RunesPage = RunesPage::const_default();
+        alacrity_page.set_keystone(&RuneKeystone::LETHAL_TEMPO);
+        alacrity_page.primary_runes[1] = &MinorRune::EMPTY_MINOR_RUNE;
+        let base_bonus_as: f32 = bonus_as(alacrity_page);
+        alacrity_page.primary_runes[1] = &MinorRune::LEGEND_ALACRITY;
+        assert!((bonus_as(alacrity_page) - base_bonus_as - 0.18).abs() < F32_TOL);
+    }
+
+    /// Test that keystones with a cooldown trigger during a fight and don't trigger again while on cooldown.
+    #[test]
+    pub fn test_keystones_cooldowns() {
+        const FIGHT_DURATION: f32 = 60.;
+        let target: Unit = Unit::new_target_dummy();
+        for (keystone, effect_id, retriggers) in [
+            (&RuneKeystone::ELECTROCUTE, EffectId::Electrocute, true),
+            (&RuneKeystone::DARK_HARVEST, EffectId::DarkHarvest, true),
+            (
+                &RuneKeystone::HAIL_OF_BLADES,
+                EffectId::HailOfBladesAS,
+                true,
+            ),
+            (&RuneKeystone::SUMMON_AERY, EffectId::SummonAery, true),
+            (&RuneKeystone::ARCANE_COMET, EffectId::ArcaneComet, true),
+            (&RuneKeystone::PHASE_RUSH, EffectId::PhaseRushMS, true),
+            (&RuneKeystone::FIRST_STRIKE, EffectId::FirstStrike, false), //only on the first hit of the fight
+        ] {
+            let mut runes_page: RunesPage = Unit::ASHE_PROPERTIES.defaults.runes_pages;
+            runes_page.set_keystone(keystone);
+            let mut champ: Unit = Unit::new(
+                &Unit::ASHE_PROPERTIES,
+                runes_page,
+                Unit::ASHE_PROPERTIES.defaults.skill_order.clone(),
+                18,
+                Build::default(),
+            )
+            .expect("Failed to create unit");
+
+            //(trigger time, cooldown end time), keystones trigger when the dmg of the action is done (at the end of the action)
+            let mut triggers: Vec<(f32, f32)> = Vec::new();
+            champ.init_fight();
+            while champ.time < FIGHT_DURATION {
+                if champ.w_cd() == 0. {
+                    champ.w(target.get_stats());
+                } else if champ.basic_attack_cd() == 0. {
+                    champ.basic_attack(target.get_stats());
+                } else {
+                    champ.walk_until_ready([champ.basic_attack_cd(), champ.w_cd()], FIGHT_DURATION);
+                }
+                let cooldown_end: Option<f32> = champ
+                    .temporary_effects_cooldowns
+                    .iter()
+                    .find(|(effect, _)| effect.id == effect_id)
+                    .map(|(_, &end_time)| end_time);
+                if let Some(end_time) = cooldown_end {
+                    if triggers
+                        .last()
+                        .is_none_or(|&(_, last_end_time)| last_end_time != end_time)
+                    {
+                        triggers.push((champ.time, end_time));
+                    }
+                }
+            }
+
+            assert!(
+                !triggers.is_empty(),
+                "{} never triggered",
+                keystone.full_name
+            );
+            assert_eq!(
+                triggers.len() > 1,
+                retriggers,
+                "{} triggered {} times",
+                keystone.full_name,
+                triggers.len()
+            );
+            for window in triggers.windows(2) {
+                assert!(
+                    window[1].0 >= window[0].1,
+                    "{} triggered at {}s while on cooldown until {}s",
+                    keystone.full_name,
+                    window[1].0,
+                    window[0].1
+                );
+            }
+        }
+    }
+}
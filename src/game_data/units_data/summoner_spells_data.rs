@@ -0,0 +1,553 @@
+use super::*;
+
+use core::fmt;
+
+/// Summoner spell of a unit, its active is triggered with the items actives (see `Unit::use_all_special_actives`).
+/// Summoner spells have long cooldowns, so their effects are weighted by their availability (see `EconomyProfile::effect_availability_formula`).
+#[derive(Debug)]
+pub struct SummonerSpell {
+    pub full_name: &'static str,
+    pub short_name: &'static str,
+    pub on_action_fns: OnActionFns,
+}
+
+impl fmt::Display for SummonerSpell {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            f.write_str(self.full_name)
+        } else {
+            f.write_str(self.short_name)
+        }
+    }
+}
+
+impl PartialEq for SummonerSpell {
+    fn eq(&self, other: &Self) -> bool {
+        self.full_name == other.full_name //every summoner spell has a different name
+    }
+}
+impl Eq for SummonerSpell {}
+
+/// Represents the two summoner spells of a champion.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SummonerSpells {
+    pub spells: [&'static SummonerSpell; 2],
+}
+
+impl fmt::Display for SummonerSpells {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let names: Vec<&str> = self
+            .spells
+            .iter()
+            .filter(|&&spell| *spell != SummonerSpell::EMPTY_SUMMONER_SPELL)
+            .map(|spell| spell.full_name)
+            .collect();
+        if names.is_empty() {
+            f.write_str("no summoner spells")
+        } else {
+            f.write_str(&names.join(" + "))
+        }
+    }
+}
+
+impl Default for SummonerSpells {
+    /// Returns empty summoner spells.
+    fn default() -> Self {
+        Self::const_default()
+    }
+}
+
+impl SummonerSpells {
+    /// Returns empty summoner spells.
+    /// Provides a default valid value for `SummonerSpells` usable in compile time constants (unlike `Default::default()` which is not const).
+    #[must_use]
+    pub const fn const_default() -> Self {
+        Self {
+            spells: [&SummonerSpell::EMPTY_SUMMONER_SPELL; 2],
+        }
+    }
+
+    /// Returns an Err with the corresponding error message if the summoner spells are invalid
+    /// (the same summoner spell cannot be taken twice, empty summoner spells are always allowed).
+    pub fn check_validity(&self) -> Result<(), String> {
+        let [spell1, spell2] = self.spells;
+        if spell1 == spell2 && *spell1 != SummonerSpell::EMPTY_SUMMONER_SPELL {
+            return Err(format!("Summoner spell '{spell1:#}' cannot be taken twice"));
+        }
+        Ok(())
+    }
+
+    /// Returns every pair of different summoner spells (without empty summoner spells, in no particular order).
+    #[must_use]
+    pub fn all_pairs() -> Vec<Self> {
+        let mut pairs: Vec<Self> = Vec::new();
+        for (idx, &spell1) in ALL_SUMMONER_SPELLS.iter().enumerate() {
+            for &spell2 in &ALL_SUMMONER_SPELLS[idx + 1..] {
+                pairs.push(Self {
+                    spells: [spell1, spell2],
+                });
+            }
+        }
+        pairs
+    }
+}
+
+impl Unit {
+    /// Sets the Unit summoner spells, returns Ok if success or Err if failure (depending on the validity of the given summoner spells).
+    /// In case of a failure, the unit is not modified.
+    pub fn set_summoner_spells(&mut self, summoner_spells: SummonerSpells) -> Result<(), String> {
+        summoner_spells.check_validity()?;
+        self.summoner_spells = summoner_spells;
+        self.reload_on_action_fns();
+        Ok(())
+    }
+}
+
+//functions for temporary effects that are only used for their cooldown
+fn cooldown_only_effect_enable(_champ: &mut Unit, _availability_coef: f32) {}
+fn cooldown_only_effect_disable(_champ: &mut Unit) {}
+
+/// Returns the value of a summoner spell that scales linearly with lvl (from `lvl_1_value` at lvl 1 to `lvl_18_value` at lvl 18).
+fn summoner_spell_value_by_lvl(lvl: NonZeroU8, lvl_1_value: f32, lvl_18_value: f32) -> f32 {
+    lvl_1_value + (lvl_18_value - lvl_1_value) * f32::from(lvl.get() - 1) / 17.
+}
+
+impl SummonerSpell {
+    pub const EMPTY_SUMMONER_SPELL: SummonerSpell = SummonerSpell {
+        full_name: "Empty summoner spell",
+        short_name: "None",
+        on_action_fns: OnActionFns {
+            on_lvl_set: None,
+            on_fight_init: None,
+            special_active: None,
+            on_ability_cast: None,
+            on_ultimate_cast: None,
+            on_ability_hit: None,
+            on_ultimate_hit: None,
+            on_basic_attack_cast: None,
+            on_basic_attack_hit: None,
+            on_phys_hit: None,
+            on_magic_hit: None,
+            on_true_dmg_hit: None,
+            on_any_hit: None,
+        },
+    };
+}
+
+//ignite
+const IGNITE_COOLDOWN: f32 = 180.;
+const IGNITE: TemporaryEffect = TemporaryEffect {
+    id: EffectId::Ignite,
+    add_stack: cooldown_only_effect_enable,
+    remove_every_stack: cooldown_only_effect_disable,
+    duration: 0.,
+    cooldown: IGNITE_COOLDOWN,
+    restore_target_debuff: None,
+};
+
+const IGNITE_N_TICKS: u8 = 5; //one tick per second
+fn ignite_active(champ: &mut Unit, target_stats: &UnitStats) -> PartDmg {
+    if !champ.add_temporary_effect(&IGNITE, 0.) {
+        return PartDmg(0., 0., 0.);
+    }
+    //grievous wounds on the target are not simulated (the target doesn't heal during fights)
+    let tick_true_dmg: f32 = champ.economy.effect_availability_formula(IGNITE_COOLDOWN)
+        * (70. + 20. * f32::from(champ.lvl.get()))
+        / f32::from(IGNITE_N_TICKS);
+    for tick in 1..=IGNITE_N_TICKS {
+        champ.schedule_dmg(
+            f32::from(tick),
+            target_stats,
+            PartDmg(0., 0., tick_true_dmg),
+            (0, 0),
+            enum_set!(),
+            1.,
+        );
+    }
+    PartDmg(0., 0., 0.)
+}
+
+impl SummonerSpell {
+    pub const IGNITE: SummonerSpell = SummonerSpell {
+        full_name: "Ignite",
+        short_name: "Ignite",
+        on_action_fns: OnActionFns {
+            on_lvl_set: None,
+            on_fight_init: None,
+            special_active: Some(ignite_active),
+            on_ability_cast: None,
+            on_ultimate_cast: None,
+            on_ability_hit: None,
+            on_ultimate_hit: None,
+            on_basic_attack_cast: None,
+            on_basic_attack_hit: None,
+            on_phys_hit: None,
+            on_magic_hit: None,
+            on_true_dmg_hit: None,
+            on_any_hit: None,
+        },
+    };
+}
+
+//heal
+fn heal_init(champ: &mut Unit) {
+    champ.effects_values[EffectValueId::HealMsPercent] = 0.;
+}
+
+fn heal_active(champ: &mut Unit, _target_stats: &UnitStats) -> PartDmg {
+    champ.add_temporary_effect(&HEAL, 0.);
+    PartDmg(0., 0., 0.)
+}
+
+fn heal_enable(champ: &mut Unit, availability_coef: f32) {
+    if champ.effects_values[EffectValueId::HealMsPercent] == 0. {
+        //heal is only given once since the effect cannot be refreshed (cooldown longer than fights)
+        champ.add_heal(
+            availability_coef * summoner_spell_value_by_lvl(champ.lvl, 80., 318.),
+            false,
+        );
+        let percent_ms_buff: f32 = availability_coef * 0.30;
+        champ.stats.ms_percent += percent_ms_buff;
+        champ.effects_values[EffectValueId::HealMsPercent] = percent_ms_buff;
+    }
+}
+
+fn heal_disable(champ: &mut Unit) {
+    champ.stats.ms_percent -= champ.effects_values[EffectValueId::HealMsPercent];
+    champ.effects_values[EffectValueId::HealMsPercent] = 0.;
+}
+
+const HEAL: TemporaryEffect = TemporaryEffect {
+    id: EffectId::Heal,
+    add_stack: heal_enable,
+    remove_every_stack: heal_disable,
+    duration: 1.,
+    cooldown: 240.,
+    restore_target_debuff: None,
+};
+
+impl SummonerSpell {
+    pub const HEAL: SummonerSpell = SummonerSpell {
+        full_name: "Heal",
+        short_name: "Heal",
+        on_action_fns: OnActionFns {
+            on_lvl_set: None,
+            on_fight_init: Some(heal_init),
+            special_active: Some(heal_active),
+            on_ability_cast: None,
+            on_ultimate_cast: None,
+            on_ability_hit: None,
+            on_ultimate_hit: None,
+            on_basic_attack_cast: None,
+            on_basic_attack_hit: None,
+            on_phys_hit: None,
+            on_magic_hit: None,
+            on_true_dmg_hit: None,
+            on_any_hit: None,
+        },
+    };
+}
+
+//barrier
+fn barrier_active(champ: &mut Unit, _target_stats: &UnitStats) -> PartDmg {
+    champ.add_temporary_effect(&BARRIER, 0.);
+    PartDmg(0., 0., 0.)
+}
+
+fn barrier_enable(champ: &mut Unit, availability_coef: f32) {
+    //shield is only given once since the effect cannot be refreshed (cooldown longer than fights), its decay is not simulated
+    champ.add_shield(
+        availability_coef * summoner_spell_value_by_lvl(champ.lvl, 120., 480.),
+        false,
+    );
+}
+
+const BARRIER: TemporaryEffect = TemporaryEffect {
+    id: EffectId::Barrier,
+    add_stack: barrier_enable,
+    remove_every_stack: cooldown_only_effect_disable,
+    duration: 2.5,
+    cooldown: 180.,
+    restore_target_debuff: None,
+};
+
+impl SummonerSpell {
+    pub const BARRIER: SummonerSpell = SummonerSpell {
+        full_name: "Barrier",
+        short_name: "Barrier",
+        on_action_fns: OnActionFns {
+            on_lvl_set: None,
+            on_fight_init: None,
+            special_active: Some(barrier_active),
+            on_ability_cast: None,
+            on_ultimate_cast: None,
+            on_ability_hit: None,
+            on_ultimate_hit: None,
+            on_basic_attack_cast: None,
+            on_basic_attack_hit: None,
+            on_phys_hit: None,
+            on_magic_hit: None,
+            on_true_dmg_hit: None,
+            on_any_hit: None,
+        },
+    };
+}
+
+//ghost
+fn ghost_init(champ: &mut Unit) {
+    champ.effects_values[EffectValueId::GhostMsPercent] = 0.;
+}
+
+fn ghost_active(champ: &mut Unit, _target_stats: &UnitStats) -> PartDmg {
+    champ.add_temporary_effect(&GHOST, 0.);
+    PartDmg(0., 0., 0.)
+}
+
+fn ghost_enable(champ: &mut Unit, availability_coef: f32) {
+    if champ.effects_values[EffectValueId::GhostMsPercent] == 0. {
+        let percent_ms_buff: f32 =
+            availability_coef * summoner_spell_value_by_lvl(champ.lvl, 0.24, 0.48);
+        champ.stats.ms_percent += percent_ms_buff;
+        champ.effects_values[EffectValueId::GhostMsPercent] = percent_ms_buff;
+    }
+}
+
+fn ghost_disable(champ: &mut Unit) {
+    champ.stats.ms_percent -= champ.effects_values[EffectValueId::GhostMsPercent];
+    champ.effects_values[EffectValueId::GhostMsPercent] = 0.;
+}
+
+const GHOST: TemporaryEffect = TemporaryEffect {
+    id: EffectId::Ghost,
+    add_stack: ghost_enable,
+    remove_every_stack: ghost_disable,
+    duration: 10.,
+    cooldown: 240.,
+    restore_target_debuff: None,
+};
+
+impl SummonerSpell {
+    pub const GHOST: SummonerSpell = SummonerSpell {
+        full_name: "Ghost",
+        short_name: "Ghost",
+        on_action_fns: OnActionFns {
+            on_lvl_set: None,
+            on_fight_init: Some(ghost_init),
+            special_active: Some(ghost_active),
+            on_ability_cast: None,
+            on_ultimate_cast: None,
+            on_ability_hit: None,
+            on_ultimate_hit: None,
+            on_basic_attack_cast: None,
+            on_basic_attack_hit: None,
+            on_phys_hit: None,
+            on_magic_hit: None,
+            on_true_dmg_hit: None,
+            on_any_hit: None,
+        },
+    };
+}
+
+//flash
+fn flash_active(champ: &mut Unit, _target_stats: &UnitStats) -> PartDmg {
+    champ.add_temporary_effect(&FLASH, 0.);
+    PartDmg(0., 0., 0.)
+}
+
+fn flash_enable(champ: &mut Unit, availability_coef: f32) {
+    //the blink distance is only travelled once since the effect cannot be refreshed (cooldown longer than fights)
+    champ.units_travelled += availability_coef * 400.;
+}
+
+const FLASH: TemporaryEffect = TemporaryEffect {
+    id: EffectId::Flash,
+    add_stack: flash_enable,
+    remove_every_stack: cooldown_only_effect_disable,
+    duration: 0.,
+    cooldown: 300.,
+    restore_target_debuff: None,
+};
+
+impl SummonerSpell {
+    pub const FLASH: SummonerSpell = SummonerSpell {
+        full_name: "Flash",
+        short_name: "Flash",
+        on_action_fns: OnActionFns {
+            on_lvl_set: None,
+            on_fight_init: None,
+            special_active: Some(flash_active),
+            on_ability_cast: None,
+            on_ultimate_cast: None,
+            on_ability_hit: None,
+            on_ultimate_hit: None,
+            on_basic_attack_cast: None,
+            on_basic_attack_hit: None,
+            on_phys_hit: None,
+            on_magic_hit: None,
+            on_true_dmg_hit: None,
+            on_any_hit: None,
+        },
+    };
+}
+
+//exhaust
+fn exhaust_init(champ: &mut Unit) {
+    champ.effects_values[EffectValueId::ExhaustDmgReceivedReduction] = 0.;
+}
+
+fn exhaust_active(champ: &mut Unit, _target_stats: &UnitStats) -> PartDmg {
+    champ.add_temporary_effect(&EXHAUST, 0.);
+    PartDmg(0., 0., 0.)
+}
+
+fn exhaust_enable(champ: &mut Unit, availability_coef: f32) {
+    //only has an effect if the unit receives dmg during the fight (see `IncomingDmgProfile`)
+    champ.effects_values[EffectValueId::ExhaustDmgReceivedReduction] = availability_coef * 0.40;
+}
+
+fn exhaust_disable(champ: &mut Unit) {
+    champ.effects_values[EffectValueId::ExhaustDmgReceivedReduction] = 0.;
+}
+
+const EXHAUST: TemporaryEffect = TemporaryEffect {
+    id: EffectId::Exhaust,
+    add_stack: exhaust_enable,
+    remove_every_stack: exhaust_disable,
+    duration: 3.,
+    cooldown: 240.,
+    restore_target_debuff: None,
+};
+
+impl SummonerSpell {
+    pub const EXHAUST: SummonerSpell = SummonerSpell {
+        full_name: "Exhaust",
+        short_name: "Exhaust",
+        on_action_fns: OnActionFns {
+            on_lvl_set: None,
+            on_fight_init: Some(exhaust_init),
+            special_active: Some(exhaust_active),
+            on_ability_cast: None,
+            on_ultimate_cast: None,
+            on_ability_hit: None,
+            on_ultimate_hit: None,
+            on_basic_attack_cast: None,
+            on_basic_attack_hit: None,
+            on_phys_hit: None,
+            on_magic_hit: None,
+            on_true_dmg_hit: None,
+            on_any_hit: None,
+        },
+    };
+}
+
+//cleanse
+fn cleanse_init(champ: &mut Unit) {
+    champ.effects_values[EffectValueId::CleanseGrievousWoundsRemoval] = 0.;
+}
+
+fn cleanse_active(champ: &mut Unit, _target_stats: &UnitStats) -> PartDmg {
+    champ.add_temporary_effect(&CLEANSE, 0.);
+    PartDmg(0., 0., 0.)
+}
+
+fn cleanse_enable(champ: &mut Unit, availability_coef: f32) {
+    //crowd controls are not simulated, only the removal of grievous wounds (from the incoming dmg, see `IncomingDmgProfile`) is
+    champ.effects_values[EffectValueId::CleanseGrievousWoundsRemoval] = availability_coef;
+}
+
+fn cleanse_disable(champ: &mut Unit) {
+    champ.effects_values[EffectValueId::CleanseGrievousWoundsRemoval] = 0.;
+}
+
+const CLEANSE: TemporaryEffect = TemporaryEffect {
+    id: EffectId::Cleanse,
+    add_stack: cleanse_enable,
+    remove_every_stack: cleanse_disable,
+    duration: 3.,
+    cooldown: 240.,
+    restore_target_debuff: None,
+};
+
+impl SummonerSpell {
+    pub const CLEANSE: SummonerSpell = SummonerSpell {
+        full_name: "Cleanse",
+        short_name: "Cleanse",
+        on_action_fns: OnActionFns {
+            on_lvl_set: None,
+            on_fight_init: Some(cleanse_init),
+            special_active: Some(cleanse_active),
+            on_ability_cast: None,
+            on_ultimate_cast: None,
+            on_ability_hit: None,
+            on_ultimate_hit: None,
+            on_basic_attack_cast: None,
+            on_basic_attack_hit: None,
+            on_phys_hit: None,
+            on_magic_hit: None,
+            on_true_dmg_hit: None,
+            on_any_hit: None,
+        },
+    };
+}
+
+pub const ALL_SUMMONER_SPELLS: [&SummonerSpell; 7] = [
+    &SummonerSpell::FLASH,
+    &SummonerSpell::IGNITE,
+    &SummonerSpell::HEAL,
+    &SummonerSpell::BARRIER,
+    &SummonerSpell::GHOST,
+    &SummonerSpell::EXHAUST,
+    &SummonerSpell::CLEANSE,
+];
+
+impl SummonerSpell {
+    /// Returns the summoner spell whose full name or short name matches the given name (case insensitive), if any.
+    /// The empty summoner spell is included in the search.
+    #[must_use]
+    pub fn find_by_name(name: &str) -> Option<&'static SummonerSpell> {
+        let name: String = name.trim().to_lowercase();
+        ALL_SUMMONER_SPELLS
+            .iter()
+            .copied()
+            .chain([&SummonerSpell::EMPTY_SUMMONER_SPELL])
+            .find(|spell| {
+                spell.full_name.to_lowercase() == name || spell.short_name.to_lowercase() == name
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use items_data::Build;
+
+    #[test]
+    pub fn test_summoner_spells() {
+        let mut summoner_spells: SummonerSpells = SummonerSpells {
+            spells: [&SummonerSpell::FLASH, &SummonerSpell::FLASH],
+        };
+        assert!(summoner_spells.check_validity().is_err());
+        summoner_spells.spells[1] = &SummonerSpell::IGNITE;
+        assert!(summoner_spells.check_validity().is_ok());
+        assert!(SummonerSpells::const_default().check_validity().is_ok());
+        assert_eq!(SummonerSpells::all_pairs().len(), 21);
+
+        //ignite dmg is done over its duration and the active cannot be used twice in the same fight
+        let target_stats: UnitStats = Unit::new_target_dummy().get_stats().clone();
+        let mut champ: Unit =
+            Unit::from_properties_defaults(&Unit::ASHE_PROPERTIES, 11, Build::default())
+                .expect("Failed to create unit");
+        champ
+            .set_summoner_spells(summoner_spells)
+            .expect("Failed to set summoner spells");
+        champ.init_fight();
+        champ.use_all_special_actives(&target_stats);
+        champ.wait(f32::from(IGNITE_N_TICKS) + 1.);
+        let ignite_dmg: f32 = champ.get_dmg_done().2;
+        assert!(ignite_dmg > 0.);
+        champ.use_all_special_actives(&target_stats);
+        champ.wait(f32::from(IGNITE_N_TICKS) + 1.);
+        assert!((champ.get_dmg_done().2 - ignite_dmg).abs() < F32_TOL);
+    }
+}
@@ -0,0 +1,465 @@
+use super::*;
+
+use runes_data::{
+    electrocute_reset_stacks, phase_rush_reset_stacks, press_the_attack_reset_exposure,
+    press_the_attack_restore_exposure,
+};
+
+use core::iter::zip;
+
+/// Maximum number of enemy units in a teamfight (a full team).
+pub const MAX_TEAMFIGHT_TARGETS: usize = 5;
+
+/// Dmg done on each enemy unit of a teamfight (indexed like the enemy units).
+pub(super) type TeamfightDmg = [f32; MAX_TEAMFIGHT_TARGETS];
+
+/// Debuffs applied by the unit on an enemy unit of a teamfight, stored while the enemy unit is not the main target
+/// (the debuffs of the main target are on the unit itself, like when fighting a single target).
+#[derive(Debug, Clone)]
+struct TargetDebuffs {
+    temporary_effects_expiries: Vec<(&'static TemporaryEffect, f32)>,
+    effects_stacks: [u8; TARGET_STACKS.len()],
+    effects_values: [f32; TARGET_VALUES.len()],
+    reductions: ResistancesReductions, //resistances reductions on the enemy unit when it stopped being the main target
+}
+
+impl TargetDebuffs {
+    /// Returns the resistances reductions on the enemy unit, if none of its temporary debuffs expired since they were stored.
+    fn reductions(&self, time: f32) -> Option<ResistancesReductions> {
+        self.temporary_effects_expiries
+            .iter()
+            .all(|&(_, expiry_time)| expiry_time > time)
+            .then_some(self.reductions)
+    }
+}
+
+/// Enemy units fought by the unit during a teamfight, each with its own stats, hp and debuffs.
+#[derive(Debug, Clone)]
+pub(super) struct TeamfightState {
+    targets_stats: Vec<UnitStats>,
+    hp_lost: TeamfightDmg,
+    targets_debuffs: [Option<TargetDebuffs>; MAX_TEAMFIGHT_TARGETS], //None for the main target and enemy units without debuffs
+    base_reductions: ResistancesReductions, //resistances reductions of the unit on enemy units without debuffs
+    current_target: usize, //index of the enemy unit currently targeted (the main target)
+}
+
+impl TeamfightState {
+    fn is_alive(&self, idx: usize) -> bool {
+        self.hp_lost[idx] < self.targets_stats[idx].hp
+    }
+
+    fn n_alive(&self) -> usize {
+        (0..self.targets_stats.len())
+            .filter(|&idx| self.is_alive(idx))
+            .count()
+    }
+
+    /// Returns the next enemy unit alive after the current target (in order), if any.
+    fn next_alive_target(&self) -> Option<usize> {
+        let n_targets: usize = self.targets_stats.len();
+        (1..n_targets)
+            .map(|offset| (self.current_target + offset) % n_targets)
+            .find(|&idx| self.is_alive(idx))
+    }
+}
+
+impl Unit {
+    /// Sets the enemy units (with their stats at the current lvl) fought during fight simulations.
+    /// With no enemy units, the unit fights a single target (the `target_stats` given to the fight simulation),
+    /// and dmg on multiple targets is approximated on this target.
+    ///
+    /// During a teamfight, dmg is mitigated by the resistances of the enemy unit it hits (with the resistances reductions
+    /// of its own debuffs) and the main target changes when it dies. The debuffs of each enemy unit are kept separately
+    /// (see `TargetDebuffs`). When every enemy unit is dead, the time to kill is recorded and a new group of enemy units
+    /// with full hp and no debuffs replaces them.
+    pub fn set_teamfight_targets(&mut self, targets_stats: &[UnitStats]) -> Result<(), String> {
+        if targets_stats.len() > MAX_TEAMFIGHT_TARGETS {
+            return Err(format!(
+                "Number of teamfight targets must be at most {MAX_TEAMFIGHT_TARGETS} (got {})",
+                targets_stats.len()
+            ));
+        }
+        if let Some(stats) = targets_stats.iter().find(|stats| stats.hp <= 0.) {
+            return Err(format!(
+                "Teamfight targets must have hp greater than 0 (got {})",
+                stats.hp
+            ));
+        }
+        self.teamfight = (!targets_stats.is_empty()).then(|| TeamfightState {
+            targets_stats: Vec::from(targets_stats),
+            hp_lost: [0.; MAX_TEAMFIGHT_TARGETS],
+            targets_debuffs: Default::default(),
+            base_reductions: self.resistances_reductions(),
+            current_target: 0,
+        });
+        Ok(())
+    }
+
+    /// Returns true if the unit fights several enemy units (see `Unit::set_teamfight_targets`).
+    #[must_use]
+    #[inline]
+    pub fn is_in_teamfight(&self) -> bool {
+        self.teamfight.is_some()
+    }
+
+    /// Returns the number of enemy units alive (1 when fighting a single target).
+    #[must_use]
+    pub fn n_targets_alive(&self) -> usize {
+        self.teamfight.as_ref().map_or(1, TeamfightState::n_alive)
+    }
+
+    /// Resets the enemy units hp and debuffs at the start of a fight (after stats init).
+    pub(crate) fn init_teamfight(&mut self) {
+        let base_reductions: ResistancesReductions = self.resistances_reductions();
+        if let Some(state) = &mut self.teamfight {
+            state.hp_lost = [0.; MAX_TEAMFIGHT_TARGETS];
+            state.targets_debuffs = Default::default();
+            state.base_reductions = base_reductions;
+            state.current_target = 0;
+        }
+    }
+
+    /// Returns the stats of the current main target during a teamfight.
+    pub(super) fn teamfight_current_target_stats(&self) -> Option<&UnitStats> {
+        self.teamfight
+            .as_ref()
+            .map(|state| &state.targets_stats[state.current_target])
+    }
+
+    /// Returns the missing hp% of the current main target during a teamfight.
+    pub(super) fn teamfight_current_target_missing_hp_percent(&self) -> Option<f32> {
+        self.teamfight.as_ref().map(|state| {
+            f32::clamp(
+                state.hp_lost[state.current_target] / state.targets_stats[state.current_target].hp,
+                0.,
+                1.,
+            )
+        })
+    }
+
+    /// Returns the total hp of the enemy units and the hp they lost during a teamfight.
+    pub(super) fn teamfight_hp_lost(&self) -> Option<(f32, f32)> {
+        self.teamfight.as_ref().map(|state| {
+            (
+                state.targets_stats.iter().map(|stats| stats.hp).sum(),
+                state.hp_lost.iter().sum(),
+            )
+        })
+    }
+
+    /// Returns the hp lost by each enemy unit during a teamfight.
+    pub(super) fn teamfight_hp_lost_by_target(&self) -> Option<TeamfightDmg> {
+        self.teamfight.as_ref().map(|state| state.hp_lost)
+    }
+
+    /// Removes `percent_to_remove` of the hp lost by each enemy unit since `hp_lost_before`
+    /// (only for enemy units that didn't die in between).
+    pub(super) fn teamfight_reduce_hp_lost_since(
+        &mut self,
+        hp_lost_before: &TeamfightDmg,
+        percent_to_remove: f32,
+    ) {
+        if let Some(state) = &mut self.teamfight {
+            for (hp_lost, &before) in state.hp_lost.iter_mut().zip(hp_lost_before) {
+                if *hp_lost >= before {
+                    *hp_lost -= percent_to_remove * (*hp_lost - before);
+                }
+            }
+        }
+    }
+
+    /// Returns the number of additionnal targets hit by an effect that can hit up to `max_additionnal_targets`
+    /// units around the main target. When fighting a single target, the other units are not simulated
+    /// so `avg_additionnal_targets` is returned, during a teamfight every other enemy unit alive can be hit.
+    #[must_use]
+    pub(crate) fn aoe_additionnal_targets(
+        &self,
+        avg_additionnal_targets: f32,
+        max_additionnal_targets: f32,
+    ) -> f32 {
+        match &self.teamfight {
+            None => avg_additionnal_targets,
+            #[allow(clippy::cast_precision_loss)] //number of targets is small
+            Some(state) => f32::min(
+                max_additionnal_targets,
+                state.n_alive().saturating_sub(1) as f32,
+            ),
+        }
+    }
+
+    /// Splits dmg (with dmg modifiers already applied but not resistances) done on `n_targets` between the enemy units.
+    /// The main target receives its share mitigated by the given resistance coefs (computed with the unit reductions stats),
+    /// the remaining shares go to the other enemy units alive in order, mitigated by their own resistances
+    /// and the resistances reductions of their own debuffs.
+    /// If `around_target` is true, the main target doesn't receive a share (dmg only hits units around it).
+    /// Shares exceeding the number of enemy units alive are lost.
+    ///
+    /// Returns the post mitigation dmg done and the dmg done on each enemy unit (None if not in a teamfight).
+    pub(super) fn teamfight_split_dmg(
+        &self,
+        part_dmg: PartDmg,
+        n_targets: f32,
+        (armor_coef, mr_coef): (f32, f32),
+        around_target: bool,
+    ) -> Option<(PartDmg, TeamfightDmg)> {
+        let state: &TeamfightState = self.teamfight.as_ref()?;
+        let mut targets_dmg: TeamfightDmg = [0.; MAX_TEAMFIGHT_TARGETS];
+        let mut dmg_done: PartDmg = PartDmg(0., 0., 0.);
+        let share: PartDmg;
+        let mut remaining_shares: f32;
+        if around_target {
+            if n_targets <= 0. {
+                return Some((dmg_done, targets_dmg));
+            }
+            share = part_dmg / n_targets;
+            remaining_shares = n_targets;
+        } else {
+            //main target
+            share = part_dmg / f32::max(1., n_targets);
+            remaining_shares = n_targets - 1.;
+            let main_dmg: PartDmg = PartDmg(share.0 * armor_coef, share.1 * mr_coef, share.2);
+            targets_dmg[state.current_target] = main_dmg.as_sum();
+            dmg_done += main_dmg;
+        }
+
+        //other targets
+        for idx in (0..state.targets_stats.len())
+            .filter(|&idx| idx != state.current_target && state.is_alive(idx))
+        {
+            if remaining_shares <= 0. {
+                break;
+            }
+            let share_percent: f32 = f32::min(1., remaining_shares);
+            remaining_shares -= share_percent;
+
+            let stats: &UnitStats = &state.targets_stats[idx];
+            let reductions: ResistancesReductions = state.targets_debuffs[idx]
+                .as_ref()
+                .and_then(|debuffs| debuffs.reductions(self.time))
+                .unwrap_or(state.base_reductions);
+            let (armor_coef, mr_coef): (f32, f32) =
+                self.resistance_coefs(stats.armor, stats.mr, reductions);
+            let dmg: PartDmg = PartDmg(
+                share_percent * share.0 * armor_coef,
+                share_percent * share.1 * mr_coef,
+                share_percent * share.2,
+            );
+            targets_dmg[idx] = dmg.as_sum();
+            dmg_done += dmg;
+        }
+        Some((dmg_done, targets_dmg))
+    }
+
+    /// Updates the hp of every enemy unit with the given post mitigation dmg, switching target on kills.
+    pub(super) fn teamfight_targets_receive_dmg(&mut self, targets_dmg: &TeamfightDmg) {
+        let Some(state) = &mut self.teamfight else {
+            return;
+        };
+        for (idx, &dmg) in targets_dmg
+            .iter()
+            .enumerate()
+            .take(state.targets_stats.len())
+        {
+            if dmg > 0. && state.is_alive(idx) {
+                state.hp_lost[idx] += dmg;
+            }
+        }
+        if state.is_alive(state.current_target) {
+            return;
+        }
+
+        //main target killed, switch to the next enemy unit alive
+        if let Some(next_target) = state.next_alive_target() {
+            self.teamfight_switch_target(next_target);
+            return;
+        }
+
+        //every enemy unit is dead, a new group replaces them
+        self.time_to_kill.get_or_insert(self.time);
+        state.hp_lost = [0.; MAX_TEAMFIGHT_TARGETS];
+        state.targets_debuffs = Default::default();
+        state.current_target = 0;
+        self.take_target_debuffs(); //debuffs on the dead main target are lost
+    }
+
+    /// Kills the current main target during a teamfight and returns its remaining hp.
+    pub(super) fn teamfight_execute_current_target(&mut self) -> Option<f32> {
+        let state: &TeamfightState = self.teamfight.as_ref()?;
+        let remaining_hp: f32 = f32::max(
+            0.,
+            state.targets_stats[state.current_target].hp - state.hp_lost[state.current_target],
+        );
+        let mut targets_dmg: TeamfightDmg = [0.; MAX_TEAMFIGHT_TARGETS];
+        targets_dmg[state.current_target] = remaining_hp;
+        self.teamfight_targets_receive_dmg(&targets_dmg);
+        Some(remaining_hp)
+    }
+
+    /// Changes the main target of a teamfight. The debuffs on the previous main target are stored until it becomes
+    /// the main target again (they are lost if it is dead) and the debuffs stored for the new main target are restored.
+    fn teamfight_switch_target(&mut self, new_target: usize) {
+        let previous_target_debuffs: TargetDebuffs = self.take_target_debuffs();
+        let Some(state) = &mut self.teamfight else {
+            return;
+        };
+        if state.is_alive(state.current_target) {
+            state.targets_debuffs[state.current_target] = Some(previous_target_debuffs);
+        }
+        state.current_target = new_target;
+        if let Some(debuffs) = state.targets_debuffs[new_target].take() {
+            self.restore_target_debuffs(debuffs);
+        }
+    }
+
+    /// Removes the debuffs applied on the main target from the unit and returns them.
+    fn take_target_debuffs(&mut self) -> TargetDebuffs {
+        let debuffs: TargetDebuffs = TargetDebuffs {
+            temporary_effects_expiries: self
+                .temporary_effects_expiries
+                .iter()
+                .filter(|(effect, _)| effect.restore_target_debuff.is_some())
+                .map(|(&effect, &expiry_time)| (effect, expiry_time))
+                .collect(),
+            effects_stacks: TARGET_STACKS.map(|stack_id| self.effects_stacks[stack_id]),
+            effects_values: TARGET_VALUES.map(|value_id| self.effects_values[value_id]),
+            reductions: self.resistances_reductions(),
+        };
+
+        //temporary effects on the target (e.g. resistances reductions)
+        for &(effect_ref, _) in &debuffs.temporary_effects_expiries {
+            self.temporary_effects_expiries.remove(effect_ref);
+            (effect_ref.remove_every_stack)(self);
+        }
+
+        //stacks and marks on the target
+        press_the_attack_reset_exposure(self);
+        electrocute_reset_stacks(self);
+        phase_rush_reset_stacks(self);
+        for stack_id in TARGET_STACKS {
+            self.effects_stacks[stack_id] = 0;
+        }
+        debuffs
+    }
+
+    /// Puts back debuffs previously removed from the unit with `Unit::take_target_debuffs`.
+    /// Temporary effects that expired in the meantime are not restored.
+    fn restore_target_debuffs(&mut self, debuffs: TargetDebuffs) {
+        for (stack_id, stacks) in zip(TARGET_STACKS, debuffs.effects_stacks) {
+            self.effects_stacks[stack_id] = stacks;
+        }
+        for (value_id, value) in zip(TARGET_VALUES, debuffs.effects_values) {
+            self.effects_values[value_id] = value;
+        }
+        press_the_attack_restore_exposure(self);
+
+        for (effect_ref, expiry_time) in debuffs.temporary_effects_expiries {
+            if let Some(restore_target_debuff) = effect_ref.restore_target_debuff {
+                restore_target_debuff(self);
+            }
+            if expiry_time > self.time {
+                //the expiry event scheduled when the effect was added is still pending
+                self.temporary_effects_expiries
+                    .insert(effect_ref, expiry_time);
+            } else {
+                (effect_ref.remove_every_stack)(self);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Test that dmg is split between the enemy units during a teamfight and that the target switches on kills.
+    #[test]
+    pub fn test_teamfight() {
+        let target: Unit = Unit::new_target_dummy();
+        let target_stats: &UnitStats = &UnitStats {
+            armor: 100., //so that armor reductions matter
+            ..target.get_stats().clone()
+        };
+        let mut champ: Unit = Unit::from_properties_defaults(
+            &Unit::SIVIR_PROPERTIES,
+            11,
+            Build([
+                &Item::BLACK_CLEAVER,
+                &Item::NULL_ITEM,
+                &Item::NULL_ITEM,
+                &Item::NULL_ITEM,
+                &Item::NULL_ITEM,
+                &Item::NULL_ITEM,
+            ]),
+        )
+        .expect("Failed to create unit");
+        champ
+            .set_teamfight_targets(&[
+                target_stats.clone(),
+                target_stats.clone(),
+                target_stats.clone(),
+            ])
+            .expect("Failed to set teamfight targets");
+
+        champ.init_fight();
+        champ.dmg_on_target(target_stats, PartDmg(0., 0., 200.), (0, 0), enum_set!(), 2.);
+        let hp_lost: TeamfightDmg = champ.teamfight_hp_lost_by_target().unwrap();
+        assert!(hp_lost[0] > 0. && hp_lost[0] == hp_lost[1] && hp_lost[2] == 0.);
+
+        //dmg around the target doesn't hit the main target
+        champ.dmg_on_target(
+            target_stats,
+            PartDmg(0., 0., 200.),
+            (0, 0),
+            enum_set!(DmgTag::AroundTarget),
+            2.,
+        );
+        let hp_lost: TeamfightDmg = champ.teamfight_hp_lost_by_target().unwrap();
+        assert!(hp_lost[0] < hp_lost[1] && hp_lost[2] > 0.);
+
+        //killing the main target switches to the next one (debuffs on the dead target are lost)
+        let has_target_debuffs = |champ: &Unit| -> bool {
+            champ
+                .temporary_effects_expiries
+                .keys()
+                .any(|effect| effect.restore_target_debuff.is_some())
+        };
+        champ.dmg_on_target(target_stats, PartDmg(1., 0., 0.), (1, 1), enum_set!(), 1.);
+        champ.effects_stacks[EffectStackId::VarusBlightStacks] = 3;
+        assert!(has_target_debuffs(&champ));
+        champ.execute_target(target_stats);
+        assert_eq!(champ.n_targets_alive(), 2);
+        assert!(!has_target_debuffs(&champ));
+        assert_eq!(champ.effects_stacks[EffectStackId::VarusBlightStacks], 0);
+        assert!(champ.get_target_missing_hp_percent(target_stats) == hp_lost[1] / target_stats.hp);
+
+        //debuffs are kept separately for each enemy unit alive
+        champ.dmg_on_target(target_stats, PartDmg(1., 0., 0.), (1, 1), enum_set!(), 1.);
+        champ.effects_stacks[EffectStackId::VarusBlightStacks] = 2;
+        let armor_red_percent: f32 = champ.stats.armor_red_percent;
+        assert!(armor_red_percent > 0.);
+        champ.teamfight_switch_target(2);
+        assert!(!has_target_debuffs(&champ));
+        assert_eq!(champ.effects_stacks[EffectStackId::VarusBlightStacks], 0);
+        assert!(champ.stats.armor_red_percent < armor_red_percent);
+
+        //dmg split between the enemy units is mitigated with the reductions of each enemy unit
+        let main_res_coefs: (f32, f32) = champ.resistance_coefs(
+            target_stats.armor,
+            target_stats.mr,
+            champ.resistances_reductions(),
+        );
+        let (_, targets_dmg): (PartDmg, TeamfightDmg) = champ
+            .teamfight_split_dmg(PartDmg(100., 0., 0.), 2., main_res_coefs, false)
+            .unwrap();
+        assert!(targets_dmg[1] > targets_dmg[2]);
+
+        champ.teamfight_switch_target(1);
+        assert!(has_target_debuffs(&champ));
+        assert_eq!(champ.effects_stacks[EffectStackId::VarusBlightStacks], 2);
+        assert!((champ.stats.armor_red_percent - armor_red_percent).abs() < 1e-6);
+
+        //the whole group dies during a long fight
+        champ.simulate_fight(target_stats, 0, 60.);
+        assert!(champ.get_time_to_kill(target_stats) < champ.get_time());
+    }
+}
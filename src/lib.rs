@@ -0,0 +1,34 @@
+//! Library part of the builds finder: champions/items data, fights simulation and builds generation.
+//! The command line interface (`main.rs`) is only one user of this library,
+//! the builds generation progress can be followed with a custom `BuildsGenerationObserver`.
+
+pub mod builds_analyzer;
+pub mod champion_optimizer;
+pub mod game_data;
+pub mod presets;
+
+pub use champion_optimizer::{
+    find_best_builds, find_best_purchase_path, find_best_runes_keystones, find_best_runes_pages,
+    find_best_skill_orders, find_best_starting_items, find_best_summoner_spells, BuildContainer,
+    BuildsGenerationObserver, BuildsGenerationSettings, ItemSlot, PurchasePath, SilentObserver,
+};
+pub use game_data::units_data::{
+    items_data::{Build, Item, ItemGroups, ItemUtils},
+    runes_data::{MinorRune, RuneKeystone, RuneShard, RuneTree, RunesPage},
+    summoner_spells_data::{SummonerSpell, SummonerSpells},
+    BasicAbilitySlot, MaxOrder, SkillOrder, Unit, UnitProperties, UnitStats,
+};
+
+/// Sorts the slice and compares adjacent elements to find if there are duplicates.
+/// Return a reference to the first duplicate found, if any.
+/// The slice given to this function will be modified, if you don't want to modify the given slice, pass a clone.
+#[must_use]
+fn find_dupes_in_slice<T: Ord>(slice: &mut [T]) -> Option<&T> {
+    slice.sort_unstable();
+    for window in slice.windows(2) {
+        if window[0] == window[1] {
+            return Some(&window[0]);
+        }
+    }
+    None
+}
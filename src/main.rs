@@ -1,26 +1,8 @@
-mod builds_analyzer;
-mod champion_optimizer;
 mod cli;
-mod game_data;
 
-use game_data::units_data::*;
+use lol_best_adc_build_finder::*;
 
-use items_data::*;
-use runes_data::*;
-
-/// Sorts the slice and compares adjacent elements to find if there are duplicates.
-/// Return a reference to the first duplicate found, if any.
-/// The slice given to this function will be modified, if you don't want to modify the given slice, pass a clone.
-#[must_use]
-fn find_dupes_in_slice<T: Ord>(slice: &mut [T]) -> Option<&T> {
-    slice.sort_unstable();
-    for window in slice.windows(2) {
-        if window[0] == window[1] {
-            return Some(&window[0]);
-        }
-    }
-    None
-}
+use std::process::ExitCode;
 
 /// Debug function for validating champions implementations.
 #[allow(dead_code)]
@@ -34,6 +16,8 @@ fn champion_test_ground() {
         properties,
         RunesPage {
             keystone: &RuneKeystone::FLEET_FOOTWORK,
+            primary_runes: [&MinorRune::EMPTY_MINOR_RUNE; 3],
+            secondary_runes: [&MinorRune::EMPTY_MINOR_RUNE; 2],
             shard1: RuneShard::Middle,
             shard2: RuneShard::Left,
             shard3: RuneShard::Left,
@@ -57,7 +41,13 @@ fn champion_test_ground() {
     println!("{}", champ.basic_attack(dummy.get_stats()));
 }
 
-fn main() {
+fn main() -> ExitCode {
     //champion_test_ground();
-    cli::launch_interface();
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if args.is_empty() {
+        cli::launch_interface();
+        ExitCode::SUCCESS
+    } else {
+        cli::launch_from_args(&args)
+    }
 }
@@ -0,0 +1,638 @@
+use super::champion_optimizer::*;
+use super::game_data::*;
+
+use items_data::*;
+use patches::*;
+use runes_data::*;
+use summoner_spells_data::*;
+use units_data::*;
+
+use serde::{Deserialize, Serialize};
+
+use core::iter::zip;
+use core::num::NonZeroUsize;
+use std::fs;
+use std::path::Path;
+
+/// Directory where presets are saved by default.
+pub const DEFAULT_PRESETS_DIR: &str = "presets";
+/// Extension of preset files.
+pub const PRESET_FILE_EXTENSION: &str = "toml";
+
+/// Human-editable representation of `BuildsGenerationSettings`, meant to be saved to/loaded from a TOML file.
+/// Items are referenced by their short name, the target and the rune keystone by their name
+/// (a target can also be detailed, see `TargetPreset`).
+///
+/// Every field is optional when loading a preset, missing fields are set to their default values for the champion.
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SettingsPreset {
+    /// Champion for which the preset was saved (only informative).
+    pub champion: Option<String>,
+    /// Patch of the game data used (the patch to load is chosen by the caller, see `to_settings`).
+    pub patch: Option<String>,
+    pub target: Option<TargetPreset>,
+    pub fight_scenario_number: Option<usize>,
+    /// Fight script used instead of the fight scenario (see `FightScript`).
+    pub fight_script: Option<String>,
+    pub fight_duration: Option<f32>,
+    pub phys_dmg_received_percent: Option<f32>,
+    /// Dmg received during the fight simulation (no dmg received if missing).
+    pub incoming_dmg: Option<IncomingDmgProfile>,
+    /// Percentage of time the target spends moving away from the champion (stationary target if missing).
+    pub target_flee_percent: Option<f32>,
+    /// Enemy units fought during a teamfight (single target if missing or empty).
+    pub teamfight_targets: Option<Vec<TargetPreset>>,
+    /// Enemies of the enemy team composition (single target if missing or empty).
+    pub enemy_team: Option<Vec<EnemyPreset>>,
+    pub runes_page: Option<RunesPagePreset>,
+    /// Full names of the two summoner spells.
+    pub summoner_spells: Option<[String; 2]>,
+    /// Max order of the basic abilities (see `MaxOrder`), default skill order of the champion if missing.
+    pub skill_order: Option<String>,
+    /// Golds and experience income of the player (missing values are set to their default values).
+    pub economy: Option<EconomyProfile>,
+    pub n_items: Option<usize>,
+    /// One item short name per slot, an empty string means no mandatory item at this slot.
+    pub mandatory_items: Option<Vec<String>>,
+    /// Item slot number, "any" or "none".
+    pub boots_slot: Option<String>,
+    /// Item slot number, "any" or "none".
+    pub supp_item_slot: Option<String>,
+    pub legendary_items_pool: Option<Vec<String>>,
+    pub boots_pool: Option<Vec<String>>,
+    pub supp_items_pool: Option<Vec<String>>,
+    pub allow_manaflow_first_item: Option<bool>,
+    pub weights: Option<WeightsPreset>,
+    pub search_threshold: Option<f32>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RunesPagePreset {
+    pub keystone: String,
+    /// Minor runes of the keystone tree, one per row (minor runes are adapted to the keystone if missing).
+    pub primary_runes: Option<[String; 3]>,
+    /// Minor runes of the secondary tree, from two different rows.
+    pub secondary_runes: Option<[String; 2]>,
+    /// Rune shards from top to bottom ("left", "middle" or "right").
+    pub shards: [String; 3],
+}
+
+/// Target of a preset, either a name (see `TargetDefinition::parse`) or a detailed target.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum TargetPreset {
+    Name(String),
+    Details(Box<TargetDetailsPreset>),
+}
+
+/// Champion with a build (if `champion` is set) or unit with custom stats (if `base_stats` is set).
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TargetDetailsPreset {
+    pub champion: Option<String>,
+    /// Items short names of the champion build.
+    pub items: Option<Vec<String>>,
+    /// Runes page of the champion (default runes page of the champion if missing).
+    pub runes_page: Option<RunesPagePreset>,
+    /// Stats of the custom target at lvl 1 (missing stats are set to 0).
+    pub base_stats: Option<UnitStats>,
+    /// Stats gained by the custom target per lvl (missing stats are set to 0).
+    pub growth_stats: Option<UnitStats>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct EnemyPreset {
+    pub target: TargetPreset,
+    pub focus_weight: f32,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct WeightsPreset {
+    pub dps: f32,
+    pub defense: f32,
+    pub mobility: f32,
+    /// Optional to keep presets saved before the time to kill weight was added.
+    #[serde(default)]
+    pub time_to_kill: f32,
+}
+
+fn rune_shard_name(shard: RuneShard) -> String {
+    format!("{shard:?}").to_lowercase()
+}
+
+impl RunesPagePreset {
+    fn from_runes_page(runes_page: &RunesPage) -> Self {
+        Self {
+            keystone: runes_page.keystone.full_name.to_string(),
+            primary_runes: Some(
+                runes_page
+                    .primary_runes
+                    .map(|rune| rune.full_name.to_string()),
+            ),
+            secondary_runes: Some(
+                runes_page
+                    .secondary_runes
+                    .map(|rune| rune.full_name.to_string()),
+            ),
+            shards: [
+                rune_shard_name(runes_page.shard1),
+                rune_shard_name(runes_page.shard2),
+                rune_shard_name(runes_page.shard3),
+            ],
+        }
+    }
+
+    /// Sets the keystone, the minor runes and the shards of the given runes page.
+    fn apply_to(&self, runes_page: &mut RunesPage) -> Result<(), String> {
+        runes_page.set_keystone(
+            RuneKeystone::find_by_name(&self.keystone)
+                .ok_or_else(|| format!("'{}' is not a recognized rune keystone", self.keystone))?,
+        );
+        let find_minor_rune = |name: &String| -> Result<&'static MinorRune, String> {
+            MinorRune::find_by_name(name)
+                .ok_or_else(|| format!("'{name}' is not a recognized minor rune"))
+        };
+        if let Some(names) = &self.primary_runes {
+            for (rune, name) in zip(&mut runes_page.primary_runes, names) {
+                *rune = find_minor_rune(name)?;
+            }
+        }
+        if let Some(names) = &self.secondary_runes {
+            for (rune, name) in zip(&mut runes_page.secondary_runes, names) {
+                *rune = find_minor_rune(name)?;
+            }
+        }
+        runes_page.shard1 = self.shards[0].parse::<RuneShard>()?;
+        runes_page.shard2 = self.shards[1].parse::<RuneShard>()?;
+        runes_page.shard3 = self.shards[2].parse::<RuneShard>()?;
+        Ok(())
+    }
+}
+
+impl TargetPreset {
+    fn from_target(target: &TargetDefinition) -> Self {
+        match target.kind() {
+            TargetKind::Dummy => Self::Name(target.properties.name.to_string()),
+            TargetKind::Champion => Self::Details(Box::new(TargetDetailsPreset {
+                champion: Some(target.properties.name.to_string()),
+                items: Some(
+                    target
+                        .build
+                        .iter()
+                        .filter(|&&item| *item != Item::NULL_ITEM)
+                        .map(|item| item.short_name.to_string())
+                        .collect(),
+                ),
+                runes_page: Some(RunesPagePreset::from_runes_page(&target.runes_page)),
+                ..Default::default()
+            })),
+            TargetKind::Custom => Self::Details(Box::new(TargetDetailsPreset {
+                base_stats: Some(target.properties.base_stats.clone()),
+                growth_stats: Some(target.properties.growth_stats.clone()),
+                ..Default::default()
+            })),
+        }
+    }
+
+    fn to_target(&self, game_data: &GameDataSet) -> Result<TargetDefinition, String> {
+        let details: &TargetDetailsPreset = match self {
+            Self::Name(name) => return TargetDefinition::parse(name, game_data),
+            Self::Details(details) => details,
+        };
+        match (&details.champion, &details.base_stats) {
+            (Some(name), None) => {
+                let properties: &'static UnitProperties = game_data
+                    .champions()
+                    .iter()
+                    .find(|properties| properties.name.eq_ignore_ascii_case(name.trim()))
+                    .copied()
+                    .ok_or_else(|| format!("'{name}' is not an available champion"))?;
+                let names: &[String] = details.items.as_deref().unwrap_or_default();
+                if names.len() > MAX_UNIT_ITEMS {
+                    return Err(format!(
+                        "Too many items for target {name} (got {}, maximum is {MAX_UNIT_ITEMS})",
+                        names.len()
+                    ));
+                }
+                let mut build: Build = Build::default();
+                for (slot, name) in zip(build.iter_mut(), names) {
+                    *slot = game_data
+                        .items_database
+                        .find_item_by_name(name)
+                        .ok_or_else(|| format!("Unknown items: '{name}' (target build)"))?;
+                }
+                let mut runes_page: RunesPage = properties.defaults.runes_pages;
+                if let Some(runes_page_preset) = &details.runes_page {
+                    runes_page_preset.apply_to(&mut runes_page)?;
+                }
+                Ok(TargetDefinition::champion(properties, build, runes_page))
+            }
+            (None, Some(base_stats)) => Ok(TargetDefinition::custom(
+                base_stats.clone(),
+                details.growth_stats.clone().unwrap_or_default(),
+            )),
+            _ => Err(
+                "A detailed target must have either a champion or base stats (custom target)"
+                    .to_string(),
+            ),
+        }
+    }
+}
+
+/// Returns the items corresponding to the given names, or pushes the unknown names into `unknown_items` with the context given.
+fn items_from_names(
+    names: &[String],
+    reference_pool: &[&'static Item],
+    context: &str,
+    unknown_items: &mut Vec<String>,
+) -> Vec<&'static Item> {
+    let mut items: Vec<&'static Item> = Vec::with_capacity(names.len());
+    for name in names {
+        if let Some(item) = find_item_by_name(name, reference_pool) {
+            items.push(item);
+        } else {
+            unknown_items.push(format!("'{name}' ({context})"));
+        }
+    }
+    items
+}
+
+impl SettingsPreset {
+    /// Creates a preset from the given settings (every field is filled).
+    #[must_use]
+    pub fn from_settings(
+        settings: &BuildsGenerationSettings,
+        champ_properties: &UnitProperties,
+    ) -> Self {
+        let items_names = |items: &[&Item]| -> Vec<String> {
+            items
+                .iter()
+                .map(|item| item.short_name.to_string())
+                .collect()
+        };
+
+        Self {
+            champion: Some(champ_properties.name.to_string()),
+            patch: Some(settings.game_data.patch.clone()),
+            target: Some(TargetPreset::from_target(&settings.target)),
+            fight_scenario_number: Some(settings.fight_scenario_number.get()),
+            fight_script: settings.fight_script.as_ref().map(ToString::to_string),
+            fight_duration: Some(settings.fight_duration),
+            phys_dmg_received_percent: Some(settings.phys_dmg_received_percent),
+            incoming_dmg: settings.incoming_dmg,
+            target_flee_percent: Some(settings.target_flee_percent),
+            teamfight_targets: Some(
+                settings
+                    .teamfight_targets
+                    .iter()
+                    .map(TargetPreset::from_target)
+                    .collect(),
+            ),
+            enemy_team: Some(
+                settings
+                    .enemy_team
+                    .iter()
+                    .map(|enemy| EnemyPreset {
+                        target: TargetPreset::from_target(&enemy.target),
+                        focus_weight: enemy.focus_weight,
+                    })
+                    .collect(),
+            ),
+            runes_page: Some(RunesPagePreset::from_runes_page(&settings.runes_page)),
+            summoner_spells: Some(
+                settings
+                    .summoner_spells
+                    .spells
+                    .map(|spell| spell.full_name.to_string()),
+            ),
+            skill_order: settings.skill_order.map(|max_order| max_order.to_string()),
+            economy: Some(settings.economy),
+            n_items: Some(settings.n_items),
+            mandatory_items: Some(
+                settings
+                    .mandatory_items
+                    .iter()
+                    .map(|&item| {
+                        if *item == Item::NULL_ITEM {
+                            String::new()
+                        } else {
+                            item.short_name.to_string()
+                        }
+                    })
+                    .collect(),
+            ),
+            boots_slot: Some(settings.boots_slot.to_string().to_lowercase()),
+            supp_item_slot: Some(settings.supp_item_slot.to_string().to_lowercase()),
+            legendary_items_pool: Some(items_names(&settings.legendary_items_pool)),
+            boots_pool: Some(items_names(&settings.boots_pool)),
+            supp_items_pool: Some(items_names(&settings.supp_items_pool)),
+            allow_manaflow_first_item: Some(settings.allow_manaflow_first_item),
+            weights: Some(WeightsPreset {
+                dps: settings.weights.0,
+                defense: settings.weights.1,
+                mobility: settings.weights.2,
+                time_to_kill: settings.weights.3,
+            }),
+            search_threshold: Some(settings.search_threshold),
+        }
+    }
+
+    /// Creates the settings described by the preset for the given champion, using items from the given game data set.
+    /// The `patch` field of the preset is ignored, it is up to the caller to load the corresponding game data set.
+    /// Missing fields are set to their default values for the champion.
+    /// Returns an Err if the preset references unknown items/target/keystone or if the resulting settings are invalid.
+    pub fn to_settings(
+        &self,
+        champ_properties: &UnitProperties,
+        game_data: &'static GameDataSet,
+    ) -> Result<BuildsGenerationSettings, String> {
+        let items_database: &ItemsDatabase = game_data.items_database;
+        let mut settings: BuildsGenerationSettings =
+            BuildsGenerationSettings::default_by_champion(champ_properties);
+        settings.use_game_data(game_data);
+
+        if let Some(target) = &self.target {
+            settings.target = target.to_target(game_data)?;
+        }
+        if let Some(number) = self.fight_scenario_number {
+            settings.fight_scenario_number = NonZeroUsize::new(number)
+                .ok_or_else(|| "Fight scenario number must be non-zero".to_string())?;
+        }
+        if let Some(fight_script) = &self.fight_script {
+            settings.fight_script = Some(
+                fight_script
+                    .parse::<FightScript>()
+                    .map_err(|error_msg| format!("Invalid fight script: {error_msg}"))?,
+            );
+        }
+        if let Some(fight_duration) = self.fight_duration {
+            settings.fight_duration = fight_duration;
+        }
+        if let Some(phys_dmg_received_percent) = self.phys_dmg_received_percent {
+            settings.phys_dmg_received_percent = phys_dmg_received_percent;
+        }
+        settings.incoming_dmg = self.incoming_dmg;
+        if let Some(target_flee_percent) = self.target_flee_percent {
+            settings.target_flee_percent = target_flee_percent;
+        }
+        if let Some(teamfight_targets) = &self.teamfight_targets {
+            settings.teamfight_targets = teamfight_targets
+                .iter()
+                .map(|target| target.to_target(game_data))
+                .collect::<Result<Vec<TargetDefinition>, String>>()?;
+        }
+        if let Some(enemy_team) = &self.enemy_team {
+            settings.enemy_team = enemy_team
+                .iter()
+                .map(|enemy| {
+                    Ok(EnemyTeamMember {
+                        target: enemy.target.to_target(game_data)?,
+                        focus_weight: enemy.focus_weight,
+                    })
+                })
+                .collect::<Result<Vec<EnemyTeamMember>, String>>()?;
+        }
+        if let Some(runes_page) = &self.runes_page {
+            runes_page.apply_to(&mut settings.runes_page)?;
+        }
+        if let Some(names) = &self.summoner_spells {
+            for (spell, name) in zip(&mut settings.summoner_spells.spells, names) {
+                *spell = SummonerSpell::find_by_name(name)
+                    .ok_or_else(|| format!("'{name}' is not a recognized summoner spell"))?;
+            }
+        }
+        if let Some(skill_order) = &self.skill_order {
+            settings.skill_order = Some(
+                skill_order
+                    .parse::<MaxOrder>()
+                    .map_err(|error_msg| format!("Invalid skill order: {error_msg}"))?,
+            );
+        }
+        if let Some(economy) = self.economy {
+            settings.economy = economy;
+        }
+        if let Some(n_items) = self.n_items {
+            settings.n_items = n_items;
+        }
+        if let Some(boots_slot) = &self.boots_slot {
+            settings.boots_slot = boots_slot.parse::<ItemSlot>()?;
+        }
+        if let Some(supp_item_slot) = &self.supp_item_slot {
+            settings.supp_item_slot = supp_item_slot.parse::<ItemSlot>()?;
+        }
+        if let Some(allow_manaflow_first_item) = self.allow_manaflow_first_item {
+            settings.allow_manaflow_first_item = allow_manaflow_first_item;
+        }
+        if let Some(weights) = &self.weights {
+            settings.weights = (
+                weights.dps,
+                weights.defense,
+                weights.mobility,
+                weights.time_to_kill,
+            );
+        }
+        if let Some(search_threshold) = self.search_threshold {
+            settings.search_threshold = search_threshold;
+        }
+
+        //items, gather every unknown item before returning to report them all at once
+        let mut unknown_items: Vec<String> = Vec::new();
+        if let Some(names) = &self.mandatory_items {
+            if names.len() > MAX_UNIT_ITEMS {
+                return Err(format!(
+                    "Too many mandatory items (got {}, maximum is {MAX_UNIT_ITEMS})",
+                    names.len()
+                ));
+            }
+            settings.mandatory_items = Build::default();
+            for (slot, name) in names.iter().enumerate() {
+                if name.trim().is_empty() {
+                    continue;
+                }
+                if let Some(item) = items_database.find_item_by_name(name) {
+                    settings.mandatory_items[slot] = item;
+                } else {
+                    unknown_items.push(format!("'{name}' (mandatory item at slot {})", slot + 1));
+                }
+            }
+        }
+        if let Some(names) = &self.legendary_items_pool {
+            settings.legendary_items_pool = items_from_names(
+                names,
+                items_database.legendary_items(),
+                "legendary items pool",
+                &mut unknown_items,
+            );
+        }
+        if let Some(names) = &self.boots_pool {
+            settings.boots_pool = items_from_names(
+                names,
+                items_database.boots(),
+                "boots pool",
+                &mut unknown_items,
+            );
+        }
+        if let Some(names) = &self.supp_items_pool {
+            settings.supp_items_pool = items_from_names(
+                names,
+                items_database.supp_items(),
+                "support items pool",
+                &mut unknown_items,
+            );
+        }
+        if !unknown_items.is_empty() {
+            return Err(format!("Unknown items: {}", unknown_items.join(", ")));
+        }
+
+        settings.check_settings(champ_properties)?;
+        Ok(settings)
+    }
+
+    /// Reads a preset from a TOML file.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let content: String = fs::read_to_string(path)
+            .map_err(|error| format!("Failed to read '{}': {error}", path.display()))?;
+        toml::from_str(&content)
+            .map_err(|error| format!("Failed to parse '{}': {error}", path.display()))
+    }
+
+    /// Writes the preset to a TOML file (creates the parent directories if needed).
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let content: String = toml::to_string_pretty(self)
+            .map_err(|error| format!("Failed to serialize preset: {error}"))?;
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(|error| {
+                format!("Failed to create directory '{}': {error}", parent.display())
+            })?;
+        }
+        fs::write(path, content)
+            .map_err(|error| format!("Failed to write '{}': {error}", path.display()))
+    }
+}
+
+/// Saves the given settings to a preset file.
+pub fn save_settings_preset(
+    path: &Path,
+    settings: &BuildsGenerationSettings,
+    champ_properties: &UnitProperties,
+) -> Result<(), String> {
+    SettingsPreset::from_settings(settings, champ_properties).save(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_settings_preset_round_trip() {
+        let champ_properties: &UnitProperties = &Unit::DRAVEN_PROPERTIES;
+        let mut settings: BuildsGenerationSettings =
+            BuildsGenerationSettings::default_by_champion(champ_properties);
+        settings.target = TargetDefinition::champion(
+            &Unit::ASHE_PROPERTIES,
+            Build([
+                &Item::KRAKEN_SLAYER,
+                &Item::BERSERKERS_GREAVES,
+                &Item::NULL_ITEM,
+                &Item::NULL_ITEM,
+                &Item::NULL_ITEM,
+                &Item::NULL_ITEM,
+            ]),
+            Unit::ASHE_PROPERTIES.defaults.runes_pages,
+        );
+        settings.fight_scenario_number = NonZeroUsize::new(2).unwrap();
+        settings.fight_script = Some(
+            "use actives; q once; aa if ready; walk"
+                .parse::<FightScript>()
+                .expect("Failed to parse valid fight script"),
+        );
+        settings.skill_order = Some(
+            "w>q>e:late"
+                .parse::<MaxOrder>()
+                .expect("Failed to parse valid max order"),
+        );
+        settings.runes_page.secondary_runes = [&MinorRune::CELERITY, &MinorRune::GATHERING_STORM];
+        settings.summoner_spells = SummonerSpells {
+            spells: [&SummonerSpell::GHOST, &SummonerSpell::EXHAUST],
+        };
+        settings.n_items = 5;
+        settings.mandatory_items[0] = &Item::INFINITY_EDGE;
+        settings.boots_slot = ItemSlot::Any;
+        settings.incoming_dmg = Some(IncomingDmgProfile {
+            grievous_wounds: true,
+            ..Default::default()
+        });
+        settings.target_flee_percent = 0.5;
+        settings.economy = EconomyProfile::from_name("shared-xp").unwrap();
+        settings.teamfight_targets = vec![
+            TargetDefinition::dummy(&TANKY_OPTIMIZER_DUMMY_PROPERTIES),
+            TargetDefinition::custom(
+                UnitStats {
+                    hp: 2000.,
+                    armor: 80.,
+                    mr: 50.,
+                    ..Default::default()
+                },
+                UnitStats::default(),
+            ),
+        ];
+        settings.weights = (1., 0.5, 0.25, 0.5);
+
+        let preset: SettingsPreset = SettingsPreset::from_settings(&settings, champ_properties);
+        let text: String = toml::to_string_pretty(&preset).expect("Failed to serialize preset");
+        let parsed: SettingsPreset = toml::from_str(&text).expect("Failed to parse preset");
+        assert_eq!(preset, parsed);
+
+        let loaded: BuildsGenerationSettings = parsed
+            .to_settings(champ_properties, GameDataSet::builtin())
+            .expect("Failed to load valid preset");
+        assert_eq!(
+            SettingsPreset::from_settings(&loaded, champ_properties),
+            preset
+        );
+
+        //enemy team (cannot be used with teamfight targets)
+        settings.teamfight_targets.clear();
+        settings.enemy_team = vec![
+            EnemyTeamMember {
+                target: settings.target,
+                focus_weight: 2.,
+            },
+            EnemyTeamMember {
+                target: TargetDefinition::dummy(&BRUISER_OPTIMIZER_DUMMY_PROPERTIES),
+                focus_weight: 1.,
+            },
+        ];
+
+        let preset: SettingsPreset = SettingsPreset::from_settings(&settings, champ_properties);
+        let text: String = toml::to_string_pretty(&preset).expect("Failed to serialize preset");
+        let parsed: SettingsPreset = toml::from_str(&text).expect("Failed to parse preset");
+        assert_eq!(preset, parsed);
+
+        let loaded: BuildsGenerationSettings = parsed
+            .to_settings(champ_properties, GameDataSet::builtin())
+            .expect("Failed to load valid preset");
+        assert_eq!(
+            SettingsPreset::from_settings(&loaded, champ_properties),
+            preset
+        );
+    }
+
+    #[test]
+    pub fn test_settings_preset_unknown_items() {
+        let preset: SettingsPreset = toml::from_str(
+            "mandatory_items = [\"IE\", \"not an item\"]\n\
+             boots_pool = [\"Berserkers\", \"IE\"]",
+        )
+        .expect("Failed to parse preset");
+        let error_msg: String = preset
+            .to_settings(&Unit::ASHE_PROPERTIES, GameDataSet::builtin())
+            .expect_err("Preset with unknown items should fail to load");
+        assert!(error_msg.contains("'not an item'"));
+        assert!(error_msg.contains("'IE' (boots pool)")); //not a boots
+    }
+}